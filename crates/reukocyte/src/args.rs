@@ -1,4 +1,5 @@
 use clap::{Parser, ValueEnum};
+use reukocyte_checker::FixMode;
 use std::path::PathBuf;
 
 /// Reuko: An extremely fast Ruby linter (Reukocyte)
@@ -20,6 +21,14 @@ pub struct Args {
     #[arg(short = 'A', long = "autocorrect-all")]
     pub autocorrect_all: bool,
 
+    /// Insert `# rubocop:disable` comments for offenses autocorrect could not fix (used with -a/-A)
+    #[arg(long = "disable-uncorrectable")]
+    pub disable_uncorrectable: bool,
+
+    /// Apply one fix per iteration instead of merging them (for debugging autocorrect)
+    #[arg(long = "fix-serially")]
+    pub fix_serially: bool,
+
     // **************** Rule Selection Options ****************
     /// Run only the given rule(s)
     #[arg(long, value_name = "RULE1,RULE2,...", value_delimiter = ',')]
@@ -41,6 +50,10 @@ pub struct Args {
     #[arg(long)]
     pub safe: bool,
 
+    /// Report only offenses autocorrect can fix (unsafe fixes count only with -A)
+    #[arg(long = "autocorrectable-only")]
+    pub autocorrectable_only: bool,
+
     // **************** Output Options ****************
     /// Choose an output formatter
     #[arg(short = 'f', long = "format", value_name = "FORMATTER")]
@@ -54,6 +67,10 @@ pub struct Args {
     #[arg(long = "no-display-cop-names")]
     pub no_display_cop_names: bool,
 
+    /// Show the offending source line(s) under each offense
+    #[arg(long = "show-source")]
+    pub show_source: bool,
+
     /// Write output to a file instead of STDOUT
     #[arg(short = 'o', long = "out", value_name = "FILE")]
     pub output_file: Option<PathBuf>,
@@ -75,12 +92,32 @@ pub struct Args {
     #[arg(short = 'c', long = "config", value_name = "FILE")]
     pub config: Option<PathBuf>,
 
+    /// Use the default configuration, ignoring --config and .rubocop.yml
+    #[arg(long = "force-default-config")]
+    pub force_default_config: bool,
+
+    /// Generate a configuration file acting as a TODO list
+    #[arg(long = "auto-gen-config")]
+    pub auto_gen_config: bool,
+
+    /// Maximum number of files listed in an Exclude by --auto-gen-config (cops above it are disabled)
+    #[arg(long = "exclude-limit", value_name = "COUNT", default_value_t = 15)]
+    pub exclude_limit: usize,
+
+    /// Generate a starter .rubocop.yml in the current directory
+    #[arg(long)]
+    pub init: bool,
+
+    /// Overwrite an existing .rubocop.yml with --init
+    #[arg(long, requires = "init")]
+    pub force: bool,
+
     /// Pipe source from STDIN, using FILE in offense reports
     #[arg(short = 's', long = "stdin", value_name = "FILE")]
     pub stdin: Option<PathBuf>,
 
     // **************** Behavior Options ****************
-    /// Minimum severity for exit with error code
+    /// Minimum severity for exit with error code (default: refactor, so `info` offenses never fail)
     #[arg(long = "fail-level", value_name = "SEVERITY")]
     pub fail_level: Option<Severity>,
 
@@ -88,6 +125,18 @@ pub struct Args {
     #[arg(short = 'F', long = "fail-fast")]
     pub fail_fast: bool,
 
+    /// Stop after reporting N offenses in total
+    #[arg(long = "max-offenses", value_name = "N", value_parser = parse_offense_limit)]
+    pub max_offenses: Option<usize>,
+
+    /// Skip files larger than BYTES (overrides AllCops.MaximumFileSize)
+    #[arg(long = "max-file-size", value_name = "BYTES")]
+    pub max_file_size: Option<u64>,
+
+    /// Fail if a FILE given on the command line is not a recognized Ruby file type
+    #[arg(long = "only-recognized-file-types")]
+    pub only_recognized_file_types: bool,
+
     /// Force exclusion of files specified in config
     #[arg(long)]
     pub force_exclusion: bool,
@@ -101,6 +150,14 @@ pub struct Args {
     pub no_parallel: bool,
 
     // **************** Debug/Info Options ****************
+    /// Print the description, an example and the options of a rule, then exit
+    #[arg(long, value_name = "RULE")]
+    pub explain: Option<String>,
+
+    /// Print the resolved configuration as YAML, then exit
+    #[arg(long = "config-dump")]
+    pub config_dump: bool,
+
     /// Display debug info
     #[arg(short = 'd', long = "debug")]
     pub debug: bool,
@@ -108,6 +165,10 @@ pub struct Args {
     /// Display elapsed time in seconds
     #[arg(long = "display-time")]
     pub display_time: bool,
+
+    /// Time each rule and print the totals to stderr after the run
+    #[arg(long)]
+    pub profile: bool,
 }
 impl Args {
     /// Check if any autocorrect mode is enabled
@@ -119,19 +180,40 @@ impl Args {
     pub fn unsafe_fixes(&self) -> bool {
         self.autocorrect_all
     }
+    /// Get how fixes are applied in each correction iteration
+    pub fn fix_mode(&self) -> FixMode {
+        if self.fix_serially { FixMode::Serial } else { FixMode::Merged }
+    }
+    /// Get the minimum severity of an offense that makes the run fail
+    pub fn fail_severity(&self) -> reukocyte_checker::Severity {
+        use reukocyte_checker::Severity as S;
+        match self.fail_level.unwrap_or(Severity::Refactor) {
+            Severity::Info => S::Info,
+            Severity::Refactor => S::Refactor,
+            Severity::Convention => S::Convention,
+            Severity::Warning => S::Warning,
+            Severity::Error => S::Error,
+            Severity::Fatal => S::Fatal,
+        }
+    }
     /// Get the effective output format
     pub fn output_format(&self) -> OutputFormat {
         self.format.unwrap_or_default()
     }
     /// Check if color should be enabled
+    ///
+    /// `--no-color` and `--color` win; otherwise a non-empty `NO_COLOR` environment
+    /// variable disables color, and it is enabled when offenses go to a terminal.
     pub fn use_color(&self) -> bool {
         if self.no_color {
             false
         } else if self.color {
             true
+        } else if std::env::var_os("NO_COLOR").is_some_and(|value| !value.is_empty()) {
+            false
         } else {
             // Auto-detect based on terminal
-            atty_check()
+            self.output_file.is_none() && atty_check(self.stderr)
         }
     }
     /// Check if cop names should be displayed
@@ -167,6 +249,12 @@ pub enum OutputFormat {
     /// Files only
     #[value(name = "files", alias = "fi")]
     Files,
+    /// JUnit XML format
+    #[value(name = "junit", alias = "ju")]
+    Junit,
+    /// Offense counts by cop
+    #[value(name = "offenses", alias = "o")]
+    Offenses,
 }
 impl Default for OutputFormat {
     fn default() -> Self {
@@ -202,10 +290,23 @@ impl Default for Severity {
     }
 }
 
-/// Check if stdout is a tty (for auto color detection)
-fn atty_check() -> bool {
-    // Simple check - in real implementation, use atty crate or std::io::IsTerminal
-    std::env::var("TERM").is_ok()
+/// Parse the `--max-offenses` limit, which must allow at least one offense.
+fn parse_offense_limit(value: &str) -> Result<usize, String> {
+    match value.parse::<usize>() {
+        Ok(0) => Err("must be at least 1".to_string()),
+        Ok(limit) => Ok(limit),
+        Err(e) => Err(e.to_string()),
+    }
+}
+
+/// Check if stdout (or stderr) is a tty (for auto color detection)
+fn atty_check(stderr: bool) -> bool {
+    use std::io::IsTerminal;
+    if stderr {
+        std::io::stderr().is_terminal()
+    } else {
+        std::io::stdout().is_terminal()
+    }
 }
 
 #[cfg(test)]
@@ -228,6 +329,12 @@ mod tests {
         assert!(args.except.is_none());
     }
 
+    #[test]
+    fn test_max_offenses_must_be_positive() {
+        assert_eq!(Args::parse_from(["reuko", "--max-offenses", "1"]).max_offenses, Some(1));
+        assert!(Args::try_parse_from(["reuko", "--max-offenses", "0"]).is_err());
+    }
+
     #[test]
     fn test_autocorrect() {
         let args = Args::parse_from(["reuko", "-a", "."]);
@@ -244,6 +351,20 @@ mod tests {
         assert!(args.unsafe_fixes());
     }
 
+    #[test]
+    fn test_fix_serially() {
+        let args = Args::parse_from(["reuko", "-a", "--fix-serially", "."]);
+        assert_eq!(args.fix_mode(), FixMode::Serial);
+        let args = Args::parse_from(["reuko", "-a", "."]);
+        assert_eq!(args.fix_mode(), FixMode::Merged);
+    }
+
+    #[test]
+    fn test_show_source() {
+        assert!(Args::parse_from(["reuko", "--show-source", "."]).show_source);
+        assert!(!Args::parse_from(["reuko", "."]).show_source);
+    }
+
     #[test]
     fn test_only_option() {
         let args = Args::parse_from(["reuko", "--only", "Layout/TrailingWhitespace,Lint/Debugger", "."]);
@@ -262,6 +383,18 @@ mod tests {
         assert_eq!(args.format, Some(OutputFormat::Json));
     }
 
+    #[test]
+    fn test_format_junit() {
+        let args = Args::parse_from(["reuko", "-f", "junit", "."]);
+        assert_eq!(args.format, Some(OutputFormat::Junit));
+    }
+
+    #[test]
+    fn test_format_offenses() {
+        let args = Args::parse_from(["reuko", "-f", "o", "."]);
+        assert_eq!(args.format, Some(OutputFormat::Offenses));
+    }
+
     #[test]
     fn test_fail_level() {
         let args = Args::parse_from(["reuko", "--fail-level", "warning", "."]);
@@ -274,6 +407,20 @@ mod tests {
         assert_eq!(args.config, Some(PathBuf::from(".rubocop.yml")));
     }
 
+    #[test]
+    fn test_init() {
+        let args = Args::parse_from(["reuko", "--init", "--force"]);
+        assert!(args.init && args.force);
+        assert!(Args::try_parse_from(["reuko", "--force"]).is_err());
+    }
+
+    #[test]
+    fn test_disable_uncorrectable() {
+        let args = Args::parse_from(["reuko", "-a", "--disable-uncorrectable", "."]);
+        assert!(args.disable_uncorrectable);
+        assert!(args.should_fix());
+    }
+
     #[test]
     fn test_fix_layout() {
         let args = Args::parse_from(["reuko", "-x", "."]);
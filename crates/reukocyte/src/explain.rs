@@ -0,0 +1,112 @@
+//! `--explain`: print the documentation of a rule.
+//!
+//! The description, example and options are extracted from the rule's sources
+//! when the checker is built (see `RuleId::doc`).
+
+use reukocyte_checker::{Config, RuleId};
+use serde::Serialize;
+
+/// The documentation of a rule, as printed by `--explain --format json`.
+#[derive(Debug, Serialize)]
+pub struct Explanation {
+    pub name: String,
+    pub description: &'static str,
+    pub example: &'static str,
+    pub enabled: bool,
+    pub severity: &'static str,
+    pub options: Vec<ExplainedOption>,
+}
+
+/// A configuration option in an `Explanation`.
+#[derive(Debug, Serialize)]
+pub struct ExplainedOption {
+    pub name: &'static str,
+    pub description: &'static str,
+}
+
+impl Explanation {
+    /// Collect the documentation and the default `Enabled`/`Severity` of a rule.
+    pub fn new(rule: RuleId) -> Self {
+        let name = rule.to_string();
+        let config = Config::default();
+        let base = config.base_config(&name);
+        let doc = rule.doc();
+        Self {
+            description: doc.map_or("", |doc| doc.description),
+            example: doc.map_or("", |doc| doc.example),
+            enabled: base.is_some_and(|base| base.enabled),
+            severity: base.map_or("warning", |base| base.severity.as_str()),
+            options: doc
+                .map(|doc| {
+                    doc.options
+                        .iter()
+                        .map(|option| ExplainedOption {
+                            name: option.name,
+                            description: option.description,
+                        })
+                        .collect()
+                })
+                .unwrap_or_default(),
+            name,
+        }
+    }
+
+    /// Render as JSON.
+    pub fn to_json(&self) -> String {
+        serde_json::to_string_pretty(self).unwrap_or_else(|_| "{}".to_string())
+    }
+
+    /// Render as text, with the example indented like a code block.
+    pub fn to_text(&self) -> String {
+        let mut text = format!("{}\n", self.name);
+        if !self.description.is_empty() {
+            text.push_str(&format!("\n{}\n", self.description));
+        }
+        let enabled = if self.enabled { "yes" } else { "no" };
+        text.push_str(&format!("\nEnabled by default: {}\nSeverity: {}\n", enabled, self.severity));
+        if !self.example.is_empty() {
+            text.push_str("\nExample:\n\n");
+            for line in self.example.lines() {
+                if line.is_empty() {
+                    text.push('\n');
+                } else {
+                    text.push_str(&format!("    {}\n", line));
+                }
+            }
+        }
+        if !self.options.is_empty() {
+            text.push_str("\nOptions:\n");
+            for option in &self.options {
+                if option.description.is_empty() {
+                    text.push_str(&format!("    {}\n", option.name));
+                } else {
+                    text.push_str(&format!("    {}: {}\n", option.name, option.description));
+                }
+            }
+        }
+        text
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use reukocyte_checker::LintRule;
+
+    #[test]
+    fn test_every_rule_is_documented() {
+        for rule in RuleId::all() {
+            let doc = rule.doc().unwrap_or_else(|| panic!("{} has no documentation", rule));
+            assert!(!doc.description.is_empty(), "{} has no description", rule);
+            assert!(doc.example.contains("# good"), "{} has no example", rule);
+        }
+    }
+
+    #[test]
+    fn test_explain_json() {
+        let json: serde_json::Value = serde_json::from_str(&Explanation::new(RuleId::Lint(LintRule::Debugger)).to_json()).unwrap();
+        assert_eq!(json["name"], "Lint/Debugger");
+        assert_eq!(json["enabled"], true);
+        assert_eq!(json["options"][0]["name"], "DebuggerMethods");
+    }
+}
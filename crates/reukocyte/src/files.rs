@@ -1,4 +1,5 @@
 use globset::{Glob, GlobSet, GlobSetBuilder};
+use rustc_hash::FxHashSet;
 use std::path::{Path, PathBuf};
 use walkdir::WalkDir;
 
@@ -146,20 +147,95 @@ pub fn collect_ruby_files(paths: &[PathBuf], exclude_patterns: &[String]) -> Vec
     files
 }
 
+/// Check that every file given explicitly in `paths` is a recognized Ruby file.
+///
+/// Directories are not checked; walking them already keeps only Ruby files.
+pub fn check_recognized_file_types(paths: &[PathBuf]) -> Result<(), String> {
+    match paths.iter().find(|path| path.is_file() && !is_ruby_file(path)) {
+        Some(path) => Err(format!("{} is not a recognized Ruby file type", path.display())),
+        None => Ok(()),
+    }
+}
+
 /// Walk a directory and collect all Ruby files.
+///
+/// Symlinks are followed, but each directory and file is visited once by its
+/// canonical path, so symlink cycles and links to already walked directories
+/// neither loop nor yield the same file twice.
 fn walk_directory(dir: &Path, exclude_matcher: Option<&GlobSet>) -> Vec<PathBuf> {
     let mut files = Vec::new();
+    let mut visited = Visited::new(dir);
     // Use walkdir for simple recursive directory traversal
     // RuboCop does NOT respect .gitignore, so we don't either
-    for entry in WalkDir::new(dir).follow_links(true).into_iter().filter_entry(|e| !is_excluded_dir(e)).flatten() {
+    // Sorted by file name so the files are checked and reported in a stable order
+    let mut walker = WalkDir::new(dir).follow_links(true).sort_by_file_name().into_iter();
+    while let Some(entry) = walker.next() {
+        let Ok(entry) = entry else {
+            continue;
+        };
+        if entry.file_type().is_dir() {
+            if is_excluded_dir(&entry) || !visited.first_visit(&entry) {
+                walker.skip_current_dir();
+            }
+            continue;
+        }
         let path = entry.path();
-        if path.is_file() && is_ruby_file(path) && !is_excluded_by_pattern(path, exclude_matcher) {
+        if path.is_file() && is_ruby_file(path) && !is_excluded_by_pattern(path, exclude_matcher) && visited.first_visit(&entry) {
             files.push(path.to_path_buf());
         }
     }
     files
 }
 
+/// The canonical paths of the directories and files walked so far.
+///
+/// Only symlinks are canonicalized. Any other entry is keyed by its walked path,
+/// rebased onto the canonical path of the walked directory or of the innermost
+/// followed symlink it sits under.
+struct Visited<'a> {
+    dir: &'a Path,
+    canonical_dir: PathBuf,
+    /// The followed symlinked directories, by walked path and canonical path.
+    links: Vec<(PathBuf, PathBuf)>,
+    paths: FxHashSet<PathBuf>,
+}
+impl<'a> Visited<'a> {
+    fn new(dir: &'a Path) -> Self {
+        Self {
+            dir,
+            canonical_dir: std::fs::canonicalize(dir).unwrap_or_else(|_| dir.to_path_buf()),
+            links: Vec::new(),
+            paths: FxHashSet::default(),
+        }
+    }
+    /// Record the canonical path of `entry`, returning false if it was already recorded.
+    ///
+    /// Symlinks that cannot be canonicalized are always treated as new.
+    fn first_visit(&mut self, entry: &walkdir::DirEntry) -> bool {
+        let path = entry.path();
+        if entry.path_is_symlink() {
+            let Ok(canonical) = std::fs::canonicalize(path) else {
+                return true;
+            };
+            if entry.file_type().is_dir() {
+                self.links.push((path.to_path_buf(), canonical.clone()));
+            }
+            return self.paths.insert(canonical);
+        }
+        // Links are walked depth first, so the last one containing the path is the innermost
+        let (walked, canonical) = self
+            .links
+            .iter()
+            .rev()
+            .find(|(walked, _)| path.starts_with(walked))
+            .map_or((self.dir, &self.canonical_dir), |(walked, canonical)| (walked.as_path(), canonical));
+        let key = path
+            .strip_prefix(walked)
+            .map_or_else(|_| path.to_path_buf(), |relative| canonical.join(relative));
+        self.paths.insert(key)
+    }
+}
+
 /// Check if a directory entry should be excluded.
 fn is_excluded_dir(entry: &walkdir::DirEntry) -> bool {
     entry.file_type().is_dir() && entry.file_name().to_str().is_some_and(|s| EXCLUDED_DIRS.contains(&s))
@@ -186,6 +262,33 @@ fn is_ruby_file(path: &Path) -> bool {
     false
 }
 
+/// Replace the contents of `path` without ever leaving it half written.
+///
+/// The contents go to a temporary file in the same directory, which takes the
+/// original file's permissions and is then renamed over it. A symlink is
+/// resolved first, so the file it points to is the one replaced.
+pub fn write_atomically(path: &Path, contents: &[u8]) -> std::io::Result<()> {
+    let target = match std::fs::canonicalize(path) {
+        Ok(target) => target,
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => path.to_path_buf(),
+        Err(e) => return Err(e),
+    };
+    let path = target.as_path();
+    let file_name = path.file_name().map(|name| name.to_string_lossy()).unwrap_or_default();
+    let temp_path = path.with_file_name(format!(".{}.reuko-tmp-{}", file_name, std::process::id()));
+    let result = std::fs::write(&temp_path, contents)
+        .and_then(|()| match std::fs::metadata(path) {
+            Ok(metadata) => std::fs::set_permissions(&temp_path, metadata.permissions()),
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => Ok(()),
+            Err(e) => Err(e),
+        })
+        .and_then(|()| std::fs::rename(&temp_path, path));
+    if result.is_err() {
+        let _ = std::fs::remove_file(&temp_path);
+    }
+    result
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -255,4 +358,103 @@ mod tests {
         // None matcher should not exclude anything
         assert!(!is_excluded_by_pattern(Path::new("vendor/gems/foo.rb"), None));
     }
+
+    #[test]
+    fn test_check_recognized_file_types() {
+        let dir = std::env::temp_dir().join(format!("reuko-{}-recognized", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        std::fs::write(dir.join("a.rb"), "").unwrap();
+        std::fs::write(dir.join("notes.txt"), "").unwrap();
+        let valid = check_recognized_file_types(&[dir.join("a.rb"), dir.clone()]);
+        let invalid = check_recognized_file_types(&[dir.join("a.rb"), dir.join("notes.txt")]);
+        std::fs::remove_dir_all(&dir).unwrap();
+        assert_eq!(valid, Ok(()));
+        assert_eq!(invalid, Err(format!("{} is not a recognized Ruby file type", dir.join("notes.txt").display())));
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_walk_directory_skips_symlink_cycles() {
+        use std::os::unix::fs::symlink;
+        let root = std::env::temp_dir().join(format!("reuko-symlinks-{}", std::process::id()));
+        std::fs::create_dir_all(root.join("sub")).unwrap();
+        std::fs::write(root.join("a.rb"), "").unwrap();
+        std::fs::write(root.join("sub/b.rb"), "").unwrap();
+        // A link back to the root, a second route into `sub` and a link to a file
+        symlink(&root, root.join("sub/loop")).unwrap();
+        symlink(root.join("sub"), root.join("alias")).unwrap();
+        symlink(root.join("a.rb"), root.join("link.rb")).unwrap();
+
+        let files = walk_directory(&root, None);
+        let mut canonical: Vec<PathBuf> = files.iter().map(|f| std::fs::canonicalize(f).unwrap()).collect();
+        canonical.sort();
+        let expected = vec![
+            std::fs::canonicalize(root.join("a.rb")).unwrap(),
+            std::fs::canonicalize(root.join("sub/b.rb")).unwrap(),
+        ];
+        std::fs::remove_dir_all(&root).unwrap();
+        assert_eq!(files.len(), 2);
+        assert_eq!(canonical, expected);
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_walk_directory_through_a_symlinked_root() {
+        use std::os::unix::fs::symlink;
+        let root = std::env::temp_dir().join(format!("reuko-linked-root-{}", std::process::id()));
+        let top = std::env::temp_dir().join(format!("reuko-linked-top-{}", std::process::id()));
+        std::fs::create_dir_all(root.join("real")).unwrap();
+        std::fs::write(root.join("real/x.rb"), "").unwrap();
+        // Routes back into `real` from inside it, from a later sibling and through that sibling
+        symlink(root.join("real"), root.join("real/inner")).unwrap();
+        symlink(root.join("real"), root.join("z")).unwrap();
+        symlink(root.join("z"), root.join("real/deep")).unwrap();
+        symlink(&root, &top).unwrap();
+
+        let files = walk_directory(&top, None);
+        std::fs::remove_file(&top).unwrap();
+        std::fs::remove_dir_all(&root).unwrap();
+        // The file keeps the path it was walked by.
+        assert_eq!(files, vec![top.join("real/x.rb")]);
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_write_atomically_keeps_permissions() {
+        use std::os::unix::fs::PermissionsExt;
+        let dir = std::env::temp_dir().join(format!("reuko-{}-atomic", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        let path = dir.join("script.rb");
+        std::fs::write(&path, "x = 1  \n").unwrap();
+        std::fs::set_permissions(&path, std::fs::Permissions::from_mode(0o754)).unwrap();
+
+        write_atomically(&path, b"x = 1\n").unwrap();
+        let contents = std::fs::read(&path).unwrap();
+        let mode = std::fs::metadata(&path).unwrap().permissions().mode();
+        let leftovers = std::fs::read_dir(&dir).unwrap().count();
+        std::fs::remove_dir_all(&dir).unwrap();
+        assert_eq!(contents, b"x = 1\n");
+        assert_eq!(mode & 0o777, 0o754);
+        assert_eq!(leftovers, 1);
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_write_atomically_follows_symlinks() {
+        use std::os::unix::fs::symlink;
+        let dir = std::env::temp_dir().join(format!("reuko-{}-atomic-link", std::process::id()));
+        std::fs::create_dir_all(dir.join("sub")).unwrap();
+        let real = dir.join("sub/real.rb");
+        std::fs::write(&real, "x = 1  \n").unwrap();
+        symlink(&real, dir.join("link.rb")).unwrap();
+
+        write_atomically(&dir.join("link.rb"), b"x = 1\n").unwrap();
+        let is_symlink = std::fs::symlink_metadata(dir.join("link.rb")).unwrap().file_type().is_symlink();
+        let contents = std::fs::read(&real).unwrap();
+        let leftovers = std::fs::read_dir(dir.join("sub")).unwrap().count();
+        std::fs::remove_dir_all(&dir).unwrap();
+        assert!(is_symlink);
+        assert_eq!(contents, b"x = 1\n");
+        assert_eq!(leftovers, 1);
+    }
 }
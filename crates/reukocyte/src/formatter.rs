@@ -0,0 +1,477 @@
+//! The built-in formatters selected with `--format`.
+
+use crate::args::{Args, OutputFormat};
+use crate::output::{JsonOutput, JunitOutput, OffensesOutput};
+use reukocyte_checker::{Diagnostic, Formatter, InspectedFile, RunSummary, Severity};
+use rustc_hash::FxHashMap;
+use std::io::Write;
+
+/// Create the formatter selected by `--format`, writing to `out`.
+pub fn formatter_for(args: &Args, out: Box<dyn Write>) -> Box<dyn Formatter> {
+    match args.output_format() {
+        OutputFormat::Simple | OutputFormat::Progress => Box::new(SimpleFormatter::new(out, args)),
+        OutputFormat::Emacs => Box::new(EmacsFormatter { out }),
+        OutputFormat::Clang => Box::new(ClangFormatter { out }),
+        OutputFormat::Github => Box::new(GithubFormatter::new(out)),
+        OutputFormat::Files => Box::new(FilesFormatter { out }),
+        OutputFormat::Quiet => Box::new(QuietFormatter),
+        OutputFormat::Json => Box::new(JsonFormatter::new(out)),
+        OutputFormat::Junit => Box::new(JunitFormatter::new(out)),
+        OutputFormat::Offenses => Box::new(OffensesFormatter::new(out)),
+    }
+}
+
+/// `simple` and `progress`: `path:line:col: C: Cop/Name message` lines.
+///
+/// With `color`, the path is bold and the severity code colored; with
+/// `show_source`, each offense is followed by its source line.
+pub struct SimpleFormatter<W> {
+    pub out: W,
+    show_cop_names: bool,
+    color: bool,
+    show_source: bool,
+}
+
+impl<W: Write> SimpleFormatter<W> {
+    pub fn new(out: W, args: &Args) -> Self {
+        Self {
+            out,
+            show_cop_names: args.show_cop_names(),
+            color: args.use_color(),
+            show_source: args.show_source,
+        }
+    }
+
+    /// Render one offense line.
+    pub fn offense_line(&self, path: &str, d: &Diagnostic) -> String {
+        let severity_color = match d.severity {
+            Severity::Error | Severity::Fatal => RED,
+            Severity::Warning => YELLOW,
+            Severity::Info | Severity::Refactor | Severity::Convention => CYAN,
+        };
+        let path = paint(path, BOLD, self.color);
+        let code = paint(d.severity.code(), severity_color, self.color);
+        if self.show_cop_names {
+            format!("{}:{}:{}: {}: {} {}", path, d.line_start, d.column_start, code, d.rule(), d.message)
+        } else {
+            format!("{}:{}:{}: {}: {}", path, d.line_start, d.column_start, code, d.message)
+        }
+    }
+}
+
+impl<W: Write> Formatter for SimpleFormatter<W> {
+    fn file_finished(&mut self, path: &str, diagnostics: &[Diagnostic]) -> std::io::Result<()> {
+        for d in diagnostics {
+            writeln!(self.out, "{}", self.offense_line(path, d))?;
+        }
+        Ok(())
+    }
+
+    fn file_inspected(&mut self, file: &InspectedFile<'_>) -> std::io::Result<()> {
+        if !self.show_source {
+            return self.file_finished(file.path, file.diagnostics);
+        }
+        for d in file.diagnostics {
+            writeln!(self.out, "{}", self.offense_line(file.path, d))?;
+            writeln!(self.out, "{}", d.source_context(file.source))?;
+        }
+        Ok(())
+    }
+
+    fn finished(&mut self, summary: &RunSummary) -> std::io::Result<()> {
+        write_summary(&mut std::io::stderr(), summary)?;
+        self.out.flush()
+    }
+}
+
+/// `emacs`: `path:line:col: C: message` lines.
+pub struct EmacsFormatter<W> {
+    pub out: W,
+}
+
+impl<W> EmacsFormatter<W> {
+    /// Render one offense line.
+    pub fn offense_line(path: &str, d: &Diagnostic) -> String {
+        format!("{}:{}:{}: {}: {}", path, d.line_start, d.column_start, d.severity.code(), d.message)
+    }
+}
+
+impl<W: Write> Formatter for EmacsFormatter<W> {
+    fn file_finished(&mut self, path: &str, diagnostics: &[Diagnostic]) -> std::io::Result<()> {
+        for d in diagnostics {
+            writeln!(self.out, "{}", Self::offense_line(path, d))?;
+        }
+        Ok(())
+    }
+
+    fn finished(&mut self, summary: &RunSummary) -> std::io::Result<()> {
+        write_summary(&mut std::io::stderr(), summary)?;
+        self.out.flush()
+    }
+}
+
+/// `clang`: compiler-style `path:line:col: warning: message` lines.
+pub struct ClangFormatter<W> {
+    pub out: W,
+}
+
+impl<W> ClangFormatter<W> {
+    /// Render one offense line.
+    pub fn offense_line(path: &str, d: &Diagnostic) -> String {
+        format!("{}:{}:{}: {}: {}", path, d.line_start, d.column_start, level(d), d.message)
+    }
+}
+
+impl<W: Write> Formatter for ClangFormatter<W> {
+    fn file_finished(&mut self, path: &str, diagnostics: &[Diagnostic]) -> std::io::Result<()> {
+        for d in diagnostics {
+            writeln!(self.out, "{}", Self::offense_line(path, d))?;
+        }
+        Ok(())
+    }
+
+    fn finished(&mut self, summary: &RunSummary) -> std::io::Result<()> {
+        write_summary(&mut std::io::stderr(), summary)?;
+        self.out.flush()
+    }
+}
+
+/// `github`: GitHub Actions workflow commands (`::warning file=...::message`).
+///
+/// The annotations are written at the end of the run, sorted by file, line,
+/// column and cop name, so runs annotate in the same order.
+pub struct GithubFormatter<W> {
+    pub out: W,
+    annotations: Vec<(String, Diagnostic)>,
+}
+
+impl<W> GithubFormatter<W> {
+    pub fn new(out: W) -> Self {
+        Self { out, annotations: Vec::new() }
+    }
+
+    /// Render one offense line.
+    pub fn offense_line(path: &str, d: &Diagnostic) -> String {
+        let level = match level(d) {
+            "note" => "notice",
+            level => level,
+        };
+        format!(
+            "::{} file={},line={},col={},title={}::{}",
+            level,
+            escape_property(path),
+            d.line_start,
+            d.column_start,
+            escape_property(&d.rule()),
+            escape_data(&d.message)
+        )
+    }
+}
+
+/// Encode a workflow command message.
+fn escape_data(data: &str) -> String {
+    data.replace('%', "%25").replace('\r', "%0D").replace('\n', "%0A")
+}
+
+/// Encode a workflow command parameter value, which also may not contain `:` or `,`.
+fn escape_property(value: &str) -> String {
+    escape_data(value).replace(':', "%3A").replace(',', "%2C")
+}
+
+impl<W: Write> Formatter for GithubFormatter<W> {
+    fn file_finished(&mut self, path: &str, diagnostics: &[Diagnostic]) -> std::io::Result<()> {
+        self.annotations.extend(diagnostics.iter().map(|d| (path.to_string(), d.clone())));
+        Ok(())
+    }
+
+    fn finished(&mut self, summary: &RunSummary) -> std::io::Result<()> {
+        self.annotations
+            .sort_by_cached_key(|(path, d)| (path.clone(), d.line_start, d.column_start, d.rule()));
+        for (path, d) in std::mem::take(&mut self.annotations) {
+            writeln!(self.out, "{}", Self::offense_line(&path, &d))?;
+        }
+        write_summary(&mut std::io::stderr(), summary)?;
+        self.out.flush()
+    }
+}
+
+/// `files`: the path of each file with offenses.
+pub struct FilesFormatter<W> {
+    pub out: W,
+}
+
+impl<W: Write> Formatter for FilesFormatter<W> {
+    fn file_finished(&mut self, path: &str, diagnostics: &[Diagnostic]) -> std::io::Result<()> {
+        if !diagnostics.is_empty() {
+            writeln!(self.out, "{}", path)?;
+        }
+        Ok(())
+    }
+
+    fn finished(&mut self, _summary: &RunSummary) -> std::io::Result<()> {
+        self.out.flush()
+    }
+}
+
+/// `quiet`: no output.
+pub struct QuietFormatter;
+
+impl Formatter for QuietFormatter {
+    fn file_finished(&mut self, _path: &str, _diagnostics: &[Diagnostic]) -> std::io::Result<()> {
+        Ok(())
+    }
+}
+
+/// `json`: the RuboCop-compatible document, written once all files are checked.
+pub struct JsonFormatter<W> {
+    pub out: W,
+    file_results: FxHashMap<String, Vec<Diagnostic>>,
+    corrected_results: FxHashMap<String, Vec<Diagnostic>>,
+}
+
+impl<W> JsonFormatter<W> {
+    pub fn new(out: W) -> Self {
+        Self {
+            out,
+            file_results: FxHashMap::default(),
+            corrected_results: FxHashMap::default(),
+        }
+    }
+}
+
+impl<W: Write> Formatter for JsonFormatter<W> {
+    fn file_finished(&mut self, path: &str, diagnostics: &[Diagnostic]) -> std::io::Result<()> {
+        self.file_results.insert(path.to_string(), diagnostics.to_vec());
+        Ok(())
+    }
+
+    fn file_inspected(&mut self, file: &InspectedFile<'_>) -> std::io::Result<()> {
+        if !file.corrected.is_empty() {
+            self.corrected_results.insert(file.path.to_string(), file.corrected.to_vec());
+        }
+        self.file_finished(file.path, file.diagnostics)
+    }
+
+    fn finished(&mut self, _summary: &RunSummary) -> std::io::Result<()> {
+        let json_output = JsonOutput::new(std::mem::take(&mut self.file_results), std::mem::take(&mut self.corrected_results));
+        json_output.write_to(&mut self.out)?;
+        self.out.flush()
+    }
+}
+
+/// `junit`: a JUnit XML document, written once all files are checked.
+pub struct JunitFormatter<W> {
+    pub out: W,
+    file_results: FxHashMap<String, Vec<Diagnostic>>,
+}
+
+impl<W> JunitFormatter<W> {
+    pub fn new(out: W) -> Self {
+        Self {
+            out,
+            file_results: FxHashMap::default(),
+        }
+    }
+}
+
+impl<W: Write> Formatter for JunitFormatter<W> {
+    fn file_finished(&mut self, path: &str, diagnostics: &[Diagnostic]) -> std::io::Result<()> {
+        self.file_results.insert(path.to_string(), diagnostics.to_vec());
+        Ok(())
+    }
+
+    fn finished(&mut self, _summary: &RunSummary) -> std::io::Result<()> {
+        writeln!(self.out, "{}", JunitOutput::new(&self.file_results).to_xml())?;
+        self.out.flush()
+    }
+}
+
+/// `offenses`: offense counts by cop, written once all files are checked.
+pub struct OffensesFormatter<W> {
+    pub out: W,
+    file_results: FxHashMap<String, Vec<Diagnostic>>,
+}
+
+impl<W> OffensesFormatter<W> {
+    pub fn new(out: W) -> Self {
+        Self {
+            out,
+            file_results: FxHashMap::default(),
+        }
+    }
+}
+
+impl<W: Write> Formatter for OffensesFormatter<W> {
+    fn file_finished(&mut self, path: &str, diagnostics: &[Diagnostic]) -> std::io::Result<()> {
+        self.file_results.insert(path.to_string(), diagnostics.to_vec());
+        Ok(())
+    }
+
+    fn finished(&mut self, _summary: &RunSummary) -> std::io::Result<()> {
+        write!(self.out, "{}", OffensesOutput::new(&self.file_results).to_table())?;
+        self.out.flush()
+    }
+}
+
+/// The compiler-style level of an offense: `error`, `warning` or `note`.
+fn level(d: &Diagnostic) -> &'static str {
+    match d.severity {
+        Severity::Error | Severity::Fatal => "error",
+        Severity::Warning => "warning",
+        Severity::Info | Severity::Refactor | Severity::Convention => "note",
+    }
+}
+
+/// Write the `N file(s) inspected, ...` summary of the line-oriented formats.
+fn write_summary(out: &mut dyn Write, summary: &RunSummary) -> std::io::Result<()> {
+    if summary.corrected_count > 0 {
+        writeln!(out)?;
+        writeln!(out, "{} offense(s) corrected", summary.corrected_count)?;
+    }
+
+    writeln!(out)?;
+    if summary.offense_count > 0 {
+        writeln!(
+            out,
+            "{} file(s) inspected, {} offense(s) detected",
+            summary.inspected_files, summary.offense_count
+        )?;
+    } else {
+        writeln!(out, "{} file(s) inspected, no offenses detected", summary.inspected_files)?;
+    }
+    if summary.skipped_files > 0 {
+        writeln!(out, "{} file(s) skipped for exceeding the maximum file size", summary.skipped_files)?;
+    }
+    Ok(())
+}
+
+/// ANSI SGR parameters used by `paint`.
+const BOLD: &str = "1";
+const RED: &str = "31";
+const YELLOW: &str = "33";
+const CYAN: &str = "36";
+
+/// Wrap `text` in an ANSI escape sequence when `color` is on.
+fn paint(text: impl std::fmt::Display, sgr: &str, color: bool) -> String {
+    if color { format!("\x1b[{}m{}\x1b[0m", sgr, text) } else { text.to_string() }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use clap::Parser;
+
+    #[test]
+    fn test_colored_simple_output() {
+        let source = b"binding.pry  \n";
+        let diagnostics = reukocyte_checker::check(source);
+        let output = |flags: &[&str]| {
+            let args = Args::parse_from(["reuko"].iter().chain(flags));
+            let mut formatter = SimpleFormatter::new(Vec::new(), &args);
+            formatter.file_finished("a.rb", &diagnostics).unwrap();
+            String::from_utf8(formatter.out).unwrap()
+        };
+
+        let colored = output(&["--color"]);
+        assert!(colored.contains("\x1b[1ma.rb\x1b[0m:1:1: \x1b[33mW\x1b[0m: Lint/Debugger "));
+        assert!(colored.contains("\x1b[1ma.rb\x1b[0m:1:12: \x1b[36mC\x1b[0m: Layout/TrailingWhitespace "));
+        let plain = output(&["--no-color"]);
+        assert!(!plain.contains('\x1b'));
+        assert!(plain.contains("a.rb:1:1: W: Lint/Debugger "));
+        assert!(!EmacsFormatter::<Vec<u8>>::offense_line("a.rb", &diagnostics[0]).contains('\x1b'));
+    }
+
+    #[test]
+    fn test_summary_counts_skipped_files() {
+        let summary = |skipped_files| {
+            let mut out = Vec::new();
+            let summary = RunSummary {
+                inspected_files: 2,
+                skipped_files,
+                ..RunSummary::default()
+            };
+            write_summary(&mut out, &summary).unwrap();
+            String::from_utf8(out).unwrap()
+        };
+        assert_eq!(summary(0), "\n2 file(s) inspected, no offenses detected\n");
+        assert_eq!(
+            summary(1),
+            "\n2 file(s) inspected, no offenses detected\n1 file(s) skipped for exceeding the maximum file size\n"
+        );
+    }
+
+    #[test]
+    fn test_offense_line_severity_tokens() {
+        let mut diagnostics = reukocyte_checker::check(b"binding.pry  \n");
+        assert_eq!(diagnostics[0].rule(), "Lint/Debugger");
+        let simple = |d: &Diagnostic, flags: &[&str]| {
+            let args = Args::parse_from(["reuko", "--no-color"].iter().chain(flags));
+            SimpleFormatter::new(std::io::sink(), &args).offense_line("a.rb", d)
+        };
+        let emacs = |d: &Diagnostic| EmacsFormatter::<Vec<u8>>::offense_line("a.rb", d);
+        let clang = |d: &Diagnostic| ClangFormatter::<Vec<u8>>::offense_line("a.rb", d);
+        let github = |d: &Diagnostic| GithubFormatter::<Vec<u8>>::offense_line("a.rb", d);
+
+        let whitespace = &diagnostics[1];
+        assert_eq!(simple(whitespace, &[]), "a.rb:1:12: C: Layout/TrailingWhitespace Trailing whitespace detected.");
+        assert_eq!(emacs(whitespace), "a.rb:1:12: C: Trailing whitespace detected.");
+        assert_eq!(clang(whitespace), "a.rb:1:12: note: Trailing whitespace detected.");
+        assert_eq!(
+            github(whitespace),
+            "::notice file=a.rb,line=1,col=12,title=Layout/TrailingWhitespace::Trailing whitespace detected."
+        );
+        assert_eq!(simple(whitespace, &["--no-display-cop-names"]), emacs(whitespace));
+
+        let debugger = &diagnostics[0];
+        assert!(simple(debugger, &[]).starts_with("a.rb:1:1: W: Lint/Debugger "));
+        assert!(emacs(debugger).starts_with("a.rb:1:1: W: "));
+        assert!(clang(debugger).starts_with("a.rb:1:1: warning: "));
+        assert!(github(debugger).starts_with("::warning "));
+
+        diagnostics[0].severity = Severity::Fatal;
+        let fatal = &diagnostics[0];
+        assert!(simple(fatal, &[]).starts_with("a.rb:1:1: F: "));
+        assert!(clang(fatal).starts_with("a.rb:1:1: error: "));
+        assert!(github(fatal).starts_with("::error "));
+    }
+
+    #[test]
+    fn test_github_annotations() {
+        let diagnostics = reukocyte_checker::check(b"binding.pry  \n");
+        let mut formatter = GithubFormatter::new(Vec::new());
+        formatter.file_finished("b.rb", &diagnostics).unwrap();
+        formatter.file_finished("a.rb", &diagnostics[1..]).unwrap();
+        formatter.finished(&RunSummary::default()).unwrap();
+        let output = String::from_utf8(formatter.out).unwrap();
+        let lines: Vec<&str> = output.lines().collect();
+        assert_eq!(lines.len(), 3);
+        assert!(lines[0].starts_with("::notice file=a.rb,line=1,col=12,title=Layout/TrailingWhitespace::"));
+        assert!(lines[1].starts_with("::warning file=b.rb,line=1,col=1,title=Lint/Debugger::"));
+        assert!(lines[2].starts_with("::notice file=b.rb,line=1,col=12,"));
+
+        let mut diagnostic = diagnostics[1].clone();
+        diagnostic.message = "100% wrong\r\nsee below".to_string();
+        assert_eq!(
+            GithubFormatter::<Vec<u8>>::offense_line("dir,1/a:b.rb", &diagnostic),
+            "::notice file=dir%2C1/a%3Ab.rb,line=1,col=12,title=Layout/TrailingWhitespace::100%25 wrong%0D%0Asee below"
+        );
+    }
+
+    #[test]
+    fn test_show_source_uses_inspected_source() {
+        let source = b"x = 1  \n";
+        let diagnostics = reukocyte_checker::check(source);
+        let args = Args::parse_from(["reuko", "--no-color", "--show-source"]);
+        let mut formatter = SimpleFormatter::new(Vec::new(), &args);
+        let file = InspectedFile {
+            path: "a.rb",
+            source,
+            diagnostics: &diagnostics,
+            corrected: &[],
+        };
+        formatter.file_inspected(&file).unwrap();
+        let output = String::from_utf8(formatter.out).unwrap();
+        assert!(output.starts_with("a.rb:1:6: C: "));
+        assert!(output.contains("x = 1"));
+    }
+}
@@ -0,0 +1,101 @@
+//! `--init`: write a starter configuration to the current directory.
+//!
+//! The values come from the checker's defaults, so the scaffold documents what
+//! the linter does out of the box and always loads as a regular config.
+
+use reukocyte_checker::{Config, DEFAULT_TARGET_RUBY_VERSION};
+use serde_yaml::{Mapping, Value};
+use std::io::Write;
+use std::path::Path;
+
+/// File written by `--init` (RuboCop compatible).
+pub const INIT_FILE: &str = ".rubocop.yml";
+
+const HEADER: &str = "\
+# This configuration was generated by
+# `reuko --init`
+# Settings not listed here use the defaults. See
+# https://docs.rubocop.org/rubocop/configuration.html for the format.
+";
+
+/// Generate the starter configuration.
+pub fn generate_init() -> String {
+    let config = Config::default();
+    // Widen through the shortest decimal form, or `3.4` is written as `3.4000000953674316`.
+    let target_ruby_version: f64 = DEFAULT_TARGET_RUBY_VERSION.to_string().parse().expect("a float always parses back");
+    let sections = [
+        (
+            "# Target Ruby version and how cops added in new releases are treated.",
+            "AllCops",
+            vec![("TargetRubyVersion", Value::from(target_ruby_version)), ("NewCops", Value::from("enable"))],
+        ),
+        (
+            "# Maximum line length, used when deciding whether code fits on one line.",
+            "Layout/LineLength",
+            vec![("Max", Value::from(config.layout.line_length.max))],
+        ),
+        (
+            "# Number of spaces per indentation level.",
+            "Layout/IndentationWidth",
+            vec![("Width", Value::from(config.layout.indentation_width.width))],
+        ),
+    ];
+
+    let mut init = String::from(HEADER);
+    for (comment, name, options) in sections {
+        let options = Mapping::from_iter(options.into_iter().map(|(key, value)| (Value::from(key), value)));
+        let entry = Mapping::from_iter([(Value::from(name), Value::Mapping(options))]);
+        init.push_str(&format!("\n{}\n", comment));
+        init.push_str(&serde_yaml::to_string(&entry).expect("a mapping of strings always serializes"));
+    }
+    init
+}
+
+/// Write the starter configuration to `path`.
+///
+/// Fails with `ErrorKind::AlreadyExists` when the file exists, unless `force` is set.
+pub fn write_init(path: &Path, force: bool) -> std::io::Result<()> {
+    let mut options = std::fs::OpenOptions::new();
+    options.write(true);
+    if force {
+        options.create(true).truncate(true);
+    } else {
+        options.create_new(true);
+    }
+    options.open(path)?.write_all(generate_init().as_bytes())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::ErrorKind;
+
+    #[test]
+    fn test_generate_init_parses_back() {
+        let init = generate_init();
+        assert!(init.starts_with(HEADER));
+        let yaml = reukocyte_checker::parse_rubocop_yaml(&init).unwrap();
+        assert_eq!(yaml.all_cops.target_ruby_version, Some(DEFAULT_TARGET_RUBY_VERSION));
+        assert_eq!(yaml.all_cops.new_cops.as_deref(), Some("enable"));
+
+        let config = Config::from_rubocop_yaml(&yaml);
+        assert_eq!(config.layout.line_length.max, Config::default().layout.line_length.max);
+        assert_eq!(config.layout.indentation_width.width, Config::default().layout.indentation_width.width);
+    }
+
+    #[test]
+    fn test_write_init_refuses_to_overwrite() {
+        let dir = std::env::temp_dir().join(format!("reuko-init-{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        let path = dir.join(INIT_FILE);
+        std::fs::write(&path, "AllCops: {}\n").unwrap();
+
+        let error = write_init(&path, false).unwrap_err();
+        assert_eq!(error.kind(), ErrorKind::AlreadyExists);
+        assert_eq!(std::fs::read_to_string(&path).unwrap(), "AllCops: {}\n");
+
+        write_init(&path, true).unwrap();
+        assert_eq!(std::fs::read_to_string(&path).unwrap(), generate_init());
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+}
@@ -0,0 +1,39 @@
+//! SIGINT handling, so that an interrupted run still reports what it checked.
+//!
+//! The handler only sets a flag; the file loop checks it between files and stops
+//! early, after which the results collected so far are printed as usual.
+
+use std::sync::atomic::{AtomicBool, Ordering};
+
+/// Set when SIGINT has been received.
+static INTERRUPTED: AtomicBool = AtomicBool::new(false);
+
+/// Get the flag set by the SIGINT handler.
+pub fn flag() -> &'static AtomicBool {
+    &INTERRUPTED
+}
+
+/// Install the SIGINT handler. A second Ctrl-C terminates the process immediately.
+#[cfg(unix)]
+pub fn install() {
+    extern "C" fn on_sigint(_: libc::c_int) {
+        INTERRUPTED.store(true, Ordering::SeqCst);
+        // Restore the default action so that a second Ctrl-C is not ignored.
+        unsafe {
+            libc::signal(libc::SIGINT, libc::SIG_DFL);
+        }
+    }
+    unsafe {
+        libc::signal(libc::SIGINT, on_sigint as extern "C" fn(libc::c_int) as libc::sighandler_t);
+    }
+}
+
+/// Install the SIGINT handler (not supported on this platform; Ctrl-C terminates as usual).
+#[cfg(not(unix))]
+pub fn install() {}
+
+/// Check whether the run was interrupted.
+#[inline]
+pub fn is_set(flag: &AtomicBool) -> bool {
+    flag.load(Ordering::SeqCst)
+}
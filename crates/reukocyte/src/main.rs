@@ -1,22 +1,36 @@
 mod args;
+mod explain;
 mod files;
+mod formatter;
+mod init;
+mod interrupt;
 mod output;
+mod rule_names;
+mod todo;
 
 use args::Args;
 use args::OutputFormat;
 use clap::Parser;
 use files::collect_ruby_files;
-use output::JsonOutput;
 use reukocyte_checker::Category;
 use reukocyte_checker::Config;
 use reukocyte_checker::Diagnostic;
-use reukocyte_checker::Severity;
-use reukocyte_checker::apply_fixes_filtered;
+use reukocyte_checker::Formatter;
+use reukocyte_checker::InspectedFile;
+use reukocyte_checker::LoadError;
+use reukocyte_checker::RunSummary;
+use reukocyte_checker::apply_fixes_filtered_with_corrected;
 use reukocyte_checker::check_with_config_and_path;
+use reukocyte_checker::disable_uncorrectable;
+use reukocyte_checker::file_mode;
 use reukocyte_checker::load_rubocop_yaml;
+use reukocyte_checker::should_apply_fix;
 use rustc_hash::FxHashMap;
 use std::io::Read;
+use std::io::Write;
+use std::path::PathBuf;
 use std::process::ExitCode;
+use std::sync::atomic::AtomicBool;
 
 /// Exit codes compatible with RuboCop
 #[allow(dead_code)]
@@ -35,9 +49,26 @@ fn main() -> ExitCode {
     }
     // Start timing if --display-time is enabled
     let start_time = std::time::Instant::now();
+    // Time each rule if --profile is enabled
+    reukocyte_checker::set_profiling(args.profile);
+    // Write a starter config instead of checking
+    if args.init {
+        return handle_init(&args);
+    }
+    // Describe a rule instead of checking
+    if let Some(ref name) = args.explain {
+        return handle_explain(&args, name);
+    }
+    // Print the resolved configuration instead of checking
+    if args.config_dump {
+        print!("{}", load_config(&args).to_yaml());
+        return ExitCode::from(exit_code::SUCCESS);
+    }
     // Handle stdin mode
     if let Some(ref stdin_file) = args.stdin {
-        return handle_stdin(&args, stdin_file);
+        let result = handle_stdin(&args, stdin_file);
+        print_profile(&args);
+        return result;
     }
     // Process files
     let result = run(&args);
@@ -46,22 +77,61 @@ fn main() -> ExitCode {
         let elapsed = start_time.elapsed();
         eprintln!("Finished in {:.2} seconds", elapsed.as_secs_f64());
     }
+    print_profile(&args);
     result
 }
 
+/// Print the per-rule timings collected with `--profile`, slowest first.
+fn print_profile(args: &Args) {
+    if args.profile {
+        eprint!("{}", format_profile(&reukocyte_checker::take_profile()));
+    }
+}
+
+/// Format per-rule timings as a `total_us  count  avg_us  Cop/Name` table.
+fn format_profile(timings: &[reukocyte_checker::RuleTiming]) -> String {
+    let mut table = format!("{:>10}  {:>8}  {:>8}  {}\n", "total_us", "count", "avg_us", "Cop/Name");
+    for timing in timings {
+        table.push_str(&format!(
+            "{:>10}  {:>8}  {:>8}  {}\n",
+            timing.total.as_micros(),
+            timing.count,
+            timing.average().as_micros(),
+            timing.rule
+        ));
+    }
+    table
+}
+
 /// Load configuration from file or use defaults.
 fn load_config(args: &Args) -> Config {
+    load_config_in(args, std::path::Path::new(""))
+}
+
+/// Load the configuration, looking for `.rubocop.yml` in `dir` when no `--config` is given.
+fn load_config_in(args: &Args, dir: &std::path::Path) -> Config {
+    if args.force_default_config {
+        if let Some(ref config_path) = args.config {
+            eprintln!("Warning: --force-default-config ignores --config {}", config_path.display());
+        }
+        return Config::default();
+    }
     if let Some(ref config_path) = args.config {
         match load_rubocop_yaml(config_path) {
             Ok(yaml) => Config::from_rubocop_yaml(&yaml),
+            Err(e @ LoadError::Yaml { .. }) => {
+                // The error already names the file and position (`path:line:col: invalid YAML: ...`).
+                eprintln!("Warning: {}", e);
+                Config::default()
+            }
             Err(e) => {
                 eprintln!("Warning: Failed to load config {}: {}", config_path.display(), e);
                 Config::default()
             }
         }
     } else {
-        // Try to find .rubocop.yml in current directory
-        let default_path = std::path::Path::new(".rubocop.yml");
+        // Try to find .rubocop.yml in the directory
+        let default_path = &dir.join(".rubocop.yml");
         if args.debug {
             eprintln!("Checking for .rubocop.yml at: {:?}, exists: {}", default_path, default_path.exists());
         }
@@ -73,6 +143,10 @@ fn load_config(args: &Args) -> Config {
                     }
                     Config::from_rubocop_yaml(&yaml)
                 }
+                Err(e @ LoadError::Yaml { .. }) => {
+                    eprintln!("Warning: {}", e);
+                    Config::default()
+                }
                 Err(e) => {
                     if args.debug {
                         eprintln!("Failed to load .rubocop.yml: {}", e);
@@ -86,30 +160,141 @@ fn load_config(args: &Args) -> Config {
     }
 }
 
+/// Write the starter configuration for `--init`.
+fn handle_init(args: &Args) -> ExitCode {
+    match init::write_init(std::path::Path::new(init::INIT_FILE), args.force) {
+        Ok(()) => {
+            eprintln!("Created {}.", init::INIT_FILE);
+            ExitCode::from(exit_code::SUCCESS)
+        }
+        Err(e) if e.kind() == std::io::ErrorKind::AlreadyExists => {
+            eprintln!("Error: {} already exists (use --force to overwrite).", init::INIT_FILE);
+            ExitCode::from(exit_code::ERROR)
+        }
+        Err(e) => {
+            eprintln!("Error writing {}: {}", init::INIT_FILE, e);
+            ExitCode::from(exit_code::ERROR)
+        }
+    }
+}
+
+/// Print the documentation of a rule for `--explain`.
+fn handle_explain(args: &Args, name: &str) -> ExitCode {
+    match explain_rule(args, name) {
+        Ok(text) => {
+            print!("{}", text);
+            ExitCode::from(exit_code::SUCCESS)
+        }
+        Err(message) => {
+            eprintln!("{}", message);
+            ExitCode::from(exit_code::ERROR)
+        }
+    }
+}
+
+/// Render the documentation of a rule, as JSON with `--format json`, or the unknown-rule error.
+fn explain_rule(args: &Args, name: &str) -> Result<String, String> {
+    let Some(rule) = rule_names::find_rule(name) else {
+        return Err(rule_names::validate_rule_names([&name.to_string()]).err().unwrap_or_default());
+    };
+    let explanation = explain::Explanation::new(rule);
+    Ok(match args.output_format() {
+        OutputFormat::Json => format!("{}\n", explanation.to_json()),
+        _ => explanation.to_text(),
+    })
+}
+
+/// Check the rule names given to `--only` and `--except`, printing the unknown ones.
+fn check_rule_names(args: &Args) -> Result<(), ExitCode> {
+    let names = args.only.iter().chain(&args.except).flatten();
+    rule_names::validate_rule_names(names).map_err(|message| {
+        eprintln!("{}", message);
+        ExitCode::from(exit_code::ERROR)
+    })
+}
+
 /// Handle reading from stdin
 fn handle_stdin(args: &Args, filename: &std::path::Path) -> ExitCode {
+    if let Err(code) = check_rule_names(args) {
+        return code;
+    }
     let mut source = Vec::new();
     if std::io::stdin().read_to_end(&mut source).is_err() {
         eprintln!("Error reading from stdin");
         return ExitCode::from(exit_code::OFFENSES);
     }
+    check_stdin_source(args, filename, &source)
+}
 
+/// Check source read from stdin as `filename`.
+///
+/// Like a file run, this only fails if an offense is at or above `--fail-level`.
+fn check_stdin_source(args: &Args, filename: &std::path::Path, source: &[u8]) -> ExitCode {
     let config = load_config(args);
+    let mut formatter = match open_output(args) {
+        Ok(out) => formatter::formatter_for(args, out),
+        Err(e) => return output_error(args, e),
+    };
     let path_str = filename.to_string_lossy();
-    let (remaining, _fixed_count) = check_file(&path_str, &source, args, &config);
+    let written = formatter.started().and_then(|()| {
+        let (remaining, corrected) = check_file(&path_str, source, None, args, &config, args.max_offenses, formatter.as_mut());
+        let summary = RunSummary {
+            inspected_files: 1,
+            offense_count: remaining.len(),
+            corrected_count: corrected.len(),
+            skipped_files: 0,
+        };
+        formatter.finished(&summary).map(|()| remaining)
+    });
+    let remaining = match written {
+        Ok(remaining) => remaining,
+        Err(e) => return output_error(args, e),
+    };
 
-    if remaining.is_empty() {
+    if !has_failing_offense(&remaining, args) {
         ExitCode::from(exit_code::SUCCESS)
     } else {
         ExitCode::from(exit_code::OFFENSES)
     }
 }
 
+/// Results of checking a set of files.
+#[derive(Debug, Default)]
+struct RunResults {
+    file_results: FxHashMap<String, Vec<Diagnostic>>,
+    corrected_results: FxHashMap<String, Vec<Diagnostic>>,
+    total_remaining: usize,
+    total_fixed: usize,
+    /// The run stopped early because of SIGINT.
+    interrupted: bool,
+    /// The run stopped early because `--max-offenses` offenses were reported.
+    offense_limit_reached: bool,
+    /// Files not checked for exceeding the maximum file size.
+    skipped_files: usize,
+}
+
 /// Run the checker on the given files and return appropriate exit code.
 fn run(args: &Args) -> ExitCode {
+    if let Err(code) = check_rule_names(args) {
+        return code;
+    }
     // Load configuration
     let config = load_config(args);
+    let mut formatter = match open_output(args) {
+        Ok(out) => formatter::formatter_for(args, out),
+        Err(e) => return output_error(args, e),
+    };
+    run_with_formatter(args, &config, formatter.as_mut())
+}
 
+/// Check the files given on the command line, reporting through `formatter`.
+fn run_with_formatter(args: &Args, config: &Config, formatter: &mut dyn Formatter) -> ExitCode {
+    if args.only_recognized_file_types {
+        if let Err(message) = files::check_recognized_file_types(&args.files) {
+            eprintln!("Error: {}", message);
+            return ExitCode::from(exit_code::ERROR);
+        }
+    }
     // Collect all Ruby files from the given paths, respecting AllCops.Exclude
     let files = collect_ruby_files(&args.files, &config.all_cops.exclude);
 
@@ -120,28 +305,128 @@ fn run(args: &Args) -> ExitCode {
         return ExitCode::from(exit_code::SUCCESS);
     }
 
-    let mut total_remaining = 0;
-    let mut total_fixed = 0;
-    let mut file_results: FxHashMap<String, Vec<Diagnostic>> = FxHashMap::default();
-    let mut corrected_counts: FxHashMap<String, usize> = FxHashMap::default();
+    if let Err(e) = formatter.started() {
+        return output_error(args, e);
+    }
+    // On Ctrl-C, stop after the current file and report what was checked so far
+    interrupt::install();
+    let results = check_files(&files, args, config, interrupt::flag(), formatter);
+    if results.interrupted {
+        eprintln!(
+            "Interrupted; showing partial results for {} of {} file(s).",
+            results.file_results.len(),
+            files.len()
+        );
+    }
+
+    // With --auto-gen-config, the offenses become a TODO config instead of a failure
+    if args.auto_gen_config {
+        let todo = todo::generate_todo(&results.file_results, args.exclude_limit);
+        if let Err(e) = std::fs::write(todo::TODO_FILE, todo) {
+            eprintln!("Error writing {}: {}", todo::TODO_FILE, e);
+            return ExitCode::from(exit_code::ERROR);
+        }
+        eprintln!("Created {}.", todo::TODO_FILE);
+        return ExitCode::from(exit_code::SUCCESS);
+    }
+
+    // Reaching --max-offenses fails only through the offenses it counted
+    let failed = has_failing_offense(results.file_results.values().flatten(), args) || results.interrupted;
+
+    let summary = RunSummary {
+        inspected_files: if results.interrupted || results.offense_limit_reached {
+            results.file_results.len()
+        } else {
+            files.len() - results.skipped_files
+        },
+        offense_count: results.total_remaining,
+        corrected_count: results.total_fixed,
+        skipped_files: results.skipped_files,
+    };
+    if let Err(e) = formatter.finished(&summary) {
+        return output_error(args, e);
+    }
+
+    if failed {
+        ExitCode::from(exit_code::OFFENSES)
+    } else {
+        ExitCode::from(exit_code::SUCCESS)
+    }
+}
+
+/// Open the destination of the formatted output: the `--out` file, stderr
+/// with `--stderr`, or stdout.
+fn open_output(args: &Args) -> std::io::Result<Box<dyn Write>> {
+    if let Some(ref path) = args.output_file {
+        Ok(Box::new(std::io::BufWriter::new(std::fs::File::create(path)?)))
+    } else if args.stderr {
+        Ok(Box::new(std::io::stderr()))
+    } else {
+        Ok(Box::new(std::io::stdout().lock()))
+    }
+}
+
+/// Report a failure to write the formatted output.
+fn output_error(args: &Args, e: std::io::Error) -> ExitCode {
+    match args.output_file {
+        Some(ref path) => eprintln!("Error writing {}: {}", path.display(), e),
+        None => eprintln!("Error writing output: {}", e),
+    }
+    ExitCode::from(exit_code::ERROR)
+}
+
+/// Check if any offense is at or above the `--fail-level` severity.
+fn has_failing_offense<'a>(diagnostics: impl IntoIterator<Item = &'a Diagnostic>, args: &Args) -> bool {
+    let fail_severity = args.fail_severity();
+    diagnostics.into_iter().any(|diagnostic| diagnostic.severity >= fail_severity)
+}
+
+/// Check each file in turn, stopping early on fail-fast or when `interrupted` is set.
+fn check_files(files: &[PathBuf], args: &Args, config: &Config, interrupted: &AtomicBool, formatter: &mut dyn Formatter) -> RunResults {
+    let mut results = RunResults::default();
+    let max_file_size = args.max_file_size.or(config.all_cops.maximum_file_size);
 
-    for path in &files {
+    for path in files {
+        if interrupt::is_set(interrupted) {
+            results.interrupted = true;
+            break;
+        }
+        // Skip files over the size limit without reading them
+        if let Some(max) = max_file_size
+            && let Ok(metadata) = std::fs::metadata(path)
+            && metadata.len() > max
+        {
+            eprintln!(
+                "Skipping {}: {} bytes is over the maximum file size of {} bytes.",
+                path.display(),
+                metadata.len(),
+                max
+            );
+            results.skipped_files += 1;
+            continue;
+        }
         match std::fs::read(path) {
             Ok(source) => {
                 let path_str = path.to_string_lossy().to_string();
-                let (remaining, fixed_count) = check_file(&path_str, &source, args, &config);
+                let limit = args.max_offenses.map(|max| max.saturating_sub(results.total_remaining));
+                let (remaining, corrected) = check_file(&path_str, &source, file_mode(path), args, config, limit, formatter);
 
-                total_remaining += remaining.len();
-                total_fixed += fixed_count;
+                results.total_remaining += remaining.len();
+                results.total_fixed += corrected.len();
 
                 // Always include files in results (RuboCop-compatible)
-                file_results.insert(path_str.clone(), remaining);
-                if fixed_count > 0 {
-                    corrected_counts.insert(path_str, fixed_count);
+                results.file_results.insert(path_str.clone(), remaining);
+                if !corrected.is_empty() {
+                    results.corrected_results.insert(path_str, corrected);
                 }
 
                 // Handle fail-fast
-                if args.fail_fast && total_remaining > 0 {
+                if args.fail_fast && results.total_remaining > 0 {
+                    break;
+                }
+                // Handle --max-offenses
+                if args.max_offenses.is_some_and(|max| results.total_remaining >= max) {
+                    results.offense_limit_reached = true;
                     break;
                 }
             }
@@ -150,25 +435,7 @@ fn run(args: &Args) -> ExitCode {
             }
         }
     }
-
-    // Output based on format
-    let format = args.output_format();
-    match format {
-        OutputFormat::Json => {
-            let json_output = JsonOutput::new(file_results, corrected_counts);
-            println!("{}", json_output.to_json());
-        }
-        _ => {
-            // Print summary for non-JSON formats
-            print_summary(args, files.len(), total_remaining, total_fixed);
-        }
-    }
-
-    if total_remaining > 0 {
-        ExitCode::from(exit_code::OFFENSES)
-    } else {
-        ExitCode::from(exit_code::SUCCESS)
-    }
+    results
 }
 
 /// Check if a diagnostic should be included based on CLI options.
@@ -197,6 +464,10 @@ fn should_include_diagnostic(diagnostic: &Diagnostic, args: &Args) -> bool {
             return false;
         }
     }
+    // Filter by --autocorrectable-only (only offenses with an applicable fix)
+    if args.autocorrectable_only && !diagnostic.fix.as_ref().is_some_and(|fix| should_apply_fix(fix, args.unsafe_fixes())) {
+        return false;
+    }
     true
 }
 
@@ -205,107 +476,467 @@ fn filter_diagnostics(diagnostics: Vec<Diagnostic>, args: &Args) -> Vec<Diagnost
     diagnostics.into_iter().filter(|d| should_include_diagnostic(d, args)).collect()
 }
 
-/// Check a file and return (remaining_diagnostics, fixed_count).
-fn check_file(path: &str, source: &[u8], args: &Args, config: &Config) -> (Vec<Diagnostic>, usize) {
-    let diagnostics = check_with_config_and_path(source, config, Some(path));
+/// Check a file, pass the results to `formatter` and return (remaining_diagnostics, corrected_diagnostics).
+///
+/// With a `limit`, at most that many remaining offenses are reported and returned.
+fn check_file(
+    path: &str,
+    source: &[u8],
+    file_mode: Option<u32>,
+    args: &Args,
+    config: &Config,
+    limit: Option<usize>,
+    formatter: &mut dyn Formatter,
+) -> (Vec<Diagnostic>, Vec<Diagnostic>) {
+    let diagnostics = check_with_config_and_path(source, config, Some(path), file_mode);
     let diagnostics = filter_diagnostics(diagnostics, args);
 
     if args.should_fix() && !diagnostics.is_empty() {
         // Create a filter closure that captures the args
         let filter = |diagnostic: &Diagnostic| should_include_diagnostic(diagnostic, args);
         // Apply fixes with filtering
-        let (fixed_source, fix_count) = apply_fixes_filtered(Some(path), source, &diagnostics, args.unsafe_fixes(), filter);
-
-        if fix_count > 0 {
-            // Write the fixed source back to the file
-            if let Err(e) = std::fs::write(path, &fixed_source) {
-                eprintln!("Error writing {}: {}", path, e);
-            }
-        }
+        let (mut fixed_source, mut corrected) =
+            apply_fixes_filtered_with_corrected(Some(path), source, &diagnostics, config, args.unsafe_fixes(), args.fix_mode(), filter);
 
         // Get remaining diagnostics (also filtered)
-        let remaining = check_with_config_and_path(&fixed_source, config, Some(path));
-        let remaining = filter_diagnostics(remaining, args);
-        print_diagnostics(path, &remaining, args);
-        (remaining, fix_count)
-    } else {
-        print_diagnostics(path, &diagnostics, args);
-        (diagnostics, 0)
-    }
-}
+        let remaining = check_with_config_and_path(&fixed_source, config, Some(path), file_mode);
+        let mut remaining = filter_diagnostics(remaining, args);
 
-/// Print diagnostics based on the selected output format.
-fn print_diagnostics(path: &str, diagnostics: &[Diagnostic], args: &Args) {
-    match args.output_format() {
-        OutputFormat::Json => {
-            // JSON output is handled by run() using JsonOutput for RuboCop compatibility
-        }
-        OutputFormat::Quiet => {
-            // Quiet mode: no output
-        }
-        OutputFormat::Simple | OutputFormat::Progress => {
-            for d in diagnostics {
-                if args.show_cop_names() {
-                    println!(
-                        "{}:{}:{}: {}: {} {}",
-                        path,
-                        d.line_start,
-                        d.column_start,
-                        d.severity.code(),
-                        d.rule(),
-                        d.message
-                    );
-                } else {
-                    println!("{}:{}:{}: {}: {}", path, d.line_start, d.column_start, d.severity.code(), d.message);
+        // Disable what autocorrect could not fix; those offenses count as corrected
+        if args.disable_uncorrectable && !remaining.is_empty() {
+            let (disabled_source, disabled_count) = disable_uncorrectable(&fixed_source, &remaining);
+            if disabled_count == remaining.len() {
+                fixed_source = disabled_source;
+                corrected.append(&mut remaining);
+            } else if disabled_count > 0 {
+                // Some offenses could not be disabled; those still reported remain
+                fixed_source = disabled_source;
+                let still_reported = filter_diagnostics(check_with_config_and_path(&fixed_source, config, Some(path), file_mode), args);
+                let mut unmatched: Vec<&Diagnostic> = still_reported.iter().collect();
+                for diagnostic in std::mem::replace(&mut remaining, still_reported.clone()) {
+                    match unmatched
+                        .iter()
+                        .position(|d| d.rule_id == diagnostic.rule_id && d.message == diagnostic.message)
+                    {
+                        Some(index) => {
+                            unmatched.swap_remove(index);
+                        }
+                        None => corrected.push(diagnostic),
+                    }
                 }
             }
         }
-        OutputFormat::Emacs => {
-            for d in diagnostics {
-                println!("{}:{}:{}: {}: {}", path, d.line_start, d.column_start, d.severity.code(), d.message);
-            }
-        }
-        OutputFormat::Github => {
-            for d in diagnostics {
-                let level = match d.severity {
-                    Severity::Error | Severity::Fatal => "error",
-                    Severity::Warning => "warning",
-                    _ => "notice",
-                };
-                println!("::{} file={},line={},col={}::{}", level, path, d.line_start, d.column_start, d.message);
+
+        if !corrected.is_empty() {
+            // Write the fixed source back to the file
+            if let Err(e) = files::write_atomically(std::path::Path::new(path), &fixed_source) {
+                eprintln!("Error writing {}: {}", path, e);
             }
         }
-        OutputFormat::Clang => {
-            for d in diagnostics {
-                println!("{}:{}:{}: {}: {}", path, d.line_start, d.column_start, d.severity.as_str(), d.message);
-            }
+
+        if let Some(limit) = limit {
+            remaining.truncate(limit);
         }
-        OutputFormat::Files => {
-            if !diagnostics.is_empty() {
-                println!("{}", path);
-            }
+        report_file(formatter, path, &fixed_source, &remaining, &corrected);
+        (remaining, corrected)
+    } else {
+        let mut diagnostics = diagnostics;
+        if let Some(limit) = limit {
+            diagnostics.truncate(limit);
         }
+        report_file(formatter, path, source, &diagnostics, &[]);
+        (diagnostics, Vec::new())
+    }
+}
+
+/// Pass a checked file to the formatter.
+///
+/// `source` is the source the diagnostics refer to, used by `--show-source`.
+fn report_file(formatter: &mut dyn Formatter, path: &str, source: &[u8], diagnostics: &[Diagnostic], corrected: &[Diagnostic]) {
+    let file = InspectedFile {
+        path,
+        source,
+        diagnostics,
+        corrected,
+    };
+    if let Err(e) = formatter.file_inspected(&file) {
+        eprintln!("Error writing output: {}", e);
     }
 }
 
-/// Print summary of the run based on output format.
-fn print_summary(args: &Args, file_count: usize, total_diagnostics: usize, total_fixed: usize) {
-    match args.output_format() {
-        OutputFormat::Quiet | OutputFormat::Json | OutputFormat::Files => {
-            // No summary for these formats
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use reukocyte_checker::Severity;
+
+    fn write_temp_file(name: &str, content: &[u8]) -> PathBuf {
+        let path = std::env::temp_dir().join(format!("reuko-{}-{}.rb", std::process::id(), name));
+        std::fs::write(&path, content).unwrap();
+        path
+    }
+
+    #[test]
+    fn test_check_files_collects_results() {
+        let args = Args::parse_from(["reuko", "--format", "quiet"]);
+        let files = vec![write_temp_file("collect", b"x = 1  \n")];
+        let results = check_files(&files, &args, &Config::default(), &AtomicBool::new(false), &mut formatter::QuietFormatter);
+        std::fs::remove_file(&files[0]).unwrap();
+        assert!(!results.interrupted);
+        assert_eq!(results.file_results.len(), 1);
+        assert_eq!(results.total_remaining, 1);
+    }
+
+    #[test]
+    fn test_explain() {
+        let args = Args::parse_from(["reuko", "--explain", "Lint/Debugger"]);
+        let text = explain_rule(&args, args.explain.as_deref().unwrap()).unwrap();
+        assert!(text.starts_with("Lint/Debugger\n\nDetects debugger statements left in code."));
+        assert!(text.contains("Example:\n\n    # bad\n    def foo\n      binding.irb\n"));
+        assert!(text.contains("Options:\n    DebuggerMethods: "));
+
+        let error = explain_rule(&args, "Lint/Debuger").unwrap_err();
+        assert_eq!(error, "Unrecognized cop: Lint/Debuger\nDid you mean? Lint/Debugger");
+    }
+
+    #[test]
+    fn test_format_profile() {
+        use reukocyte_checker::{LayoutRule, RuleId, RuleTiming};
+        use std::time::Duration;
+        let timings = [RuleTiming {
+            rule: RuleId::Layout(LayoutRule::TrailingWhitespace),
+            total: Duration::from_micros(1200),
+            count: 4,
+        }];
+        assert_eq!(
+            format_profile(&timings),
+            "  total_us     count    avg_us  Cop/Name\n      1200         4       300  Layout/TrailingWhitespace\n"
+        );
+    }
+
+    #[test]
+    fn test_autocorrectable_only_filter() {
+        let diagnostics = reukocyte_checker::check(b"binding.pry  \n");
+        assert_eq!(diagnostics.len(), 2);
+        let args = Args::parse_from(["reuko", "--autocorrectable-only"]);
+        let filtered = filter_diagnostics(diagnostics.clone(), &args);
+        assert_eq!(filtered.len(), 1);
+        assert_eq!(filtered[0].rule(), "Layout/TrailingWhitespace");
+        let args = Args::parse_from(["reuko"]);
+        assert_eq!(filter_diagnostics(diagnostics, &args).len(), 2);
+    }
+
+    #[test]
+    fn test_fail_level_compares_severity() {
+        let file = write_temp_file("fail-level", b"x = 1  \n");
+        let config = std::env::temp_dir().join(format!("reuko-{}-fail-level.yml", std::process::id()));
+        std::fs::write(&config, "Layout/TrailingWhitespace:\n  Severity: info\n").unwrap();
+        let exit_code = |fail_level: &str| {
+            let args = Args::parse_from([
+                "reuko".as_ref(),
+                "--format".as_ref(),
+                "quiet".as_ref(),
+                "-c".as_ref(),
+                config.as_os_str(),
+                "--fail-level".as_ref(),
+                fail_level.as_ref(),
+                file.as_os_str(),
+            ]);
+            run(&args)
+        };
+        let error = exit_code("error");
+        let info = exit_code("info");
+        std::fs::remove_file(&file).unwrap();
+        std::fs::remove_file(&config).unwrap();
+        assert_eq!(error, ExitCode::from(exit_code::SUCCESS));
+        assert_eq!(info, ExitCode::from(exit_code::OFFENSES));
+    }
+
+    #[test]
+    fn test_unknown_only_rule_is_an_error() {
+        let args = Args::parse_from(["reuko", "--only", "Layuot/TrailingWhitespace", "."]);
+        assert_eq!(run(&args), ExitCode::from(exit_code::ERROR));
+        let args = Args::parse_from(["reuko", "--except", "Lint/Debugger,Nonsense", "."]);
+        assert_eq!(run(&args), ExitCode::from(exit_code::ERROR));
+    }
+
+    #[test]
+    fn test_info_offenses_do_not_fail_by_default() {
+        let mut diagnostics = reukocyte_checker::check(b"x = 1  \n");
+        let args = Args::parse_from(["reuko"]);
+        assert!(has_failing_offense(&diagnostics, &args));
+        diagnostics[0].severity = Severity::Info;
+        assert!(!has_failing_offense(&diagnostics, &args));
+    }
+
+    #[test]
+    fn test_json_output_to_file() {
+        let file = write_temp_file("out-json", b"x = 1  \n");
+        let out = std::env::temp_dir().join(format!("reuko-{}-out.json", std::process::id()));
+        let args = Args::parse_from([
+            "reuko".as_ref(),
+            "-f".as_ref(),
+            "json".as_ref(),
+            "-o".as_ref(),
+            out.as_os_str(),
+            file.as_os_str(),
+        ]);
+        let exit_code = run(&args);
+        let written = std::fs::read_to_string(&out).unwrap();
+        std::fs::remove_file(&file).unwrap();
+        std::fs::remove_file(&out).unwrap();
+        assert_eq!(exit_code, ExitCode::from(exit_code::OFFENSES));
+        let json: serde_json::Value = serde_json::from_str(&written).unwrap();
+        assert_eq!(json["summary"]["offense_count"], 1);
+        assert_eq!(json["files"][0]["offenses"][0]["cop_name"], "Layout/TrailingWhitespace");
+    }
+
+    #[test]
+    fn test_check_files_stops_when_interrupted() {
+        let args = Args::parse_from(["reuko", "--format", "quiet"]);
+        let files = vec![write_temp_file("interrupt", b"x = 1  \n")];
+        // Simulate Ctrl-C arriving before the file is checked.
+        let results = check_files(&files, &args, &Config::default(), &AtomicBool::new(true), &mut formatter::QuietFormatter);
+        std::fs::remove_file(&files[0]).unwrap();
+        assert!(results.interrupted);
+        assert!(results.file_results.is_empty());
+        assert_eq!(results.total_remaining, 0);
+    }
+
+    /// Records what a run passes to its formatter.
+    #[derive(Default)]
+    struct CapturingFormatter {
+        started: bool,
+        files: Vec<(String, Vec<Diagnostic>)>,
+        summary: Option<RunSummary>,
+    }
+
+    impl Formatter for CapturingFormatter {
+        fn started(&mut self) -> std::io::Result<()> {
+            self.started = true;
+            Ok(())
         }
-        _ => {
-            if total_fixed > 0 {
-                eprintln!();
-                eprintln!("{} offense(s) corrected", total_fixed);
-            }
 
-            eprintln!();
-            if total_diagnostics > 0 {
-                eprintln!("{} file(s) inspected, {} offense(s) detected", file_count, total_diagnostics);
-            } else {
-                eprintln!("{} file(s) inspected, no offenses detected", file_count);
-            }
+        fn file_finished(&mut self, path: &str, diagnostics: &[Diagnostic]) -> std::io::Result<()> {
+            self.files.push((path.to_string(), diagnostics.to_vec()));
+            Ok(())
+        }
+
+        fn finished(&mut self, summary: &RunSummary) -> std::io::Result<()> {
+            self.summary = Some(*summary);
+            Ok(())
         }
     }
+
+    #[test]
+    fn test_run_with_custom_formatter() {
+        let file = write_temp_file("formatter", b"x = 1  \n");
+        let args = Args::parse_from(["reuko".as_ref(), file.as_os_str()]);
+        let mut formatter = CapturingFormatter::default();
+        let exit_code = run_with_formatter(&args, &Config::default(), &mut formatter);
+        std::fs::remove_file(&file).unwrap();
+        assert_eq!(exit_code, ExitCode::from(exit_code::OFFENSES));
+        assert!(formatter.started);
+        assert_eq!(formatter.files.len(), 1);
+        assert_eq!(formatter.files[0].0, file.to_string_lossy());
+        assert_eq!(formatter.files[0].1[0].rule(), "Layout/TrailingWhitespace");
+        assert_eq!(
+            formatter.summary,
+            Some(RunSummary {
+                inspected_files: 1,
+                offense_count: 1,
+                corrected_count: 0,
+                skipped_files: 0,
+            })
+        );
+    }
+
+    #[test]
+    fn test_config_dump_shows_loaded_options() {
+        let config = std::env::temp_dir().join(format!("reuko-{}-config-dump.yml", std::process::id()));
+        std::fs::write(&config, "Layout/IndentationWidth:\n  Width: 4\n").unwrap();
+        let args = Args::parse_from(["reuko".as_ref(), "--config-dump".as_ref(), "-c".as_ref(), config.as_os_str()]);
+        let dumped = load_config(&args).to_yaml();
+        std::fs::remove_file(&config).unwrap();
+        assert!(args.config_dump);
+        let yaml: serde_yaml::Value = serde_yaml::from_str(&dumped).unwrap();
+        assert_eq!(yaml["Layout/IndentationWidth"]["Width"], 4);
+        assert_eq!(yaml["Layout/TrailingWhitespace"]["Enabled"], true);
+    }
+
+    #[test]
+    fn test_force_default_config_ignores_project_config() {
+        let dir = std::env::temp_dir().join(format!("reuko-{}-force-default-config", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        std::fs::write(dir.join(".rubocop.yml"), "Layout/TrailingWhitespace:\n  Enabled: false\n").unwrap();
+        let source = b"x = 1  \n";
+        let fires = |config: &Config| {
+            reukocyte_checker::check_with_config(source, config)
+                .iter()
+                .any(|d| d.rule() == "Layout/TrailingWhitespace")
+        };
+
+        let args = Args::parse_from(["reuko"]);
+        assert!(!fires(&load_config_in(&args, &dir)));
+        let args = Args::parse_from(["reuko", "--force-default-config"]);
+        assert!(fires(&load_config_in(&args, &dir)));
+        // It also wins over --config.
+        let config = dir.join(".rubocop.yml");
+        let args = Args::parse_from(["reuko".as_ref(), "--force-default-config".as_ref(), "-c".as_ref(), config.as_os_str()]);
+        assert!(fires(&load_config_in(&args, &dir)));
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn test_max_file_size_skips_large_files() {
+        let dir = std::env::temp_dir().join(format!("reuko-{}-max-file-size", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        std::fs::write(dir.join("large.rb"), "x = 1  \n".repeat(10)).unwrap();
+        std::fs::write(dir.join("small.rb"), "x = 1  \n").unwrap();
+        let yaml = reukocyte_checker::parse_rubocop_yaml("AllCops:\n  MaximumFileSize: 20\n").unwrap();
+        let config = Config::from_rubocop_yaml(&yaml);
+        let args = Args::parse_from(["reuko".as_ref(), dir.as_os_str()]);
+        let mut formatter = CapturingFormatter::default();
+        run_with_formatter(&args, &config, &mut formatter);
+
+        assert_eq!(formatter.files.len(), 1);
+        assert!(formatter.files[0].0.ends_with("small.rb"));
+        let summary = formatter.summary.unwrap();
+        assert_eq!((summary.inspected_files, summary.skipped_files, summary.offense_count), (1, 1, 1));
+
+        // The flag overrides the config.
+        let args = Args::parse_from(["reuko".as_ref(), "--max-file-size".as_ref(), "1000".as_ref(), dir.as_os_str()]);
+        let mut formatter = CapturingFormatter::default();
+        run_with_formatter(&args, &config, &mut formatter);
+        std::fs::remove_dir_all(&dir).unwrap();
+        assert_eq!(formatter.summary.unwrap().skipped_files, 0);
+        assert_eq!(formatter.files.len(), 2);
+    }
+
+    #[test]
+    fn test_max_offenses_stops_early() {
+        let dir = std::env::temp_dir().join(format!("reuko-{}-max-offenses", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        // Ten offenses across two files
+        std::fs::write(dir.join("a.rb"), "a = 1  \nb = 2  \nc = 3  \nd = 4  \ne = 5  \n").unwrap();
+        std::fs::write(dir.join("b.rb"), "a = 1  \nb = 2  \nc = 3  \nd = 4  \ne = 5  \n").unwrap();
+        let args = Args::parse_from(["reuko".as_ref(), "--max-offenses".as_ref(), "3".as_ref(), dir.as_os_str()]);
+        let mut formatter = CapturingFormatter::default();
+        let exit_code = run_with_formatter(&args, &Config::default(), &mut formatter);
+        std::fs::remove_dir_all(&dir).unwrap();
+
+        assert_eq!(exit_code, ExitCode::from(exit_code::OFFENSES));
+        assert_eq!(formatter.files.len(), 1);
+        assert_eq!(formatter.files[0].1.len(), 3);
+        assert_eq!(formatter.summary.map(|summary| (summary.inspected_files, summary.offense_count)), Some((1, 3)));
+    }
+
+    #[test]
+    fn test_max_offenses_respects_fail_level() {
+        let path = write_temp_file("max-offenses-fail-level", b"x = 1  \n");
+        let args = Args::parse_from([
+            "reuko".as_ref(),
+            "--max-offenses".as_ref(),
+            "1".as_ref(),
+            "--fail-level".as_ref(),
+            "error".as_ref(),
+            path.as_os_str(),
+        ]);
+        let exit_code = run_with_formatter(&args, &Config::default(), &mut CapturingFormatter::default());
+        std::fs::remove_file(&path).unwrap();
+        assert_eq!(exit_code, ExitCode::from(exit_code::SUCCESS));
+    }
+
+    #[test]
+    fn test_stdin_exit_code_respects_fail_level() {
+        let filename = std::path::Path::new("stdin.rb");
+        let exit_code = |flags: &[&str]| {
+            let args = Args::parse_from(["reuko", "--format", "quiet", "--stdin", "stdin.rb"].iter().chain(flags));
+            check_stdin_source(&args, filename, b"x = 1  \n")
+        };
+        assert_eq!(exit_code(&["--fail-level", "error"]), ExitCode::from(exit_code::SUCCESS));
+        assert_eq!(exit_code(&[]), ExitCode::from(exit_code::OFFENSES));
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_autocorrect_keeps_file_permissions() {
+        use std::os::unix::fs::PermissionsExt;
+        let path = write_temp_file("autocorrect-mode", b"x = 1  \n");
+        std::fs::set_permissions(&path, std::fs::Permissions::from_mode(0o754)).unwrap();
+        let args = Args::parse_from(["reuko", "--format", "quiet", "-a"]);
+        let results = check_files(
+            std::slice::from_ref(&path),
+            &args,
+            &Config::default(),
+            &AtomicBool::new(false),
+            &mut formatter::QuietFormatter,
+        );
+        let contents = std::fs::read(&path).unwrap();
+        let mode = std::fs::metadata(&path).unwrap().permissions().mode();
+        std::fs::remove_file(&path).unwrap();
+        assert_eq!(results.total_remaining, 0);
+        assert_eq!(contents, b"x = 1\n");
+        assert_eq!(mode & 0o777, 0o754);
+    }
+
+    #[test]
+    fn test_autocorrect_all_elsif_assignment_chain_is_stable() {
+        let source = b"if cond\n  result = 1\nelsif other\n  result = 2\nelse\n  result = 3\nend\n";
+        let path = write_temp_file("elsif-chain", source);
+        let args = Args::parse_from(["reuko", "--format", "quiet", "-A"]);
+        let correct = || {
+            let results = check_files(
+                std::slice::from_ref(&path),
+                &args,
+                &Config::default(),
+                &AtomicBool::new(false),
+                &mut formatter::QuietFormatter,
+            );
+            (results.total_remaining, std::fs::read(&path).unwrap())
+        };
+        let first = correct();
+        let second = correct();
+        std::fs::remove_file(&path).unwrap();
+        assert_eq!(first, (0, b"result = if cond\n  1\nelsif other\n  2\nelse\n  3\n         end\n".to_vec()));
+        assert_eq!(second, first);
+    }
+
+    #[test]
+    fn test_disable_uncorrectable_after_a_comment() {
+        let path = write_temp_file("disable-commented", b"binding.pry # debug\n");
+        let args = Args::parse_from(["reuko", "--format", "quiet", "-a", "--disable-uncorrectable"]);
+        let run = || {
+            let results = check_files(
+                std::slice::from_ref(&path),
+                &args,
+                &Config::default(),
+                &AtomicBool::new(false),
+                &mut formatter::QuietFormatter,
+            );
+            (results.corrected_results.values().map(Vec::len).sum::<usize>(), results.total_remaining)
+        };
+        let first = run();
+        let second = run();
+        let contents = std::fs::read(&path).unwrap();
+        std::fs::remove_file(&path).unwrap();
+        assert_eq!(first, (1, 0));
+        assert_eq!(second, (0, 0));
+        assert_eq!(contents, b"binding.pry # debug # rubocop:disable Lint/Debugger\n");
+    }
+
+    #[test]
+    fn test_only_recognized_file_types() {
+        let path = std::env::temp_dir().join(format!("reuko-{}-notes.txt", std::process::id()));
+        std::fs::write(&path, "x = 1  \n").unwrap();
+        let run = |flags: &[&str]| {
+            let args = Args::parse_from(["reuko".as_ref(), path.as_os_str()].into_iter().chain(flags.iter().map(|flag| flag.as_ref())));
+            let mut formatter = CapturingFormatter::default();
+            (run_with_formatter(&args, &Config::default(), &mut formatter), formatter.files.len())
+        };
+        let strict = run(&["--only-recognized-file-types"]);
+        let lenient = run(&[]);
+        std::fs::remove_file(&path).unwrap();
+        assert_eq!(strict, (ExitCode::from(exit_code::ERROR), 0));
+        assert_eq!(lenient, (ExitCode::from(exit_code::SUCCESS), 0));
+    }
 }
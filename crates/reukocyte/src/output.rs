@@ -1,6 +1,8 @@
 use reukocyte_checker::Diagnostic;
+use reukocyte_checker::RuleId;
 use rustc_hash::FxHashMap;
 use serde::Serialize;
+use std::io::Write;
 
 /// RuboCop-compatible JSON output format.
 #[derive(Debug, Serialize)]
@@ -47,9 +49,35 @@ pub struct Offense {
     pub cop_name: String,
     pub corrected: bool,
     pub correctable: bool,
+    /// What the fix does; omitted for offenses without one.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub fix_description: Option<&'static str>,
     pub location: Location,
 }
 
+impl Offense {
+    /// Create an offense entry from a diagnostic.
+    fn new(diagnostic: &Diagnostic, corrected: bool) -> Self {
+        Self {
+            severity: diagnostic.severity.as_str().to_string(),
+            message: diagnostic.message.clone(),
+            cop_name: diagnostic.rule().to_string(),
+            corrected,
+            correctable: diagnostic.fix.is_some(),
+            fix_description: diagnostic.fix_description,
+            location: Location {
+                start_line: diagnostic.line_start,
+                start_column: diagnostic.column_start,
+                last_line: diagnostic.line_end,
+                last_column: diagnostic.column_end,
+                length: diagnostic.end.saturating_sub(diagnostic.start),
+                line: diagnostic.line_start,
+                column: diagnostic.column_start,
+            },
+        }
+    }
+}
+
 /// Location of an offense.
 #[derive(Debug, Serialize)]
 pub struct Location {
@@ -70,34 +98,30 @@ pub struct Summary {
     pub inspected_file_count: usize,
 }
 
+/// Sort offenses by position, with ties broken by cop name.
+fn sort_offenses(offenses: &mut [Offense]) {
+    offenses.sort_by(|a, b| (a.location.start_line, a.location.start_column, &a.cop_name).cmp(&(b.location.start_line, b.location.start_column, &b.cop_name)));
+}
+
 impl JsonOutput {
     /// Create a new JSON output from inspection results.
-    pub fn new(file_results: FxHashMap<String, Vec<Diagnostic>>, corrected_counts: FxHashMap<String, usize>) -> Self {
+    ///
+    /// Offenses fixed during this run are listed with `corrected: true`
+    /// alongside the offenses that remain. Files are sorted by path and
+    /// offenses by line, column and cop name, so the output is stable.
+    pub fn new(file_results: FxHashMap<String, Vec<Diagnostic>>, corrected_results: FxHashMap<String, Vec<Diagnostic>>) -> Self {
         let mut total_offenses = 0;
         let mut files = Vec::new();
         for (path, diagnostics) in &file_results {
-            let corrected_count = corrected_counts.get(path).copied().unwrap_or(0);
-            let offenses: Vec<Offense> = diagnostics
+            let corrected = corrected_results.get(path).map(Vec::as_slice).unwrap_or_default();
+            let mut offenses: Vec<Offense> = corrected
                 .iter()
-                .map(|d| Offense {
-                    severity: d.severity.as_str().to_string(),
-                    message: d.message.clone(),
-                    cop_name: d.rule().to_string(),
-                    corrected: false, // Already remaining offenses
-                    correctable: d.fix.is_some(),
-                    location: Location {
-                        start_line: d.line_start,
-                        start_column: d.column_start,
-                        last_line: d.line_end,
-                        last_column: d.column_end,
-                        length: d.end.saturating_sub(d.start),
-                        line: d.line_start,
-                        column: d.column_start,
-                    },
-                })
+                .map(|d| Offense::new(d, true))
+                .chain(diagnostics.iter().map(|d| Offense::new(d, false)))
                 .collect();
+            sort_offenses(&mut offenses);
 
-            total_offenses += offenses.len() + corrected_count;
+            total_offenses += offenses.len();
 
             files.push(FileOffenses { path: path.clone(), offenses });
         }
@@ -120,6 +144,131 @@ impl JsonOutput {
     pub fn to_json(&self) -> String {
         serde_json::to_string(self).unwrap_or_else(|_| "{}".to_string())
     }
+
+    /// Write the JSON followed by a newline.
+    pub fn write_to(&self, out: &mut dyn Write) -> std::io::Result<()> {
+        writeln!(out, "{}", self.to_json())
+    }
+}
+
+/// JUnit XML output format.
+///
+/// Each inspected file is a `<testcase>`, and each offense in it is a `<failure>`.
+#[derive(Debug)]
+pub struct JunitOutput {
+    pub files: Vec<FileOffenses>,
+}
+
+impl JunitOutput {
+    /// Create a new JUnit output from inspection results.
+    pub fn new(file_results: &FxHashMap<String, Vec<Diagnostic>>) -> Self {
+        let mut files: Vec<FileOffenses> = file_results
+            .iter()
+            .map(|(path, diagnostics)| {
+                let mut offenses: Vec<Offense> = diagnostics.iter().map(|d| Offense::new(d, false)).collect();
+                sort_offenses(&mut offenses);
+                FileOffenses { path: path.clone(), offenses }
+            })
+            .collect();
+
+        // Sort files by path for deterministic output
+        files.sort_by(|a, b| a.path.cmp(&b.path));
+
+        Self { files }
+    }
+
+    /// Serialize to a JUnit XML document.
+    pub fn to_xml(&self) -> String {
+        let failure_count: usize = self.files.iter().map(|file| file.offenses.len()).sum();
+        let mut xml = String::from("<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n<testsuites>\n");
+        xml.push_str(&format!(
+            "  <testsuite name=\"reukocyte\" tests=\"{}\" failures=\"{}\">\n",
+            self.files.len(),
+            failure_count
+        ));
+        for file in &self.files {
+            let path = escape_xml(&file.path);
+            if file.offenses.is_empty() {
+                xml.push_str(&format!("    <testcase classname=\"{}\" name=\"{}\"/>\n", path, path));
+                continue;
+            }
+            xml.push_str(&format!("    <testcase classname=\"{}\" name=\"{}\">\n", path, path));
+            for offense in &file.offenses {
+                xml.push_str(&format!(
+                    "      <failure type=\"{}\" message=\"{}\">{}:{}:{}</failure>\n",
+                    escape_xml(&offense.cop_name),
+                    escape_xml(&offense.message),
+                    path,
+                    offense.location.line,
+                    offense.location.column
+                ));
+            }
+            xml.push_str("    </testcase>\n");
+        }
+        xml.push_str("  </testsuite>\n</testsuites>");
+        xml
+    }
+}
+
+/// Offense counts grouped by cop (RuboCop's `offenses` formatter).
+#[derive(Debug)]
+pub struct OffensesOutput {
+    /// Offense count of each cop, most frequent first and ties sorted by cop name.
+    pub counts: Vec<(RuleId, usize)>,
+    /// Number of files with at least one offense.
+    pub offending_files: usize,
+}
+
+impl OffensesOutput {
+    /// Create a new offense count table from inspection results.
+    pub fn new(file_results: &FxHashMap<String, Vec<Diagnostic>>) -> Self {
+        let mut counts: FxHashMap<RuleId, usize> = FxHashMap::default();
+        for diagnostic in file_results.values().flatten() {
+            *counts.entry(diagnostic.rule_id).or_default() += 1;
+        }
+        let mut counts: Vec<(RuleId, usize)> = counts.into_iter().collect();
+        counts.sort_by(|(a_rule, a_count), (b_rule, b_count)| b_count.cmp(a_count).then_with(|| a_rule.to_string().cmp(&b_rule.to_string())));
+        let offending_files = file_results.values().filter(|diagnostics| !diagnostics.is_empty()).count();
+        Self { counts, offending_files }
+    }
+
+    /// Render the table: one `count  Cop/Name` line per cop, then the total.
+    pub fn to_table(&self) -> String {
+        let total: usize = self.counts.iter().map(|(_, count)| count).sum();
+        // Counts are left-aligned in a column as wide as the total.
+        let width = total.to_string().len() + 2;
+        let mut table = String::new();
+        for (rule, count) in &self.counts {
+            table.push_str(&format!("{:<width$}{}\n", count, rule));
+        }
+        table.push_str("--\n");
+        table.push_str(&format!("{:<width$}Total in {} files\n", total, self.offending_files));
+        table
+    }
+}
+
+/// Escape XML special characters for use in text and attribute values.
+///
+/// Tabs and line breaks become character references, so attribute values keep
+/// them, and the other control characters, which XML 1.0 does not allow at all,
+/// are replaced with U+FFFD.
+fn escape_xml(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for c in text.chars() {
+        match c {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' => escaped.push_str("&quot;"),
+            '\'' => escaped.push_str("&apos;"),
+            '\t' => escaped.push_str("&#9;"),
+            '\n' => escaped.push_str("&#10;"),
+            '\r' => escaped.push_str("&#13;"),
+            '\u{0}'..='\u{1f}' | '\u{fffe}' | '\u{ffff}' => escaped.push(char::REPLACEMENT_CHARACTER),
+            _ => escaped.push(c),
+        }
+    }
+    escaped
 }
 
 #[cfg(test)]
@@ -132,4 +281,139 @@ mod tests {
         assert_eq!(output.summary.offense_count, 0);
         assert!(output.files.is_empty());
     }
+
+    #[test]
+    fn test_json_output_ordering() {
+        let mut file_results = FxHashMap::default();
+        for path in ["lib/b.rb", "a.rb", "lib/a.rb", "B.rb", "z.rb"] {
+            file_results.insert(path.to_string(), reukocyte_checker::check(b"x = 1  \n"));
+        }
+        // Offenses passed in out of order.
+        let mut diagnostics = reukocyte_checker::check(b"debugger  \nbinding.pry\n");
+        diagnostics.reverse();
+        file_results.insert("m.rb".to_string(), diagnostics);
+
+        let output = JsonOutput::new(file_results, FxHashMap::default());
+        let paths: Vec<&str> = output.files.iter().map(|file| file.path.as_str()).collect();
+        assert_eq!(paths, ["B.rb", "a.rb", "lib/a.rb", "lib/b.rb", "m.rb", "z.rb"]);
+        let json: serde_json::Value = serde_json::from_str(&output.to_json()).unwrap();
+        assert_eq!(json["files"][4]["path"], "m.rb");
+        let positions: Vec<(usize, usize, &str)> = output.files[4]
+            .offenses
+            .iter()
+            .map(|offense| (offense.location.start_line, offense.location.start_column, offense.cop_name.as_str()))
+            .collect();
+        let mut sorted = positions.clone();
+        sorted.sort();
+        assert_eq!(positions, sorted);
+        assert_eq!(positions[0].2, "Lint/Debugger");
+    }
+
+    #[test]
+    fn test_json_fix_description() {
+        let mut file_results = FxHashMap::default();
+        file_results.insert("a.rb".to_string(), reukocyte_checker::check(b"debugger  \n"));
+        let json: serde_json::Value = serde_json::from_str(&JsonOutput::new(file_results, FxHashMap::default()).to_json()).unwrap();
+        let offenses = &json["files"][0]["offenses"];
+        assert_eq!(offenses[0]["cop_name"], "Lint/Debugger");
+        assert_eq!(offenses[0]["correctable"], false);
+        assert!(offenses[0].get("fix_description").is_none());
+        assert_eq!(offenses[1]["correctable"], true);
+        assert_eq!(offenses[1]["fix_description"], "remove trailing whitespace");
+    }
+
+    #[test]
+    fn test_json_output_corrected_flag() {
+        let source = b"def foo  \n  binding.pry\nend\n";
+        let diagnostics = reukocyte_checker::check(source);
+        let (fixed, corrected) = reukocyte_checker::apply_fixes_filtered_with_corrected(
+            None,
+            source,
+            &diagnostics,
+            &reukocyte_checker::Config::default(),
+            false,
+            reukocyte_checker::FixMode::Merged,
+            |_| true,
+        );
+        let remaining = reukocyte_checker::check(&fixed);
+
+        let mut file_results = FxHashMap::default();
+        file_results.insert("a.rb".to_string(), remaining);
+        let mut corrected_results = FxHashMap::default();
+        corrected_results.insert("a.rb".to_string(), corrected);
+        let output = JsonOutput::new(file_results, corrected_results);
+
+        assert_eq!(output.summary.offense_count, 2);
+        let offenses = &output.files[0].offenses;
+        assert_eq!(offenses.len(), 2);
+        assert_eq!(offenses[0].cop_name, "Layout/TrailingWhitespace");
+        assert!(offenses[0].corrected);
+        assert_eq!(offenses[1].cop_name, "Lint/Debugger");
+        assert!(!offenses[1].corrected);
+        assert!(output.to_json().contains("\"corrected\":true"));
+    }
+
+    #[test]
+    fn test_junit_output_document() {
+        let mut file_results = FxHashMap::default();
+        file_results.insert("a.rb".to_string(), reukocyte_checker::check(b"binding.pry  \ndebugger\n"));
+        file_results.insert("b&c.rb".to_string(), reukocyte_checker::check(b"x = 1  \n"));
+        file_results.insert("clean.rb".to_string(), Vec::new());
+
+        let xml = JunitOutput::new(&file_results).to_xml();
+        let expected = concat!(
+            "<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n",
+            "<testsuites>\n",
+            "  <testsuite name=\"reukocyte\" tests=\"3\" failures=\"4\">\n",
+            "    <testcase classname=\"a.rb\" name=\"a.rb\">\n",
+            "      <failure type=\"Lint/Debugger\" message=\"Debugger statement `binding.pry` detected.\">a.rb:1:1</failure>\n",
+            "      <failure type=\"Layout/TrailingWhitespace\" message=\"Trailing whitespace detected.\">a.rb:1:12</failure>\n",
+            "      <failure type=\"Lint/Debugger\" message=\"Debugger statement `debugger` detected.\">a.rb:2:1</failure>\n",
+            "    </testcase>\n",
+            "    <testcase classname=\"b&amp;c.rb\" name=\"b&amp;c.rb\">\n",
+            "      <failure type=\"Layout/TrailingWhitespace\" message=\"Trailing whitespace detected.\">b&amp;c.rb:1:6</failure>\n",
+            "    </testcase>\n",
+            "    <testcase classname=\"clean.rb\" name=\"clean.rb\"/>\n",
+            "  </testsuite>\n",
+            "</testsuites>",
+        );
+        assert_eq!(xml, expected);
+    }
+
+    #[test]
+    fn test_junit_output_control_characters() {
+        let mut diagnostics = reukocyte_checker::check(b"x = 1  \n");
+        diagnostics[0].message = "bad\u{1b}[0m\tbyte\u{0}\nnext".to_string();
+        let mut file_results = FxHashMap::default();
+        file_results.insert("a.rb".to_string(), diagnostics);
+        let xml = JunitOutput::new(&file_results).to_xml();
+        assert!(xml.contains("message=\"bad\u{fffd}[0m&#9;byte\u{fffd}&#10;next\""));
+        assert!(!xml.chars().any(|c| c.is_control() && c != '\n'));
+    }
+
+    #[test]
+    fn test_offenses_output_sorted_by_count() {
+        let mut file_results = FxHashMap::default();
+        file_results.insert("a.rb".to_string(), reukocyte_checker::check(b"x = 1  \ny = 2  \n"));
+        file_results.insert("b.rb".to_string(), reukocyte_checker::check(b"binding.pry  \n"));
+        file_results.insert("clean.rb".to_string(), Vec::new());
+
+        let output = OffensesOutput::new(&file_results);
+        assert_eq!(output.offending_files, 2);
+        assert_eq!(output.to_table(), "3  Layout/TrailingWhitespace\n1  Lint/Debugger\n--\n4  Total in 2 files\n");
+    }
+
+    #[test]
+    fn test_offenses_output_ties_sorted_by_name() {
+        let mut file_results = FxHashMap::default();
+        file_results.insert("a.rb".to_string(), reukocyte_checker::check(b"debugger  \n"));
+        let table = OffensesOutput::new(&file_results).to_table();
+        assert_eq!(table, "1  Layout/TrailingWhitespace\n1  Lint/Debugger\n--\n2  Total in 1 files\n");
+    }
+
+    #[test]
+    fn test_escape_xml() {
+        assert_eq!(escape_xml("Use `a <=> b` & \"c\" 'd'"), "Use `a &lt;=&gt; b` &amp; &quot;c&quot; &apos;d&apos;");
+        assert_eq!(escape_xml("a\u{7}b\r\n"), "a\u{fffd}b&#13;&#10;");
+    }
 }
@@ -0,0 +1,102 @@
+//! Validation of the rule names given to `--only` and `--except`.
+
+use reukocyte_checker::RuleId;
+
+/// Check that every name matches a known rule, full (`Layout/TrailingWhitespace`)
+/// or short (`TrailingWhitespace`), ignoring case like the offense filters do.
+///
+/// Returns the error message for the unknown names, with a "Did you mean?"
+/// suggestion for names close to a known rule.
+pub fn validate_rule_names<'a>(names: impl IntoIterator<Item = &'a String>) -> Result<(), String> {
+    let mut lines = Vec::new();
+    for name in names {
+        if RuleId::all().iter().any(|rule| matches_rule(rule, name)) {
+            continue;
+        }
+        lines.push(format!("Unrecognized cop: {}", name));
+        if let Some(suggestion) = suggestion(name) {
+            lines.push(format!("Did you mean? {}", suggestion));
+        }
+    }
+    if lines.is_empty() { Ok(()) } else { Err(lines.join("\n")) }
+}
+
+/// Find the rule a name refers to, matched like `validate_rule_names` does.
+pub fn find_rule(name: &str) -> Option<RuleId> {
+    RuleId::all().iter().find(|rule| matches_rule(rule, name)).copied()
+}
+
+/// Check if a name given on the command line refers to the rule.
+fn matches_rule(rule: &RuleId, name: &str) -> bool {
+    rule.to_string().eq_ignore_ascii_case(name) || rule.name().eq_ignore_ascii_case(name)
+}
+
+/// Find the known rule closest to a misspelled name, if any is close enough.
+fn suggestion(name: &str) -> Option<String> {
+    let name = name.to_ascii_lowercase();
+    let max_distance = (name.len() / 4).max(2);
+    RuleId::all()
+        .iter()
+        .map(|rule| {
+            // Compare like with like: a short name against the short rule names.
+            let candidate = if name.contains('/') { rule.to_string() } else { rule.name().to_string() };
+            (edit_distance(&name, &candidate.to_ascii_lowercase()), rule)
+        })
+        .filter(|&(distance, _)| distance <= max_distance)
+        .min_by_key(|&(distance, _)| distance)
+        .map(|(_, rule)| rule.to_string())
+}
+
+/// Levenshtein distance between two strings, by bytes.
+fn edit_distance(a: &str, b: &str) -> usize {
+    let mut previous: Vec<usize> = (0..=b.len()).collect();
+    for (i, ca) in a.bytes().enumerate() {
+        let mut current = vec![i + 1];
+        for (j, cb) in b.bytes().enumerate() {
+            let substitution = previous[j] + usize::from(ca != cb);
+            current.push(substitution.min(previous[j + 1] + 1).min(current[j] + 1));
+        }
+        previous = current;
+    }
+    previous[b.len()]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn names(names: &[&str]) -> Vec<String> {
+        names.iter().map(|name| name.to_string()).collect()
+    }
+
+    #[test]
+    fn test_known_names() {
+        assert!(validate_rule_names(&names(&["Layout/TrailingWhitespace", "lint/debugger", "TRAILINGWHITESPACE"])).is_ok());
+    }
+
+    #[test]
+    fn test_unknown_names_with_suggestions() {
+        let message = validate_rule_names(&names(&["Layuot/TrailingWhitespace", "Debuger", "Lint/Nonsense"])).unwrap_err();
+        assert_eq!(
+            message,
+            "Unrecognized cop: Layuot/TrailingWhitespace\n\
+             Did you mean? Layout/TrailingWhitespace\n\
+             Unrecognized cop: Debuger\n\
+             Did you mean? Lint/Debugger\n\
+             Unrecognized cop: Lint/Nonsense"
+        );
+    }
+
+    #[test]
+    fn test_find_rule() {
+        assert_eq!(find_rule("lint/debugger"), Some(RuleId::Lint(reukocyte_checker::LintRule::Debugger)));
+        assert_eq!(find_rule("Debuger"), None);
+    }
+
+    #[test]
+    fn test_edit_distance() {
+        assert_eq!(edit_distance("layuot", "layout"), 2);
+        assert_eq!(edit_distance("", "abc"), 3);
+        assert_eq!(edit_distance("debugger", "debugger"), 0);
+    }
+}
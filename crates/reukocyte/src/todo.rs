@@ -0,0 +1,104 @@
+//! `--auto-gen-config`: turn the current offenses into a TODO configuration.
+//!
+//! Each offending cop gets an `Exclude` list of the files it flagged, or
+//! `Enabled: false` when more files than `--exclude-limit` are affected, so a
+//! codebase can adopt the linter and fix the offenses one cop at a time.
+
+use reukocyte_checker::Diagnostic;
+use rustc_hash::FxHashMap;
+use serde_yaml::{Mapping, Value};
+use std::collections::{BTreeMap, BTreeSet};
+
+/// File written by `--auto-gen-config` (RuboCop compatible).
+pub const TODO_FILE: &str = ".rubocop_todo.yml";
+
+const HEADER: &str = "\
+# This configuration was generated by
+# `reuko --auto-gen-config`
+# The point is for the user to remove these configuration records
+# one by one as the offenses are removed from the code base.
+";
+
+/// Generate the TODO configuration for the given offenses, with cops sorted by name.
+pub fn generate_todo(file_results: &FxHashMap<String, Vec<Diagnostic>>, exclude_limit: usize) -> String {
+    // rule name -> (offense count, offending files)
+    let mut offenses: BTreeMap<String, (usize, BTreeSet<&str>)> = BTreeMap::new();
+    for (path, diagnostics) in file_results {
+        for diagnostic in diagnostics {
+            let (count, files) = offenses.entry(diagnostic.rule()).or_default();
+            *count += 1;
+            files.insert(path);
+        }
+    }
+
+    let mut todo = String::from(HEADER);
+    for (rule, (count, files)) in offenses {
+        let mut cop = Mapping::new();
+        if files.len() > exclude_limit {
+            cop.insert(Value::from("Enabled"), Value::from(false));
+        } else {
+            let exclude = files.into_iter().map(Value::from).collect::<Vec<_>>();
+            cop.insert(Value::from("Exclude"), Value::Sequence(exclude));
+        }
+        let entry = Mapping::from_iter([(Value::from(rule), Value::Mapping(cop))]);
+        todo.push_str(&format!("\n# Offense count: {}\n", count));
+        todo.push_str(&serde_yaml::to_string(&entry).expect("a mapping of strings always serializes"));
+    }
+    todo
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::args::Args;
+    use crate::files::collect_ruby_files;
+    use clap::Parser;
+    use reukocyte_checker::Config;
+    use std::sync::atomic::AtomicBool;
+
+    /// Check a temp tree with two trailing-whitespace offenses in `a.rb` and a debugger call in `b.rb`.
+    fn check_temp_tree(name: &str) -> FxHashMap<String, Vec<Diagnostic>> {
+        let dir = std::env::temp_dir().join(format!("reuko-todo-{}-{}", std::process::id(), name));
+        std::fs::create_dir_all(&dir).unwrap();
+        std::fs::write(dir.join("a.rb"), b"x = 1  \ny = 2  \n").unwrap();
+        std::fs::write(dir.join("b.rb"), b"binding.pry\n").unwrap();
+        let args = Args::parse_from(["reuko", "--format", "quiet"]);
+        let files = collect_ruby_files(std::slice::from_ref(&dir), &[]);
+        let results = crate::check_files(
+            &files,
+            &args,
+            &Config::default(),
+            &AtomicBool::new(false),
+            &mut crate::formatter::QuietFormatter,
+        );
+        std::fs::remove_dir_all(&dir).unwrap();
+        results.file_results
+    }
+
+    #[test]
+    fn test_generate_todo_with_exclude_lists() {
+        let file_results = check_temp_tree("exclude");
+        let todo = generate_todo(&file_results, 15);
+        assert!(todo.starts_with(HEADER));
+        assert!(todo.contains("# Offense count: 2\nLayout/TrailingWhitespace:\n"));
+        assert!(todo.contains("# Offense count: 1\nLint/Debugger:\n"));
+
+        let yaml: Value = serde_yaml::from_str(&todo).unwrap();
+        let exclude = yaml["Layout/TrailingWhitespace"]["Exclude"].as_sequence().unwrap();
+        assert_eq!(exclude.len(), 1);
+        assert!(exclude[0].as_str().unwrap().ends_with("a.rb"));
+        let exclude = yaml["Lint/Debugger"]["Exclude"].as_sequence().unwrap();
+        assert!(exclude[0].as_str().unwrap().ends_with("b.rb"));
+        // The generated file must load as a regular config.
+        assert!(reukocyte_checker::parse_rubocop_yaml(&todo).is_ok());
+    }
+
+    #[test]
+    fn test_generate_todo_disables_cops_over_limit() {
+        let file_results = check_temp_tree("limit");
+        let yaml: Value = serde_yaml::from_str(&generate_todo(&file_results, 0)).unwrap();
+        assert_eq!(yaml["Layout/TrailingWhitespace"]["Enabled"], Value::from(false));
+        assert_eq!(yaml["Lint/Debugger"]["Enabled"], Value::from(false));
+        assert!(yaml["Lint/Debugger"].get("Exclude").is_none());
+    }
+}
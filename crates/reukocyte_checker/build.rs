@@ -180,8 +180,15 @@ fn main() {
     // Generate the rule registry
     generate_registry(&out_dir, &rule_impls);
 
-    // Tell Cargo to rerun if any rule file changes
+    // Generate the rule documentation shown by `--explain` and the list of all rules
+    let config_dir = PathBuf::from(&manifest_dir).join("src/config");
+    let docs = scan_rule_docs(&rules_dir, &config_dir);
+    generate_rule_docs(&out_dir, &docs);
+    generate_rule_list(&out_dir, &docs);
+
+    // Tell Cargo to rerun if any rule or config file changes
     println!("cargo:rerun-if-changed=src/rules");
+    println!("cargo:rerun-if-changed=src/config");
 }
 
 /// Scans all .rs files under the rules directory for `#[check(NodeType)]` attributes.
@@ -297,6 +304,20 @@ fn generate_registry(out_dir: &str, rule_impls: &HashMap<String, Vec<RuleInfo>>)
     writeln!(file, "}}").unwrap();
     writeln!(file).unwrap();
 
+    // Generate the config accessors of every AST-based rule, so callers can tell
+    // whether the AST traversal has anything to run
+    let mut config_paths: Vec<String> = rule_impls.values().flatten().map(RuleInfo::config_path).collect();
+    config_paths.sort();
+    config_paths.dedup();
+    writeln!(file, "/// The categories and configs of all AST-based rules.").unwrap();
+    writeln!(file, "const __REUKO_AST_RULE_CONFIGS: &[(crate::rule::Category, BaseConfigAccessor)] = &[").unwrap();
+    for config_path in &config_paths {
+        let category = to_pascal_case(config_path.split('.').next().unwrap());
+        writeln!(file, "    (crate::rule::Category::{}, |config| &config.{}.base),", category, config_path).unwrap();
+    }
+    writeln!(file, "];").unwrap();
+    writeln!(file).unwrap();
+
     // Generate a macro for ALL node types (including those without rules)
     for type_path in ALL_NODE_TYPES {
         let name = node_name(type_path);
@@ -311,16 +332,28 @@ fn generate_registry(out_dir: &str, rule_impls: &HashMap<String, Vec<RuleInfo>>)
             for rule in rules {
                 let full_path = format!("crate::rules::{}::{}", rule.module, rule.name);
                 let config_path = rule.config_path();
-                // Generate enabled check and include/exclude check before calling the rule
+                // Generate enabled, category and include/exclude checks before calling the rule
                 writeln!(file, "        {{").unwrap();
                 writeln!(file, "            let cfg = &$checker.config().{};", config_path).unwrap();
-                writeln!(file, "            if cfg.base.enabled && $checker.should_run_cop(&cfg.base.include, &cfg.base.exclude) {{").unwrap();
                 writeln!(
                     file,
-                    "                <{} as crate::rule::Check<{}<'_>>>::check($node, $checker);",
+                    "            if cfg.base.enabled && $checker.runs_category(<{} as crate::rule::Rule>::ID.category()) && $checker.should_run_cop(&cfg.base) {{",
+                    full_path
+                )
+                .unwrap();
+                writeln!(
+                    file,
+                    "                crate::profile::timed($checker, <{} as crate::rule::Rule>::ID, |checker| {{",
+                    full_path
+                )
+                .unwrap();
+                writeln!(
+                    file,
+                    "                    <{} as crate::rule::Check<{}<'_>>>::check($node, checker)",
                     full_path, type_path
                 )
                 .unwrap();
+                writeln!(file, "                }});").unwrap();
                 writeln!(file, "            }}").unwrap();
                 writeln!(file, "        }}").unwrap();
             }
@@ -333,6 +366,202 @@ fn generate_registry(out_dir: &str, rule_impls: &HashMap<String, Vec<RuleInfo>>)
     }
 }
 
+#[derive(Debug, Clone)]
+struct RuleDocInfo {
+    /// e.g. `Layout`
+    category: String,
+    /// e.g. `TrailingWhitespace`
+    name: String,
+    description: String,
+    example: String,
+    /// `(option name, description)` pairs, e.g. `("EnforcedStyle", "...")`
+    options: Vec<(String, String)>,
+}
+
+/// Extracts the documentation of every rule from its source file and its config file.
+///
+/// The description and example come from the rule's module doc (`//! Layout/Name`)
+/// or from the doc comment of its `pub struct`; the options are the documented
+/// fields of the config struct, besides `base`.
+fn scan_rule_docs(rules_dir: &Path, config_dir: &Path) -> Vec<RuleDocInfo> {
+    let mut docs = Vec::new();
+    for entry in walkdir(rules_dir) {
+        let module = get_module_path(rules_dir, &entry);
+        let Some((category, file)) = module.split_once("::") else {
+            continue;
+        };
+        let Ok(content) = fs::read_to_string(&entry) else {
+            continue;
+        };
+        let category = to_pascal_case(category);
+        let name = to_pascal_case(file);
+        let (description, example) = split_rule_doc(&format!("{}/{}", category, name), &rule_doc_lines(&content, &name));
+        let config_path = config_dir.join(entry.strip_prefix(rules_dir).unwrap());
+        let options = fs::read_to_string(config_path).map(|config| config_options(&config, &name)).unwrap_or_default();
+        docs.push(RuleDocInfo {
+            category,
+            name,
+            description,
+            example,
+            options,
+        });
+    }
+    docs.sort_by(|a, b| (&a.category, &a.name).cmp(&(&b.category, &b.name)));
+    docs
+}
+
+/// The doc comment lines describing a rule: the module doc, or the doc comment of `pub struct <name>;`.
+fn rule_doc_lines(content: &str, name: &str) -> Vec<String> {
+    let module_doc: Vec<String> = content
+        .lines()
+        .map_while(|line| line.strip_prefix("//!"))
+        .map(|line| line.strip_prefix(' ').unwrap_or(line).to_string())
+        .collect();
+    if !module_doc.is_empty() {
+        return module_doc;
+    }
+    let lines: Vec<&str> = content.lines().collect();
+    let struct_line = format!("pub struct {};", name);
+    let Some(index) = lines.iter().position(|line| *line == struct_line) else {
+        return Vec::new();
+    };
+    let mut doc: Vec<String> = lines[..index]
+        .iter()
+        .rev()
+        .skip_while(|line| line.starts_with("#["))
+        .map_while(|line| line.strip_prefix("///"))
+        .map(|line| line.strip_prefix(' ').unwrap_or(line).to_string())
+        .collect();
+    doc.reverse();
+    doc
+}
+
+/// Splits doc lines into the description (without the `Layout/Name rule` title and
+/// headings) and the first Ruby example.
+fn split_rule_doc(full_name: &str, lines: &[String]) -> (String, String) {
+    let fence = lines.iter().position(|line| line.starts_with("```")).unwrap_or(lines.len());
+    let title: String;
+    let mut description: Vec<&str> = lines[..fence].iter().map(String::as_str).filter(|line| !line.starts_with('#')).collect();
+    if let Some(first) = description.first().copied() {
+        if first == full_name || first == format!("{} rule.", full_name) {
+            description.remove(0);
+        } else if let Some(rest) = first.strip_prefix(&format!("{} rule - ", full_name)) {
+            let mut chars = rest.chars();
+            title = chars.next().map(|c| c.to_uppercase().chain(chars).collect()).unwrap_or_default();
+            description[0] = &title;
+        }
+    }
+    let description = description.join("\n").trim().to_string();
+    let example = lines
+        .iter()
+        .skip(fence + 1)
+        .take_while(|line| !line.starts_with("```"))
+        .map(String::as_str)
+        .collect::<Vec<_>>()
+        .join("\n");
+    (description, example)
+}
+
+/// The documented fields of `pub struct <name> {` in a config file, as YAML option names.
+fn config_options(content: &str, name: &str) -> Vec<(String, String)> {
+    let rename_pattern = Regex::new(r#"#\[serde\(rename = "(\w+)"\)\]"#).unwrap();
+    let field_pattern = Regex::new(r"^pub (\w+):").unwrap();
+    let struct_line = format!("pub struct {} {{", name);
+    let mut options = Vec::new();
+    let mut doc = Vec::new();
+    let mut rename = None;
+    for line in content.lines().skip_while(|line| *line != struct_line).skip(1).map(str::trim) {
+        if line == "}" {
+            break;
+        } else if let Some(text) = line.strip_prefix("///") {
+            doc.push(text.trim().to_string());
+        } else if let Some(cap) = rename_pattern.captures(line) {
+            rename = Some(cap[1].to_string());
+        } else if let Some(cap) = field_pattern.captures(line) {
+            if &cap[1] != "base" {
+                options.push((rename.take().unwrap_or_else(|| to_pascal_case(&cap[1])), doc.join(" ")));
+            }
+            doc.clear();
+            rename = None;
+        }
+    }
+    options
+}
+
+/// Generates the rule documentation file.
+fn generate_rule_docs(out_dir: &str, docs: &[RuleDocInfo]) {
+    let dest_path = Path::new(out_dir).join("rule_docs.rs");
+    let mut file = File::create(&dest_path).unwrap();
+
+    writeln!(file, "// Auto-generated by build.rs - DO NOT EDIT").unwrap();
+    writeln!(file).unwrap();
+    writeln!(file, "/// The documentation of every rule, sorted by category and name.").unwrap();
+    writeln!(file, "const __REUKO_RULE_DOCS: &[(RuleId, RuleDoc)] = &[").unwrap();
+    for doc in docs {
+        writeln!(file, "    (").unwrap();
+        writeln!(file, "        RuleId::{}({}Rule::{}),", doc.category, doc.category, doc.name).unwrap();
+        writeln!(file, "        RuleDoc {{").unwrap();
+        writeln!(file, "            description: {:?},", doc.description).unwrap();
+        writeln!(file, "            example: {:?},", doc.example).unwrap();
+        writeln!(file, "            options: &[").unwrap();
+        for (name, description) in &doc.options {
+            writeln!(file, "                RuleOption {{ name: {:?}, description: {:?} }},", name, description).unwrap();
+        }
+        writeln!(file, "            ],").unwrap();
+        writeln!(file, "        }},").unwrap();
+        writeln!(file, "    ),").unwrap();
+    }
+    writeln!(file, "];").unwrap();
+}
+
+/// Generates the list of every rule, one per rule file, and the lookup of
+/// each rule's base configuration.
+fn generate_rule_list(out_dir: &str, docs: &[RuleDocInfo]) {
+    let dest_path = Path::new(out_dir).join("rule_list.rs");
+    let mut file = File::create(&dest_path).unwrap();
+
+    writeln!(file, "// Auto-generated by build.rs - DO NOT EDIT").unwrap();
+    writeln!(file).unwrap();
+    writeln!(file, "/// Every known rule, sorted by category and name.").unwrap();
+    writeln!(file, "const __REUKO_ALL_RULES: &[RuleId] = &[").unwrap();
+    for doc in docs {
+        writeln!(file, "    RuleId::{}({}Rule::{}),", doc.category, doc.category, doc.name).unwrap();
+    }
+    writeln!(file, "];").unwrap();
+    writeln!(file).unwrap();
+    writeln!(file, "/// Get the base configuration of `rule` in `config`.").unwrap();
+    writeln!(
+        file,
+        "fn __reuko_rule_base_config(config: &crate::config::Config, rule: RuleId) -> &crate::config::BaseCopConfig {{"
+    )
+    .unwrap();
+    writeln!(file, "    match rule {{").unwrap();
+    for doc in docs {
+        writeln!(
+            file,
+            "        RuleId::{}({}Rule::{}) => &config.{}.{}.base,",
+            doc.category,
+            doc.category,
+            doc.name,
+            doc.category.to_lowercase(),
+            to_snake_case(&doc.name)
+        )
+        .unwrap();
+    }
+    writeln!(file, "    }}").unwrap();
+    writeln!(file, "}}").unwrap();
+}
+
+/// Converts snake_case to PascalCase.
+fn to_pascal_case(s: &str) -> String {
+    s.split('_')
+        .map(|part| {
+            let mut chars = part.chars();
+            chars.next().map(|c| c.to_uppercase().chain(chars).collect::<String>()).unwrap_or_default()
+        })
+        .collect()
+}
+
 /// Converts PascalCase to snake_case.
 fn to_snake_case(s: &str) -> String {
     let mut result = String::new();
@@ -0,0 +1,107 @@
+//! Checking many files at once, for embedders that do not need the CLI.
+
+use crate::config::{Config, build_glob_set, is_excluded};
+use crate::{Diagnostic, check_with_config_and_path};
+use rustc_hash::FxHashMap;
+use std::io;
+use std::path::{Path, PathBuf};
+
+/// Results of `check_files`.
+#[derive(Debug, Default)]
+pub struct FileResults {
+    /// Diagnostics per checked file, including files without offenses.
+    pub diagnostics: FxHashMap<PathBuf, Vec<Diagnostic>>,
+    /// Files that could not be read, with the error.
+    pub errors: Vec<(PathBuf, io::Error)>,
+}
+
+/// Read and check the given files with `config`, in parallel.
+///
+/// Files matching `AllCops.Exclude` are skipped, and read errors are collected
+/// in `FileResults::errors` instead of stopping the run. Each file is checked
+/// with its path (for cop `Include`/`Exclude`) and its permission bits.
+pub fn check_files(paths: &[PathBuf], config: &Config) -> FileResults {
+    let exclude = build_glob_set(&config.all_cops.exclude);
+    let paths: Vec<&PathBuf> = paths.iter().filter(|path| !is_excluded(path, exclude.as_ref())).collect();
+    let workers = std::thread::available_parallelism().map_or(1, |n| n.get());
+    let chunk_size = paths.len().div_ceil(workers).max(1);
+
+    let mut results = FileResults::default();
+    std::thread::scope(|scope| {
+        let handles: Vec<_> = paths
+            .chunks(chunk_size)
+            .map(|chunk| scope.spawn(move || chunk.iter().map(|&path| (path, check_file(path, config))).collect::<Vec<_>>()))
+            .collect();
+        for handle in handles {
+            for (path, result) in handle.join().expect("checker thread panicked") {
+                match result {
+                    Ok(diagnostics) => {
+                        results.diagnostics.insert(path.clone(), diagnostics);
+                    }
+                    Err(e) => results.errors.push((path.clone(), e)),
+                }
+            }
+        }
+    });
+    results
+}
+
+/// Read and check one file.
+fn check_file(path: &Path, config: &Config) -> io::Result<Vec<Diagnostic>> {
+    let source = std::fs::read(path)?;
+    let path_str = path.to_string_lossy();
+    Ok(check_with_config_and_path(&source, config, Some(&path_str), file_mode(path)))
+}
+
+/// Get the permission bits of a file, for cops that inspect file metadata.
+#[cfg(unix)]
+pub fn file_mode(path: &Path) -> Option<u32> {
+    use std::os::unix::fs::PermissionsExt;
+    std::fs::metadata(path).ok().map(|metadata| metadata.permissions().mode())
+}
+
+/// Get the permission bits of a file; not available on this platform.
+#[cfg(not(unix))]
+pub fn file_mode(_path: &Path) -> Option<u32> {
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn temp_file(name: &str, content: &str) -> PathBuf {
+        let path = std::env::temp_dir().join(format!("reuko-batch-{}-{}", std::process::id(), name));
+        std::fs::write(&path, content).unwrap();
+        path
+    }
+
+    #[test]
+    fn test_check_files() {
+        let debugger = temp_file("debugger.rb", "binding.pry\n");
+        let clean = temp_file("clean.rb", "x = 1\n");
+        let missing = std::env::temp_dir().join(format!("reuko-batch-{}-missing.rb", std::process::id()));
+        let results = check_files(&[debugger.clone(), clean.clone(), missing.clone()], &Config::default());
+        std::fs::remove_file(&debugger).unwrap();
+        std::fs::remove_file(&clean).unwrap();
+
+        assert_eq!(results.diagnostics.len(), 2);
+        let rules: Vec<String> = results.diagnostics[&debugger].iter().map(|d| d.rule()).collect();
+        assert_eq!(rules, ["Lint/Debugger"]);
+        assert!(results.diagnostics[&clean].is_empty());
+        assert_eq!(results.errors.len(), 1);
+        assert_eq!(results.errors[0].0, missing);
+        assert_eq!(results.errors[0].1.kind(), io::ErrorKind::NotFound);
+    }
+
+    #[test]
+    fn test_check_files_respects_all_cops_exclude() {
+        let excluded = temp_file("excluded.rb", "binding.pry\n");
+        let mut config = Config::default();
+        config.all_cops.exclude = vec![format!("**/reuko-batch-{}-excluded.rb", std::process::id())];
+        let results = check_files(std::slice::from_ref(&excluded), &config);
+        std::fs::remove_file(&excluded).unwrap();
+        assert!(results.diagnostics.is_empty());
+        assert!(results.errors.is_empty());
+    }
+}
@@ -1,31 +1,62 @@
+use crate::config::BaseCopConfig;
 use crate::config::Config;
+use crate::config::DEFAULT_TARGET_RUBY_VERSION;
+use crate::config::matches_patterns;
 use crate::custom_nodes::AssignmentNode;
+use crate::diagnostic::Applicability;
 use crate::diagnostic::Diagnostic;
 use crate::diagnostic::Fix;
 use crate::diagnostic::RawDiagnostic;
 use crate::diagnostic::Severity;
+use crate::directive::Directives;
 use crate::locator::LineIndex;
-use crate::rule::RuleId;
+use crate::rule::{Category, CategorySet, RuleId};
 use crate::semantic::SemanticModel;
+use globset::GlobSet;
 use ruby_prism::*;
 use rustc_hash::FxHashSet;
 
+/// Get a rule's base config out of the full config.
+type BaseConfigAccessor = fn(&Config) -> &BaseCopConfig;
+
 // Include the auto-generated rule registry macros
 include!(concat!(env!("OUT_DIR"), "/rule_registry.rs"));
 
+/// Check if the source has to be parsed before checking it with `config`,
+/// running only the rules in `categories`.
+///
+/// Parsing is only skipped when no AST-based rule is enabled, no enabled
+/// line-based rule reads comments or literal ranges, and the source has no
+/// `rubocop:` directive that could disable an offense.
+pub fn needs_parse(source: &[u8], config: &Config, categories: CategorySet) -> bool {
+    __REUKO_AST_RULE_CONFIGS
+        .iter()
+        .any(|(category, base)| categories.contains(*category) && base(config).enabled)
+        || (categories.contains(Category::Layout) && (config.layout.leading_comment_space.base.enabled || config.layout.extra_spacing.base.enabled))
+        || may_have_directives(source)
+}
+
+/// Check if the source may contain a `rubocop:` directive comment.
+pub(crate) fn may_have_directives(source: &[u8]) -> bool {
+    source.windows(b"rubocop:".len()).any(|window| window == b"rubocop:")
+}
+
 /// A visitor that builds the node index before rules run.
 /// This ensures all nodes have assigned IDs for rules to reference.
 struct IndexingVisitor<'rk, 'checker> {
     semantic: &'checker mut SemanticModel<'rk>,
+    literal_ranges: &'checker mut Vec<(usize, usize)>,
 }
 impl<'rk> Visit<'rk> for IndexingVisitor<'rk, '_> {
     fn visit_branch_node_enter(&mut self, node: Node<'rk>) {
+        self.literal_ranges.extend(literal_range(&node));
         self.semantic.push_node(node);
     }
     fn visit_branch_node_leave(&mut self) {
         self.semantic.pop_node();
     }
     fn visit_leaf_node_enter(&mut self, node: Node<'rk>) {
+        self.literal_ranges.extend(literal_range(&node));
         self.semantic.push_node(node);
     }
     fn visit_leaf_node_leave(&mut self) {
@@ -33,14 +64,60 @@ impl<'rk> Visit<'rk> for IndexingVisitor<'rk, '_> {
     }
 }
 
+/// Get the byte range of a string-like literal whose contents line-based rules must not touch.
+///
+/// For heredocs only the body is returned, since the opening (`<<~EOS`) sits in ordinary code.
+fn literal_range(node: &Node) -> Option<(usize, usize)> {
+    let is_heredoc = |opening: Option<Location>| opening.is_some_and(|loc| loc.as_slice().starts_with(b"<<"));
+    let location = match node {
+        Node::StringNode { .. } => {
+            let string = node.as_string_node().unwrap();
+            if is_heredoc(string.opening_loc()) {
+                string.content_loc()
+            } else {
+                node.location()
+            }
+        }
+        Node::XStringNode { .. } => {
+            let x_string = node.as_x_string_node().unwrap();
+            if is_heredoc(Some(x_string.opening_loc())) {
+                x_string.content_loc()
+            } else {
+                node.location()
+            }
+        }
+        // Heredoc bodies are covered by their `StringNode` parts.
+        Node::InterpolatedStringNode { .. } if is_heredoc(node.as_interpolated_string_node().unwrap().opening_loc()) => return None,
+        Node::InterpolatedXStringNode { .. } if is_heredoc(Some(node.as_interpolated_x_string_node().unwrap().opening_loc())) => return None,
+        Node::InterpolatedStringNode { .. }
+        | Node::InterpolatedXStringNode { .. }
+        | Node::RegularExpressionNode { .. }
+        | Node::InterpolatedRegularExpressionNode { .. }
+        | Node::SymbolNode { .. }
+        | Node::InterpolatedSymbolNode { .. } => node.location(),
+        // Percent literals (`%w[a  b]`, `%i[a  b]`) separate their elements with whitespace.
+        Node::ArrayNode { .. } if node.as_array_node().unwrap().opening_loc().is_some_and(|loc| loc.as_slice().starts_with(b"%")) => node.location(),
+        _ => return None,
+    };
+    Some((location.start_offset(), location.end_offset()))
+}
+
 /// The main checker that traverses the AST and runs rules.
 pub struct Checker<'rk> {
     source: &'rk [u8],
     config: &'rk Config,
     file_path: Option<&'rk str>,
+    file_mode: Option<u32>,
+    categories: CategorySet,
     ignored_nodes: FxHashSet<(usize, usize)>,
     line_index: LineIndex<'rk>,
+    literal_ranges: Vec<(usize, usize)>,
+    comment_ranges: Vec<(usize, usize)>,
     raw_diagnostics: Vec<RawDiagnostic>,
+    on_diagnostic: Option<Box<dyn FnMut(Diagnostic) + 'rk>>,
+    /// Rule, range and message of each streamed diagnostic, so that exact duplicates are passed on once.
+    streamed: FxHashSet<(RuleId, usize, usize, String)>,
+    directives: Directives,
     semantic: SemanticModel<'rk>,
 }
 impl<'rk> Checker<'rk> {
@@ -50,9 +127,16 @@ impl<'rk> Checker<'rk> {
             source,
             config,
             file_path: None,
+            file_mode: None,
+            categories: CategorySet::ALL,
             ignored_nodes: FxHashSet::default(),
             line_index: LineIndex::from_source(source),
+            literal_ranges: Vec::new(),
+            comment_ranges: Vec::new(),
             raw_diagnostics: Vec::new(),
+            on_diagnostic: None,
+            streamed: FxHashSet::default(),
+            directives: Directives::default(),
             semantic: SemanticModel::new(),
         }
     }
@@ -63,32 +147,128 @@ impl<'rk> Checker<'rk> {
             source,
             config,
             file_path: Some(file_path),
+            file_mode: None,
+            categories: CategorySet::ALL,
             ignored_nodes: FxHashSet::default(),
             line_index: LineIndex::from_source(source),
+            literal_ranges: Vec::new(),
+            comment_ranges: Vec::new(),
             raw_diagnostics: Vec::new(),
+            on_diagnostic: None,
+            streamed: FxHashSet::default(),
+            directives: Directives::default(),
             semantic: SemanticModel::new(),
         }
     }
+    /// Create a new Checker for source that has already been parsed.
+    ///
+    /// The inline directives and comments are taken from `parse_result`, which
+    /// must be the result of parsing `source`. Running the rules still needs the
+    /// root node (`parse_result.node()`), so the same parse can be shared with
+    /// other tools instead of parsing again.
+    pub fn from_parse_result(source: &'rk [u8], config: &'rk Config, file_path: Option<&'rk str>, parse_result: &ParseResult<'_>) -> Self {
+        let mut checker = match file_path {
+            Some(file_path) => Self::with_file_path(source, config, file_path),
+            None => Self::new(source, config),
+        };
+        checker.set_directives(Directives::from_comments(parse_result.comments(), checker.line_index()));
+        checker.set_comments(parse_result.comments());
+        checker
+    }
+    /// Stream diagnostics to a callback instead of buffering them.
+    ///
+    /// Each diagnostic is resolved and passed to the callback as soon as it is
+    /// reported, in traversal order (not sorted by position). Exact duplicates
+    /// (same rule, range and message) are passed on once, as in `into_diagnostics`.
+    pub fn set_on_diagnostic(&mut self, callback: impl FnMut(Diagnostic) + 'rk) {
+        self.on_diagnostic = Some(Box::new(callback));
+    }
+    /// Set the permission bits of the checked file (`st_mode`), for cops that inspect file metadata.
+    pub fn set_file_mode(&mut self, file_mode: Option<u32>) {
+        self.file_mode = file_mode;
+    }
+    /// Restrict the rules that run to those in `categories`.
+    pub fn set_categories(&mut self, categories: CategorySet) {
+        self.categories = categories;
+    }
+    /// Set the inline `# rubocop:disable` directives used to suppress diagnostics.
+    pub fn set_directives(&mut self, directives: Directives) {
+        self.directives = directives;
+    }
+    /// Set the comments of the parsed file (`ParseResult::comments`).
+    pub fn set_comments<'pr>(&mut self, comments: impl Iterator<Item = Comment<'pr>>) {
+        self.comment_ranges = comments
+            .map(|comment| (comment.location().start_offset(), comment.location().end_offset()))
+            .collect();
+    }
     /// Build the node index by traversing the AST before running rules.
+    ///
+    /// String-like literal ranges are collected in the same pass and merged into
+    /// sorted, disjoint ranges for `is_in_literal`.
     pub fn build_index(&mut self, root: &Node<'rk>) {
-        let mut visitor = IndexingVisitor { semantic: &mut self.semantic };
+        let mut literal_ranges = Vec::new();
+        let mut visitor = IndexingVisitor {
+            semantic: &mut self.semantic,
+            literal_ranges: &mut literal_ranges,
+        };
         visitor.visit(root);
+        literal_ranges.sort_unstable();
+        for (start, end) in literal_ranges {
+            match self.literal_ranges.last_mut() {
+                Some(last) if start <= last.1 => last.1 = last.1.max(end),
+                _ => self.literal_ranges.push((start, end)),
+            }
+        }
     }
     /// Get the source code being checked.
     #[inline]
     pub fn source(&self) -> &[u8] {
         self.source
     }
+    /// Get the path of the file being checked, if known.
+    #[inline]
+    pub fn file_path(&self) -> Option<&'rk str> {
+        self.file_path
+    }
+    /// Get the permission bits of the file being checked, if known.
+    #[inline]
+    pub fn file_mode(&self) -> Option<u32> {
+        self.file_mode
+    }
+    /// Check if the rules of `category` run.
+    #[inline]
+    pub fn runs_category(&self, category: Category) -> bool {
+        self.categories.contains(category)
+    }
     /// Get the configuration used by the checker.
     #[inline]
     pub fn config(&self) -> &Config {
         self.config
     }
+    /// Get the Ruby version the checked code targets (`AllCops.TargetRubyVersion`).
+    #[inline]
+    pub fn target_ruby_version(&self) -> f32 {
+        self.config.all_cops.target_ruby_version.unwrap_or(DEFAULT_TARGET_RUBY_VERSION)
+    }
     /// Get the line index for offset-to-line/column mapping.
     #[inline]
     pub fn line_index(&self) -> &LineIndex<'rk> {
         &self.line_index
     }
+    /// Check if the offset is inside a string, symbol, regexp or percent array literal.
+    ///
+    /// Only meaningful after `build_index`; line-based rules use it to leave literal contents alone.
+    pub fn is_in_literal(&self, offset: usize) -> bool {
+        let index = self.literal_ranges.partition_point(|&(start, _)| start <= offset);
+        index > 0 && offset < self.literal_ranges[index - 1].1
+    }
+    /// Get the `(start, end)` offsets of every comment, in source order.
+    ///
+    /// Inline comments start with `#`; `=begin`/`=end` documents are included as well.
+    #[inline]
+    pub fn comments(&self) -> &[(usize, usize)] {
+        &self.comment_ranges
+    }
     /// Get access to the semantic model.
     #[inline]
     pub fn semantic(&self) -> &SemanticModel<'rk> {
@@ -97,71 +277,41 @@ impl<'rk> Checker<'rk> {
 
     /// Check if the current file matches any of the given glob patterns.
     ///
+    /// `compiled` is used when the patterns have been compiled ahead of time.
     /// Returns `Some(true)` if the file matches, `Some(false)` if it doesn't match,
     /// or `None` if matching cannot be performed (no file path, no patterns, or build error).
-    fn matches_patterns(&self, patterns: &[String]) -> Option<bool> {
-        if patterns.is_empty() {
-            return None;
-        }
-        let file_path = self.file_path?;
-
-        // Build glob matcher
-        use globset::{Glob, GlobSetBuilder};
-        let mut builder = GlobSetBuilder::new();
-        for pattern in patterns {
-            if let Ok(glob) = Glob::new(pattern) {
-                builder.add(glob);
-            }
-        }
-        let glob_set = builder.build().ok()?;
-
-        // Try matching with different path forms
-        let path = std::path::Path::new(file_path);
-        if glob_set.is_match(path) {
-            return Some(true);
-        }
-        // Try without "./" prefix
-        if let Some(stripped) = file_path.strip_prefix("./") {
-            if glob_set.is_match(std::path::Path::new(stripped)) {
-                return Some(true);
-            }
-        }
-        // Try matching just the filename
-        if let Some(filename) = path.file_name() {
-            if glob_set.is_match(std::path::Path::new(filename)) {
-                return Some(true);
-            }
-        }
-        Some(false)
+    fn matches_patterns(&self, compiled: Option<&GlobSet>, patterns: &[String]) -> Option<bool> {
+        matches_patterns(compiled, patterns, self.file_path?)
     }
 
-    /// Check if the current file is excluded by the given exclude patterns.
+    /// Check if the current file is excluded by the cop's `Exclude` patterns.
     ///
     /// Returns `true` if the file matches any of the exclude patterns.
     #[inline]
-    pub fn is_file_excluded(&self, exclude_patterns: &[String]) -> bool {
+    pub fn is_file_excluded(&self, base: &BaseCopConfig) -> bool {
         // If no patterns or can't match, don't exclude (safe default: run the cop)
-        self.matches_patterns(exclude_patterns).unwrap_or(false)
+        self.matches_patterns(base.exclude_set(), base.exclude()).unwrap_or(false)
     }
 
-    /// Check if the current file is included by the given include patterns.
+    /// Check if the current file is included by the cop's `Include` patterns.
     ///
     /// Returns `true` if include patterns are empty (no restriction) or if the file matches any pattern.
     /// Returns `false` if include patterns are specified but the file doesn't match any.
+    /// Without a file path (e.g. the library `check()` functions), every cop is included.
     #[inline]
-    pub fn is_file_included(&self, include_patterns: &[String]) -> bool {
+    pub fn is_file_included(&self, base: &BaseCopConfig) -> bool {
         // If no patterns or can't match, include by default (safe default: run the cop)
-        self.matches_patterns(include_patterns).unwrap_or(true)
+        self.matches_patterns(base.include_set(), base.include()).unwrap_or(true)
     }
 
-    /// Check if a cop should run on the current file based on include/exclude patterns.
+    /// Check if a cop should run on the current file based on its include/exclude patterns.
     ///
     /// A cop should run if:
     /// 1. The file is included (matches include patterns, or no include patterns specified)
     /// 2. The file is NOT excluded (doesn't match any exclude patterns)
     #[inline]
-    pub fn should_run_cop(&self, include_patterns: &[String], exclude_patterns: &[String]) -> bool {
-        self.is_file_included(include_patterns) && !self.is_file_excluded(exclude_patterns)
+    pub fn should_run_cop(&self, base: &BaseCopConfig) -> bool {
+        self.is_file_included(base) && !self.is_file_excluded(base)
     }
 
     // ========= Node stack management ==========
@@ -197,24 +347,93 @@ impl<'rk> Checker<'rk> {
 
     /// Report a diagnostic (deferred line/column calculation).
     /// Severity is passed directly from the rule (already resolved from config).
+    ///
+    /// If a callback is set, the diagnostic is resolved immediately and passed to it.
+    /// Diagnostics on lines where the rule is disabled by a directive are dropped.
+    /// Safe fixes of a cop configured with `SafeAutoCorrect: false` are reported as unsafe.
     #[inline]
     pub fn report(&mut self, rule_id: RuleId, message: String, severity: Severity, start_offset: usize, end_offset: usize, fix: Option<Fix>) {
-        self.raw_diagnostics.push(RawDiagnostic {
+        let fix_description = fix.as_ref().map(Fix::description);
+        self.push_diagnostic(RawDiagnostic {
             rule_id,
             message,
             severity,
             start: start_offset,
             end: end_offset,
             fix,
+            fix_description,
         });
     }
+    /// Report a fixable offense like `report`, describing what the fix does
+    /// (e.g. "remove trailing whitespace") instead of using `Fix::description`.
+    #[allow(clippy::too_many_arguments)]
+    pub fn report_with_fix_description(
+        &mut self,
+        rule_id: RuleId,
+        message: String,
+        severity: Severity,
+        start_offset: usize,
+        end_offset: usize,
+        fix: Fix,
+        fix_description: &'static str,
+    ) {
+        self.push_diagnostic(RawDiagnostic {
+            rule_id,
+            message,
+            severity,
+            start: start_offset,
+            end: end_offset,
+            fix: Some(fix),
+            fix_description: Some(fix_description),
+        });
+    }
+    /// Record or stream a reported diagnostic, see `report`.
+    fn push_diagnostic(&mut self, mut raw: RawDiagnostic) {
+        if !self.directives.is_empty() && self.directives.is_disabled(raw.rule_id, self.line_index.line_number(raw.start)) {
+            return;
+        }
+        if raw.fix.is_some() {
+            let base = raw.rule_id.base_config(self.config);
+            // With `AutoCorrect: false` the offense is reported as not correctable.
+            if !base.auto_correct {
+                raw.fix = None;
+                raw.fix_description = None;
+            } else if let Some(fix) = raw.fix.as_mut()
+                && fix.applicability == Applicability::Safe
+                && !base.safe_autocorrect
+            {
+                fix.applicability = Applicability::Unsafe;
+            }
+        }
+        if let Some(callback) = self.on_diagnostic.as_mut() {
+            if !self.streamed.insert((raw.rule_id, raw.start, raw.end, raw.message.clone())) {
+                return;
+            }
+            let (line_start, column_start) = self.line_index.line_column(raw.start);
+            let (line_end, column_end) = self.line_index.line_column(raw.end);
+            callback(raw.resolve(line_start, line_end, column_start, column_end));
+        } else {
+            self.raw_diagnostics.push(raw);
+        }
+    }
     /// Convert raw diagnostics to full diagnostics with line/column info.
     /// Uses batch processing for efficient line number resolution.
+    ///
+    /// Exact duplicates (same rule, range and message), e.g. from a rule
+    /// reached through two node entry points, are reported once.
     pub fn into_diagnostics(mut self) -> Vec<Diagnostic> {
         if self.raw_diagnostics.is_empty() {
             return Vec::new();
         }
         self.raw_diagnostics.sort_by_key(|d| (d.start, d.end));
+        let mut seen = FxHashSet::default();
+        let unique: Vec<bool> = self
+            .raw_diagnostics
+            .iter()
+            .map(|d| seen.insert((d.rule_id, d.start, d.end, d.message.as_str())))
+            .collect();
+        let mut unique = unique.into_iter();
+        self.raw_diagnostics.retain(|_| unique.next().unwrap_or(true));
         let offsets: Vec<(usize, usize)> = self.raw_diagnostics.iter().map(|d| (d.start, d.end)).collect();
         let resolved = self.line_index.batch_line_column(&offsets);
         self.raw_diagnostics
@@ -1170,3 +1389,51 @@ impl Visit<'_> for Checker<'_> {
         self.pop_node();
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::rule::{LayoutRule, LintRule};
+
+    #[test]
+    fn test_into_diagnostics_drops_exact_duplicates() {
+        let config = Config::default();
+        let mut checker = Checker::new(b"x = 1\n", &config);
+        let lint = RuleId::Lint(LintRule::Debugger);
+        let layout = RuleId::Layout(LayoutRule::ExtraSpacing);
+        for _ in 0..2 {
+            checker.report(lint, "Remove debugger entry point `x`.".to_string(), Severity::Warning, 0, 1, None);
+        }
+        // Distinct offenses at the same range are kept.
+        checker.report(lint, "Another message.".to_string(), Severity::Warning, 0, 1, None);
+        checker.report(layout, "Remove debugger entry point `x`.".to_string(), Severity::Convention, 0, 1, None);
+        checker.report(lint, "Remove debugger entry point `x`.".to_string(), Severity::Warning, 0, 2, None);
+
+        let diagnostics = checker.into_diagnostics();
+        assert_eq!(diagnostics.len(), 4);
+        assert_eq!(diagnostics[0].message, "Remove debugger entry point `x`.");
+        assert_eq!(diagnostics[1].message, "Another message.");
+        assert_eq!(diagnostics[2].rule_id, layout);
+        assert_eq!(diagnostics[3].end, 2);
+    }
+
+    #[test]
+    fn test_streamed_duplicates_are_dropped() {
+        let config = Config::default();
+        let mut streamed = Vec::new();
+        {
+            let mut checker = Checker::new(b"x = 1\n", &config);
+            checker.set_on_diagnostic(|diagnostic| streamed.push(diagnostic));
+            let lint = RuleId::Lint(LintRule::Debugger);
+            for _ in 0..2 {
+                checker.report(lint, "Remove debugger entry point `x`.".to_string(), Severity::Warning, 0, 1, None);
+            }
+            // Distinct offenses at the same range are kept.
+            checker.report(lint, "Another message.".to_string(), Severity::Warning, 0, 1, None);
+            checker.report(RuleId::Layout(LayoutRule::ExtraSpacing), String::new(), Severity::Convention, 0, 1, None);
+            checker.report(lint, "Remove debugger entry point `x`.".to_string(), Severity::Warning, 0, 2, None);
+        }
+        assert_eq!(streamed.len(), 4);
+        assert_eq!(streamed[1].message, "Another message.");
+    }
+}
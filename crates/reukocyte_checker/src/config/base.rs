@@ -1,8 +1,10 @@
 //! Base configuration shared by all cops.
 
-use crate::config::serde_helpers::{deserialize_enabled, deserialize_severity};
+use crate::config::serde_helpers::{deserialize_auto_correct, deserialize_enabled, deserialize_severity, serialize_severity};
 use crate::diagnostic::Severity;
-use serde::Deserialize;
+use globset::{Glob, GlobSet, GlobSetBuilder};
+use serde::{Deserialize, Serialize};
+use std::path::Path;
 
 /// Base configuration fields shared by all cops.
 ///
@@ -19,21 +21,41 @@ use serde::Deserialize;
 ///     pub my_option: String,
 /// }
 /// ```
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, Deserialize, Serialize)]
 #[serde(default, rename_all = "PascalCase")]
 pub struct BaseCopConfig {
     /// Whether this cop is enabled.
     #[serde(deserialize_with = "deserialize_enabled")]
     pub enabled: bool,
     /// Severity level for this cop.
-    #[serde(deserialize_with = "deserialize_severity")]
+    #[serde(deserialize_with = "deserialize_severity", serialize_with = "serialize_severity")]
     pub severity: Severity,
-    /// Files to exclude from this cop.
+    /// Files to exclude from this cop, see `set_exclude`.
     #[serde(default)]
-    pub exclude: Vec<String>,
-    /// Files to include for this cop (cop only runs on matching files).
+    exclude: Vec<String>,
+    /// Files to include for this cop (cop only runs on matching files), see `set_include`.
     #[serde(default)]
-    pub include: Vec<String>,
+    include: Vec<String>,
+    /// Whether the cop's fixes are safe (`SafeAutoCorrect`).
+    ///
+    /// When `false`, all of the cop's fixes are treated as unsafe and only
+    /// applied with `-A`, even if the cop itself marks them as safe.
+    #[serde(rename = "SafeAutoCorrect", alias = "SafeAutocorrect")]
+    pub safe_autocorrect: bool,
+    /// Whether the cop's fixes are applied at all (`AutoCorrect`).
+    ///
+    /// When `false`, offenses are still reported but never corrected.
+    #[serde(rename = "AutoCorrect", alias = "Autocorrect", deserialize_with = "deserialize_auto_correct")]
+    pub auto_correct: bool,
+    /// Compiled `exclude` patterns, kept in sync with `exclude` by its setter.
+    #[serde(skip)]
+    exclude_set: Option<GlobSet>,
+    /// Compiled `include` patterns, kept in sync with `include` by its setter.
+    #[serde(skip)]
+    include_set: Option<GlobSet>,
+    /// Option keys set explicitly in YAML or through `ConfigBuilder`, see `Config::merged_with`.
+    #[serde(skip)]
+    explicit_keys: Vec<String>,
 }
 
 impl Default for BaseCopConfig {
@@ -43,6 +65,11 @@ impl Default for BaseCopConfig {
             severity: Severity::Convention,
             exclude: Vec::new(),
             include: Vec::new(),
+            safe_autocorrect: true,
+            auto_correct: true,
+            exclude_set: None,
+            include_set: None,
+            explicit_keys: Vec::new(),
         }
     }
 }
@@ -55,4 +82,125 @@ impl BaseCopConfig {
             ..Default::default()
         }
     }
+
+    /// Files to exclude from this cop (`Exclude`).
+    pub fn exclude(&self) -> &[String] {
+        &self.exclude
+    }
+
+    /// Files to include for this cop (`Include`).
+    pub fn include(&self) -> &[String] {
+        &self.include
+    }
+
+    /// Replace the `Exclude` patterns, compiling them.
+    pub fn set_exclude(&mut self, patterns: Vec<String>) {
+        self.exclude_set = build_glob_set(&patterns);
+        self.exclude = patterns;
+    }
+
+    /// Replace the `Include` patterns, compiling them.
+    pub fn set_include(&mut self, patterns: Vec<String>) {
+        self.include_set = build_glob_set(&patterns);
+        self.include = patterns;
+    }
+
+    /// The compiled `Exclude` patterns, if they have been compiled.
+    pub(crate) fn exclude_set(&self) -> Option<&GlobSet> {
+        self.exclude_set.as_ref()
+    }
+
+    /// The compiled `Include` patterns, if they have been compiled.
+    pub(crate) fn include_set(&self) -> Option<&GlobSet> {
+        self.include_set.as_ref()
+    }
+
+    /// The option keys that were set explicitly in YAML (e.g. `Enabled`, `Max`).
+    pub(crate) fn explicit_keys(&self) -> &[String] {
+        &self.explicit_keys
+    }
+
+    /// Record the option keys that were set explicitly.
+    pub(crate) fn set_explicit_keys(&mut self, keys: Vec<String>) {
+        self.explicit_keys = keys;
+    }
+
+    /// Compile the `Exclude`/`Include` patterns so they aren't rebuilt for every file.
+    ///
+    /// Deserialized configs start without compiled patterns, which are then
+    /// built on each match. Empty pattern lists are left as `None`.
+    pub fn compile_patterns(&mut self) {
+        self.exclude_set = build_glob_set(&self.exclude);
+        self.include_set = build_glob_set(&self.include);
+    }
+
+    /// Check if the cop runs on `file_path` according to its `Include`/`Exclude` patterns.
+    ///
+    /// Empty pattern lists do not restrict anything.
+    pub fn applies_to(&self, file_path: &str) -> bool {
+        matches_patterns(self.include_set.as_ref(), &self.include, file_path).unwrap_or(true)
+            && !matches_patterns(self.exclude_set.as_ref(), &self.exclude, file_path).unwrap_or(false)
+    }
+}
+
+/// Build a glob set from patterns, skipping invalid ones.
+///
+/// Returns `None` if there are no patterns or the set cannot be built.
+pub(crate) fn build_glob_set(patterns: &[String]) -> Option<GlobSet> {
+    if patterns.is_empty() {
+        return None;
+    }
+    let mut builder = GlobSetBuilder::new();
+    for pattern in patterns {
+        if let Ok(glob) = Glob::new(pattern) {
+            builder.add(glob);
+        }
+    }
+    builder.build().ok()
+}
+
+/// Check if `file_path` matches any of the given glob patterns, as given, without
+/// a `./` prefix or by its file name alone.
+///
+/// `compiled` is used when the patterns have been compiled ahead of time.
+/// Returns `None` if there are no patterns or they cannot be built.
+pub(crate) fn matches_patterns(compiled: Option<&GlobSet>, patterns: &[String], file_path: &str) -> Option<bool> {
+    if patterns.is_empty() {
+        return None;
+    }
+    let built;
+    let glob_set = match compiled {
+        Some(glob_set) => glob_set,
+        None => {
+            built = build_glob_set(patterns)?;
+            &built
+        }
+    };
+
+    // Try matching with different path forms
+    let path = Path::new(file_path);
+    if glob_set.is_match(path) {
+        return Some(true);
+    }
+    // Try without "./" prefix
+    if let Some(stripped) = file_path.strip_prefix("./") {
+        if glob_set.is_match(Path::new(stripped)) {
+            return Some(true);
+        }
+    }
+    // Try matching just the filename
+    if let Some(filename) = path.file_name() {
+        if glob_set.is_match(Path::new(filename)) {
+            return Some(true);
+        }
+    }
+    Some(false)
+}
+
+/// Check if a path matches `AllCops.Exclude`, as given or without a `./` prefix.
+pub(crate) fn is_excluded(path: &Path, exclude: Option<&GlobSet>) -> bool {
+    let Some(exclude) = exclude else {
+        return false;
+    };
+    exclude.is_match(path) || path.strip_prefix("./").is_ok_and(|stripped| exclude.is_match(stripped))
 }
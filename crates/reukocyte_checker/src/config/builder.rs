@@ -0,0 +1,141 @@
+//! Programmatic construction of a `Config`.
+//!
+//! ```ignore
+//! let config = Config::builder()
+//!     .enable("Layout/TrailingWhitespace")
+//!     .disable("Lint/Debugger")
+//!     .set_option("Layout/IndentationWidth", "Width", 4)
+//!     .build()?;
+//! ```
+//!
+//! Options are collected as YAML values and deserialized into the same per-cop
+//! config structs as `.rubocop.yml`, so option names and value types match RuboCop's.
+
+use super::Config;
+use super::yaml::{COP_NAMES, RubocopYaml, explicit_cop_options};
+use serde_yaml::{Mapping, Value};
+
+/// Error type for building a `Config`.
+#[derive(Debug)]
+pub enum ConfigBuildError {
+    /// The cop name is not a known cop.
+    UnknownCop(String),
+    /// An option of the cop has a value of the wrong type.
+    InvalidOption { cop: String, source: serde_yaml::Error },
+}
+impl std::fmt::Display for ConfigBuildError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ConfigBuildError::UnknownCop(cop) => write!(f, "Unknown cop: {}", cop),
+            ConfigBuildError::InvalidOption { cop, source } => write!(f, "Invalid option for {}: {}", cop, source),
+        }
+    }
+}
+impl std::error::Error for ConfigBuildError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            ConfigBuildError::UnknownCop(_) => None,
+            ConfigBuildError::InvalidOption { source, .. } => Some(source),
+        }
+    }
+}
+
+/// Fluent builder for `Config`. Cops that are not mentioned keep their defaults.
+#[derive(Debug, Clone, Default)]
+pub struct ConfigBuilder {
+    /// Options per cop, in the order the cops were first mentioned.
+    cops: Vec<(String, Mapping)>,
+}
+impl ConfigBuilder {
+    /// Create a builder starting from the default configuration.
+    pub fn new() -> Self {
+        Self::default()
+    }
+    /// Enable a cop (`Enabled: true`).
+    pub fn enable(self, cop: &str) -> Self {
+        self.set_option(cop, "Enabled", true)
+    }
+    /// Disable a cop (`Enabled: false`).
+    pub fn disable(self, cop: &str) -> Self {
+        self.set_option(cop, "Enabled", false)
+    }
+    /// Set a cop option by its YAML key (e.g. `"Width"`, `"EnforcedStyle"`).
+    pub fn set_option(mut self, cop: &str, key: &str, value: impl Into<Value>) -> Self {
+        let index = match self.cops.iter().position(|(name, _)| name == cop) {
+            Some(index) => index,
+            None => {
+                self.cops.push((cop.to_string(), Mapping::new()));
+                self.cops.len() - 1
+            }
+        };
+        self.cops[index].1.insert(Value::from(key), value.into());
+        self
+    }
+    /// Build the configuration.
+    ///
+    /// Fails on the first unknown cop name or option value that does not deserialize.
+    pub fn build(self) -> Result<Config, ConfigBuildError> {
+        let mut root = Mapping::new();
+        for (cop, options) in self.cops {
+            if !COP_NAMES.contains(&cop.as_str()) {
+                return Err(ConfigBuildError::UnknownCop(cop));
+            }
+            // Deserialize each cop on its own so errors name the offending cop.
+            let single = Mapping::from_iter([(Value::from(cop.as_str()), Value::Mapping(options.clone()))]);
+            if let Err(source) = serde_yaml::from_value::<RubocopYaml>(Value::Mapping(single)) {
+                return Err(ConfigBuildError::InvalidOption { cop, source });
+            }
+            root.insert(Value::from(cop), Value::Mapping(options));
+        }
+        let cop_options = explicit_cop_options(&Value::Mapping(root.clone()));
+        let mut yaml: RubocopYaml = serde_yaml::from_value(Value::Mapping(root)).expect("each cop was validated individually");
+        yaml.cop_options = cop_options;
+        Ok(Config::from_rubocop_yaml(&yaml))
+    }
+}
+
+impl Config {
+    /// Start building a configuration programmatically.
+    pub fn builder() -> ConfigBuilder {
+        ConfigBuilder::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_build_config() {
+        let config = Config::builder()
+            .enable("Layout/TrailingWhitespace")
+            .disable("Lint/Debugger")
+            .set_option("Layout/IndentationWidth", "Width", 4)
+            .build()
+            .unwrap();
+        assert!(config.layout.trailing_whitespace.base.enabled);
+        assert!(!config.lint.debugger.base.enabled);
+        assert_eq!(config.layout.indentation_width.width, 4);
+        // Cops that are not mentioned keep their defaults.
+        assert!(config.layout.end_alignment.base.enabled);
+    }
+
+    #[test]
+    fn test_later_options_override_earlier_ones() {
+        let config = Config::builder().disable("Lint/Debugger").enable("Lint/Debugger").build().unwrap();
+        assert!(config.lint.debugger.base.enabled);
+    }
+
+    #[test]
+    fn test_unknown_cop() {
+        let err = Config::builder().enable("Layout/NoSuchCop").build().unwrap_err();
+        assert!(matches!(err, ConfigBuildError::UnknownCop(ref cop) if cop == "Layout/NoSuchCop"));
+        assert_eq!(err.to_string(), "Unknown cop: Layout/NoSuchCop");
+    }
+
+    #[test]
+    fn test_invalid_option_type() {
+        let err = Config::builder().set_option("Layout/IndentationWidth", "Width", "wide").build().unwrap_err();
+        assert!(matches!(err, ConfigBuildError::InvalidOption { ref cop, .. } if cop == "Layout/IndentationWidth"));
+    }
+}
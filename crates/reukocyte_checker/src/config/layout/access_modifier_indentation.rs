@@ -1,8 +1,8 @@
 use crate::config::BaseCopConfig;
-use serde::Deserialize;
+use serde::{Deserialize, Serialize};
 
 /// Configuration for Layout/AccessModifierIndentation.
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, Deserialize, Serialize)]
 #[serde(default, rename_all = "PascalCase")]
 pub struct AccessModifierIndentation {
     /// Base configuration (enabled, severity, exclude, include).
@@ -23,7 +23,7 @@ impl Default for AccessModifierIndentation {
 }
 
 /// Alignment style for Layout/AccessModifierIndentation.
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Deserialize, Serialize)]
 #[serde(rename_all = "snake_case")]
 pub enum EnforcedStyle {
     #[default]
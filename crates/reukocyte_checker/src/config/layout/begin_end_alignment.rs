@@ -1,14 +1,15 @@
 use crate::config::BaseCopConfig;
 use crate::diagnostic::Severity;
-use serde::Deserialize;
+use serde::{Deserialize, Serialize};
 
 /// Configuration for Layout/BeginEndAlignment.
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, Deserialize, Serialize)]
 #[serde(default, rename_all = "PascalCase")]
 pub struct BeginEndAlignment {
     /// Base configuration (enabled, severity, exclude, include).
     #[serde(flatten)]
     pub base: BaseCopConfig,
+    /// What the `end` of a `begin` block is aligned with.
     pub enforced_style_align_with: EnforcedStyleAlignWith,
 }
 
@@ -22,7 +23,7 @@ impl Default for BeginEndAlignment {
 }
 
 /// Alignment style for Layout/BeginEndAlignment.
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Deserialize, Serialize)]
 #[serde(rename_all = "snake_case")]
 pub enum EnforcedStyleAlignWith {
     #[default]
@@ -0,0 +1,35 @@
+use crate::config::BaseCopConfig;
+use serde::{Deserialize, Serialize};
+
+/// Configuration for Layout/BlockAlignment.
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[serde(default, rename_all = "PascalCase")]
+pub struct BlockAlignment {
+    /// Base configuration (enabled, severity, exclude, include).
+    #[serde(flatten)]
+    pub base: BaseCopConfig,
+    /// What the closing `end`/`}` of a block is aligned with.
+    pub enforced_style_align_with: EnforcedStyleAlignWith,
+}
+
+impl Default for BlockAlignment {
+    fn default() -> Self {
+        Self {
+            base: BaseCopConfig::default(),
+            enforced_style_align_with: EnforcedStyleAlignWith::default(),
+        }
+    }
+}
+
+/// Alignment style for Layout/BlockAlignment.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Deserialize, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum EnforcedStyleAlignWith {
+    /// Either `start_of_block` or `start_of_line`.
+    #[default]
+    Either,
+    /// The start of the line where `do`/`{` appears.
+    StartOfBlock,
+    /// The start of the line where the expression containing the block starts.
+    StartOfLine,
+}
@@ -0,0 +1,11 @@
+use crate::config::BaseCopConfig;
+use serde::{Deserialize, Serialize};
+
+/// Configuration for Layout/BlockEndNewline.
+#[derive(Debug, Clone, Default, Deserialize, Serialize)]
+#[serde(default, rename_all = "PascalCase")]
+pub struct BlockEndNewline {
+    /// Base configuration (enabled, severity, exclude, include).
+    #[serde(flatten)]
+    pub base: BaseCopConfig,
+}
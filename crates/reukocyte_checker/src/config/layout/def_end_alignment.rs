@@ -1,14 +1,15 @@
 use crate::config::BaseCopConfig;
 use crate::diagnostic::Severity;
-use serde::Deserialize;
+use serde::{Deserialize, Serialize};
 
 /// Configuration for Layout/DefEndAlignment.
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, Deserialize, Serialize)]
 #[serde(default, rename_all = "PascalCase")]
 pub struct DefEndAlignment {
     /// Base configuration (enabled, severity, exclude, include).
     #[serde(flatten)]
     pub base: BaseCopConfig,
+    /// What the `end` of a method definition is aligned with.
     pub enforced_style_align_with: EnforcedStyleAlignWith,
 }
 
@@ -22,7 +23,7 @@ impl Default for DefEndAlignment {
 }
 
 /// Alignment style for Layout/DefEndAlignment.
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Deserialize, Serialize)]
 #[serde(rename_all = "snake_case")]
 pub enum EnforcedStyleAlignWith {
     #[default]
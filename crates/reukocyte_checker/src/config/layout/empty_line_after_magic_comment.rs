@@ -0,0 +1,19 @@
+use crate::config::BaseCopConfig;
+use serde::{Deserialize, Serialize};
+
+/// Configuration for Layout/EmptyLineAfterMagicComment.
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[serde(default, rename_all = "PascalCase")]
+pub struct EmptyLineAfterMagicComment {
+    /// Base configuration (enabled, severity, exclude, include).
+    #[serde(flatten)]
+    pub base: BaseCopConfig,
+}
+
+impl Default for EmptyLineAfterMagicComment {
+    fn default() -> Self {
+        Self {
+            base: BaseCopConfig::default(),
+        }
+    }
+}
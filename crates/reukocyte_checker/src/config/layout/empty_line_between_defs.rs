@@ -0,0 +1,51 @@
+use crate::config::BaseCopConfig;
+use crate::config::serde_helpers::{deserialize_count_range, serialize_count_range};
+use serde::{Deserialize, Serialize};
+use std::ops::RangeInclusive;
+
+/// Configuration for Layout/EmptyLineBetweenDefs.
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[serde(default, rename_all = "PascalCase")]
+pub struct EmptyLineBetweenDefs {
+    /// Base configuration (enabled, severity, exclude, include).
+    #[serde(flatten)]
+    pub base: BaseCopConfig,
+    /// Check empty lines between method definitions.
+    pub empty_line_between_method_defs: bool,
+    /// Check empty lines between class definitions.
+    pub empty_line_between_class_defs: bool,
+    /// Check empty lines between module definitions.
+    pub empty_line_between_module_defs: bool,
+    /// Allow one-line definitions on adjacent lines without an empty line.
+    pub allow_adjacent_one_line_defs: bool,
+    /// Allowed number of empty lines, given as a number or a `[min, max]` range.
+    #[serde(deserialize_with = "deserialize_count_range", serialize_with = "serialize_count_range")]
+    pub number_of_empty_lines: RangeInclusive<usize>,
+}
+
+impl Default for EmptyLineBetweenDefs {
+    fn default() -> Self {
+        Self {
+            base: BaseCopConfig::default(),
+            empty_line_between_method_defs: true,
+            empty_line_between_class_defs: true,
+            empty_line_between_module_defs: true,
+            allow_adjacent_one_line_defs: true,
+            number_of_empty_lines: 1..=1,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_number_of_empty_lines() {
+        let config: EmptyLineBetweenDefs = serde_yaml::from_str("NumberOfEmptyLines: 2\n").unwrap();
+        assert_eq!(config.number_of_empty_lines, 2..=2);
+        let config: EmptyLineBetweenDefs = serde_yaml::from_str("NumberOfEmptyLines: [1, 2]\n").unwrap();
+        assert_eq!(config.number_of_empty_lines, 1..=2);
+        assert!(serde_yaml::from_str::<EmptyLineBetweenDefs>("NumberOfEmptyLines: [2, 1]\n").is_err());
+    }
+}
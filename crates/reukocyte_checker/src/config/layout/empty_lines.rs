@@ -1,8 +1,8 @@
 use crate::config::BaseCopConfig;
-use serde::Deserialize;
+use serde::{Deserialize, Serialize};
 
 /// Configuration for Layout/EmptyLines.
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, Deserialize, Serialize)]
 #[serde(default, rename_all = "PascalCase")]
 pub struct EmptyLines {
     /// Base configuration (enabled, severity, exclude, include).
@@ -0,0 +1,21 @@
+use crate::config::BaseCopConfig;
+use serde::{Deserialize, Serialize};
+
+/// Configuration for Layout/EmptyLinesAroundMethodBody.
+///
+/// There is no `EnforcedStyle`: method bodies never start or end with empty lines.
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[serde(default, rename_all = "PascalCase")]
+pub struct EmptyLinesAroundMethodBody {
+    /// Base configuration (enabled, severity, exclude, include).
+    #[serde(flatten)]
+    pub base: BaseCopConfig,
+}
+
+impl Default for EmptyLinesAroundMethodBody {
+    fn default() -> Self {
+        Self {
+            base: BaseCopConfig::default(),
+        }
+    }
+}
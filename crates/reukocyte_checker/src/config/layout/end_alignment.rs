@@ -1,9 +1,9 @@
 use crate::config::BaseCopConfig;
 use crate::diagnostic::Severity;
-use serde::Deserialize;
+use serde::{Deserialize, Serialize};
 
 /// Configuration for Layout/EndAlignment.
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, Deserialize, Serialize)]
 #[serde(default, rename_all = "PascalCase")]
 pub struct EndAlignment {
     /// Base configuration (enabled, severity, exclude, include).
@@ -23,7 +23,7 @@ impl Default for EndAlignment {
 }
 
 /// Alignment style for Layout/EndAlignment.
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Deserialize, Serialize)]
 #[serde(rename_all = "snake_case")]
 pub enum EnforcedStyleAlignWith {
     #[default]
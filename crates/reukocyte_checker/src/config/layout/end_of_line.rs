@@ -0,0 +1,37 @@
+use crate::config::BaseCopConfig;
+use serde::{Deserialize, Serialize};
+
+/// Configuration for Layout/EndOfLine.
+#[derive(Debug, Clone, Default, Deserialize, Serialize)]
+#[serde(default, rename_all = "PascalCase")]
+pub struct EndOfLine {
+    /// Base configuration (enabled, severity, exclude, include).
+    #[serde(flatten)]
+    pub base: BaseCopConfig,
+    /// Line terminator to use: `native` (the platform's), `lf` or `crlf`.
+    pub enforced_style: EnforcedStyle,
+}
+
+/// Line terminator style for Layout/EndOfLine.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Deserialize, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum EnforcedStyle {
+    /// `crlf` on Windows, `lf` elsewhere.
+    #[default]
+    Native,
+    /// Unix line endings (`\n`).
+    Lf,
+    /// Windows line endings (`\r\n`).
+    Crlf,
+}
+
+impl EnforcedStyle {
+    /// Check if lines should end with `\r\n`.
+    pub fn uses_crlf(self) -> bool {
+        match self {
+            EnforcedStyle::Native => cfg!(windows),
+            EnforcedStyle::Lf => false,
+            EnforcedStyle::Crlf => true,
+        }
+    }
+}
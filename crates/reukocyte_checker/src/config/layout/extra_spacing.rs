@@ -0,0 +1,34 @@
+use crate::config::BaseCopConfig;
+use serde::{Deserialize, Serialize};
+
+/// Configuration for Layout/ExtraSpacing.
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[serde(default, rename_all = "PascalCase")]
+pub struct ExtraSpacing {
+    /// Base configuration (enabled, severity, exclude, include).
+    #[serde(flatten)]
+    pub base: BaseCopConfig,
+    /// Allow extra spaces that align a token with the same token on an adjacent line.
+    pub allow_for_alignment: bool,
+}
+
+impl Default for ExtraSpacing {
+    fn default() -> Self {
+        Self {
+            base: BaseCopConfig::default(),
+            allow_for_alignment: true,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_allow_for_alignment_parsing() {
+        let config: ExtraSpacing = serde_yaml::from_str("AllowForAlignment: false\n").unwrap();
+        assert!(!config.allow_for_alignment);
+        assert!(ExtraSpacing::default().allow_for_alignment);
+    }
+}
@@ -1,16 +1,17 @@
 use crate::config::BaseCopConfig;
-use serde::Deserialize;
+use serde::{Deserialize, Serialize};
 
 const NORMAL: &str = "normal";
 const INDENTED_INTERNAL_METHODS: &str = "indented_internal_methods";
 
 /// Configuration for Layout/IndentationConsistency.
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, Deserialize, Serialize)]
 #[serde(default, rename_all = "PascalCase")]
 pub struct IndentationConsistency {
     /// Base configuration (enabled, severity, exclude, include).
     #[serde(flatten)]
     pub base: BaseCopConfig,
+    /// How the methods after a bare access modifier are indented.
     pub enforced_style: EnforcedStyle,
 }
 
@@ -24,7 +25,7 @@ impl Default for IndentationConsistency {
 }
 
 /// Indentation style for Layout/IndentationConsistency.
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Deserialize, Serialize)]
 #[serde(rename_all = "snake_case")]
 pub enum EnforcedStyle {
     #[default]
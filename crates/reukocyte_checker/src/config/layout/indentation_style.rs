@@ -1,8 +1,8 @@
 use crate::config::BaseCopConfig;
-use serde::Deserialize;
+use serde::{Deserialize, Serialize};
 
 /// Configuration for Layout/IndentationStyle.
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, Deserialize, Serialize)]
 #[serde(default, rename_all = "PascalCase")]
 pub struct IndentationStyle {
     /// Base configuration (enabled, severity, exclude, include).
@@ -25,7 +25,7 @@ impl Default for IndentationStyle {
 }
 
 /// Enforced style for indentation.
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Deserialize, Serialize)]
 #[serde(rename_all = "snake_case")]
 pub enum EnforcedStyle {
     /// Use spaces for indentation.
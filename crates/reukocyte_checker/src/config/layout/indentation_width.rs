@@ -1,14 +1,16 @@
 use crate::config::BaseCopConfig;
-use serde::Deserialize;
+use serde::{Deserialize, Serialize};
 
 /// Configuration for Layout/IndentationWidth.
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, Deserialize, Serialize)]
 #[serde(default, rename_all = "PascalCase")]
 pub struct IndentationWidth {
     /// Base configuration (enabled, severity, exclude, include).
     #[serde(flatten)]
     pub base: BaseCopConfig,
+    /// Number of spaces per indentation level.
     pub width: i32,
+    /// Patterns of lines whose indentation is not checked.
     pub allowed_patterns: Vec<i32>,
 }
 
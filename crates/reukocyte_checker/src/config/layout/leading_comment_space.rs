@@ -0,0 +1,29 @@
+use crate::config::BaseCopConfig;
+use serde::{Deserialize, Serialize};
+
+/// Configuration for Layout/LeadingCommentSpace.
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[serde(default, rename_all = "PascalCase")]
+pub struct LeadingCommentSpace {
+    /// Base configuration (enabled, severity, exclude, include).
+    #[serde(flatten)]
+    pub base: BaseCopConfig,
+    /// Allow a `#!` shebang on the first line.
+    pub allow_shebang: bool,
+    /// Allow `#:` markers (RBS inline annotations, rdoc `#:nodoc:`).
+    #[serde(rename = "AllowRBSInlineAnnotation")]
+    pub allow_rbs_inline_annotation: bool,
+    /// Allow comments starting with `#=` (`#=> value`, `#=begin`).
+    pub allow_equal_sign: bool,
+}
+
+impl Default for LeadingCommentSpace {
+    fn default() -> Self {
+        Self {
+            base: BaseCopConfig::default(),
+            allow_shebang: true,
+            allow_rbs_inline_annotation: false,
+            allow_equal_sign: true,
+        }
+    }
+}
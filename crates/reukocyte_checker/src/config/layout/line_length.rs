@@ -0,0 +1,25 @@
+use crate::config::BaseCopConfig;
+use serde::{Deserialize, Serialize};
+
+/// Configuration for Layout/LineLength.
+///
+/// The cop itself is not implemented yet; `Max` is read by other cops
+/// (Layout/RedundantLineBreak) to decide whether code fits on one line.
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[serde(default, rename_all = "PascalCase")]
+pub struct LineLength {
+    /// Base configuration (enabled, severity, exclude, include).
+    #[serde(flatten)]
+    pub base: BaseCopConfig,
+    /// Maximum line length in characters.
+    pub max: usize,
+}
+
+impl Default for LineLength {
+    fn default() -> Self {
+        Self {
+            base: BaseCopConfig::default(),
+            max: 120,
+        }
+    }
+}
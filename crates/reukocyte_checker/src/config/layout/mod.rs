@@ -1,12 +1,33 @@
 pub mod access_modifier_indentation;
 pub mod begin_end_alignment;
+pub mod block_alignment;
+pub mod block_end_newline;
+pub mod closing_heredoc_indentation;
+pub mod closing_parenthesis_indentation;
 pub mod def_end_alignment;
+pub mod empty_line_after_magic_comment;
+pub mod empty_line_between_defs;
 pub mod empty_lines;
+pub mod empty_lines_around_method_body;
 pub mod end_alignment;
+pub mod end_of_line;
+pub mod extra_spacing;
 pub mod indentation_consistency;
 pub mod indentation_style;
 pub mod indentation_width;
+pub mod leading_comment_space;
 pub mod leading_empty_lines;
+pub mod line_length;
+pub mod multiline_method_definition_brace_layout;
+pub mod redundant_line_break;
+pub mod space_after_colon;
+pub mod space_after_comma;
+pub mod space_after_method_name;
+pub mod space_before_block_braces;
+pub mod space_before_colon;
+pub mod space_before_comma;
+pub mod space_inside_array_literal_brackets;
+pub mod space_inside_string_interpolation;
 pub mod trailing_empty_lines;
 pub mod trailing_whitespace;
 
@@ -15,13 +36,34 @@ pub mod trailing_whitespace;
 pub struct LayoutConfig {
     pub access_modifier_indentation: access_modifier_indentation::AccessModifierIndentation,
     pub begin_end_alignment: begin_end_alignment::BeginEndAlignment,
+    pub block_alignment: block_alignment::BlockAlignment,
+    pub block_end_newline: block_end_newline::BlockEndNewline,
+    pub closing_heredoc_indentation: closing_heredoc_indentation::ClosingHeredocIndentation,
+    pub closing_parenthesis_indentation: closing_parenthesis_indentation::ClosingParenthesisIndentation,
     pub def_end_alignment: def_end_alignment::DefEndAlignment,
+    pub empty_line_after_magic_comment: empty_line_after_magic_comment::EmptyLineAfterMagicComment,
+    pub empty_line_between_defs: empty_line_between_defs::EmptyLineBetweenDefs,
     pub empty_lines: empty_lines::EmptyLines,
+    pub empty_lines_around_method_body: empty_lines_around_method_body::EmptyLinesAroundMethodBody,
     pub end_alignment: end_alignment::EndAlignment,
+    pub end_of_line: end_of_line::EndOfLine,
+    pub extra_spacing: extra_spacing::ExtraSpacing,
     pub indentation_consistency: indentation_consistency::IndentationConsistency,
     pub indentation_style: indentation_style::IndentationStyle,
     pub indentation_width: indentation_width::IndentationWidth,
+    pub leading_comment_space: leading_comment_space::LeadingCommentSpace,
     pub leading_empty_lines: leading_empty_lines::LeadingEmptyLines,
+    pub line_length: line_length::LineLength,
+    pub multiline_method_definition_brace_layout: multiline_method_definition_brace_layout::MultilineMethodDefinitionBraceLayout,
+    pub redundant_line_break: redundant_line_break::RedundantLineBreak,
+    pub space_after_colon: space_after_colon::SpaceAfterColon,
+    pub space_after_comma: space_after_comma::SpaceAfterComma,
+    pub space_after_method_name: space_after_method_name::SpaceAfterMethodName,
+    pub space_before_block_braces: space_before_block_braces::SpaceBeforeBlockBraces,
+    pub space_before_colon: space_before_colon::SpaceBeforeColon,
+    pub space_before_comma: space_before_comma::SpaceBeforeComma,
+    pub space_inside_array_literal_brackets: space_inside_array_literal_brackets::SpaceInsideArrayLiteralBrackets,
+    pub space_inside_string_interpolation: space_inside_string_interpolation::SpaceInsideStringInterpolation,
     pub trailing_empty_lines: trailing_empty_lines::TrailingEmptyLines,
     pub trailing_whitespace: trailing_whitespace::TrailingWhitespace,
 }
@@ -0,0 +1,36 @@
+use crate::config::BaseCopConfig;
+use serde::{Deserialize, Serialize};
+
+/// Configuration for Layout/MultilineMethodDefinitionBraceLayout.
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[serde(default, rename_all = "PascalCase")]
+pub struct MultilineMethodDefinitionBraceLayout {
+    /// Base configuration (enabled, severity, exclude, include).
+    #[serde(flatten)]
+    pub base: BaseCopConfig,
+    /// Where the closing parenthesis of a multiline parameter list goes.
+    pub enforced_style: EnforcedStyle,
+}
+
+impl Default for MultilineMethodDefinitionBraceLayout {
+    fn default() -> Self {
+        Self {
+            base: BaseCopConfig::default(),
+            enforced_style: EnforcedStyle::default(),
+        }
+    }
+}
+
+/// Brace layout style shared by the multiline brace layout cops.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Deserialize, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum EnforcedStyle {
+    /// The closing brace follows the opening brace: on the same line as the
+    /// last element when the opening brace is on the same line as the first.
+    #[default]
+    Symmetrical,
+    /// The closing brace is always on its own line.
+    NewLine,
+    /// The closing brace is always on the same line as the last element.
+    SameLine,
+}
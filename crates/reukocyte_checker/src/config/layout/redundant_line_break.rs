@@ -0,0 +1,48 @@
+use crate::config::BaseCopConfig;
+use serde::{Deserialize, Serialize};
+
+/// Configuration for Layout/RedundantLineBreak.
+///
+/// Disabled by default, as in RuboCop.
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[serde(default, rename_all = "PascalCase")]
+pub struct RedundantLineBreak {
+    /// Base configuration (enabled, severity, exclude, include).
+    #[serde(flatten)]
+    pub base: BaseCopConfig,
+    /// Enforced style for line breaks.
+    pub enforced_style: EnforcedStyle,
+}
+
+impl Default for RedundantLineBreak {
+    fn default() -> Self {
+        let mut base = BaseCopConfig::default();
+        base.enabled = false;
+        Self {
+            base,
+            enforced_style: EnforcedStyle::default(),
+        }
+    }
+}
+
+/// Enforced style for line breaks.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Deserialize, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum EnforcedStyle {
+    /// Expressions that fit within `Layout/LineLength` `Max` must be on one line.
+    #[default]
+    ForbidRedundantLineBreaks,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_disabled_by_default() {
+        assert!(!RedundantLineBreak::default().base.enabled);
+        let config: RedundantLineBreak = serde_yaml::from_str("Enabled: true\nEnforcedStyle: forbid_redundant_line_breaks\n").unwrap();
+        assert!(config.base.enabled);
+        assert_eq!(config.enforced_style, EnforcedStyle::ForbidRedundantLineBreaks);
+    }
+}
@@ -0,0 +1,19 @@
+use crate::config::BaseCopConfig;
+use serde::{Deserialize, Serialize};
+
+/// Configuration for Layout/SpaceAfterColon.
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[serde(default, rename_all = "PascalCase")]
+pub struct SpaceAfterColon {
+    /// Base configuration (enabled, severity, exclude, include).
+    #[serde(flatten)]
+    pub base: BaseCopConfig,
+}
+
+impl Default for SpaceAfterColon {
+    fn default() -> Self {
+        Self {
+            base: BaseCopConfig::default(),
+        }
+    }
+}
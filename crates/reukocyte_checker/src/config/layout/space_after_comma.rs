@@ -0,0 +1,19 @@
+use crate::config::BaseCopConfig;
+use serde::{Deserialize, Serialize};
+
+/// Configuration for Layout/SpaceAfterComma.
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[serde(default, rename_all = "PascalCase")]
+pub struct SpaceAfterComma {
+    /// Base configuration (enabled, severity, exclude, include).
+    #[serde(flatten)]
+    pub base: BaseCopConfig,
+}
+
+impl Default for SpaceAfterComma {
+    fn default() -> Self {
+        Self {
+            base: BaseCopConfig::default(),
+        }
+    }
+}
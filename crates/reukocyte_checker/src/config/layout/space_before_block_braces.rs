@@ -0,0 +1,36 @@
+use crate::config::BaseCopConfig;
+use serde::{Deserialize, Serialize};
+
+/// Configuration for Layout/SpaceBeforeBlockBraces.
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[serde(default, rename_all = "PascalCase")]
+pub struct SpaceBeforeBlockBraces {
+    /// Base configuration (enabled, severity, exclude, include).
+    #[serde(flatten)]
+    pub base: BaseCopConfig,
+    /// Whether a space is required before the `{` of a block.
+    pub enforced_style: EnforcedStyle,
+    /// Same as `enforced_style`, for empty blocks (`{}`).
+    pub enforced_style_for_empty_braces: EnforcedStyle,
+}
+
+impl Default for SpaceBeforeBlockBraces {
+    fn default() -> Self {
+        Self {
+            base: BaseCopConfig::default(),
+            enforced_style: EnforcedStyle::default(),
+            enforced_style_for_empty_braces: EnforcedStyle::default(),
+        }
+    }
+}
+
+/// Spacing style for Layout/SpaceBeforeBlockBraces.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Deserialize, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum EnforcedStyle {
+    /// `foo { bar }`
+    #[default]
+    Space,
+    /// `foo{ bar }`
+    NoSpace,
+}
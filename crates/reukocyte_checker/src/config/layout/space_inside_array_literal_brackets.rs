@@ -0,0 +1,64 @@
+use crate::config::BaseCopConfig;
+use serde::{Deserialize, Serialize};
+
+/// Configuration for Layout/SpaceInsideArrayLiteralBrackets.
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[serde(default, rename_all = "PascalCase")]
+pub struct SpaceInsideArrayLiteralBrackets {
+    /// Base configuration (enabled, severity, exclude, include).
+    #[serde(flatten)]
+    pub base: BaseCopConfig,
+    /// Spacing inside the brackets of non-empty arrays.
+    pub enforced_style: EnforcedStyle,
+    /// Spacing inside the brackets of empty arrays (`[]`).
+    pub enforced_style_for_empty_brackets: EnforcedStyleForEmptyBrackets,
+}
+
+impl Default for SpaceInsideArrayLiteralBrackets {
+    fn default() -> Self {
+        Self {
+            base: BaseCopConfig::default(),
+            enforced_style: EnforcedStyle::default(),
+            enforced_style_for_empty_brackets: EnforcedStyleForEmptyBrackets::default(),
+        }
+    }
+}
+
+/// Spacing style for Layout/SpaceInsideArrayLiteralBrackets.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Deserialize, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum EnforcedStyle {
+    /// `[1, 2]`
+    #[default]
+    NoSpace,
+    /// `[ 1, 2 ]`
+    Space,
+    /// `[[ 1, 2 ], [ 3 ]]`: spaces, except between consecutive brackets.
+    Compact,
+}
+
+/// Spacing style for empty arrays in Layout/SpaceInsideArrayLiteralBrackets.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Deserialize, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum EnforcedStyleForEmptyBrackets {
+    /// `[]`
+    #[default]
+    NoSpace,
+    /// `[ ]`
+    Space,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_deserialize_styles() {
+        let config: SpaceInsideArrayLiteralBrackets = serde_yaml::from_str("EnforcedStyle: compact\nEnforcedStyleForEmptyBrackets: space\n").unwrap();
+        assert_eq!(config.enforced_style, EnforcedStyle::Compact);
+        assert_eq!(config.enforced_style_for_empty_brackets, EnforcedStyleForEmptyBrackets::Space);
+        let config = SpaceInsideArrayLiteralBrackets::default();
+        assert_eq!(config.enforced_style, EnforcedStyle::NoSpace);
+        assert_eq!(config.enforced_style_for_empty_brackets, EnforcedStyleForEmptyBrackets::NoSpace);
+    }
+}
@@ -0,0 +1,42 @@
+use crate::config::BaseCopConfig;
+use serde::{Deserialize, Serialize};
+
+const NO_SPACE: &str = "no_space";
+const SPACE: &str = "space";
+
+/// Configuration for Layout/SpaceInsideStringInterpolation.
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[serde(default, rename_all = "PascalCase")]
+pub struct SpaceInsideStringInterpolation {
+    /// Base configuration (enabled, severity, exclude, include).
+    #[serde(flatten)]
+    pub base: BaseCopConfig,
+    /// Spacing inside `#{` and `}` (`no_space` or `space`).
+    pub enforced_style: EnforcedStyle,
+}
+
+impl Default for SpaceInsideStringInterpolation {
+    fn default() -> Self {
+        Self {
+            base: BaseCopConfig::default(),
+            enforced_style: EnforcedStyle::default(),
+        }
+    }
+}
+
+/// Spacing style for Layout/SpaceInsideStringInterpolation.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Deserialize, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum EnforcedStyle {
+    #[default]
+    NoSpace,
+    Space,
+}
+impl EnforcedStyle {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::NoSpace => NO_SPACE,
+            Self::Space => SPACE,
+        }
+    }
+}
@@ -1,8 +1,8 @@
 use crate::config::BaseCopConfig;
-use serde::Deserialize;
+use serde::{Deserialize, Serialize};
 
 /// Configuration for Layout/TrailingEmptyLines.
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, Deserialize, Serialize)]
 #[serde(default, rename_all = "PascalCase")]
 pub struct TrailingEmptyLines {
     /// Base configuration (enabled, severity, exclude, include).
@@ -22,7 +22,7 @@ impl Default for TrailingEmptyLines {
 }
 
 /// Enforced style for trailing empty lines.
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Deserialize, Serialize)]
 #[serde(rename_all = "snake_case")]
 pub enum EnforcedStyle {
     /// Require exactly one final newline (no trailing blank lines).
@@ -1,8 +1,8 @@
 use crate::config::BaseCopConfig;
-use serde::Deserialize;
+use serde::{Deserialize, Serialize};
 
 /// Configuration for Layout/TrailingWhitespace.
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, Deserialize, Serialize)]
 #[serde(default, rename_all = "PascalCase")]
 pub struct TrailingWhitespace {
     /// Base configuration (enabled, severity, exclude, include).
@@ -30,7 +30,7 @@ Exclude:
   - "vendor/**/*"
 "#;
         let config: TrailingWhitespace = serde_yaml::from_str(yaml).unwrap();
-        assert_eq!(config.base.exclude.len(), 2);
-        assert_eq!(config.base.exclude[0], "test.rb");
+        assert_eq!(config.base.exclude().len(), 2);
+        assert_eq!(config.base.exclude()[0], "test.rb");
     }
 }
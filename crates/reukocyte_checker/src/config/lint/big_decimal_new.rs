@@ -0,0 +1,20 @@
+use crate::config::BaseCopConfig;
+use crate::diagnostic::Severity;
+use serde::{Deserialize, Serialize};
+
+/// Configuration for Lint/BigDecimalNew.
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[serde(default, rename_all = "PascalCase")]
+pub struct BigDecimalNew {
+    /// Base configuration (enabled, severity, exclude, include).
+    #[serde(flatten)]
+    pub base: BaseCopConfig,
+}
+
+impl Default for BigDecimalNew {
+    fn default() -> Self {
+        Self {
+            base: BaseCopConfig::with_severity(Severity::Warning),
+        }
+    }
+}
@@ -0,0 +1,20 @@
+use crate::config::BaseCopConfig;
+use crate::diagnostic::Severity;
+use serde::{Deserialize, Serialize};
+
+/// Configuration for Lint/BooleanSymbol.
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[serde(default, rename_all = "PascalCase")]
+pub struct BooleanSymbol {
+    /// Base configuration (enabled, severity, exclude, include).
+    #[serde(flatten)]
+    pub base: BaseCopConfig,
+}
+
+impl Default for BooleanSymbol {
+    fn default() -> Self {
+        Self {
+            base: BaseCopConfig::with_severity(Severity::Warning),
+        }
+    }
+}
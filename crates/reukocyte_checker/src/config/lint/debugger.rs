@@ -1,20 +1,122 @@
 use crate::config::BaseCopConfig;
 use crate::diagnostic::Severity;
-use serde::Deserialize;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+
+/// Default debugger entry points, grouped as in RuboCop's `DebuggerMethods`.
+const DEFAULT_DEBUGGER_METHODS: &[(&str, &[&str])] = &[
+    ("Kernel", &["binding.irb", "Kernel.binding.irb"]),
+    ("Byebug", &["byebug", "remote_byebug", "Kernel.byebug", "Kernel.remote_byebug"]),
+    (
+        "Capybara",
+        &[
+            "page.save_and_open_page",
+            "page.save_and_open_screenshot",
+            "page.save_page",
+            "page.save_screenshot",
+            "save_and_open_page",
+            "save_and_open_screenshot",
+            "save_page",
+            "save_screenshot",
+        ],
+    ),
+    ("debug.rb", &["binding.b", "binding.break", "Kernel.binding.b", "Kernel.binding.break"]),
+    (
+        "Pry",
+        &[
+            "binding.pry",
+            "binding.remote_pry",
+            "binding.pry_remote",
+            "Kernel.binding.pry",
+            "Kernel.binding.remote_pry",
+            "Kernel.binding.pry_remote",
+            "Pry.rescue",
+            "pry",
+        ],
+    ),
+    ("Rails", &["debugger", "Kernel.debugger"]),
+    ("RubyJard", &["jard"]),
+    ("WebConsole", &["binding.console"]),
+];
 
 /// Configuration for Lint/Debugger.
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, Deserialize, Serialize)]
 #[serde(default, rename_all = "PascalCase")]
 pub struct Debugger {
     /// Base configuration (enabled, severity, exclude, include).
     #[serde(flatten)]
     pub base: BaseCopConfig,
+    /// Calls treated as debugger entry points, either bare (`byebug`) or with
+    /// their receiver chain (`binding.irb`, `Pry.rescue`).
+    #[serde(deserialize_with = "deserialize_debugger_methods")]
+    pub debugger_methods: Vec<String>,
 }
 
 impl Default for Debugger {
     fn default() -> Self {
         Self {
             base: BaseCopConfig::with_severity(Severity::Warning),
+            debugger_methods: flatten_groups(default_groups()),
+        }
+    }
+}
+
+fn default_groups() -> Vec<(String, Vec<String>)> {
+    DEFAULT_DEBUGGER_METHODS
+        .iter()
+        .map(|(group, methods)| (group.to_string(), methods.iter().map(|m| m.to_string()).collect()))
+        .collect()
+}
+
+fn flatten_groups(groups: Vec<(String, Vec<String>)>) -> Vec<String> {
+    groups.into_iter().flat_map(|(_, methods)| methods).collect()
+}
+
+/// Deserialize `DebuggerMethods`, which may be a flat list replacing the defaults or
+/// a mapping of groups merged into the default groups. A group set to `~` is removed.
+fn deserialize_debugger_methods<'de, D>(deserializer: D) -> Result<Vec<String>, D::Error>
+where
+    D: serde::Deserializer<'de>,
+{
+    #[derive(Deserialize)]
+    #[serde(untagged)]
+    enum MethodsValue {
+        List(Vec<String>),
+        Groups(BTreeMap<String, Option<Vec<String>>>),
+    }
+
+    match MethodsValue::deserialize(deserializer)? {
+        MethodsValue::List(methods) => Ok(methods),
+        MethodsValue::Groups(overrides) => {
+            let mut groups = default_groups();
+            for (name, methods) in overrides {
+                let existing = groups.iter().position(|(group, _)| *group == name);
+                match (existing, methods) {
+                    (Some(index), Some(methods)) => groups[index].1 = methods,
+                    (Some(index), None) => {
+                        groups.remove(index);
+                    }
+                    (None, Some(methods)) => groups.push((name, methods)),
+                    (None, None) => {}
+                }
+            }
+            Ok(flatten_groups(groups))
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_deserialize_debugger_methods() {
+        let config: Debugger = serde_yaml::from_str("DebuggerMethods:\n  Custom:\n    - my_debugger\n  Pry: ~\n").unwrap();
+        assert!(config.debugger_methods.iter().any(|m| m == "my_debugger"));
+        assert!(config.debugger_methods.iter().any(|m| m == "binding.irb"));
+        assert!(!config.debugger_methods.iter().any(|m| m == "binding.pry"));
+
+        let config: Debugger = serde_yaml::from_str("DebuggerMethods:\n  - my_debugger\n").unwrap();
+        assert_eq!(config.debugger_methods, vec!["my_debugger".to_string()]);
+    }
+}
@@ -0,0 +1,26 @@
+use crate::config::BaseCopConfig;
+use crate::diagnostic::Severity;
+use serde::{Deserialize, Serialize};
+
+/// Configuration for Lint/EmptyBlock.
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[serde(default, rename_all = "PascalCase")]
+pub struct EmptyBlock {
+    /// Base configuration (enabled, severity, exclude, include).
+    #[serde(flatten)]
+    pub base: BaseCopConfig,
+    /// Allow empty blocks that contain a comment.
+    pub allow_comments: bool,
+    /// Allow empty lambdas and procs (`-> {}`, `lambda {}`, `proc {}` and `Proc.new {}`).
+    pub allow_empty_lambdas: bool,
+}
+
+impl Default for EmptyBlock {
+    fn default() -> Self {
+        Self {
+            base: BaseCopConfig::with_severity(Severity::Warning),
+            allow_comments: true,
+            allow_empty_lambdas: true,
+        }
+    }
+}
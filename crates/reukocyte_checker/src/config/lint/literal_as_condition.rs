@@ -0,0 +1,23 @@
+use crate::config::BaseCopConfig;
+use crate::diagnostic::Severity;
+use serde::{Deserialize, Serialize};
+
+/// Configuration for Lint/LiteralAsCondition.
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[serde(default, rename_all = "PascalCase")]
+pub struct LiteralAsCondition {
+    /// Base configuration (enabled, severity, exclude, include).
+    #[serde(flatten)]
+    pub base: BaseCopConfig,
+    /// Allow `while true` and `until false`, infinite loops left with `break`.
+    pub allow_infinite_loops: bool,
+}
+
+impl Default for LiteralAsCondition {
+    fn default() -> Self {
+        Self {
+            base: BaseCopConfig::with_severity(Severity::Warning),
+            allow_infinite_loops: true,
+        }
+    }
+}
@@ -1,7 +1,33 @@
+pub mod big_decimal_new;
+pub mod boolean_symbol;
 pub mod debugger;
+pub mod else_layout;
+pub mod empty_block;
+pub mod empty_interpolation;
+pub mod implicit_string_concatenation;
+pub mod literal_as_condition;
+pub mod percent_string_array;
+pub mod percent_symbol_array;
+pub mod redundant_with_index;
+pub mod redundant_with_object;
+pub mod script_permission;
+pub mod to_enum_arguments;
 
 /// Lint cop configurations.
 #[derive(Debug, Clone, Default)]
 pub struct LintConfig {
+    pub big_decimal_new: big_decimal_new::BigDecimalNew,
+    pub boolean_symbol: boolean_symbol::BooleanSymbol,
     pub debugger: debugger::Debugger,
+    pub else_layout: else_layout::ElseLayout,
+    pub empty_block: empty_block::EmptyBlock,
+    pub empty_interpolation: empty_interpolation::EmptyInterpolation,
+    pub implicit_string_concatenation: implicit_string_concatenation::ImplicitStringConcatenation,
+    pub literal_as_condition: literal_as_condition::LiteralAsCondition,
+    pub percent_string_array: percent_string_array::PercentStringArray,
+    pub percent_symbol_array: percent_symbol_array::PercentSymbolArray,
+    pub redundant_with_index: redundant_with_index::RedundantWithIndex,
+    pub redundant_with_object: redundant_with_object::RedundantWithObject,
+    pub script_permission: script_permission::ScriptPermission,
+    pub to_enum_arguments: to_enum_arguments::ToEnumArguments,
 }
@@ -1,4 +1,4 @@
-use super::yaml::{RubocopYaml, merge_configs};
+use super::yaml::{RubocopYaml, explicit_cop_options, merge_configs};
 use rustc_hash::FxHashSet;
 use std::io;
 use std::path::{Path, PathBuf};
@@ -8,8 +8,8 @@ use std::path::{Path, PathBuf};
 pub enum LoadError {
     /// IO error when reading a file.
     Io(io::Error),
-    /// YAML parsing error.
-    Yaml(serde_yaml::Error),
+    /// YAML parsing error, with the file it came from when known.
+    Yaml { path: Option<PathBuf>, error: serde_yaml::Error },
     /// Circular inheritance detected.
     CircularInheritance(PathBuf),
 }
@@ -17,7 +17,17 @@ impl std::fmt::Display for LoadError {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
             LoadError::Io(e) => write!(f, "IO error: {}", e),
-            LoadError::Yaml(e) => write!(f, "YAML parsing error: {}", e),
+            LoadError::Yaml { path, error } => match (path, error.location()) {
+                (Some(path), Some(location)) => {
+                    // The location is already in the prefix, so drop serde_yaml's " at line N column M".
+                    let message = error.to_string();
+                    let position = format!(" at line {} column {}", location.line(), location.column());
+                    let message = message.replacen(&position, "", 1);
+                    write!(f, "{}:{}:{}: invalid YAML: {}", path.display(), location.line(), location.column(), message)
+                }
+                (Some(path), None) => write!(f, "{}: invalid YAML: {}", path.display(), error),
+                (None, _) => write!(f, "YAML parsing error: {}", error),
+            },
             LoadError::CircularInheritance(p) => {
                 write!(f, "Circular inheritance detected: {}", p.display())
             }
@@ -28,7 +38,7 @@ impl std::error::Error for LoadError {
     fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
         match self {
             LoadError::Io(e) => Some(e),
-            LoadError::Yaml(e) => Some(e),
+            LoadError::Yaml { error, .. } => Some(error),
             LoadError::CircularInheritance(_) => None,
         }
     }
@@ -40,7 +50,26 @@ impl From<io::Error> for LoadError {
 }
 impl From<serde_yaml::Error> for LoadError {
     fn from(e: serde_yaml::Error) -> Self {
-        LoadError::Yaml(e)
+        LoadError::Yaml { path: None, error: e }
+    }
+}
+impl LoadError {
+    /// Get the 1-indexed `(line, column)` of a YAML parse failure, if known.
+    pub fn location(&self) -> Option<(usize, usize)> {
+        match self {
+            LoadError::Yaml { error, .. } => error.location().map(|location| (location.line(), location.column())),
+            _ => None,
+        }
+    }
+    /// Attach the file a YAML error came from.
+    fn with_path(self, path: &Path) -> Self {
+        match self {
+            LoadError::Yaml { error, .. } => LoadError::Yaml {
+                path: Some(path.to_path_buf()),
+                error,
+            },
+            other => other,
+        }
     }
 }
 
@@ -64,7 +93,9 @@ pub fn load_rubocop_yaml(path: &Path) -> Result<RubocopYaml, LoadError> {
 
 /// Load a .rubocop.yml file from a string (useful for testing).
 pub fn parse_rubocop_yaml(content: &str) -> Result<RubocopYaml, LoadError> {
-    Ok(serde_yaml::from_str(content)?)
+    let mut config: RubocopYaml = serde_yaml::from_str(content)?;
+    config.cop_options = explicit_cop_options(&serde_yaml::from_str(content)?);
+    Ok(config)
 }
 
 /// Internal function that tracks visited files to detect circular inheritance.
@@ -78,7 +109,7 @@ fn load_with_inheritance(path: &Path, visited: &mut FxHashSet<PathBuf>) -> Resul
 
     // Read and parse the YAML file
     let content = std::fs::read_to_string(path)?;
-    let mut config: RubocopYaml = serde_yaml::from_str(&content)?;
+    let mut config = parse_rubocop_yaml(&content).map_err(|e| e.with_path(path))?;
 
     // Resolve inherit_from
     if !config.inherit_from.is_empty() {
@@ -166,7 +197,7 @@ AllCops:
         let merged = merge_configs(parent, child);
 
         // Child's ruby version overrides parent
-        assert_eq!(merged.all_cops.target_ruby_version, Some("3.2".to_string()));
+        assert_eq!(merged.all_cops.target_ruby_version, Some(3.2));
         // Parent's exclude is kept (child didn't specify)
         assert_eq!(merged.all_cops.exclude.len(), 1);
     }
@@ -180,4 +211,21 @@ Layout/EndAlignment:
         let config = parse_rubocop_yaml(yaml).unwrap();
         assert!(!config.end_alignment.base.enabled);
     }
+
+    #[test]
+    fn test_yaml_error_location() {
+        let path = std::env::temp_dir().join(format!("reuko-loader-{}-bad.yml", std::process::id()));
+        std::fs::write(&path, "Layout/EndAlignment:\n    Enabled: false\n  EnforcedStyleAlignWith: variable\n").unwrap();
+        let error = load_rubocop_yaml(&path).unwrap_err();
+        std::fs::remove_file(&path).unwrap();
+
+        let (line, column) = error.location().unwrap();
+        assert!(line > 0);
+        assert!(matches!(&error, LoadError::Yaml { path: Some(p), .. } if *p == path));
+        let message = error.to_string();
+        assert!(message.starts_with(&format!("{}:{}:{}: invalid YAML: ", path.display(), line, column)));
+        assert!(!message.contains(&format!(" at line {} column {}", line, column)));
+        // Without a file there is no path to report.
+        assert!(parse_rubocop_yaml("a: [").unwrap_err().to_string().starts_with("YAML parsing error: "));
+    }
 }
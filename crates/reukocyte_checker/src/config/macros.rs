@@ -1,6 +1,11 @@
 /// Define all cops and generate:
+/// - `COP_NAMES`, the list of all configurable cop names
 /// - `RubocopYaml` struct fields with serde rename attributes
 /// - `from_rubocop_yaml` implementation for Config
+/// - `Config::base_config` / `base_config_mut`, looking up a cop's base configuration by name
+/// - `Config::set_cop_from_yaml`, replacing one cop's configuration by name
+/// - `Config::cop_to_yaml`, serializing one cop's configuration by name
+/// - `Config::to_yaml`, serializing the resolved configuration back to YAML
 /// - `merge_configs` function for configuration inheritance
 ///
 /// # Usage
@@ -13,6 +18,9 @@
 ///     lint {
 ///         "Lint/Debugger" => Debugger, debugger,
 ///     }
+///     style {
+///         "Style/ExpandPathArguments" => ExpandPathArguments, expand_path_arguments,
+///     }
 /// }
 /// ```
 macro_rules! define_cops {
@@ -23,7 +31,17 @@ macro_rules! define_cops {
         lint {
             $($lint_rename:literal => $lint_cop:ident, $lint_field:ident),* $(,)?
         }
+        style {
+            $($style_rename:literal => $style_cop:ident, $style_field:ident),* $(,)?
+        }
     ) => {
+        // ============================================================
+        // COP_NAMES
+        // ============================================================
+
+        /// Names of all configurable cops (e.g. `Layout/EndAlignment`).
+        pub(super) const COP_NAMES: &[&str] = &[$($layout_rename,)* $($lint_rename,)* $($style_rename,)*];
+
         // ============================================================
         // RubocopYaml struct
         // ============================================================
@@ -45,6 +63,12 @@ macro_rules! define_cops {
             #[serde(rename = "AllCops", default)]
             pub all_cops: AllCopsConfig,
 
+            /// Options set explicitly per cop, as raw YAML keyed by cop name.
+            ///
+            /// Filled by `parse_rubocop_yaml`, `load_rubocop_yaml` and `ConfigBuilder`.
+            #[serde(skip)]
+            pub cop_options: std::collections::BTreeMap<String, serde_yaml::Mapping>,
+
             // Layout cops
             $(
                 #[serde(rename = $layout_rename, default)]
@@ -56,6 +80,12 @@ macro_rules! define_cops {
                 #[serde(rename = $lint_rename, default)]
                 pub $lint_field: super::lint::$lint_field::$lint_cop,
             )*
+
+            // Style cops
+            $(
+                #[serde(rename = $style_rename, default)]
+                pub $style_field: super::style::$style_field::$style_cop,
+            )*
         }
 
         // ============================================================
@@ -64,8 +94,11 @@ macro_rules! define_cops {
 
         impl super::Config {
             /// Create a Config from a parsed RubocopYaml.
+            ///
+            /// Each cop's `Include`/`Exclude` patterns are compiled once here, and the
+            /// options set explicitly in `yaml.cop_options` are recorded for `merged_with`.
             pub fn from_rubocop_yaml(yaml: &RubocopYaml) -> Self {
-                super::Config {
+                let mut config = super::Config {
                     all_cops: yaml.all_cops.clone(),
                     layout: super::layout::LayoutConfig {
                         $(
@@ -77,8 +110,101 @@ macro_rules! define_cops {
                             $lint_field: yaml.$lint_field.clone(),
                         )*
                     },
+                    style: super::style::StyleConfig {
+                        $(
+                            $style_field: yaml.$style_field.clone(),
+                        )*
+                    },
+                };
+                $(
+                    config.layout.$layout_field.base.compile_patterns();
+                )*
+                $(
+                    config.lint.$lint_field.base.compile_patterns();
+                )*
+                $(
+                    config.style.$style_field.base.compile_patterns();
+                )*
+                for (cop, options) in &yaml.cop_options {
+                    if let Some(base) = config.base_config_mut(cop) {
+                        base.set_explicit_keys(options.keys().filter_map(|key| key.as_str().map(str::to_string)).collect());
+                    }
+                }
+                config
+            }
+        }
+
+        impl super::Config {
+            /// Get the base configuration of a cop by name (e.g. `Layout/EndAlignment`).
+            pub fn base_config(&self, cop: &str) -> Option<&super::BaseCopConfig> {
+                match cop {
+                    $($layout_rename => Some(&self.layout.$layout_field.base),)*
+                    $($lint_rename => Some(&self.lint.$lint_field.base),)*
+                    $($style_rename => Some(&self.style.$style_field.base),)*
+                    _ => None,
                 }
             }
+
+            /// Get the base configuration of a cop by name, mutably.
+            pub(super) fn base_config_mut(&mut self, cop: &str) -> Option<&mut super::BaseCopConfig> {
+                match cop {
+                    $($layout_rename => Some(&mut self.layout.$layout_field.base),)*
+                    $($lint_rename => Some(&mut self.lint.$lint_field.base),)*
+                    $($style_rename => Some(&mut self.style.$style_field.base),)*
+                    _ => None,
+                }
+            }
+
+            /// Replace the configuration of a cop by name with the one in `yaml`,
+            /// compiling its `Include`/`Exclude` patterns. Unknown names are ignored.
+            pub(super) fn set_cop_from_yaml(&mut self, cop: &str, yaml: &RubocopYaml) {
+                match cop {
+                    $($layout_rename => {
+                        self.layout.$layout_field = yaml.$layout_field.clone();
+                        self.layout.$layout_field.base.compile_patterns();
+                    })*
+                    $($lint_rename => {
+                        self.lint.$lint_field = yaml.$lint_field.clone();
+                        self.lint.$lint_field.base.compile_patterns();
+                    })*
+                    $($style_rename => {
+                        self.style.$style_field = yaml.$style_field.clone();
+                        self.style.$style_field.base.compile_patterns();
+                    })*
+                    _ => {}
+                }
+            }
+
+            /// Serialize the configuration of a cop by name to its YAML options.
+            /// Unknown names give `None`.
+            pub(super) fn cop_to_yaml(&self, cop: &str) -> Option<serde_yaml::Mapping> {
+                fn mapping(config: &impl serde::Serialize) -> serde_yaml::Mapping {
+                    match serde_yaml::to_value(config).expect("configuration serializes to YAML") {
+                        serde_yaml::Value::Mapping(mapping) => mapping,
+                        _ => unreachable!("cop configurations serialize to mappings"),
+                    }
+                }
+                match cop {
+                    $($layout_rename => Some(mapping(&self.layout.$layout_field)),)*
+                    $($lint_rename => Some(mapping(&self.lint.$lint_field)),)*
+                    $($style_rename => Some(mapping(&self.style.$style_field)),)*
+                    _ => None,
+                }
+            }
+
+            /// Serialize the resolved configuration in `.rubocop.yml` form: `AllCops`,
+            /// then every cop with its `Enabled`, `Severity` and options.
+            pub fn to_yaml(&self) -> String {
+                fn value(config: &impl serde::Serialize) -> serde_yaml::Value {
+                    serde_yaml::to_value(config).expect("configuration serializes to YAML")
+                }
+                let mut root = serde_yaml::Mapping::new();
+                root.insert("AllCops".into(), value(&self.all_cops));
+                $(root.insert($layout_rename.into(), value(&self.layout.$layout_field));)*
+                $(root.insert($lint_rename.into(), value(&self.lint.$lint_field));)*
+                $(root.insert($style_rename.into(), value(&self.style.$style_field));)*
+                serde_yaml::to_string(&root).expect("configuration serializes to YAML")
+            }
         }
 
         // ============================================================
@@ -105,6 +231,7 @@ macro_rules! define_cops {
             RubocopYaml {
                 inherit_from: child.inherit_from,
                 all_cops: merge_all_cops(parent.all_cops, child.all_cops),
+                cop_options: child.cop_options,
                 $(
                     $layout_field: merge_cop!(
                         parent.$layout_field,
@@ -119,6 +246,13 @@ macro_rules! define_cops {
                         super::lint::$lint_field::$lint_cop::default()
                     ),
                 )*
+                $(
+                    $style_field: merge_cop!(
+                        parent.$style_field,
+                        child.$style_field,
+                        super::style::$style_field::$style_cop::default()
+                    ),
+                )*
             }
         }
     };
@@ -1,16 +1,23 @@
 mod base;
+mod builder;
 pub mod layout;
 pub mod lint;
 mod loader;
 mod macros;
 pub(crate) mod serde_helpers;
+pub mod style;
 mod yaml;
 
 pub use base::BaseCopConfig;
+pub(crate) use base::{build_glob_set, is_excluded, matches_patterns};
+pub use builder::{ConfigBuildError, ConfigBuilder};
 
 pub use layout::*;
 pub use loader::{LoadError, load_rubocop_yaml, parse_rubocop_yaml};
-pub use yaml::{AllCopsConfig, InheritFrom, RubocopYaml};
+pub use yaml::{AllCopsConfig, DEFAULT_TARGET_RUBY_VERSION, InheritFrom, RubocopYaml};
+
+use crate::rule::RuleId;
+use std::path::PathBuf;
 
 /// The main configuration struct.
 #[derive(Debug, Clone, Default)]
@@ -21,6 +28,62 @@ pub struct Config {
     pub layout: layout::LayoutConfig,
     /// Lint cop-specific configurations.
     pub lint: lint::LintConfig,
+    /// Style cop-specific configurations.
+    pub style: style::StyleConfig,
 }
 
 // Note: Config::from_rubocop_yaml is generated by define_cops! macro in yaml.rs
+
+impl Config {
+    /// Filter `candidates` down to the files a cop runs on.
+    ///
+    /// A file is kept unless `AllCops.Exclude` excludes it, and only if the cop's own
+    /// `Include`/`Exclude` patterns let it run there, as when checking. A disabled cop
+    /// runs on no files.
+    pub fn target_files_for_cop(&self, rule: RuleId, candidates: &[PathBuf]) -> Vec<PathBuf> {
+        let base = self.base_config(&rule.to_string());
+        if base.is_some_and(|base| !base.enabled) {
+            return Vec::new();
+        }
+        let exclude = build_glob_set(&self.all_cops.exclude);
+        candidates
+            .iter()
+            .filter(|path| !is_excluded(path, exclude.as_ref()))
+            .filter(|path| base.is_none_or(|base| base.applies_to(&path.to_string_lossy())))
+            .cloned()
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::rule::{LayoutRule, LintRule};
+
+    #[test]
+    fn test_target_files_for_cop() {
+        let yaml = parse_rubocop_yaml("AllCops:\n  Exclude:\n    - db/**/*\nLayout/TrailingWhitespace:\n  Exclude:\n    - spec/**/*\n").unwrap();
+        let config = Config::from_rubocop_yaml(&yaml);
+        let candidates: Vec<PathBuf> = ["app/user.rb", "spec/user_spec.rb", "./spec/models/post_spec.rb", "db/schema.rb"]
+            .iter()
+            .map(PathBuf::from)
+            .collect();
+
+        let trailing_whitespace = config.target_files_for_cop(RuleId::Layout(LayoutRule::TrailingWhitespace), &candidates);
+        assert_eq!(trailing_whitespace, [PathBuf::from("app/user.rb")]);
+        let debugger = config.target_files_for_cop(RuleId::Lint(LintRule::Debugger), &candidates);
+        assert_eq!(debugger, candidates[..3]);
+    }
+
+    #[test]
+    fn test_target_files_for_disabled_or_included_cop() {
+        let yaml = parse_rubocop_yaml("Lint/Debugger:\n  Enabled: false\nLayout/TrailingWhitespace:\n  Include:\n    - lib/**/*.rb\n").unwrap();
+        let config = Config::from_rubocop_yaml(&yaml);
+        let candidates = [PathBuf::from("lib/a.rb"), PathBuf::from("app/b.rb")];
+        assert!(config.target_files_for_cop(RuleId::Lint(LintRule::Debugger), &candidates).is_empty());
+        assert_eq!(
+            config.target_files_for_cop(RuleId::Layout(LayoutRule::TrailingWhitespace), &candidates),
+            [PathBuf::from("lib/a.rb")]
+        );
+    }
+}
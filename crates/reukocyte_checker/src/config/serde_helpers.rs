@@ -1,7 +1,8 @@
-//! Serde helpers for deserializing RuboCop YAML configuration.
+//! Serde helpers for (de)serializing RuboCop YAML configuration.
 
 use crate::diagnostic::Severity;
 use serde::Deserialize;
+use std::ops::RangeInclusive;
 
 /// Deserialize the `Enabled` field which can be a bool or string like "pending".
 pub fn deserialize_enabled<'de, D>(deserializer: D) -> Result<bool, D::Error>
@@ -21,6 +22,25 @@ where
     }
 }
 
+/// Deserialize the `AutoCorrect` field which can be a bool or a string like
+/// "always", "contextual" or "disabled".
+pub fn deserialize_auto_correct<'de, D>(deserializer: D) -> Result<bool, D::Error>
+where
+    D: serde::Deserializer<'de>,
+{
+    #[derive(Deserialize)]
+    #[serde(untagged)]
+    enum AutoCorrectValue {
+        Bool(bool),
+        String(String),
+    }
+
+    match AutoCorrectValue::deserialize(deserializer)? {
+        AutoCorrectValue::Bool(b) => Ok(b),
+        AutoCorrectValue::String(s) => Ok(!matches!(s.to_lowercase().as_str(), "false" | "disabled")),
+    }
+}
+
 /// Deserialize the `Severity` field from a string.
 pub fn deserialize_severity<'de, D>(deserializer: D) -> Result<Severity, D::Error>
 where
@@ -30,6 +50,33 @@ where
     Ok(parse_severity(&s))
 }
 
+/// Serialize the `Severity` field as its lowercase name.
+pub fn serialize_severity<S>(severity: &Severity, serializer: S) -> Result<S::Ok, S::Error>
+where
+    S: serde::Serializer,
+{
+    serializer.serialize_str(severity.as_str())
+}
+
+/// Deserialize `TargetRubyVersion`, which may be written as a number (`2.7`) or a string (`'2.7'`).
+pub fn deserialize_ruby_version<'de, D>(deserializer: D) -> Result<Option<f32>, D::Error>
+where
+    D: serde::Deserializer<'de>,
+{
+    #[derive(Deserialize)]
+    #[serde(untagged)]
+    enum VersionValue {
+        Number(f32),
+        String(String),
+    }
+
+    match Option::<VersionValue>::deserialize(deserializer)? {
+        Some(VersionValue::Number(version)) => Ok(Some(version)),
+        Some(VersionValue::String(s)) => Ok(s.trim().parse().ok()),
+        None => Ok(None),
+    }
+}
+
 /// Parse severity from string.
 pub fn parse_severity(s: &str) -> Severity {
     match s.to_lowercase().as_str() {
@@ -41,3 +88,35 @@ pub fn parse_severity(s: &str) -> Severity {
         _ => Severity::Warning,
     }
 }
+
+/// Deserialize a line count that may be a number (`1`) or a `[min, max]` range (`[1, 2]`).
+pub fn deserialize_count_range<'de, D>(deserializer: D) -> Result<RangeInclusive<usize>, D::Error>
+where
+    D: serde::Deserializer<'de>,
+{
+    #[derive(Deserialize)]
+    #[serde(untagged)]
+    enum CountValue {
+        Number(usize),
+        Range([usize; 2]),
+    }
+
+    match CountValue::deserialize(deserializer)? {
+        CountValue::Number(count) => Ok(count..=count),
+        CountValue::Range([min, max]) if min <= max => Ok(min..=max),
+        CountValue::Range([min, max]) => Err(serde::de::Error::custom(format!("invalid range [{min}, {max}]: min must not exceed max"))),
+    }
+}
+
+/// Serialize a count range as a number when it holds a single count, or as `[min, max]`.
+pub fn serialize_count_range<S>(range: &RangeInclusive<usize>, serializer: S) -> Result<S::Ok, S::Error>
+where
+    S: serde::Serializer,
+{
+    use serde::Serialize;
+    if range.start() == range.end() {
+        range.start().serialize(serializer)
+    } else {
+        [*range.start(), *range.end()].serialize(serializer)
+    }
+}
@@ -0,0 +1,42 @@
+use crate::config::BaseCopConfig;
+use serde::{Deserialize, Serialize};
+
+const NESTED: &str = "nested";
+const COMPACT: &str = "compact";
+
+/// Configuration for Style/ClassAndModuleChildren.
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[serde(default, rename_all = "PascalCase")]
+pub struct ClassAndModuleChildren {
+    /// Base configuration (enabled, severity, exclude, include).
+    #[serde(flatten)]
+    pub base: BaseCopConfig,
+    /// How nested classes and modules are written (`nested` or `compact`).
+    pub enforced_style: EnforcedStyle,
+}
+
+impl Default for ClassAndModuleChildren {
+    fn default() -> Self {
+        Self {
+            base: BaseCopConfig::default(),
+            enforced_style: EnforcedStyle::default(),
+        }
+    }
+}
+
+/// Definition style for Style/ClassAndModuleChildren.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Deserialize, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum EnforcedStyle {
+    #[default]
+    Nested,
+    Compact,
+}
+impl EnforcedStyle {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Nested => NESTED,
+            Self::Compact => COMPACT,
+        }
+    }
+}
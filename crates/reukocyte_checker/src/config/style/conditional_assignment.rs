@@ -0,0 +1,44 @@
+use crate::config::BaseCopConfig;
+use serde::{Deserialize, Serialize};
+
+const ASSIGN_TO_CONDITION: &str = "assign_to_condition";
+const ASSIGN_INSIDE_CONDITION: &str = "assign_inside_condition";
+
+/// Configuration for Style/ConditionalAssignment.
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[serde(default, rename_all = "PascalCase")]
+pub struct ConditionalAssignment {
+    /// Base configuration (enabled, severity, exclude, include).
+    #[serde(flatten)]
+    pub base: BaseCopConfig,
+    /// Whether to assign the result of a conditional or assign inside its branches.
+    pub enforced_style: EnforcedStyle,
+}
+
+impl Default for ConditionalAssignment {
+    fn default() -> Self {
+        Self {
+            base: BaseCopConfig::default(),
+            enforced_style: EnforcedStyle::default(),
+        }
+    }
+}
+
+/// Where Style/ConditionalAssignment wants the assignment.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Deserialize, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum EnforcedStyle {
+    /// `x = if c then 1 else 2 end`
+    #[default]
+    AssignToCondition,
+    /// `if c then x = 1 else x = 2 end`
+    AssignInsideCondition,
+}
+impl EnforcedStyle {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::AssignToCondition => ASSIGN_TO_CONDITION,
+            Self::AssignInsideCondition => ASSIGN_INSIDE_CONDITION,
+        }
+    }
+}
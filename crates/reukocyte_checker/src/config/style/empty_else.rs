@@ -0,0 +1,48 @@
+use crate::config::BaseCopConfig;
+use serde::{Deserialize, Serialize};
+
+const BOTH: &str = "both";
+const EMPTY: &str = "empty";
+const NIL: &str = "nil";
+
+/// Configuration for Style/EmptyElse.
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[serde(default, rename_all = "PascalCase")]
+pub struct EmptyElse {
+    /// Base configuration (enabled, severity, exclude, include).
+    #[serde(flatten)]
+    pub base: BaseCopConfig,
+    /// Which `else` clauses are flagged: empty ones, `else nil`, or both.
+    pub enforced_style: EnforcedStyle,
+}
+
+impl Default for EmptyElse {
+    fn default() -> Self {
+        Self {
+            base: BaseCopConfig::default(),
+            enforced_style: EnforcedStyle::default(),
+        }
+    }
+}
+
+/// Which `else` branches Style/EmptyElse reports.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Deserialize, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum EnforcedStyle {
+    /// Both empty `else` and `else nil`.
+    #[default]
+    Both,
+    /// Only empty `else`.
+    Empty,
+    /// Only `else nil`.
+    Nil,
+}
+impl EnforcedStyle {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Both => BOTH,
+            Self::Empty => EMPTY,
+            Self::Nil => NIL,
+        }
+    }
+}
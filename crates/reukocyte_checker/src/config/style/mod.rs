@@ -0,0 +1,29 @@
+pub mod class_and_module_children;
+pub mod conditional_assignment;
+pub mod empty_else;
+pub mod expand_path_arguments;
+pub mod negated_while;
+pub mod next;
+pub mod redundant_double_splat_hash_braces;
+pub mod redundant_regexp_escape;
+pub mod redundant_return;
+pub mod redundant_sort_by;
+pub mod safe_navigation;
+pub mod stderr_puts;
+
+/// Style cop configurations.
+#[derive(Debug, Clone, Default)]
+pub struct StyleConfig {
+    pub class_and_module_children: class_and_module_children::ClassAndModuleChildren,
+    pub conditional_assignment: conditional_assignment::ConditionalAssignment,
+    pub empty_else: empty_else::EmptyElse,
+    pub expand_path_arguments: expand_path_arguments::ExpandPathArguments,
+    pub negated_while: negated_while::NegatedWhile,
+    pub next: next::Next,
+    pub redundant_double_splat_hash_braces: redundant_double_splat_hash_braces::RedundantDoubleSplatHashBraces,
+    pub redundant_regexp_escape: redundant_regexp_escape::RedundantRegexpEscape,
+    pub redundant_return: redundant_return::RedundantReturn,
+    pub redundant_sort_by: redundant_sort_by::RedundantSortBy,
+    pub safe_navigation: safe_navigation::SafeNavigation,
+    pub stderr_puts: stderr_puts::StderrPuts,
+}
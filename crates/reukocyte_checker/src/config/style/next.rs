@@ -0,0 +1,36 @@
+use crate::config::BaseCopConfig;
+use serde::{Deserialize, Serialize};
+
+/// Configuration for Style/Next.
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[serde(default, rename_all = "PascalCase")]
+pub struct Next {
+    /// Base configuration (enabled, severity, exclude, include).
+    #[serde(flatten)]
+    pub base: BaseCopConfig,
+    /// Whether modifier `if`/`unless` bodies are reported as well.
+    pub enforced_style: EnforcedStyle,
+    /// Lines from `if`/`unless` to `end` a conditional must exceed to be reported.
+    pub min_body_length: usize,
+}
+
+impl Default for Next {
+    fn default() -> Self {
+        Self {
+            base: BaseCopConfig::default(),
+            enforced_style: EnforcedStyle::default(),
+            min_body_length: 3,
+        }
+    }
+}
+
+/// Whether Style/Next reports modifier conditionals.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Deserialize, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum EnforcedStyle {
+    /// Modifier `if`/`unless` are not reported.
+    #[default]
+    SkipModifierIfs,
+    /// Modifier `if`/`unless` are reported as well.
+    Always,
+}
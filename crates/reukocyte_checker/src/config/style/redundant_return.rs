@@ -0,0 +1,22 @@
+use crate::config::BaseCopConfig;
+use serde::{Deserialize, Serialize};
+
+/// Configuration for Style/RedundantReturn.
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[serde(default, rename_all = "PascalCase")]
+pub struct RedundantReturn {
+    /// Base configuration (enabled, severity, exclude, include).
+    #[serde(flatten)]
+    pub base: BaseCopConfig,
+    /// Allow `return a, b` as the last statement (implicit array return).
+    pub allow_multiple_return_values: bool,
+}
+
+impl Default for RedundantReturn {
+    fn default() -> Self {
+        Self {
+            base: BaseCopConfig::default(),
+            allow_multiple_return_values: false,
+        }
+    }
+}
@@ -0,0 +1,22 @@
+use crate::config::BaseCopConfig;
+use serde::{Deserialize, Serialize};
+
+/// Configuration for Style/SafeNavigation.
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[serde(default, rename_all = "PascalCase")]
+pub struct SafeNavigation {
+    /// Base configuration (enabled, severity, exclude, include).
+    #[serde(flatten)]
+    pub base: BaseCopConfig,
+    /// Methods that are commonly called on `nil`-checked objects and should not be converted.
+    pub allowed_methods: Vec<String>,
+}
+
+impl Default for SafeNavigation {
+    fn default() -> Self {
+        Self {
+            base: BaseCopConfig::default(),
+            allowed_methods: ["present?", "blank?", "presence", "try", "try!"].iter().map(|s| s.to_string()).collect(),
+        }
+    }
+}
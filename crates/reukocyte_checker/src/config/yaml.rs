@@ -4,10 +4,13 @@
 //! configuration format. The `define_cops!` macro generates the `RubocopYaml`
 //! struct and related functionality.
 
-use serde::Deserialize;
+use serde::{Deserialize, Serialize};
+use serde_yaml::{Mapping, Value};
+use std::collections::BTreeMap;
 use std::path::PathBuf;
 
 use super::macros::define_cops;
+use super::serde_helpers::deserialize_ruby_version;
 
 // ============================================================================
 // Define all cops in one place
@@ -16,7 +19,7 @@ use super::macros::define_cops;
 // To add a new cop:
 // 1. Add it here: `"Category/CopName" => CopName, field_name`
 // 2. Create the config module (e.g., `layout/new_cop.rs`)
-// 3. Export it from `layout/mod.rs`
+// 3. Export it from `layout/mod.rs` (or `lint/mod.rs`, `style/mod.rs`)
 //
 // That's it! RubocopYaml, from_rubocop_yaml, and merge_configs are auto-generated.
 
@@ -24,18 +27,66 @@ define_cops! {
     layout {
         "Layout/AccessModifierIndentation" => AccessModifierIndentation, access_modifier_indentation,
         "Layout/BeginEndAlignment" => BeginEndAlignment, begin_end_alignment,
+        "Layout/BlockAlignment" => BlockAlignment, block_alignment,
+        "Layout/BlockEndNewline" => BlockEndNewline, block_end_newline,
+        "Layout/ClosingHeredocIndentation" => ClosingHeredocIndentation, closing_heredoc_indentation,
+        "Layout/ClosingParenthesisIndentation" => ClosingParenthesisIndentation, closing_parenthesis_indentation,
         "Layout/DefEndAlignment" => DefEndAlignment, def_end_alignment,
+        "Layout/EmptyLineAfterMagicComment" => EmptyLineAfterMagicComment, empty_line_after_magic_comment,
+        "Layout/EmptyLineBetweenDefs" => EmptyLineBetweenDefs, empty_line_between_defs,
         "Layout/EmptyLines" => EmptyLines, empty_lines,
+        "Layout/EmptyLinesAroundMethodBody" => EmptyLinesAroundMethodBody, empty_lines_around_method_body,
         "Layout/EndAlignment" => EndAlignment, end_alignment,
+        "Layout/EndOfLine" => EndOfLine, end_of_line,
+        "Layout/ExtraSpacing" => ExtraSpacing, extra_spacing,
         "Layout/IndentationConsistency" => IndentationConsistency, indentation_consistency,
         "Layout/IndentationStyle" => IndentationStyle, indentation_style,
         "Layout/IndentationWidth" => IndentationWidth, indentation_width,
+        "Layout/LeadingCommentSpace" => LeadingCommentSpace, leading_comment_space,
         "Layout/LeadingEmptyLines" => LeadingEmptyLines, leading_empty_lines,
+        "Layout/LineLength" => LineLength, line_length,
+        "Layout/MultilineMethodDefinitionBraceLayout" => MultilineMethodDefinitionBraceLayout, multiline_method_definition_brace_layout,
+        "Layout/RedundantLineBreak" => RedundantLineBreak, redundant_line_break,
+        "Layout/SpaceAfterColon" => SpaceAfterColon, space_after_colon,
+        "Layout/SpaceAfterComma" => SpaceAfterComma, space_after_comma,
+        "Layout/SpaceAfterMethodName" => SpaceAfterMethodName, space_after_method_name,
+        "Layout/SpaceBeforeBlockBraces" => SpaceBeforeBlockBraces, space_before_block_braces,
+        "Layout/SpaceBeforeColon" => SpaceBeforeColon, space_before_colon,
+        "Layout/SpaceBeforeComma" => SpaceBeforeComma, space_before_comma,
+        "Layout/SpaceInsideArrayLiteralBrackets" => SpaceInsideArrayLiteralBrackets, space_inside_array_literal_brackets,
+        "Layout/SpaceInsideStringInterpolation" => SpaceInsideStringInterpolation, space_inside_string_interpolation,
         "Layout/TrailingEmptyLines" => TrailingEmptyLines, trailing_empty_lines,
         "Layout/TrailingWhitespace" => TrailingWhitespace, trailing_whitespace,
     }
     lint {
+        "Lint/BigDecimalNew" => BigDecimalNew, big_decimal_new,
+        "Lint/BooleanSymbol" => BooleanSymbol, boolean_symbol,
         "Lint/Debugger" => Debugger, debugger,
+        "Lint/ElseLayout" => ElseLayout, else_layout,
+        "Lint/EmptyBlock" => EmptyBlock, empty_block,
+        "Lint/EmptyInterpolation" => EmptyInterpolation, empty_interpolation,
+        "Lint/ImplicitStringConcatenation" => ImplicitStringConcatenation, implicit_string_concatenation,
+        "Lint/LiteralAsCondition" => LiteralAsCondition, literal_as_condition,
+        "Lint/PercentStringArray" => PercentStringArray, percent_string_array,
+        "Lint/PercentSymbolArray" => PercentSymbolArray, percent_symbol_array,
+        "Lint/RedundantWithIndex" => RedundantWithIndex, redundant_with_index,
+        "Lint/RedundantWithObject" => RedundantWithObject, redundant_with_object,
+        "Lint/ScriptPermission" => ScriptPermission, script_permission,
+        "Lint/ToEnumArguments" => ToEnumArguments, to_enum_arguments,
+    }
+    style {
+        "Style/ClassAndModuleChildren" => ClassAndModuleChildren, class_and_module_children,
+        "Style/ConditionalAssignment" => ConditionalAssignment, conditional_assignment,
+        "Style/EmptyElse" => EmptyElse, empty_else,
+        "Style/ExpandPathArguments" => ExpandPathArguments, expand_path_arguments,
+        "Style/NegatedWhile" => NegatedWhile, negated_while,
+        "Style/Next" => Next, next,
+        "Style/RedundantDoubleSplatHashBraces" => RedundantDoubleSplatHashBraces, redundant_double_splat_hash_braces,
+        "Style/RedundantRegexpEscape" => RedundantRegexpEscape, redundant_regexp_escape,
+        "Style/RedundantReturn" => RedundantReturn, redundant_return,
+        "Style/RedundantSortBy" => RedundantSortBy, redundant_sort_by,
+        "Style/SafeNavigation" => SafeNavigation, safe_navigation,
+        "Style/StderrPuts" => StderrPuts, stderr_puts,
     }
 }
 
@@ -75,13 +126,16 @@ impl InheritFrom {
 // AllCopsConfig (not generated by macro)
 // ============================================================================
 
+/// Ruby version assumed when `TargetRubyVersion` is not configured (the latest supported).
+pub const DEFAULT_TARGET_RUBY_VERSION: f32 = 3.4;
+
 /// Global configuration that applies to all cops.
-#[derive(Debug, Clone, Default, Deserialize)]
+#[derive(Debug, Clone, Default, Deserialize, Serialize)]
 #[serde(rename_all = "PascalCase")]
 pub struct AllCopsConfig {
-    /// Target Ruby version (e.g., "3.2", "3.3").
-    #[serde(default)]
-    pub target_ruby_version: Option<String>,
+    /// Target Ruby version (e.g., 3.2, 3.3). Defaults to `DEFAULT_TARGET_RUBY_VERSION` when unset.
+    #[serde(default, deserialize_with = "deserialize_ruby_version", skip_serializing_if = "Option::is_none")]
+    pub target_ruby_version: Option<f32>,
     /// Files to exclude from all cops.
     #[serde(default)]
     pub exclude: Vec<String>,
@@ -89,17 +143,20 @@ pub struct AllCopsConfig {
     #[serde(default)]
     pub include: Vec<String>,
     /// Whether to use cache.
-    #[serde(default)]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub use_cache: Option<bool>,
     /// Cache root directory.
-    #[serde(default)]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub cache_root_directory: Option<String>,
     /// New cops behavior: enable, disable, or pending.
-    #[serde(default)]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub new_cops: Option<String>,
     /// Suggested extensions.
-    #[serde(default)]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub suggested_extensions: Option<bool>,
+    /// Files larger than this many bytes are skipped instead of checked.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub maximum_file_size: Option<u64>,
 }
 
 /// Merge AllCops configuration. Child values override parent values.
@@ -112,6 +169,66 @@ pub(super) fn merge_all_cops(parent: AllCopsConfig, child: AllCopsConfig) -> All
         cache_root_directory: child.cache_root_directory.or(parent.cache_root_directory),
         new_cops: child.new_cops.or(parent.new_cops),
         suggested_extensions: child.suggested_extensions.or(parent.suggested_extensions),
+        maximum_file_size: child.maximum_file_size.or(parent.maximum_file_size),
+    }
+}
+
+/// Collect the options of each known cop from the root of a parsed YAML document.
+pub(super) fn explicit_cop_options(root: &Value) -> BTreeMap<String, Mapping> {
+    let Value::Mapping(root) = root else {
+        return BTreeMap::new();
+    };
+    root.iter()
+        .filter_map(|(key, value)| match (key, value) {
+            (Value::String(cop), Value::Mapping(options)) if COP_NAMES.contains(&cop.as_str()) => Some((cop.clone(), options.clone())),
+            _ => None,
+        })
+        .collect()
+}
+
+impl super::Config {
+    /// Layer `other` on top of this configuration and return the result.
+    ///
+    /// For each cop, the options `other` changes override this configuration key by key,
+    /// keeping this configuration's remaining keys. An option counts as changed when it
+    /// differs from the cop's default, whether it was set in YAML or in code, or when it
+    /// was set explicitly in YAML or through `ConfigBuilder`, even to its default value.
+    /// `AllCops` settings of `other` win when present.
+    pub fn merged_with(&self, other: &super::Config) -> super::Config {
+        let defaults = super::Config::default();
+        let mut merged = self.clone();
+        merged.all_cops = merge_all_cops(self.all_cops.clone(), other.all_cops.clone());
+        for cop in COP_NAMES {
+            let (Some(theirs), Some(default), Some(other_base)) = (other.cop_to_yaml(cop), defaults.cop_to_yaml(cop), other.base_config(cop)) else {
+                continue;
+            };
+            let changed: Vec<&Value> = theirs
+                .iter()
+                .filter(|(key, value)| default.get(*key) != Some(*value) || key.as_str().is_some_and(|key| other_base.explicit_keys().iter().any(|k| k == key)))
+                .map(|(key, _)| key)
+                .collect();
+            if changed.is_empty() {
+                continue;
+            }
+            // Start from this configuration's cop as it is, including what was set in code.
+            let mut resolved = self.cop_to_yaml(cop).unwrap_or_default();
+            let mut explicit = self.base_config(cop).map(|base| base.explicit_keys().to_vec()).unwrap_or_default();
+            for key in changed {
+                resolved.insert(key.clone(), theirs[key].clone());
+                if let Some(key) = key.as_str()
+                    && !explicit.iter().any(|k| k == key)
+                {
+                    explicit.push(key.to_string());
+                }
+            }
+            let single = Mapping::from_iter([(Value::from(*cop), Value::Mapping(resolved))]);
+            let yaml: RubocopYaml = serde_yaml::from_value(Value::Mapping(single)).expect("both configs serialized these options");
+            merged.set_cop_from_yaml(cop, &yaml);
+            if let Some(base) = merged.base_config_mut(cop) {
+                base.set_explicit_keys(explicit);
+            }
+        }
+        merged
     }
 }
 
@@ -119,6 +236,8 @@ pub(super) fn merge_all_cops(parent: AllCopsConfig, child: AllCopsConfig) -> All
 mod tests {
     use super::*;
     use crate::config::layout::end_alignment::EnforcedStyleAlignWith;
+    use crate::config::{Config, parse_rubocop_yaml};
+    use crate::diagnostic::Severity;
 
     #[test]
     fn test_parse_simple_yaml() {
@@ -166,11 +285,21 @@ AllCops:
     - 'db/schema.rb'
 "#;
         let config: RubocopYaml = serde_yaml::from_str(yaml).unwrap();
-        assert_eq!(config.all_cops.target_ruby_version, Some("3.2".to_string()));
+        assert_eq!(config.all_cops.target_ruby_version, Some(3.2));
         assert_eq!(config.all_cops.new_cops, Some("enable".to_string()));
         assert_eq!(config.all_cops.exclude.len(), 2);
     }
 
+    #[test]
+    fn test_parse_target_ruby_version() {
+        let config: RubocopYaml = serde_yaml::from_str("AllCops:\n  TargetRubyVersion: 2.7\n").unwrap();
+        assert_eq!(config.all_cops.target_ruby_version, Some(2.7));
+        let config: RubocopYaml = serde_yaml::from_str("AllCops:\n  TargetRubyVersion: '3.3'\n").unwrap();
+        assert_eq!(config.all_cops.target_ruby_version, Some(3.3));
+        let config: RubocopYaml = serde_yaml::from_str("AllCops:\n  NewCops: enable\n").unwrap();
+        assert_eq!(config.all_cops.target_ruby_version, None);
+    }
+
     #[test]
     fn test_parse_enabled_false() {
         let yaml = r#"
@@ -191,4 +320,77 @@ Layout/EndAlignment:
         // "pending" is treated as enabled (not explicitly disabled)
         assert!(config.end_alignment.base.enabled);
     }
+
+    #[test]
+    fn test_merged_with_keeps_both_sides() {
+        let width = Config::builder().set_option("Layout/IndentationWidth", "Width", 4).build().unwrap();
+        let trailing = Config::builder().disable("Layout/TrailingWhitespace").build().unwrap();
+        let merged = width.merged_with(&trailing);
+        assert_eq!(merged.layout.indentation_width.width, 4);
+        assert!(!merged.layout.trailing_whitespace.base.enabled);
+        let merged = trailing.merged_with(&width);
+        assert_eq!(merged.layout.indentation_width.width, 4);
+        assert!(!merged.layout.trailing_whitespace.base.enabled);
+    }
+
+    #[test]
+    fn test_merged_with_overrides_per_key() {
+        let base = parse_rubocop_yaml("Layout/IndentationWidth:\n  Width: 4\n  Severity: warning\n").unwrap();
+        let base = Config::from_rubocop_yaml(&base);
+        let overlay = parse_rubocop_yaml("Layout/IndentationWidth:\n  Severity: error\nAllCops:\n  TargetRubyVersion: 3.1\n").unwrap();
+        let merged = base.merged_with(&Config::from_rubocop_yaml(&overlay));
+        assert_eq!(merged.layout.indentation_width.width, 4);
+        assert_eq!(merged.layout.indentation_width.base.severity, Severity::Error);
+        assert_eq!(merged.all_cops.target_ruby_version, Some(3.1));
+        // Cops set in code that the overlay does not mention are kept.
+        let mut base = Config::default();
+        base.layout.end_alignment.base.enabled = false;
+        assert!(!base.merged_with(&Config::default()).layout.end_alignment.base.enabled);
+    }
+
+    #[test]
+    fn test_merged_with_keeps_options_set_in_code() {
+        let mut base = Config::default();
+        base.layout.indentation_width.width = 4;
+        let severity = Config::builder().set_option("Layout/IndentationWidth", "Severity", "error").build().unwrap();
+        let merged = base.merged_with(&severity);
+        assert_eq!(merged.layout.indentation_width.width, 4);
+        assert_eq!(merged.layout.indentation_width.base.severity, Severity::Error);
+
+        // Merging a default configuration changes nothing.
+        assert_eq!(base.merged_with(&Config::default()).to_yaml(), base.to_yaml());
+    }
+
+    #[test]
+    fn test_merged_with_applies_options_set_in_code_on_other() {
+        let base = Config::from_rubocop_yaml(&parse_rubocop_yaml("Layout/IndentationWidth:\n  Width: 4\n").unwrap());
+        let mut overlay = Config::default();
+        overlay.layout.indentation_width.base.severity = Severity::Error;
+        overlay.lint.debugger.base.set_exclude(vec!["spec/**/*".to_string()]);
+        let merged = base.merged_with(&overlay);
+        assert_eq!(merged.layout.indentation_width.width, 4);
+        assert_eq!(merged.layout.indentation_width.base.severity, Severity::Error);
+        assert_eq!(merged.lint.debugger.base.exclude(), ["spec/**/*"]);
+        assert!(merged.lint.debugger.base.exclude_set().is_some());
+
+        // An option set explicitly to its default value still overrides.
+        let mut base = Config::default();
+        base.layout.end_alignment.base.enabled = false;
+        let enabled = Config::builder().set_option("Layout/EndAlignment", "Enabled", true).build().unwrap();
+        assert!(base.merged_with(&enabled).layout.end_alignment.base.enabled);
+    }
+
+    #[test]
+    fn test_to_yaml_round_trips() {
+        let yaml = parse_rubocop_yaml("Layout/IndentationWidth:\n  Width: 4\nLayout/EmptyLineBetweenDefs:\n  NumberOfEmptyLines: [1, 2]\n").unwrap();
+        let dumped = Config::from_rubocop_yaml(&yaml).to_yaml();
+        assert!(dumped.contains("Layout/IndentationWidth:\n  Enabled: true\n  Severity: convention\n"));
+        assert!(dumped.contains("  Width: 4\n"));
+
+        let reloaded = Config::from_rubocop_yaml(&parse_rubocop_yaml(&dumped).unwrap());
+        assert_eq!(reloaded.layout.indentation_width.width, 4);
+        assert_eq!(reloaded.layout.empty_line_between_defs.number_of_empty_lines, 1..=2);
+        assert_eq!(reloaded.lint.debugger.debugger_methods, Config::default().lint.debugger.debugger_methods);
+        assert_eq!(reloaded.to_yaml(), dumped);
+    }
 }
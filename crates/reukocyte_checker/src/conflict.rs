@@ -21,6 +21,10 @@ impl ConflictRegistry {
     pub fn mark_applied(&mut self, rule_id: RuleId) {
         self.applied_rules.insert(rule_id);
     }
+    /// Forget that a rule was applied, e.g. once its fixes were displaced.
+    pub fn unmark_applied(&mut self, rule_id: RuleId) {
+        self.applied_rules.remove(&rule_id);
+    }
     /// Check if a rule was already applied in this iteration.
     pub fn was_applied(&self, rule_id: RuleId) -> bool {
         self.applied_rules.contains(&rule_id)
@@ -101,6 +105,18 @@ mod tests {
         assert!(!registry.was_applied(RULE_A));
     }
 
+    #[test]
+    fn test_unmark_applied() {
+        let mut registry = ConflictRegistry::new();
+
+        registry.mark_applied(RULE_A);
+        registry.mark_applied(RULE_B);
+        registry.unmark_applied(RULE_A);
+        assert!(!registry.was_applied(RULE_A));
+        assert!(registry.was_applied(RULE_B));
+        assert_eq!(registry.applied_count(), 1);
+    }
+
     #[test]
     fn test_was_applied() {
         let mut registry = ConflictRegistry::new();
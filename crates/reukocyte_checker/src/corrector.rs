@@ -18,75 +18,125 @@ pub enum ClobberingError {
     },
 }
 
+/// A range of the original source replaced by `Corrector::apply_with_changes`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Change {
+    /// Start byte offset in the original source.
+    pub start: usize,
+    /// End byte offset in the original source.
+    pub end: usize,
+    /// Number of bytes inserted in place of the range.
+    pub inserted: usize,
+}
+
 /// Corrector that merges multiple fixes and applies them to source code.
 #[derive(Debug, Default)]
 pub struct Corrector {
     edits: Vec<Edit>,
+    /// The merged fixes in merge order, `None` once displaced by a safe fix.
+    fixes: Vec<Option<Fix>>,
 }
 impl Corrector {
     /// Create a new empty corrector.
     pub fn new() -> Self {
-        Self { edits: Vec::new() }
+        Self {
+            edits: Vec::new(),
+            fixes: Vec::new(),
+        }
     }
     /// Merge a fix into this corrector.
-    pub fn merge(&mut self, fix: &Fix) -> Result<(), ClobberingError> {
-        for new_edit in &fix.edits {
-            self.check_conflict(new_edit)?;
+    ///
+    /// Conflicting fixes are rejected (first wins), except that a safe fix
+    /// replacing a range with different content than unsafe fixes merged before
+    /// displaces them. Returns the merge-order indices of the displaced fixes,
+    /// counting every successful merge.
+    pub fn merge(&mut self, fix: &Fix) -> Result<Vec<usize>, ClobberingError> {
+        match check_fix_conflict(&self.edits, fix) {
+            Ok(()) => {
+                self.add(fix);
+                Ok(Vec::new())
+            }
+            Err(error @ ClobberingError::DifferentReplacements { .. }) if fix.applicability == Applicability::Safe => {
+                let displaced = self.displace_unsafe(fix).ok_or(error)?;
+                self.add(fix);
+                Ok(displaced)
+            }
+            Err(error) => Err(error),
         }
-        // No conflicts, add all edits
+    }
+    /// Remove the merged fixes conflicting with `fix`, if they are all unsafe and
+    /// `fix` fits once they are gone. Returns their indices.
+    fn displace_unsafe(&mut self, fix: &Fix) -> Option<Vec<usize>> {
+        let displaced: Vec<usize> = self
+            .fixes
+            .iter()
+            .enumerate()
+            .filter(|(_, merged)| merged.as_ref().is_some_and(|merged| check_fix_conflict(&merged.edits, fix).is_err()))
+            .map(|(index, _)| index)
+            .collect();
+        if displaced
+            .iter()
+            .any(|&index| self.fixes[index].as_ref().is_some_and(|merged| merged.applicability != Applicability::Unsafe))
+        {
+            return None;
+        }
+        let mut remaining = Corrector::new();
+        for merged in self
+            .fixes
+            .iter()
+            .enumerate()
+            .filter(|(index, _)| !displaced.contains(index))
+            .filter_map(|(_, merged)| merged.as_ref())
+        {
+            remaining.add(merged);
+        }
+        check_fix_conflict(&remaining.edits, fix).ok()?;
+        self.edits = remaining.edits;
+        for &index in &displaced {
+            self.fixes[index] = None;
+        }
+        Some(displaced)
+    }
+    /// Add the edits of a fix that does not conflict.
+    fn add(&mut self, fix: &Fix) {
         self.edits.extend(fix.edits.iter().cloned());
         // Keep sorted for efficient application
         self.edits.sort_by_key(|e| (e.start, e.end));
-        Ok(())
+        self.merge_crossing_deletions();
+        self.fixes.push(Some(fix.clone()));
     }
-    /// Check if a new edit conflicts with existing edits.
-    fn check_conflict(&self, new_edit: &Edit) -> Result<(), ClobberingError> {
-        for existing_edit in &self.edits {
-            // 1. Same range with different content
-            if existing_edit.start == new_edit.start && existing_edit.end == new_edit.end {
-                if existing_edit.content != new_edit.content {
-                    return Err(ClobberingError::DifferentReplacements {
-                        range: (existing_edit.start, existing_edit.end),
-                        existing_content: existing_edit.content.clone(),
-                        new_content: new_edit.content.clone(),
-                    });
-                }
-                continue;
-            }
-            // 2. Insertion swallowed by deletion
-            // New edit is an insertion (start == end) inside an existing deletion
-            if new_edit.start == new_edit.end && existing_edit.content.is_empty() && existing_edit.start < new_edit.start && new_edit.start < existing_edit.end
+    /// Merge overlapping deletions into a single deletion spanning their union.
+    ///
+    /// Edits must already be sorted by range.
+    fn merge_crossing_deletions(&mut self) {
+        let mut merged: Vec<Edit> = Vec::with_capacity(self.edits.len());
+        for edit in self.edits.drain(..) {
+            if let Some(last) = merged.last_mut()
+                && is_deletion(last)
+                && is_deletion(&edit)
+                && ranges_overlap(last.start, last.end, edit.start, edit.end)
             {
-                return Err(ClobberingError::SwallowedInsertion {
-                    insertion_pos: new_edit.start,
-                    deletion_range: (existing_edit.start, existing_edit.end),
-                });
-            }
-            // Also check reverse: existing insertion inside new deletion
-            if existing_edit.start == existing_edit.end
-                && new_edit.content.is_empty()
-                && new_edit.start < existing_edit.start
-                && existing_edit.start < new_edit.end
-            {
-                return Err(ClobberingError::SwallowedInsertion {
-                    insertion_pos: existing_edit.start,
-                    deletion_range: (new_edit.start, new_edit.end),
-                });
-            }
-            // 3. Overlapping ranges (not identical)
-            // RuboCop uses `crossing_deletions: :accept` which merges overlapping deletions.
-            // We instead reject overlaps and let the next iteration handle them.
-            // This is simpler and produces the same final result, though may require
-            // more iterations in rare cases.
-            if ranges_overlap(existing_edit.start, existing_edit.end, new_edit.start, new_edit.end) {
-                return Err(ClobberingError::Overlapping {
-                    existing: (existing_edit.start, existing_edit.end),
-                    new: (new_edit.start, new_edit.end),
-                });
+                last.end = last.end.max(edit.end);
+                continue;
             }
+            merged.push(edit);
         }
-
-        Ok(())
+        self.edits = merged;
+    }
+    /// Apply all merged edits to the source code, also reporting the replaced ranges.
+    ///
+    /// Changes are sorted and do not overlap.
+    pub fn apply_with_changes(&self, source: &[u8]) -> (Vec<u8>, Vec<Change>) {
+        let changes = self
+            .edits
+            .iter()
+            .map(|edit| Change {
+                start: edit.start,
+                end: edit.end,
+                inserted: edit.content.len(),
+            })
+            .collect();
+        (self.apply(source), changes)
     }
     /// Apply all merged edits to the source code.
     pub fn apply(&self, source: &[u8]) -> Vec<u8> {
@@ -121,6 +171,59 @@ impl Corrector {
     }
 }
 
+/// Check if any edit of a fix conflicts with existing edits.
+fn check_fix_conflict(edits: &[Edit], fix: &Fix) -> Result<(), ClobberingError> {
+    fix.edits.iter().try_for_each(|new_edit| check_conflict(edits, new_edit))
+}
+
+/// Check if a new edit conflicts with existing edits.
+fn check_conflict(edits: &[Edit], new_edit: &Edit) -> Result<(), ClobberingError> {
+    for existing_edit in edits {
+        // 1. Same range with different content
+        if existing_edit.start == new_edit.start && existing_edit.end == new_edit.end {
+            if existing_edit.content != new_edit.content {
+                return Err(ClobberingError::DifferentReplacements {
+                    range: (existing_edit.start, existing_edit.end),
+                    existing_content: existing_edit.content.clone(),
+                    new_content: new_edit.content.clone(),
+                });
+            }
+            continue;
+        }
+        // 2. Insertion swallowed by deletion
+        // New edit is an insertion (start == end) inside an existing deletion
+        if new_edit.start == new_edit.end && existing_edit.content.is_empty() && existing_edit.start < new_edit.start && new_edit.start < existing_edit.end {
+            return Err(ClobberingError::SwallowedInsertion {
+                insertion_pos: new_edit.start,
+                deletion_range: (existing_edit.start, existing_edit.end),
+            });
+        }
+        // Also check reverse: existing insertion inside new deletion
+        if existing_edit.start == existing_edit.end && new_edit.content.is_empty() && new_edit.start < existing_edit.start && existing_edit.start < new_edit.end
+        {
+            return Err(ClobberingError::SwallowedInsertion {
+                insertion_pos: existing_edit.start,
+                deletion_range: (new_edit.start, new_edit.end),
+            });
+        }
+        // 3. Overlapping ranges (not identical)
+        // Like RuboCop's `crossing_deletions: :accept`, overlapping deletions are
+        // accepted and merged into their union. Any other overlap is rejected and
+        // left to the next iteration.
+        if is_deletion(existing_edit) && is_deletion(new_edit) {
+            continue;
+        }
+        if ranges_overlap(existing_edit.start, existing_edit.end, new_edit.start, new_edit.end) {
+            return Err(ClobberingError::Overlapping {
+                existing: (existing_edit.start, existing_edit.end),
+                new: (new_edit.start, new_edit.end),
+            });
+        }
+    }
+
+    Ok(())
+}
+
 /// Check if two ranges overlap.
 fn ranges_overlap(start1: usize, end1: usize, start2: usize, end2: usize) -> bool {
     // Ranges overlap if one starts before the other ends
@@ -128,6 +231,11 @@ fn ranges_overlap(start1: usize, end1: usize, start2: usize, end2: usize) -> boo
     start1 < end2 && start2 < end1
 }
 
+/// Check if an edit removes a non-empty range without inserting anything.
+fn is_deletion(edit: &Edit) -> bool {
+    edit.content.is_empty() && edit.start < edit.end
+}
+
 /// Check if a fix should be applied based on its applicability.
 pub fn should_apply_fix(fix: &Fix, unsafe_fixes: bool) -> bool {
     match fix.applicability {
@@ -182,6 +290,31 @@ mod tests {
         assert_eq!(corrector.edit_count(), 1); // Only first fix applied
     }
 
+    #[test]
+    fn test_merge_prefers_safe_over_unsafe() {
+        let mut corrector = Corrector::new();
+        corrector.merge(&make_fix(vec![make_edit(21, 22, "!")])).unwrap();
+        corrector.merge(&Fix::r#unsafe(vec![make_edit(0, 5, "world"), make_edit(10, 11, "?")])).unwrap();
+
+        // The safe fix displaces the whole unsafe fix (merged second), edits and all
+        assert_eq!(corrector.merge(&make_fix(vec![make_edit(0, 5, "hello")])), Ok(vec![1]));
+        assert_eq!(corrector.apply(b"aaaaa bbbb ccccccccc d"), b"hello bbbb ccccccccc !");
+
+        // An unsafe fix after a safe one loses, like any later fix
+        let result = corrector.merge(&Fix::r#unsafe(vec![make_edit(0, 5, "world")]));
+        assert!(matches!(result, Err(ClobberingError::DifferentReplacements { .. })));
+        assert_eq!(corrector.edit_count(), 2);
+    }
+
+    #[test]
+    fn test_merge_safe_does_not_displace_on_other_conflicts() {
+        let mut corrector = Corrector::new();
+        corrector.merge(&Fix::r#unsafe(vec![make_edit(0, 5, "world")])).unwrap();
+        // Overlapping, but not the same range
+        assert!(corrector.merge(&make_fix(vec![make_edit(2, 8, "hello")])).is_err());
+        assert_eq!(corrector.apply(b"aaaaa bbbb"), b"world bbbb");
+    }
+
     #[test]
     fn test_merge_same_replacement() {
         let mut corrector = Corrector::new();
@@ -208,6 +341,42 @@ mod tests {
         assert_eq!(corrector.edit_count(), 1);
     }
 
+    #[test]
+    fn test_merge_overlapping_deletions() {
+        let mut corrector = Corrector::new();
+        let source = b"0123456789abcdef";
+
+        let fix1 = make_fix(vec![make_edit(2, 8, "")]);
+        let fix2 = make_fix(vec![make_edit(5, 12, "")]);
+        let fix3 = make_fix(vec![make_edit(3, 4, "")]); // Contained in the merged deletion
+
+        assert!(corrector.merge(&fix1).is_ok());
+        assert!(corrector.merge(&fix2).is_ok());
+        assert!(corrector.merge(&fix3).is_ok());
+        assert_eq!(corrector.edit_count(), 1);
+        assert_eq!(corrector.apply(source), b"01cdef");
+    }
+
+    #[test]
+    fn test_merge_deletion_overlapping_replacement() {
+        let mut corrector = Corrector::new();
+
+        let fix1 = make_fix(vec![make_edit(0, 10, "")]);
+        let fix2 = make_fix(vec![make_edit(5, 15, "world")]);
+
+        assert!(corrector.merge(&fix1).is_ok());
+        let result = corrector.merge(&fix2);
+
+        assert_eq!(
+            result,
+            Err(ClobberingError::Overlapping {
+                existing: (0, 10),
+                new: (5, 15)
+            })
+        );
+        assert_eq!(corrector.edit_count(), 1);
+    }
+
     #[test]
     fn test_merge_swallowed_insertion() {
         let mut corrector = Corrector::new();
@@ -250,6 +419,27 @@ mod tests {
         assert_eq!(result, b"AAA bbb CCC");
     }
 
+    #[test]
+    fn test_apply_with_changes() {
+        let mut corrector = Corrector::new();
+        corrector.merge(&make_fix(vec![make_edit(8, 11, "CCC!")])).unwrap();
+        corrector.merge(&make_fix(vec![make_edit(3, 4, "")])).unwrap();
+
+        let (result, changes) = corrector.apply_with_changes(b"aaa bbb ccc");
+        assert_eq!(result, b"aaabbb CCC!");
+        assert_eq!(
+            changes,
+            vec![
+                Change { start: 3, end: 4, inserted: 0 },
+                Change {
+                    start: 8,
+                    end: 11,
+                    inserted: 4
+                }
+            ]
+        );
+    }
+
     #[test]
     fn test_apply_deletion() {
         let mut corrector = Corrector::new();
@@ -1,3 +1,4 @@
+use crate::locator::LineIndex;
 use crate::rule::RuleId;
 
 /// Raw diagnostic without line/column information (for deferred resolution).
@@ -9,6 +10,7 @@ pub struct RawDiagnostic {
     pub start: usize,
     pub end: usize,
     pub fix: Option<Fix>,
+    pub fix_description: Option<&'static str>,
 }
 impl RawDiagnostic {
     /// Convert to full Diagnostic with resolved line/column.
@@ -25,6 +27,7 @@ impl RawDiagnostic {
             column_start,
             column_end,
             fix: self.fix,
+            fix_description: self.fix_description,
         }
     }
 }
@@ -42,6 +45,10 @@ pub struct Diagnostic {
     pub column_start: usize, // Start column number
     pub column_end: usize,   // End column number
     pub fix: Option<Fix>,    // Optional fix for the issue
+    /// What the fix does, e.g. "remove trailing whitespace", for editor quick-fix previews.
+    ///
+    /// Set whenever `fix` is; rules without a specific description get a generic one.
+    pub fix_description: Option<&'static str>,
 }
 impl Diagnostic {
     /// Create a new diagnostic.
@@ -67,6 +74,7 @@ impl Diagnostic {
             line_end,
             column_start,
             column_end,
+            fix_description: fix.as_ref().map(Fix::description),
             fix,
         }
     }
@@ -82,10 +90,54 @@ impl Diagnostic {
     pub fn correctable(&self) -> bool {
         self.fix.is_some()
     }
+    /// Check if the diagnostic carries a fix that autocorrect can apply (safe or unsafe).
+    pub fn is_autocorrectable(&self) -> bool {
+        self.fix.as_ref().is_some_and(|fix| fix.applicability != Applicability::DisplayOnly)
+    }
+    /// Get the edits of the fix, if any.
+    pub fn fix_edits(&self) -> Option<&[Edit]> {
+        self.fix.as_ref().map(|fix| fix.edits.as_slice())
+    }
+    /// Get the applicability of the fix, if any.
+    pub fn fix_applicability(&self) -> Option<Applicability> {
+        self.fix.as_ref().map(|fix| fix.applicability)
+    }
     /// Get the length of the diagnostic range.
     pub fn length(&self) -> usize {
         self.end.saturating_sub(self.start)
     }
+    /// Get the range as LSP positions: zero-based `(line, character)` for the start and end.
+    ///
+    /// LSP characters are UTF-16 code units, so they are computed from `source`
+    /// (the source the diagnostic was reported on) instead of the byte-based columns.
+    pub fn to_lsp_range(&self, source: &[u8]) -> ((u32, u32), (u32, u32)) {
+        let position = |line: usize, offset: usize| {
+            let offset = offset.min(source.len());
+            let line_start = source[..offset].iter().rposition(|&b| b == b'\n').map_or(0, |pos| pos + 1);
+            let character: usize = String::from_utf8_lossy(&source[line_start..offset]).chars().map(char::len_utf16).sum();
+            (line.saturating_sub(1) as u32, character as u32)
+        };
+        (position(self.line_start, self.start), position(self.line_end, self.end))
+    }
+    /// Render the offending source lines with a `^` underline (see `LineIndex::snippet`).
+    ///
+    /// `source` must be the source the diagnostic was reported on.
+    pub fn source_context(&self, source: &[u8]) -> String {
+        LineIndex::from_source(source).snippet(self.line_start, self.column_start, self.line_end, self.column_end)
+    }
+    /// Get the LSP `DiagnosticSeverity` (1 = Error, 2 = Warning, 3 = Information, 4 = Hint).
+    pub fn to_lsp_severity(&self) -> u8 {
+        match self.severity {
+            Severity::Fatal | Severity::Error => 1,
+            Severity::Warning => 2,
+            Severity::Convention => 3,
+            Severity::Refactor | Severity::Info => 4,
+        }
+    }
+    /// Get a stable code for LSP's `Diagnostic.code` (the full rule name, e.g. `Layout/TrailingWhitespace`).
+    pub fn lsp_code(&self) -> String {
+        self.rule()
+    }
 }
 
 /// Severity level for a diagnostic.
@@ -160,6 +212,16 @@ impl Fix {
             edits,
         }
     }
+    /// Describe the fix generically, from whether its edits insert, remove or replace code.
+    pub fn description(&self) -> &'static str {
+        if self.edits.iter().all(|edit| edit.content.is_empty()) {
+            "remove code"
+        } else if self.edits.iter().all(|edit| edit.start == edit.end) {
+            "insert code"
+        } else {
+            "replace code"
+        }
+    }
 }
 
 /// A single text edit (replacement).
@@ -191,3 +253,70 @@ impl Edit {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::check;
+
+    #[test]
+    fn test_generic_fix_description() {
+        assert_eq!(Fix::safe(vec![Edit::deletion(0, 1)]).description(), "remove code");
+        assert_eq!(Fix::safe(vec![Edit::insertion(0, "x".to_string())]).description(), "insert code");
+        let replacement = Fix::safe(vec![Edit::deletion(0, 1), Edit::insertion(2, "x".to_string())]);
+        assert_eq!(replacement.description(), "replace code");
+    }
+
+    #[test]
+    fn test_trailing_whitespace_is_autocorrectable() {
+        let diagnostics = check(b"foo  \n");
+        assert_eq!(diagnostics.len(), 1);
+        assert!(diagnostics[0].is_autocorrectable());
+        assert_eq!(diagnostics[0].fix_edits().map(|edits| edits.len()), Some(1));
+        assert_eq!(diagnostics[0].fix_applicability(), Some(Applicability::Safe));
+    }
+
+    #[test]
+    fn test_debugger_is_not_autocorrectable() {
+        let diagnostics = check(b"binding.pry\n");
+        assert_eq!(diagnostics.len(), 1);
+        assert!(!diagnostics[0].is_autocorrectable());
+        assert!(diagnostics[0].fix_edits().is_none());
+        assert!(diagnostics[0].fix_applicability().is_none());
+    }
+
+    #[test]
+    fn test_to_lsp_range_uses_utf16_columns() {
+        // The emoji is 4 bytes in UTF-8 but 2 code units in UTF-16.
+        let source = "x = 1\ny = \"\u{1F600}\"  \n".as_bytes();
+        let diagnostics = check(source);
+        assert_eq!(diagnostics.len(), 1);
+        assert_eq!(diagnostics[0].column_start, 11);
+        assert_eq!(diagnostics[0].to_lsp_range(source), ((1, 8), (1, 10)));
+    }
+
+    #[test]
+    fn test_source_context() {
+        let source = "s = \"日本\"  \n".as_bytes();
+        let diagnostics = crate::check(source);
+        assert_eq!(diagnostics.len(), 1);
+        assert_eq!(diagnostics[0].source_context(source), "s = \"日本\"  \n        ^^");
+    }
+
+    #[test]
+    fn test_lsp_severity_and_code() {
+        let diagnostics = check(b"foo  \nbinding.pry\n");
+        assert_eq!(diagnostics[0].to_lsp_severity(), 3);
+        assert_eq!(diagnostics[0].lsp_code(), "Layout/TrailingWhitespace");
+        assert_eq!(diagnostics[1].to_lsp_severity(), 2);
+        assert_eq!(diagnostics[1].lsp_code(), "Lint/Debugger");
+    }
+
+    #[test]
+    fn test_display_only_fix_is_not_autocorrectable() {
+        let mut diagnostic = check(b"foo  \n").remove(0);
+        diagnostic.set_fix(Fix::display_only(vec![Edit::deletion(3, 5)]));
+        assert!(diagnostic.correctable());
+        assert!(!diagnostic.is_autocorrectable());
+    }
+}
@@ -0,0 +1,227 @@
+//! Inline comment directives that turn rules off for part of a file.
+//!
+//! Supported forms (same as RuboCop):
+//! - `# rubocop:disable Layout/TrailingWhitespace` on its own line disables the
+//!   rule until a matching `# rubocop:enable` (or the end of the file).
+//! - The same comment after code on a line disables the rule for that line only.
+//! - `# rubocop:todo` is an alias of `disable`, used for generated todo markers.
+//!
+//! A rule can be named by its full name (`Layout/TrailingWhitespace`), by its
+//! department (`Layout`), or with `all`.
+
+use ruby_prism::Comment;
+use rustc_hash::FxHashMap;
+
+use crate::locator::LineIndex;
+use crate::rule::RuleId;
+
+/// A range of lines (1-indexed, inclusive) where a rule selector is disabled.
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct DisabledRange {
+    selector: String,
+    start_line: usize,
+    end_line: usize,
+}
+
+/// Disabled line ranges collected from the comments of a file.
+#[derive(Debug, Clone, Default)]
+pub struct Directives {
+    ranges: Vec<DisabledRange>,
+}
+impl Directives {
+    /// Collect directives from the comments of a parsed file.
+    pub fn from_comments<'pr>(comments: impl Iterator<Item = Comment<'pr>>, line_index: &LineIndex) -> Self {
+        let mut ranges = Vec::new();
+        // Start lines of directives that are still open, keyed by selector.
+        let mut open: FxHashMap<String, usize> = FxHashMap::default();
+
+        for comment in comments {
+            let Some((mode, selectors)) = parse_directive(comment.text()) else {
+                continue;
+            };
+            let offset = comment.location().start_offset();
+            let line = line_index.line_number(offset);
+            match mode {
+                Mode::Disable if !line_index.is_first_on_line(offset) => {
+                    ranges.extend(selectors.into_iter().map(|selector| DisabledRange {
+                        selector,
+                        start_line: line,
+                        end_line: line,
+                    }));
+                }
+                Mode::Disable => {
+                    for selector in selectors {
+                        open.entry(selector).or_insert(line);
+                    }
+                }
+                Mode::Enable => {
+                    // An enable closes the open disables it covers, so `Layout`
+                    // also ends an earlier `# rubocop:disable Layout/Foo`.
+                    let closed: Vec<String> = open
+                        .keys()
+                        .filter(|key| selectors.iter().any(|selector| selector_covers(selector, key)))
+                        .cloned()
+                        .collect();
+                    for selector in closed {
+                        if let Some(start_line) = open.remove(&selector) {
+                            ranges.push(DisabledRange {
+                                selector,
+                                start_line,
+                                end_line: line,
+                            });
+                        }
+                    }
+                }
+            }
+        }
+        ranges.extend(open.into_iter().map(|(selector, start_line)| DisabledRange {
+            selector,
+            start_line,
+            end_line: usize::MAX,
+        }));
+        Self { ranges }
+    }
+
+    /// Check if there are no directives.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.ranges.is_empty()
+    }
+
+    /// Check if the rule is disabled on the given line (1-indexed).
+    pub fn is_disabled(&self, rule_id: RuleId, line: usize) -> bool {
+        self.ranges
+            .iter()
+            .any(|range| range.start_line <= line && line <= range.end_line && selector_matches(&range.selector, rule_id))
+    }
+}
+
+/// Check if `selector` names everything `key` names: the same selector, `all`,
+/// or the department of a rule.
+fn selector_covers(selector: &str, key: &str) -> bool {
+    selector == "all" || selector == key || key.strip_prefix(selector).is_some_and(|rest| rest.starts_with('/'))
+}
+
+/// Directive mode. `todo` is parsed as `Disable`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Mode {
+    Disable,
+    Enable,
+}
+
+/// Parse `# rubocop:<mode> <names>` into its mode and rule selectors.
+///
+/// Like RuboCop, the directive may follow other text in the comment
+/// (`# debug # rubocop:disable Lint/Debugger`).
+fn parse_directive(text: &[u8]) -> Option<(Mode, Vec<String>)> {
+    let text = std::str::from_utf8(text).ok()?;
+    text.match_indices('#').find_map(|(start, _)| parse_directive_at(&text[start..]))
+}
+
+/// Parse a directive starting at the `#` of `text`.
+fn parse_directive_at(text: &str) -> Option<(Mode, Vec<String>)> {
+    let rest = text.strip_prefix('#')?.trim_start();
+    let rest = rest.strip_prefix("rubocop")?.trim_start();
+    let rest = rest.strip_prefix(':')?.trim_start();
+
+    let mode_len = rest.find(|c: char| !c.is_ascii_alphabetic()).unwrap_or(rest.len());
+    let mode = match &rest[..mode_len] {
+        "disable" | "todo" => Mode::Disable,
+        "enable" => Mode::Enable,
+        _ => return None,
+    };
+
+    // Names are separated by commas; anything after them (e.g. `-- reason`) is ignored.
+    let mut selectors = Vec::new();
+    for part in rest[mode_len..].split(',') {
+        let mut words = part.split_whitespace();
+        let Some(name) = words.next() else {
+            break;
+        };
+        if !name.chars().all(|c| c.is_ascii_alphanumeric() || c == '_' || c == '/') {
+            break;
+        }
+        selectors.push(name.to_string());
+        if words.next().is_some() {
+            break;
+        }
+    }
+    if selectors.is_empty() {
+        return None;
+    }
+    Some((mode, selectors))
+}
+
+/// Check if a selector (`all`, a department, or a full rule name) covers the rule.
+fn selector_matches(selector: &str, rule_id: RuleId) -> bool {
+    if selector == "all" {
+        return true;
+    }
+    match selector.split_once('/') {
+        Some((department, name)) => department == rule_id.category().as_str() && name == rule_id.name(),
+        None => selector == rule_id.category().as_str(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::check;
+
+    fn trailing_whitespace_count(source: &[u8]) -> usize {
+        check(source).iter().filter(|d| d.rule() == "Layout/TrailingWhitespace").count()
+    }
+
+    #[test]
+    fn test_parse_directive() {
+        assert_eq!(
+            parse_directive(b"# rubocop:disable Lint/Debugger"),
+            Some((Mode::Disable, vec!["Lint/Debugger".to_string()]))
+        );
+        assert_eq!(
+            parse_directive(b"# rubocop:todo Layout, Lint/Debugger"),
+            Some((Mode::Disable, vec!["Layout".to_string(), "Lint/Debugger".to_string()]))
+        );
+        assert_eq!(parse_directive(b"#rubocop:enable all -- done"), Some((Mode::Enable, vec!["all".to_string()])));
+        assert_eq!(
+            parse_directive(b"# debug # rubocop:disable Lint/Debugger"),
+            Some((Mode::Disable, vec!["Lint/Debugger".to_string()]))
+        );
+        assert_eq!(parse_directive(b"# just a comment"), None);
+        assert_eq!(parse_directive(b"# rubocop:disable"), None);
+    }
+
+    #[test]
+    fn test_todo_suppresses_offense_on_line() {
+        let source = b"x = 1 # rubocop:todo Layout/TrailingWhitespace  \ny = 2  \n";
+        assert_eq!(trailing_whitespace_count(source), 1);
+    }
+
+    #[test]
+    fn test_disable_until_enable() {
+        let source = b"# rubocop:disable Layout/TrailingWhitespace\nx = 1  \n# rubocop:enable Layout/TrailingWhitespace\ny = 2  \n";
+        assert_eq!(trailing_whitespace_count(source), 1);
+    }
+
+    #[test]
+    fn test_department_enable_closes_rule_disable() {
+        let source = b"# rubocop:disable Layout/TrailingWhitespace\nx = 1  \n# rubocop:enable Layout\ny = 2  \n";
+        assert_eq!(trailing_whitespace_count(source), 1);
+        let source = b"# rubocop:disable Layout/TrailingWhitespace\nx = 1  \n# rubocop:enable Lay\ny = 2  \n";
+        assert_eq!(trailing_whitespace_count(source), 0);
+    }
+
+    #[test]
+    fn test_todo_until_end_of_file() {
+        let source = b"# rubocop:todo Layout\nx = 1  \ny = 2  \n";
+        assert_eq!(trailing_whitespace_count(source), 0);
+    }
+
+    #[test]
+    fn test_other_rules_are_not_suppressed() {
+        let source = b"binding.pry # rubocop:todo Layout/TrailingWhitespace\n";
+        assert_eq!(check(source).len(), 1);
+        let source = b"binding.pry # rubocop:todo all\n";
+        assert!(check(source).is_empty());
+    }
+}
@@ -0,0 +1,227 @@
+//! Insert `# rubocop:disable` comments for offenses that autocorrect could not fix.
+//!
+//! This is the equivalent of RuboCop's `--disable-uncorrectable`:
+//! - Offenses on a single line get an inline comment at the end of that line.
+//!   Offenses sharing a line are grouped into one comment, and an existing
+//!   `# rubocop:disable` comment on the line is extended with the new cop names.
+//! - Offenses spanning several lines are wrapped in a
+//!   `# rubocop:disable` / `# rubocop:enable` pair.
+
+use std::collections::BTreeMap;
+
+use crate::corrector::Corrector;
+use crate::diagnostic::{Diagnostic, Edit, Fix};
+use crate::locator::LineIndex;
+
+const DISABLE_DIRECTIVE: &str = "# rubocop:disable ";
+
+/// Build a fix that disables each of the given diagnostics with directive comments.
+///
+/// Returns `None` if there is nothing to disable.
+pub fn disable_uncorrectable_fix(source: &[u8], diagnostics: &[Diagnostic]) -> Option<Fix> {
+    disable_fix_with_count(source, diagnostics).map(|(fix, _)| fix)
+}
+
+/// Build the fix of `disable_uncorrectable_fix`, along with the number of
+/// diagnostics it disables.
+fn disable_fix_with_count(source: &[u8], diagnostics: &[Diagnostic]) -> Option<(Fix, usize)> {
+    if diagnostics.is_empty() {
+        return None;
+    }
+    let line_index = LineIndex::from_source(source);
+
+    // Cop names to disable inline, keyed by 0-indexed line, with their number of offenses.
+    let mut inline: BTreeMap<usize, Vec<(String, usize)>> = BTreeMap::new();
+    // Cop names to wrap in disable/enable pairs, keyed by (first line, last line).
+    let mut wrapped: BTreeMap<(usize, usize), Vec<(String, usize)>> = BTreeMap::new();
+
+    for diagnostic in diagnostics {
+        let first_line = diagnostic.line_start - 1;
+        let mut last_line = diagnostic.line_end - 1;
+        // An offense ending right after a newline does not cover the next line.
+        if last_line > first_line && diagnostic.column_end == 1 {
+            last_line -= 1;
+        }
+        let names = if first_line == last_line {
+            inline.entry(first_line).or_default()
+        } else {
+            wrapped.entry((first_line, last_line)).or_default()
+        };
+        let name = diagnostic.rule();
+        match names.iter_mut().find(|(existing, _)| *existing == name) {
+            Some((_, count)) => *count += 1,
+            None => names.push((name, 1)),
+        }
+    }
+    let mut disabled = 0;
+
+    // Insertions keyed by (offset, order) so that an `enable` closing one range
+    // comes before a `disable` opening the next one at the same offset.
+    let mut insertions: BTreeMap<(usize, u8), String> = BTreeMap::new();
+
+    for (line, names) in inline {
+        let Some(text) = line_index.line(line) else {
+            continue;
+        };
+        let line_start = line_index.line_start(line).unwrap_or(0);
+        let line_end = line_start + text.len();
+        let (position, content) = match find_disable_directive(text) {
+            Some(directive) => {
+                let existing = &text[directive + DISABLE_DIRECTIVE.len()..];
+                let existing: Vec<&str> = std::str::from_utf8(existing).unwrap_or("").split(',').map(str::trim).collect();
+                let missing: Vec<&(String, usize)> = names.iter().filter(|(name, _)| !existing.contains(&name.as_str())).collect();
+                if missing.is_empty() {
+                    continue;
+                }
+                disabled += missing.iter().map(|(_, count)| count).sum::<usize>();
+                let trimmed_end = line_start + text.trim_ascii_end().len();
+                let joined: Vec<&str> = missing.iter().map(|(name, _)| name.as_str()).collect();
+                (trimmed_end, format!(", {}", joined.join(", ")))
+            }
+            None => {
+                disabled += names.iter().map(|(_, count)| count).sum::<usize>();
+                let joined: Vec<&str> = names.iter().map(|(name, _)| name.as_str()).collect();
+                (line_end, format!(" {}{}", DISABLE_DIRECTIVE, joined.join(", ")))
+            }
+        };
+        insertions.entry((position, 1)).or_default().push_str(&content);
+    }
+
+    for ((first_line, last_line), names) in wrapped {
+        let Some(first_text) = line_index.line(first_line) else {
+            continue;
+        };
+        let indent_len = first_text.iter().take_while(|b| matches!(b, b' ' | b'\t')).count();
+        let indent = String::from_utf8_lossy(&first_text[..indent_len]);
+        disabled += names.iter().map(|(_, count)| count).sum::<usize>();
+        let names: Vec<&str> = names.iter().map(|(name, _)| name.as_str()).collect();
+        let names = names.join(", ");
+
+        let disable_at = line_index.line_start(first_line).unwrap_or(0);
+        insertions
+            .entry((disable_at, 2))
+            .or_default()
+            .push_str(&format!("{}{}{}\n", indent, DISABLE_DIRECTIVE, names));
+
+        let enable = format!("{}# rubocop:enable {}\n", indent, names);
+        match line_index.line_start(last_line + 1) {
+            Some(enable_at) => insertions.entry((enable_at, 0)).or_default().push_str(&enable),
+            // The range ends on the last line, which has no trailing newline.
+            None => insertions.entry((source.len(), 0)).or_default().push_str(&format!("\n{}", enable)),
+        }
+    }
+
+    // Merge insertions at the same offset into a single edit.
+    let mut merged: BTreeMap<usize, String> = BTreeMap::new();
+    for ((position, _), content) in insertions {
+        merged.entry(position).or_default().push_str(&content);
+    }
+    if merged.is_empty() {
+        return None;
+    }
+    let edits = merged.into_iter().map(|(position, content)| Edit::insertion(position, content)).collect();
+    Some((Fix::safe(edits), disabled))
+}
+
+/// Apply `disable_uncorrectable_fix` to the source.
+///
+/// Returns the new source and the number of diagnostics that were disabled.
+pub fn disable_uncorrectable(source: &[u8], diagnostics: &[Diagnostic]) -> (Vec<u8>, usize) {
+    let Some((fix, disabled)) = disable_fix_with_count(source, diagnostics) else {
+        return (source.to_vec(), 0);
+    };
+    let mut corrector = Corrector::new();
+    if corrector.merge(&fix).is_err() {
+        return (source.to_vec(), 0);
+    }
+    (corrector.apply(source), disabled)
+}
+
+/// Find the byte offset of a trailing `# rubocop:disable` comment in the line.
+fn find_disable_directive(line: &[u8]) -> Option<usize> {
+    let directive = DISABLE_DIRECTIVE.as_bytes();
+    line.windows(directive.len()).rposition(|window| window == directive)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::check;
+
+    fn disabled(source: &[u8]) -> String {
+        let diagnostics = check(source);
+        let (result, _) = disable_uncorrectable(source, &diagnostics);
+        String::from_utf8(result).unwrap()
+    }
+
+    #[test]
+    fn test_inline_disable() {
+        let source = b"def foo\n  binding.pry\nend\n";
+        assert_eq!(disabled(source), "def foo\n  binding.pry # rubocop:disable Lint/Debugger\nend\n");
+    }
+
+    #[test]
+    fn test_same_line_offenses_are_grouped() {
+        let source = b"def foo\n  binding.pry; debugger; File.expand_path('..', __FILE__)\nend\n";
+        assert_eq!(
+            disabled(source),
+            "def foo\n  binding.pry; debugger; File.expand_path('..', __FILE__) # rubocop:disable Lint/Debugger, Style/ExpandPathArguments\nend\n"
+        );
+    }
+
+    #[test]
+    fn test_existing_directive_is_extended() {
+        let source = b"binding.pry # rubocop:disable Style/ExpandPathArguments\n";
+        let diagnostics: Vec<_> = check(source).into_iter().filter(|d| d.rule() == "Lint/Debugger").collect();
+        let (result, count) = disable_uncorrectable(source, &diagnostics);
+        assert_eq!(count, 1);
+        assert_eq!(
+            String::from_utf8(result).unwrap(),
+            "binding.pry # rubocop:disable Style/ExpandPathArguments, Lint/Debugger\n"
+        );
+    }
+
+    #[test]
+    fn test_line_with_a_comment() {
+        let source = b"binding.pry # debug\n";
+        let (result, count) = disable_uncorrectable(source, &check(source));
+        assert_eq!(count, 1);
+        assert_eq!(result, b"binding.pry # debug # rubocop:disable Lint/Debugger\n");
+        assert!(check(&result).is_empty());
+    }
+
+    #[test]
+    fn test_count_excludes_offenses_already_disabled() {
+        // The offenses of the same lines without the existing directive
+        let diagnostics = check(b"binding.pry\ndebugger\n");
+        assert_eq!(diagnostics.len(), 2);
+        let source = b"binding.pry # rubocop:disable Lint/Debugger\ndebugger\n";
+        let (result, count) = disable_uncorrectable(source, &diagnostics);
+        assert_eq!(count, 1);
+        assert_eq!(
+            result,
+            b"binding.pry # rubocop:disable Lint/Debugger\ndebugger # rubocop:disable Lint/Debugger\n"
+        );
+    }
+
+    #[test]
+    fn test_already_disabled_cop_is_not_duplicated() {
+        let source = b"binding.pry # rubocop:disable Lint/Debugger\n";
+        let diagnostics: Vec<_> = check(source).into_iter().filter(|d| d.rule() == "Lint/Debugger").collect();
+        assert!(disable_uncorrectable_fix(source, &diagnostics).is_none());
+    }
+
+    #[test]
+    fn test_multiline_offense_is_wrapped() {
+        let source = b"def foo\n  Pry.rescue do\n    bar\n  end\nend\n";
+        assert_eq!(
+            disabled(source),
+            "def foo\n  # rubocop:disable Lint/Debugger\n  Pry.rescue do\n    bar\n  end\n  # rubocop:enable Lint/Debugger\nend\n"
+        );
+    }
+
+    #[test]
+    fn test_no_diagnostics() {
+        assert!(disable_uncorrectable_fix(b"foo\n", &[]).is_none());
+    }
+}
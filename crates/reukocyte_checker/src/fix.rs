@@ -1,8 +1,9 @@
+use crate::config::Config;
 use crate::conflict::ConflictRegistry;
 use crate::corrector;
-use crate::corrector::Corrector;
+use crate::corrector::{Change, Corrector};
 use crate::rule::RuleId;
-use crate::{Diagnostic, check};
+use crate::{Diagnostic, LineIndex, check, check_unparsed_line_rules, check_with_config_and_path, is_unparsed_line_rule};
 use rustc_hash::{FxHashSet, FxHasher};
 use std::hash::{Hash, Hasher};
 
@@ -10,20 +11,34 @@ use std::hash::{Hash, Hasher};
 /// RuboCop uses 200 as well.
 const MAX_ITERATIONS: usize = 200;
 
+/// How fixes are applied in each iteration of the correction loop.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum FixMode {
+    /// Merge all non-conflicting fixes into one pass (RuboCop behavior).
+    #[default]
+    Merged,
+    /// Apply exactly one fix per iteration and re-check in between.
+    ///
+    /// Much slower, but makes it easy to tell which rule produced a bad fix.
+    Serial,
+}
+
 /// Detects infinite loops during autocorrection.
 ///
 /// Tracks checksums of source code to detect when the same state
 /// is reached again (A -> B -> A pattern).
 struct LoopDetector {
     path: Option<String>,
+    max_iterations: usize,
     seen_checksums: Vec<u64>,
     rules_by_iteration: Vec<FxHashSet<RuleId>>,
 }
 impl LoopDetector {
     /// Create a new LoopDetector.
-    fn new(path: Option<&str>) -> Self {
+    fn new(path: Option<&str>, max_iterations: usize) -> Self {
         Self {
             path: path.map(|s| s.to_string()),
+            max_iterations,
             seen_checksums: Vec::new(),
             rules_by_iteration: Vec::new(),
         }
@@ -55,10 +70,10 @@ impl LoopDetector {
     }
     /// Check if we've exceeded the maximum iterations.
     fn check_max_iterations(&self) -> Result<(), InfiniteCorrectionLoop> {
-        if self.seen_checksums.len() >= MAX_ITERATIONS {
+        if self.seen_checksums.len() >= self.max_iterations {
             return Err(InfiniteCorrectionLoop {
                 path: self.path.clone(),
-                iteration: MAX_ITERATIONS,
+                iteration: self.max_iterations,
                 loop_start: None,
                 offending_rules: Vec::new(),
             });
@@ -96,7 +111,7 @@ impl std::fmt::Display for InfiniteCorrectionLoop {
         } else if let Some(start) = self.loop_start {
             write!(f, ": iteration {} produced the same source as iteration {}", self.iteration, start)?;
         } else {
-            write!(f, ": exceeded {} iterations", MAX_ITERATIONS)?;
+            write!(f, ": exceeded {} iterations", self.iteration)?;
         }
         Ok(())
     }
@@ -145,7 +160,7 @@ pub fn apply_fixes_with_loop_detection(
     diagnostics: &[Diagnostic],
     unsafe_fixes: bool,
 ) -> Result<(Vec<u8>, usize), InfiniteCorrectionLoop> {
-    apply_fixes_filtered_with_loop_detection(path, source, diagnostics, unsafe_fixes, |_| true)
+    apply_fixes_filtered_with_loop_detection(path, source, diagnostics, &Config::default(), unsafe_fixes, FixMode::Merged, |_| true)
 }
 
 /// Apply fixes with a filter function for diagnostics.
@@ -159,7 +174,7 @@ pub fn apply_fixes_filtered<F>(path: Option<&str>, source: &[u8], diagnostics: &
 where
     F: Fn(&Diagnostic) -> bool,
 {
-    match apply_fixes_filtered_with_loop_detection(path, source, diagnostics, unsafe_fixes, filter) {
+    match apply_fixes_filtered_with_loop_detection(path, source, diagnostics, &Config::default(), unsafe_fixes, FixMode::Merged, filter) {
         Ok((source, count)) => (source, count),
         Err(err) => {
             eprintln!("Warning: {}", err);
@@ -169,42 +184,140 @@ where
 }
 
 /// Apply fixes with a filter and infinite loop detection.
+///
+/// The source is re-checked with `config` after each iteration.
+/// With `FixMode::Serial`, only the first applicable fix is applied per iteration.
 pub fn apply_fixes_filtered_with_loop_detection<F>(
     path: Option<&str>,
     source: &[u8],
     diagnostics: &[Diagnostic],
+    config: &Config,
     unsafe_fixes: bool,
+    mode: FixMode,
     filter: F,
 ) -> Result<(Vec<u8>, usize), InfiniteCorrectionLoop>
+where
+    F: Fn(&Diagnostic) -> bool,
+{
+    correct_iteratively(path, source, diagnostics, config, unsafe_fixes, mode, Recheck::for_mode(mode), filter)
+        .map(|(source, edit_count, _)| (source, edit_count))
+}
+
+/// Apply fixes with a filter, returning the diagnostics whose fixes were applied.
+///
+/// Unlike `apply_fixes_filtered`, which counts edits, this reports each corrected
+/// offense, including ones that only appeared after an earlier iteration's fixes.
+pub fn apply_fixes_filtered_with_corrected<F>(
+    path: Option<&str>,
+    source: &[u8],
+    diagnostics: &[Diagnostic],
+    config: &Config,
+    unsafe_fixes: bool,
+    mode: FixMode,
+    filter: F,
+) -> (Vec<u8>, Vec<Diagnostic>)
+where
+    F: Fn(&Diagnostic) -> bool,
+{
+    match correct_iteratively(path, source, diagnostics, config, unsafe_fixes, mode, Recheck::for_mode(mode), filter) {
+        Ok((source, _, corrected)) => (source, corrected),
+        Err(err) => {
+            eprintln!("Warning: {}", err);
+            (source.to_vec(), Vec::new())
+        }
+    }
+}
+
+/// How the source is re-checked after each iteration of the correction loop.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Recheck {
+    /// Parse and check the whole source again.
+    Full,
+    /// When the iteration only removed trailing blanks (see `recheck_locally`),
+    /// re-run just the line-based rules and keep the other offenses.
+    Incremental,
+}
+impl Recheck {
+    /// Serial mode re-checks fully, so that every fix is checked against a fresh parse.
+    fn for_mode(mode: FixMode) -> Self {
+        match mode {
+            FixMode::Merged => Self::Incremental,
+            FixMode::Serial => Self::Full,
+        }
+    }
+}
+
+/// Run the correction loop, returning the corrected source, the number of edits
+/// applied, and the diagnostics whose fixes were applied.
+#[allow(clippy::too_many_arguments)]
+fn correct_iteratively<F>(
+    path: Option<&str>,
+    source: &[u8],
+    diagnostics: &[Diagnostic],
+    config: &Config,
+    unsafe_fixes: bool,
+    mode: FixMode,
+    recheck: Recheck,
+    filter: F,
+) -> Result<(Vec<u8>, usize, Vec<Diagnostic>), InfiniteCorrectionLoop>
 where
     F: Fn(&Diagnostic) -> bool,
 {
     let mut current_source = source.to_vec();
     let mut total_fixed = 0;
+    let mut corrected = Vec::new();
     let mut current_diagnostics: Vec<Diagnostic> = diagnostics.iter().filter(|d| filter(d)).cloned().collect();
-    let mut loop_detector = LoopDetector::new(path);
-
-    for iteration in 0..MAX_ITERATIONS {
+    // Serial mode needs (at least) one iteration per fix.
+    let max_iterations = match mode {
+        FixMode::Merged => MAX_ITERATIONS,
+        FixMode::Serial => MAX_ITERATIONS + current_diagnostics.len(),
+    };
+    let mut loop_detector = LoopDetector::new(path, max_iterations);
+
+    for iteration in 0..max_iterations {
         loop_detector.check(&current_source, iteration)?;
 
         let mut corrector = Corrector::new();
         let mut conflict_registry = ConflictRegistry::new();
         let mut applied_rules_this_iteration: FxHashSet<RuleId> = FxHashSet::default();
-
-        for diagnostic in &current_diagnostics {
-            if let Some(fix) = &diagnostic.fix {
-                if !corrector::should_apply_fix(fix, unsafe_fixes) {
-                    continue;
-                }
-                if conflict_registry.conflicts_with_applied(diagnostic.rule_id) {
+        // In merge order, so that fixes displaced by a safe one can be taken back out
+        let mut corrected_this_iteration: Vec<Option<Diagnostic>> = Vec::new();
+        let mut uncorrected = Vec::new();
+
+        let mut diagnostics = current_diagnostics.into_iter();
+        for diagnostic in diagnostics.by_ref() {
+            let merged = diagnostic
+                .fix
+                .as_ref()
+                .filter(|fix| {
+                    corrector::should_apply_fix(fix, unsafe_fixes)
+                        && allows_autocorrect(config, diagnostic.rule_id)
+                        && !conflict_registry.conflicts_with_applied(diagnostic.rule_id)
+                })
+                .and_then(|fix| corrector.merge(fix).ok());
+            let Some(displaced) = merged else {
+                uncorrected.push(diagnostic);
+                continue;
+            };
+            for index in displaced {
+                let Some(displaced) = corrected_this_iteration[index].take() else {
                     continue;
+                };
+                // The rule no longer counts as applied unless another of its fixes is still merged
+                if !corrected_this_iteration.iter().flatten().any(|merged| merged.rule_id == displaced.rule_id) {
+                    conflict_registry.unmark_applied(displaced.rule_id);
+                    applied_rules_this_iteration.remove(&displaced.rule_id);
                 }
-                if corrector.merge(fix).is_ok() {
-                    conflict_registry.mark_applied(diagnostic.rule_id);
-                    applied_rules_this_iteration.insert(diagnostic.rule_id);
-                }
+                uncorrected.push(displaced);
+            }
+            conflict_registry.mark_applied(diagnostic.rule_id);
+            applied_rules_this_iteration.insert(diagnostic.rule_id);
+            corrected_this_iteration.push(Some(diagnostic));
+            if mode == FixMode::Serial {
+                break;
             }
         }
+        uncorrected.extend(diagnostics);
 
         loop_detector.record_rules(applied_rules_this_iteration);
 
@@ -214,10 +327,22 @@ where
         }
 
         total_fixed += corrector.edit_count();
-        current_source = corrector.apply(&current_source);
+        corrected.extend(corrected_this_iteration.into_iter().flatten());
+        let (new_source, changes) = corrector.apply_with_changes(&current_source);
 
         // Re-check and apply filter
-        current_diagnostics = check(&current_source).into_iter().filter(|d| filter(d)).collect();
+        let local = match recheck {
+            Recheck::Incremental => recheck_locally(&current_source, &new_source, &changes, uncorrected, config, path),
+            Recheck::Full => None,
+        };
+        current_source = new_source;
+        current_diagnostics = match local {
+            Some((kept, rechecked)) => merge_sorted(kept, rechecked.into_iter().filter(|d| filter(d)).collect()),
+            None => check_with_config_and_path(&current_source, config, path, None)
+                .into_iter()
+                .filter(|d| filter(d))
+                .collect(),
+        };
 
         if current_diagnostics.iter().all(|d| d.fix.is_none()) {
             break;
@@ -225,7 +350,109 @@ where
     }
 
     loop_detector.check_max_iterations()?;
-    Ok((current_source, total_fixed))
+    Ok((current_source, total_fixed, corrected))
+}
+
+/// Check if the config lets `rule_id`'s fixes be applied (`AutoCorrect`).
+fn allows_autocorrect(config: &Config, rule_id: RuleId) -> bool {
+    rule_id.base_config(config).auto_correct
+}
+
+/// Re-check a source after an iteration that only removed blanks at the end of
+/// non-blank lines, without parsing it again.
+///
+/// Such edits leave the AST, the comments, and every line and column before
+/// them unchanged, so only the line-based rules that need no parse are re-run
+/// (over the whole source: some of them look across lines). The other offenses
+/// left uncorrected this iteration are kept, with their offsets moved past the
+/// removed bytes.
+///
+/// Returns `(kept, rechecked)`, or `None` when a full check is needed: an edit
+/// was something else, a kept offense touches an edit, an enabled AST rule
+/// depends on line widths, or the source may have `rubocop:` directives.
+fn recheck_locally(
+    old_source: &[u8],
+    new_source: &[u8],
+    changes: &[Change],
+    uncorrected: Vec<Diagnostic>,
+    config: &Config,
+    path: Option<&str>,
+) -> Option<(Vec<Diagnostic>, Vec<Diagnostic>)> {
+    if !changes.iter().all(|change| is_trailing_blank_removal(old_source, change)) || has_width_dependent_rule(config) {
+        return None;
+    }
+    let kept: Vec<Diagnostic> = uncorrected.into_iter().filter(|d| !is_unparsed_line_rule(d.rule_id)).collect();
+    if kept.iter().any(|d| touches_changes(d, changes)) {
+        return None;
+    }
+    let rechecked = check_unparsed_line_rules(new_source, config, path)?;
+    let line_index = LineIndex::from_source(new_source);
+    let kept = kept.into_iter().map(|d| shift_diagnostic(d, changes, &line_index)).collect();
+    Some((kept, rechecked))
+}
+
+/// Check if an AST rule whose offenses depend on how wide lines are is enabled.
+///
+/// Removing trailing blanks narrows lines, which can make such a rule report
+/// an offense it did not before (an expression that now fits `Layout/LineLength`).
+fn has_width_dependent_rule(config: &Config) -> bool {
+    config.layout.redundant_line_break.base.enabled
+}
+
+/// Check if a change only deletes spaces and tabs that follow other content on their line.
+fn is_trailing_blank_removal(source: &[u8], change: &Change) -> bool {
+    let (start, end) = (change.start, change.end);
+    change.inserted == 0
+        && start > 0
+        && start < end
+        && !matches!(source[start - 1], b' ' | b'\t' | b'\n' | b'\r')
+        && source[start..end].iter().all(|&b| b == b' ' || b == b'\t')
+        && matches!(source.get(end), None | Some(b'\n' | b'\r'))
+}
+
+/// Check if the range of a diagnostic, or of one of its edits, overlaps or touches a change.
+fn touches_changes(diagnostic: &Diagnostic, changes: &[Change]) -> bool {
+    let edits = diagnostic.fix.iter().flat_map(|fix| &fix.edits).map(|edit| (edit.start, edit.end));
+    std::iter::once((diagnostic.start, diagnostic.end))
+        .chain(edits)
+        .any(|(start, end)| changes.iter().any(|change| start <= change.end && change.start <= end))
+}
+
+/// Move an offset of the old source to the new one, past the deleted `changes`.
+fn shift_offset(offset: usize, changes: &[Change]) -> usize {
+    let removed: usize = changes
+        .iter()
+        .take_while(|change| change.end <= offset)
+        .map(|change| change.end - change.start)
+        .sum();
+    offset - removed
+}
+
+/// Move a diagnostic (and its fix) to the new source, recomputing its lines and columns.
+fn shift_diagnostic(mut diagnostic: Diagnostic, changes: &[Change], line_index: &LineIndex) -> Diagnostic {
+    diagnostic.start = shift_offset(diagnostic.start, changes);
+    diagnostic.end = shift_offset(diagnostic.end, changes);
+    if let Some(fix) = &mut diagnostic.fix {
+        for edit in &mut fix.edits {
+            edit.start = shift_offset(edit.start, changes);
+            edit.end = shift_offset(edit.end, changes);
+        }
+    }
+    let (line_start, line_end, column_start, column_end) = line_index.batch_line_column(&[(diagnostic.start, diagnostic.end)])[0];
+    diagnostic.line_start = line_start;
+    diagnostic.line_end = line_end;
+    diagnostic.column_start = column_start;
+    diagnostic.column_end = column_end;
+    diagnostic
+}
+
+/// Merge two lists sorted by range, keeping `first` before `second` on ties like a full check does.
+fn merge_sorted(first: Vec<Diagnostic>, second: Vec<Diagnostic>) -> Vec<Diagnostic> {
+    let mut merged = first;
+    merged.extend(second);
+    // `sort_by_key` is stable
+    merged.sort_by_key(|d| (d.start, d.end));
+    merged
 }
 
 /// Apply fixes and return the result along with remaining diagnostics.
@@ -240,6 +467,8 @@ pub fn apply_fixes_with_remaining(path: Option<&str>, source: &[u8], diagnostics
 mod tests {
     use super::*;
     use crate::check;
+    use crate::rule::{LintRule, StyleRule};
+    use crate::{Edit, Fix, Severity};
 
     #[test]
     fn test_apply_trailing_whitespace_fix() {
@@ -309,18 +538,207 @@ mod tests {
         assert_eq!(remaining[0].rule(), "Lint/Debugger");
     }
 
+    #[test]
+    fn test_apply_fixes_with_corrected() {
+        let source = b"def foo  \n  binding.pry\nend\n";
+        let diagnostics = check(source);
+
+        let (fixed, corrected) = apply_fixes_filtered_with_corrected(None, source, &diagnostics, &Config::default(), false, FixMode::Merged, |_| true);
+
+        assert_eq!(fixed, b"def foo\n  binding.pry\nend\n");
+        assert_eq!(corrected.len(), 1);
+        assert_eq!(corrected[0].rule(), "Layout/TrailingWhitespace");
+    }
+
+    #[test]
+    fn test_serial_and_merged_fixes_converge() {
+        let source = b"x = 1  \n\n\n\nif a\n  b\nelse\nend\nfoo(:true)   \n";
+        let diagnostics = check(source);
+
+        let (merged, merged_corrected) = apply_fixes_filtered_with_corrected(None, source, &diagnostics, &Config::default(), true, FixMode::Merged, |_| true);
+        let (serial, serial_corrected) = apply_fixes_filtered_with_corrected(None, source, &diagnostics, &Config::default(), true, FixMode::Serial, |_| true);
+
+        assert_eq!(serial, merged);
+        assert_eq!(serial, b"x = 1\n\nif a\n  b\nend\nfoo(true)\n");
+        assert_eq!(serial_corrected.len(), merged_corrected.len());
+    }
+
+    #[test]
+    fn test_safe_autocorrect_false_requires_unsafe_fixes() {
+        let yaml = crate::config::parse_rubocop_yaml("Style/StderrPuts:\n  SafeAutoCorrect: false\n").unwrap();
+        let config = Config::from_rubocop_yaml(&yaml);
+        let source = b"$stderr.puts 'oops'\n";
+        let diagnostics = crate::check_with_config(source, &config);
+        assert_eq!(diagnostics.len(), 1);
+        assert_eq!(diagnostics[0].fix_applicability(), Some(crate::Applicability::Unsafe));
+
+        // `-a` reports the offense but withholds the fix; `-A` applies it.
+        let (fixed, corrected) = apply_fixes_filtered_with_corrected(None, source, &diagnostics, &config, false, FixMode::Merged, |_| true);
+        assert_eq!(fixed, source);
+        assert!(corrected.is_empty());
+        let (fixed, _) = apply_fixes_filtered_with_corrected(None, source, &diagnostics, &config, true, FixMode::Merged, |_| true);
+        assert_eq!(fixed, b"warn 'oops'\n");
+    }
+
+    #[test]
+    fn test_auto_correct_false_reports_without_fixing() {
+        let yaml = crate::config::parse_rubocop_yaml("Layout/TrailingWhitespace:\n  AutoCorrect: false\n").unwrap();
+        let config = Config::from_rubocop_yaml(&yaml);
+        assert!(!config.layout.trailing_whitespace.base.auto_correct);
+        let source = b"$stderr.puts 'oops'  \n";
+        let diagnostics = crate::check_with_config(source, &config);
+        assert_eq!(diagnostics.len(), 2);
+        // The offense is not correctable, so `--autocorrectable-only` and the like leave it out.
+        let trailing = diagnostics.iter().find(|d| d.rule() == "Layout/TrailingWhitespace").unwrap();
+        assert!(trailing.fix.is_none());
+
+        // Other cops are still corrected under `-a`.
+        let (fixed, corrected) = apply_fixes_filtered_with_corrected(None, source, &diagnostics, &config, false, FixMode::Merged, |_| true);
+        assert_eq!(fixed, b"warn 'oops'  \n");
+        assert_eq!(corrected.len(), 1);
+        let (fixed, _) = apply_fixes_filtered_with_corrected(None, source, &diagnostics, &config, true, FixMode::Serial, |_| true);
+        assert_eq!(fixed, b"warn 'oops'  \n");
+    }
+
+    #[test]
+    fn test_auto_correct_accepts_strings() {
+        let yaml =
+            crate::config::parse_rubocop_yaml("Layout/TrailingWhitespace:\n  AutoCorrect: disabled\nLayout/EmptyLines:\n  AutoCorrect: always\n").unwrap();
+        let config = Config::from_rubocop_yaml(&yaml);
+        assert!(!config.layout.trailing_whitespace.base.auto_correct);
+        assert!(config.layout.empty_lines.base.auto_correct);
+    }
+
+    #[test]
+    fn test_safe_autocorrect_is_respected_after_recheck() {
+        let yaml = crate::config::parse_rubocop_yaml("Style/StderrPuts:\n  SafeAutoCorrect: false\n").unwrap();
+        let config = Config::from_rubocop_yaml(&yaml);
+        let source = b"$stderr.puts 'oops'  \n";
+        let diagnostics = crate::check_with_config(source, &config);
+        let (fixed, corrected) = apply_fixes_filtered_with_corrected(None, source, &diagnostics, &config, false, FixMode::Serial, |_| true);
+        assert_eq!(fixed, b"$stderr.puts 'oops'\n");
+        assert_eq!(corrected.len(), 1);
+        // The default config keeps the fix safe.
+        assert_eq!(
+            check(source)
+                .iter()
+                .filter(|d| d.fix_applicability() == Some(crate::Applicability::Safe))
+                .count(),
+            2
+        );
+    }
+
+    #[test]
+    fn test_serial_mode_applies_one_fix_per_iteration() {
+        let source = b"a = 1  \nb = 2  \nc = 3  \n";
+        let diagnostics = check(source);
+
+        let (fixed, count) =
+            apply_fixes_filtered_with_loop_detection(None, source, &diagnostics, &Config::default(), false, FixMode::Serial, |_| true).unwrap();
+
+        assert_eq!(count, 3);
+        assert_eq!(fixed, b"a = 1\nb = 2\nc = 3\n");
+    }
+
     #[test]
     fn test_whitespace_only_line() {
         let source = b"def foo\n   \nend\n";
-        let diagnostics = check(source);
+        let is_trailing_whitespace = |d: &Diagnostic| d.rule() == "Layout/TrailingWhitespace";
+        let diagnostics: Vec<Diagnostic> = check(source).into_iter().filter(is_trailing_whitespace).collect();
 
         assert_eq!(diagnostics.len(), 1);
 
-        let (fixed, count) = apply_fixes(None, source, &diagnostics, false);
+        let (fixed, count) = apply_fixes_filtered(None, source, &diagnostics, false, is_trailing_whitespace);
 
         assert_eq!(count, 1);
         assert_eq!(fixed, b"def foo\n\nend\n");
     }
+
+    #[test]
+    fn test_safe_fix_wins_over_unsafe_on_the_same_range() {
+        let source = b"x = 1\n";
+        let diagnostic = |rule_id, fix: Fix| Diagnostic::new(rule_id, String::new(), Severity::Warning, 4, 5, 1, 1, 5, 6, Some(fix));
+        let diagnostics = [
+            diagnostic(
+                RuleId::Lint(LintRule::BooleanSymbol),
+                Fix::r#unsafe(vec![Edit::replacement(4, 5, "2".to_string())]),
+            ),
+            diagnostic(
+                RuleId::Style(StyleRule::RedundantReturn),
+                Fix::safe(vec![Edit::replacement(4, 5, "3".to_string())]),
+            ),
+        ];
+        let config = Config::default();
+        let (fixed, corrected) = apply_fixes_filtered_with_corrected(None, source, &diagnostics, &config, true, FixMode::Merged, |_| true);
+        assert_eq!(fixed, b"x = 3\n");
+        assert_eq!(corrected, vec![diagnostics[1].clone()]);
+    }
+
+    /// Run the loop on `source` with both re-check strategies.
+    fn correct_both_ways(source: &[u8], unsafe_fixes: bool, config: &Config) -> [(Vec<u8>, usize, Vec<Diagnostic>); 2] {
+        let diagnostics = crate::check_with_config(source, config);
+        [Recheck::Incremental, Recheck::Full]
+            .map(|recheck| correct_iteratively(None, source, &diagnostics, config, unsafe_fixes, FixMode::Merged, recheck, |_| true).unwrap())
+    }
+
+    #[test]
+    fn test_incremental_recheck_matches_full() {
+        let sources: [&[u8]; 5] = [
+            b"def foo  \n  bar\t\nend  \n",
+            b"foo(:true)  \nx = [ 1 ]\n",
+            b"def foo  \n\n  bar # note  \nend\n\n\n",
+            b"x = 1  \n   \n\n\ny = 2 \n",
+            b"x = 1  # rubocop:disable Layout/TrailingWhitespace\ny = 2  \n",
+        ];
+        for source in sources {
+            for unsafe_fixes in [false, true] {
+                let [incremental, full] = correct_both_ways(source, unsafe_fixes, &Config::default());
+                assert_eq!(incremental, full, "source: {:?}", String::from_utf8_lossy(source));
+            }
+        }
+
+        // Removing the trailing blanks makes the call fit on one line.
+        let mut config = Config::default();
+        config.layout.redundant_line_break.base.enabled = true;
+        config.layout.line_length.max = 12;
+        let [incremental, full] = correct_both_ways(b"foo(a,\n    b)     \n", true, &config);
+        assert_eq!(full.0, b"foo(a, b)\n");
+        assert_eq!(incremental, full);
+    }
+
+    #[test]
+    fn test_recheck_locally_after_trailing_whitespace() {
+        let config = Config::default();
+        let source = b"foo(:true)  \nx = [ 1 ]\t\n";
+        let (trailing, others): (Vec<Diagnostic>, Vec<Diagnostic>) = check(source).into_iter().partition(|d| d.rule() == "Layout/TrailingWhitespace");
+        let mut corrector = Corrector::new();
+        for diagnostic in &trailing {
+            corrector.merge(diagnostic.fix.as_ref().unwrap()).unwrap();
+        }
+        let (new_source, changes) = corrector.apply_with_changes(source);
+
+        let (kept, rechecked) = recheck_locally(source, &new_source, &changes, others, &config, None).unwrap();
+        assert_eq!(kept.len(), 3);
+        assert!(rechecked.is_empty());
+        assert_eq!(merge_sorted(kept, rechecked), check(&new_source));
+    }
+
+    #[test]
+    fn test_recheck_locally_falls_back() {
+        let config = Config::default();
+        let recheck = |source: &[u8], start: usize, end: usize| {
+            let changes = [Change { start, end, inserted: 0 }];
+            let mut new_source = source[..start].to_vec();
+            new_source.extend_from_slice(&source[end..]);
+            recheck_locally(source, &new_source, &changes, check(source), &config, None)
+        };
+        assert!(recheck(b"x = 1  \n", 5, 7).is_some());
+        // Blanks of a whitespace-only line, or blanks before more content
+        assert!(recheck(b"x = 1\n  \ny = 2\n", 6, 8).is_none());
+        assert!(recheck(b"x = 1  + 2\n", 5, 6).is_none());
+        // Directives are read from the parsed comments
+        assert!(recheck(b"x = 1  # rubocop:disable all\n", 5, 6).is_none());
+    }
 }
 
 /// Tests for rule conflict handling.
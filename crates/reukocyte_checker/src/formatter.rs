@@ -0,0 +1,55 @@
+//! The output formatter interface.
+//!
+//! A run tells its formatter when it starts, hands over each file's offenses as
+//! the file is checked and passes the totals at the end. The `--format` options
+//! of the CLI are all built on this trait, and embedders can supply their own.
+
+use crate::diagnostic::Diagnostic;
+use std::io;
+
+/// Totals of a run, passed to `Formatter::finished`.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct RunSummary {
+    /// Number of files inspected.
+    pub inspected_files: usize,
+    /// Number of offenses left after autocorrection.
+    pub offense_count: usize,
+    /// Number of offenses corrected.
+    pub corrected_count: usize,
+    /// Number of files skipped for exceeding `AllCops.MaximumFileSize`.
+    pub skipped_files: usize,
+}
+
+/// A checked file, passed to `Formatter::file_inspected`.
+#[derive(Debug, Clone, Copy)]
+pub struct InspectedFile<'a> {
+    pub path: &'a str,
+    /// The source the offenses refer to; the corrected source when fixes were applied.
+    pub source: &'a [u8],
+    /// Offenses left in the file.
+    pub diagnostics: &'a [Diagnostic],
+    /// Offenses corrected in the file.
+    pub corrected: &'a [Diagnostic],
+}
+
+/// Receives the results of a run as it progresses.
+pub trait Formatter {
+    /// Called once, before the first file is checked.
+    fn started(&mut self) -> io::Result<()> {
+        Ok(())
+    }
+
+    /// Called with the offenses left in each checked file, including files without any.
+    fn file_finished(&mut self, path: &str, diagnostics: &[Diagnostic]) -> io::Result<()>;
+
+    /// Called for each checked file instead of `file_finished`, for formatters that
+    /// also need the source or the corrected offenses.
+    fn file_inspected(&mut self, file: &InspectedFile<'_>) -> io::Result<()> {
+        self.file_finished(file.path, file.diagnostics)
+    }
+
+    /// Called once, after the last file.
+    fn finished(&mut self, _summary: &RunSummary) -> io::Result<()> {
+        Ok(())
+    }
+}
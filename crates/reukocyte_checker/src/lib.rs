@@ -1,30 +1,44 @@
+mod batch;
 mod checker;
 mod config;
 mod conflict;
 mod corrector;
 pub mod custom_nodes;
 mod diagnostic;
+mod directive;
+mod disable_uncorrectable;
 mod fix;
+mod formatter;
 mod locator;
+mod profile;
 mod rule;
 mod semantic;
 mod utility;
 
 pub mod rules;
 
+pub use batch::{FileResults, check_files, file_mode};
 pub use checker::Checker;
 pub use config::{
-    load_rubocop_yaml, parse_rubocop_yaml, AllCopsConfig, Config,
-    InheritFrom, LayoutConfig, LoadError, RubocopYaml,
+    load_rubocop_yaml, parse_rubocop_yaml, AllCopsConfig, Config, ConfigBuildError,
+    ConfigBuilder, DEFAULT_TARGET_RUBY_VERSION, InheritFrom, LayoutConfig, LoadError, RubocopYaml,
 };
 pub use conflict::ConflictRegistry;
-pub use corrector::{ClobberingError, Corrector};
+pub use corrector::{Change, ClobberingError, Corrector, should_apply_fix};
 pub use diagnostic::{Applicability, Diagnostic, Edit, Fix, Severity};
-pub use fix::{InfiniteCorrectionLoop, apply_fixes, apply_fixes_filtered, apply_fixes_with_loop_detection, apply_fixes_with_remaining};
+pub use directive::Directives;
+pub use disable_uncorrectable::{disable_uncorrectable, disable_uncorrectable_fix};
+pub use fix::{
+    FixMode, InfiniteCorrectionLoop, apply_fixes, apply_fixes_filtered, apply_fixes_filtered_with_corrected, apply_fixes_with_loop_detection,
+    apply_fixes_with_remaining,
+};
+pub use formatter::{Formatter, InspectedFile, RunSummary};
 pub use locator::LineIndex;
-pub use rule::{Category, Check, LayoutRule, LintRule, Rule, RuleId};
+pub use profile::{RuleTiming, is_profiling, set_profiling, take_profile};
+pub use rule::{Category, CategorySet, Check, LayoutRule, LintRule, Rule, RuleDoc, RuleId, RuleOption, StyleRule};
 
-use ruby_prism::Visit;
+use checker::{may_have_directives, needs_parse};
+use ruby_prism::{Node, ParseResult, Visit};
 
 /// Check a Ruby source file for violations with default configuration.
 ///
@@ -38,44 +52,219 @@ pub fn check(source: &[u8]) -> Vec<Diagnostic> {
 
 /// Check a Ruby source file for violations with custom configuration.
 pub fn check_with_config(source: &[u8], config: &Config) -> Vec<Diagnostic> {
-    check_with_config_and_path(source, config, None)
+    check_with_config_and_path(source, config, None, None)
 }
 
 /// Check a Ruby source file for violations with custom configuration and file path.
 ///
-/// The file path is used for cop-specific Exclude pattern matching.
+/// The file path is used for cop-specific Exclude pattern matching, and the
+/// file mode (permission bits) by cops that inspect file metadata.
+/// When only line-based rules that need no AST are enabled, the source is not
+/// parsed at all.
 pub fn check_with_config_and_path(
     source: &[u8],
     config: &Config,
     file_path: Option<&str>,
+    file_mode: Option<u32>,
+) -> Vec<Diagnostic> {
+    check_with_categories(source, config, file_path, file_mode, CategorySet::ALL)
+}
+
+/// Check a Ruby source file, running only the rules in `categories`.
+///
+/// Like `check_with_config_and_path`, but e.g. `CategorySet::only(Category::Layout)`
+/// gives a layout-only pass. When no AST-based rule of `categories` is enabled,
+/// the source is neither parsed nor indexed.
+pub fn check_with_categories(
+    source: &[u8],
+    config: &Config,
+    file_path: Option<&str>,
+    file_mode: Option<u32>,
+    categories: CategorySet,
 ) -> Vec<Diagnostic> {
-    let parse_result = ruby_prism::parse(source);
-    let mut checker = if let Some(path) = file_path {
-        Checker::with_file_path(source, config, path)
-    } else {
-        Checker::new(source, config)
-    };
+    let parse_result = needs_parse(source, config, categories).then(|| ruby_prism::parse(source));
+    let mut checker = new_checker(source, config, file_path, parse_result.as_ref());
+    checker.set_file_mode(file_mode);
+    checker.set_categories(categories);
+    match &parse_result {
+        Some(parse_result) => run_rules(&mut checker, &parse_result.node()),
+        None => run_line_rules(&mut checker),
+    }
+    checker.into_diagnostics()
+}
+
+/// Check a Ruby source file, passing each diagnostic to `callback` as it is reported.
+///
+/// Diagnostics are not buffered, so they arrive in traversal order (AST rules
+/// first, then line-based rules) rather than sorted by position.
+pub fn check_with_callback<'rk>(source: &'rk [u8], config: &'rk Config, file_path: Option<&'rk str>, callback: impl FnMut(Diagnostic) + 'rk) {
+    let parse_result = needs_parse(source, config, CategorySet::ALL).then(|| ruby_prism::parse(source));
+    let mut checker = new_checker(source, config, file_path, parse_result.as_ref());
+    checker.set_on_diagnostic(callback);
+    match &parse_result {
+        Some(parse_result) => run_rules(&mut checker, &parse_result.node()),
+        None => run_line_rules(&mut checker),
+    }
+}
+
+/// Create the checker, from the parse result when the source was parsed.
+fn new_checker<'rk>(
+    source: &'rk [u8],
+    config: &'rk Config,
+    file_path: Option<&'rk str>,
+    parse_result: Option<&ParseResult<'_>>,
+) -> Checker<'rk> {
+    match (parse_result, file_path) {
+        (Some(parse_result), _) => Checker::from_parse_result(source, config, file_path, parse_result),
+        (None, Some(path)) => Checker::with_file_path(source, config, path),
+        (None, None) => Checker::new(source, config),
+    }
+}
 
+/// Run all rules on the parsed source.
+fn run_rules<'rk>(checker: &mut Checker<'rk>, root: &Node<'rk>) {
     // Phase 1: Build node index (pre-index all nodes before rules run)
-    checker.build_index(&parse_result.node());
+    checker.build_index(root);
 
     // Phase 2: Run AST-based rules (single traversal)
-    checker.visit(&parse_result.node());
+    checker.visit(root);
 
     // Phase 3: Run line-based rules (after AST, can use collected info)
-    rules::layout::trailing_whitespace::check(&mut checker);
-    rules::layout::trailing_empty_lines::check(&mut checker);
-    rules::layout::leading_empty_lines::check(&mut checker);
-    rules::layout::empty_lines::check(&mut checker);
-    rules::layout::indentation_style::check(&mut checker);
+    run_line_rules(checker);
+}
 
-    checker.into_diagnostics()
+/// Run the line-based rules.
+fn run_line_rules(checker: &mut Checker) {
+    if checker.runs_category(Category::Layout) {
+        profile::timed(checker, rules::layout::trailing_whitespace::RULE_ID, rules::layout::trailing_whitespace::check);
+        profile::timed(checker, rules::layout::end_of_line::RULE_ID, rules::layout::end_of_line::check);
+        profile::timed(checker, rules::layout::trailing_empty_lines::RULE_ID, rules::layout::trailing_empty_lines::check);
+        profile::timed(checker, rules::layout::leading_empty_lines::RULE_ID, rules::layout::leading_empty_lines::check);
+        profile::timed(checker, rules::layout::empty_line_after_magic_comment::RULE_ID, rules::layout::empty_line_after_magic_comment::check);
+        profile::timed(checker, rules::layout::leading_comment_space::RULE_ID, rules::layout::leading_comment_space::check);
+        profile::timed(checker, rules::layout::empty_lines::RULE_ID, rules::layout::empty_lines::check);
+        profile::timed(checker, rules::layout::indentation_style::RULE_ID, rules::layout::indentation_style::check);
+        profile::timed(checker, rules::layout::extra_spacing::RULE_ID, rules::layout::extra_spacing::check);
+    }
+    if checker.runs_category(Category::Lint) {
+        profile::timed(checker, rules::lint::script_permission::RULE_ID, rules::lint::script_permission::check);
+    }
+}
+
+/// The line-based rules that need neither the AST nor the parsed comments.
+const UNPARSED_LINE_RULES: &[RuleId] = &[
+    rules::layout::trailing_whitespace::RULE_ID,
+    rules::layout::end_of_line::RULE_ID,
+    rules::layout::trailing_empty_lines::RULE_ID,
+    rules::layout::leading_empty_lines::RULE_ID,
+    rules::layout::empty_line_after_magic_comment::RULE_ID,
+    rules::layout::empty_lines::RULE_ID,
+    rules::layout::indentation_style::RULE_ID,
+    rules::lint::script_permission::RULE_ID,
+];
+
+/// Check if a rule is re-run by `check_unparsed_line_rules`.
+pub(crate) fn is_unparsed_line_rule(rule: RuleId) -> bool {
+    UNPARSED_LINE_RULES.contains(&rule)
+}
+
+/// Run only the line-based rules that work without parsing the source.
+///
+/// Returns `None` when the source may have `rubocop:` directives, which are
+/// read from the parsed comments.
+pub(crate) fn check_unparsed_line_rules(source: &[u8], config: &Config, file_path: Option<&str>) -> Option<Vec<Diagnostic>> {
+    if may_have_directives(source) {
+        return None;
+    }
+    let mut checker = new_checker(source, config, file_path, None);
+    profile::timed(&mut checker, rules::layout::trailing_whitespace::RULE_ID, rules::layout::trailing_whitespace::check);
+    profile::timed(&mut checker, rules::layout::end_of_line::RULE_ID, rules::layout::end_of_line::check);
+    profile::timed(&mut checker, rules::layout::trailing_empty_lines::RULE_ID, rules::layout::trailing_empty_lines::check);
+    profile::timed(&mut checker, rules::layout::leading_empty_lines::RULE_ID, rules::layout::leading_empty_lines::check);
+    profile::timed(&mut checker, rules::layout::empty_line_after_magic_comment::RULE_ID, rules::layout::empty_line_after_magic_comment::check);
+    profile::timed(&mut checker, rules::layout::empty_lines::RULE_ID, rules::layout::empty_lines::check);
+    profile::timed(&mut checker, rules::layout::indentation_style::RULE_ID, rules::layout::indentation_style::check);
+    profile::timed(&mut checker, rules::lint::script_permission::RULE_ID, rules::lint::script_permission::check);
+    Some(checker.into_diagnostics())
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    /// Line-based rules that need neither the AST nor comments.
+    const UNPARSED_LINE_RULES: &[&str] = &[
+        "Layout/EmptyLineAfterMagicComment",
+        "Layout/EmptyLines",
+        "Layout/EndOfLine",
+        "Layout/IndentationStyle",
+        "Layout/LeadingEmptyLines",
+        "Layout/TrailingEmptyLines",
+        "Layout/TrailingWhitespace",
+        "Lint/ScriptPermission",
+    ];
+
+    /// A config with every rule disabled except `UNPARSED_LINE_RULES`.
+    fn line_rules_config() -> Config {
+        let yaml: String = RuleId::all()
+            .iter()
+            .map(RuleId::to_string)
+            .filter(|rule| !UNPARSED_LINE_RULES.contains(&rule.as_str()))
+            .map(|rule| format!("{rule}:\n  Enabled: false\n"))
+            .collect();
+        Config::from_rubocop_yaml(&parse_rubocop_yaml(&yaml).unwrap())
+    }
+
+    #[test]
+    fn test_line_rules_skip_parse() {
+        let config = line_rules_config();
+        let source = b"\n# frozen_string_literal: true\nclass Foo  \n\tdef bar\n  x = <<~EOS  \n    text\n  EOS\n\n\n  binding.pry; end\nend\n\n";
+        assert!(!needs_parse(source, &config, CategorySet::ALL));
+        assert!(needs_parse(source, &Config::default(), CategorySet::ALL));
+
+        let fast = check_with_config(source, &config);
+        let full: Vec<Diagnostic> = check(source)
+            .into_iter()
+            .filter(|d| UNPARSED_LINE_RULES.contains(&d.rule().as_str()))
+            .collect();
+        assert!(fast.len() >= 5, "got: {:?}", fast);
+        assert_eq!(fast, full);
+
+        let mut streamed = Vec::new();
+        check_with_callback(source, &config, None, |d| streamed.push(d));
+        streamed.sort_by_key(|d| (d.start, d.end));
+        assert_eq!(streamed, fast);
+    }
+
+    #[test]
+    fn test_check_layout_categories() {
+        let source = b"def foo  \n  binding.pry\n    x = \"a\"\nend\n";
+        let config = Config::default();
+        let layout = CategorySet::only(Category::Layout);
+        let fast = check_with_categories(source, &config, None, None, layout);
+        let full: Vec<Diagnostic> = check(source).into_iter().filter(|d| d.rule_id.category() == Category::Layout).collect();
+        assert!(fast.len() >= 2, "got: {:?}", fast);
+        assert_eq!(fast, full);
+
+        let lint_and_style = CategorySet::only(Category::Lint).with(Category::Style);
+        let rest: Vec<Diagnostic> = check(source).into_iter().filter(|d| d.rule_id.category() != Category::Layout).collect();
+        assert!(!rest.is_empty());
+        assert_eq!(check_with_categories(source, &config, None, None, lint_and_style), rest);
+
+        // With only line-based Layout rules enabled, a layout-only pass skips the parse.
+        assert!(needs_parse(source, &config, CategorySet::ALL));
+        assert!(!needs_parse(source, &line_rules_config(), layout));
+        assert!(check_with_categories(source, &config, None, None, CategorySet::NONE).is_empty());
+    }
+
+    #[test]
+    fn test_directives_force_parse() {
+        let config = line_rules_config();
+        let source = b"x = 1  # rubocop:disable Layout/TrailingWhitespace  \n";
+        assert!(needs_parse(source, &config, CategorySet::ALL));
+        assert!(check_with_config(source, &config).is_empty());
+    }
+
     #[test]
     fn test_check_empty_source() {
         let source = b"";
@@ -115,4 +304,83 @@ mod tests {
         assert_eq!(diagnostics[0].rule(), "Layout/TrailingWhitespace");
         assert_eq!(diagnostics[1].rule(), "Lint/Debugger");
     }
+
+    #[test]
+    fn test_check_with_callback_matches_check() {
+        let source = b"def foo  \n  binding.pry\n\n\n  debugger\nend\n";
+        let expected = check(source);
+        let mut streamed = Vec::new();
+        check_with_callback(source, &Config::default(), None, |diagnostic| streamed.push(diagnostic));
+        assert_eq!(streamed.len(), expected.len());
+        for diagnostic in &expected {
+            assert!(streamed.contains(diagnostic));
+        }
+    }
+
+    #[test]
+    fn test_assignment_rhs_is_reported_once() {
+        // Layout/EndAlignment reaches the `if` through the assignment and through the `if` itself.
+        let sources: [&[u8]; 4] = [
+            b"x = if a\n  b\n  end\n",
+            b"@x ||= if a\n  b\n  end\n",
+            b"foo[0] = if a\n  b\n  end\n",
+            b"a, b = if c\n  d\n  end\n",
+        ];
+        for source in sources {
+            let expected = check(source);
+            let mut streamed = Vec::new();
+            check_with_callback(source, &Config::default(), None, |diagnostic| streamed.push(diagnostic));
+            streamed.sort_by_key(|d| (d.start, d.end));
+            for diagnostics in [&expected, &streamed] {
+                let end_alignment = diagnostics.iter().filter(|d| d.rule() == "Layout/EndAlignment").count();
+                assert_eq!(end_alignment, 1, "source: {:?}", String::from_utf8_lossy(source));
+            }
+            assert_eq!(streamed, expected);
+        }
+    }
+
+    #[test]
+    fn test_from_parse_result_matches_check() {
+        let source = b"def foo  \n  binding.pry # rubocop:disable Lint/Debugger\n\n\n  debugger\nend\n";
+        let config = Config::default();
+        let parse_result = ruby_prism::parse(source);
+        let mut checker = Checker::from_parse_result(source, &config, None, &parse_result);
+        run_rules(&mut checker, &parse_result.node());
+        assert_eq!(checker.into_diagnostics(), check(source));
+    }
+
+    #[test]
+    fn test_cop_include_restricts_files() {
+        let yaml = "Lint/Debugger:\n  Include:\n    - \"spec/**/*\"\nLayout/TrailingWhitespace:\n  Include:\n    - \"spec/**/*\"\n";
+        let config = Config::from_rubocop_yaml(&config::parse_rubocop_yaml(yaml).unwrap());
+        assert!(config.lint.debugger.base.include_set().is_some());
+        let source = b"def foo  \n  binding.pry\nend\n";
+        assert_eq!(check_with_config_and_path(source, &config, Some("spec/models/user_spec.rb"), None).len(), 2);
+        assert_eq!(check_with_config_and_path(source, &config, Some("./spec/user_spec.rb"), None).len(), 2);
+        assert!(check_with_config_and_path(source, &config, Some("lib/user.rb"), None).is_empty());
+    }
+
+    #[test]
+    fn test_cop_patterns_changed_after_loading() {
+        let yaml = "Lint/Debugger:\n  Include:\n    - \"spec/**/*\"\n";
+        let mut config = Config::from_rubocop_yaml(&config::parse_rubocop_yaml(yaml).unwrap());
+        config.lint.debugger.base.set_include(vec!["lib/**/*".to_string()]);
+        config.lint.debugger.base.set_exclude(vec!["lib/generated/**/*".to_string()]);
+        let source = b"binding.pry\n";
+        assert!(check_with_config_and_path(source, &config, Some("spec/user_spec.rb"), None).is_empty());
+        assert_eq!(check_with_config_and_path(source, &config, Some("lib/user.rb"), None).len(), 1);
+        assert!(check_with_config_and_path(source, &config, Some("lib/generated/user.rb"), None).is_empty());
+    }
+
+    #[test]
+    fn test_cop_include_without_file_path() {
+        // Without a path, cops with `Include` still run instead of being silently disabled.
+        let mut config = Config::default();
+        config.lint.debugger.base.set_include(vec!["spec/**/*".to_string()]);
+        let source = b"binding.pry\n";
+        assert_eq!(check_with_config(source, &config).len(), 1);
+        // Patterns that were not compiled ahead of time are still honored.
+        assert!(check_with_config_and_path(source, &config, Some("lib/user.rb"), None).is_empty());
+        assert_eq!(check_with_config_and_path(source, &config, Some("spec/user_spec.rb"), None).len(), 1);
+    }
 }
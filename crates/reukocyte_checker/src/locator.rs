@@ -190,6 +190,47 @@ impl<'rk> LineIndex<'rk> {
         let line_start = self.line_starts[line_index];
         offset - line_start
     }
+
+    // ========================================================================
+    // Snippet rendering
+    // ========================================================================
+
+    /// Render the source lines of a range with a `^` underline, like `rustc`.
+    ///
+    /// Lines and columns are 1-indexed and byte-based, as in `Diagnostic`. A
+    /// multi-line range shows its first line underlined to the end and its last
+    /// line underlined from the indentation to `column_end`. Carets are placed
+    /// by character, so they line up under multibyte text.
+    pub fn snippet(&self, line_start: usize, column_start: usize, line_end: usize, column_end: usize) -> String {
+        let Some(first) = self.line(line_start.saturating_sub(1)) else {
+            return String::new();
+        };
+        if line_end <= line_start {
+            return underline(first, column_start.saturating_sub(1), column_end.saturating_sub(1));
+        }
+        let last = self.line(line_end - 1).unwrap_or_default();
+        let indentation = last.iter().take_while(|&&b| b == b' ' || b == b'\t').count();
+        format!(
+            "{}\n{}",
+            underline(first, column_start.saturating_sub(1), first.len()),
+            underline(last, indentation, column_end.saturating_sub(1))
+        )
+    }
+}
+
+/// Render a line followed by carets under the bytes `start..end` (at least one caret).
+///
+/// Tabs before the carets are kept so the underline lines up however tabs are displayed.
+fn underline(line: &[u8], start: usize, end: usize) -> String {
+    let line = line.strip_suffix(b"\r").unwrap_or(line);
+    let start = start.min(line.len());
+    let end = end.clamp(start, line.len());
+    let padding: String = String::from_utf8_lossy(&line[..start])
+        .chars()
+        .map(|c| if c == '\t' { '\t' } else { ' ' })
+        .collect();
+    let width = String::from_utf8_lossy(&line[start..end]).chars().count().max(1);
+    format!("{}\n{}{}", String::from_utf8_lossy(line), padding, "^".repeat(width))
 }
 
 #[cfg(test)]
@@ -331,4 +372,27 @@ mod tests {
         assert_eq!(index.column(4), 0); // first space of line 2
         assert_eq!(index.column(6), 2); // 'd'
     }
+
+    #[test]
+    fn test_snippet_ascii() {
+        let index = LineIndex::from_source(b"x = 1\nfoo(bar)  \n");
+        assert_eq!(index.snippet(2, 9, 2, 11), "foo(bar)  \n        ^^");
+        // Zero-width ranges get a single caret.
+        assert_eq!(index.snippet(1, 3, 1, 3), "x = 1\n  ^");
+    }
+
+    #[test]
+    fn test_snippet_multibyte() {
+        // "ã" is 2 bytes: `bar` starts at (1-indexed) byte column 16 but character column 13.
+        let index = LineIndex::from_source("x = \"ããã\" + bar\n".as_bytes());
+        assert_eq!(index.snippet(1, 16, 1, 19), "x = \"ããã\" + bar\n            ^^^");
+        assert_eq!(index.snippet(1, 6, 1, 12), "x = \"ããã\" + bar\n     ^^^");
+    }
+
+    #[test]
+    fn test_snippet_multi_line() {
+        let index = LineIndex::from_source(b"foo(a,\n\t  b)\n");
+        assert_eq!(index.snippet(1, 4, 2, 6), "foo(a,\n   ^^^\n\t  b)\n\t  ^^");
+        assert_eq!(index.snippet(9, 1, 9, 2), "");
+    }
 }
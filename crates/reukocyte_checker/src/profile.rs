@@ -0,0 +1,92 @@
+//! Per-rule timing, for finding slow rules (`--profile`).
+//!
+//! Profiling is off by default and costs a single relaxed load per rule call;
+//! once enabled, every rule run is timed and added to a process-wide registry.
+
+use crate::checker::Checker;
+use crate::rule::RuleId;
+use rustc_hash::FxHashMap;
+use std::sync::Mutex;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::time::{Duration, Instant};
+
+static ENABLED: AtomicBool = AtomicBool::new(false);
+static REGISTRY: Mutex<Option<FxHashMap<RuleId, RuleTiming>>> = Mutex::new(None);
+
+/// The accumulated time spent in one rule.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RuleTiming {
+    pub rule: RuleId,
+    /// Total time spent in the rule.
+    pub total: Duration,
+    /// Number of times the rule was run (once per node for AST rules, once per file otherwise).
+    pub count: u64,
+}
+impl RuleTiming {
+    /// Average time per run.
+    pub fn average(&self) -> Duration {
+        Duration::from_nanos((self.total.as_nanos() / u128::from(self.count.max(1))) as u64)
+    }
+}
+
+/// Turn per-rule timing on or off for the whole process.
+pub fn set_profiling(enabled: bool) {
+    ENABLED.store(enabled, Ordering::Relaxed);
+}
+
+/// Check if per-rule timing is on.
+#[inline]
+pub fn is_profiling() -> bool {
+    ENABLED.load(Ordering::Relaxed)
+}
+
+/// Take the timings recorded so far, slowest rule first, and reset the registry.
+pub fn take_profile() -> Vec<RuleTiming> {
+    let registry = REGISTRY.lock().unwrap_or_else(|e| e.into_inner()).take().unwrap_or_default();
+    let mut timings: Vec<RuleTiming> = registry.into_values().collect();
+    timings.sort_by(|a, b| b.total.cmp(&a.total).then(a.rule.cmp(&b.rule)));
+    timings
+}
+
+/// Run a rule, timing it when profiling is on.
+#[inline(always)]
+pub(crate) fn timed<'rk>(checker: &mut Checker<'rk>, rule: RuleId, run: impl FnOnce(&mut Checker<'rk>)) {
+    if !is_profiling() {
+        run(checker);
+        return;
+    }
+    let start = Instant::now();
+    run(checker);
+    record(rule, start.elapsed());
+}
+
+fn record(rule: RuleId, elapsed: Duration) {
+    let mut registry = REGISTRY.lock().unwrap_or_else(|e| e.into_inner());
+    let timing = registry.get_or_insert_with(FxHashMap::default).entry(rule).or_insert(RuleTiming {
+        rule,
+        total: Duration::ZERO,
+        count: 0,
+    });
+    timing.total += elapsed;
+    timing.count += 1;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::rule::LayoutRule;
+
+    #[test]
+    fn test_profile_accumulates() {
+        set_profiling(true);
+        crate::check(b"def foo  \n  bar\nend\n");
+        set_profiling(false);
+
+        let timings = take_profile();
+        let trailing = timings.iter().find(|t| t.rule == RuleId::Layout(LayoutRule::TrailingWhitespace)).unwrap();
+        assert!(trailing.count >= 1);
+        // AST rules are timed per node
+        assert!(timings.iter().any(|t| t.rule == RuleId::Layout(LayoutRule::DefEndAlignment)));
+        assert!(timings.windows(2).all(|pair| pair[0].total >= pair[1].total));
+    }
+}
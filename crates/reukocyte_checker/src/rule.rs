@@ -7,6 +7,7 @@ use crate::checker::Checker;
 pub enum RuleId {
     Layout(LayoutRule),
     Lint(LintRule),
+    Style(StyleRule),
 }
 impl fmt::Display for RuleId {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
@@ -14,12 +15,37 @@ impl fmt::Display for RuleId {
     }
 }
 
+/// Every known rule, sorted by category and name (generated from the rule files).
+const ALL_RULES: &[RuleId] = __REUKO_ALL_RULES;
+
 impl RuleId {
+    /// Get every known rule, sorted by category and name.
+    pub const fn all() -> &'static [RuleId] {
+        ALL_RULES
+    }
+    /// Parse a rule name, either qualified (`Layout/TrailingWhitespace`) or
+    /// bare (`TrailingWhitespace`).
+    ///
+    /// A bare name only matches when exactly one category has a rule by that name.
+    pub fn from_name(name: &str) -> Option<RuleId> {
+        if let Some((category, name)) = name.split_once('/') {
+            return ALL_RULES
+                .iter()
+                .find(|rule| rule.category().as_str() == category && rule.name() == name)
+                .copied();
+        }
+        let mut matches = ALL_RULES.iter().filter(|rule| rule.name() == name);
+        match (matches.next(), matches.next()) {
+            (Some(rule), None) => Some(*rule),
+            _ => None,
+        }
+    }
     /// Get the category of the rule.
     pub const fn category(&self) -> Category {
         match self {
             Self::Layout(_) => Category::Layout,
             Self::Lint(_) => Category::Lint,
+            Self::Style(_) => Category::Style,
         }
     }
     /// Get the rule name without category.
@@ -27,6 +53,7 @@ impl RuleId {
         match self {
             Self::Layout(rule) => rule.name(),
             Self::Lint(rule) => rule.name(),
+            Self::Style(rule) => rule.name(),
         }
     }
 
@@ -40,16 +67,61 @@ impl RuleId {
     pub fn conflicts_with(&self) -> &'static [RuleId] {
         match self {
             Self::Layout(LayoutRule::BeginEndAlignment) => &[],
+            Self::Layout(LayoutRule::BlockAlignment) => &[],
+            Self::Layout(LayoutRule::BlockEndNewline) => &[],
+            Self::Layout(LayoutRule::ClosingHeredocIndentation) => &[],
+            Self::Layout(LayoutRule::ClosingParenthesisIndentation) => &[],
             Self::Layout(LayoutRule::DefEndAlignment) => &[],
+            Self::Layout(LayoutRule::EmptyLineAfterMagicComment) => &[],
+            Self::Layout(LayoutRule::EmptyLineBetweenDefs) => &[],
             Self::Layout(LayoutRule::EmptyLines) => &[],
+            Self::Layout(LayoutRule::EmptyLinesAroundMethodBody) => &[],
             Self::Layout(LayoutRule::EndAlignment) => &[],
+            Self::Layout(LayoutRule::EndOfLine) => &[],
+            Self::Layout(LayoutRule::ExtraSpacing) => &[],
             Self::Layout(LayoutRule::IndentationConsistency) => &[],
             Self::Layout(LayoutRule::IndentationStyle) => &[],
             Self::Layout(LayoutRule::IndentationWidth) => &[],
+            Self::Layout(LayoutRule::LeadingCommentSpace) => &[],
             Self::Layout(LayoutRule::LeadingEmptyLines) => &[],
+            Self::Layout(LayoutRule::MultilineMethodDefinitionBraceLayout) => &[],
+            Self::Layout(LayoutRule::RedundantLineBreak) => &[],
+            Self::Layout(LayoutRule::SpaceAfterColon) => &[],
+            Self::Layout(LayoutRule::SpaceAfterComma) => &[],
+            Self::Layout(LayoutRule::SpaceAfterMethodName) => &[],
+            Self::Layout(LayoutRule::SpaceBeforeBlockBraces) => &[],
+            Self::Layout(LayoutRule::SpaceBeforeColon) => &[],
+            Self::Layout(LayoutRule::SpaceBeforeComma) => &[],
+            Self::Layout(LayoutRule::SpaceInsideArrayLiteralBrackets) => &[],
+            Self::Layout(LayoutRule::SpaceInsideStringInterpolation) => &[],
             Self::Layout(LayoutRule::TrailingEmptyLines) => &[],
             Self::Layout(LayoutRule::TrailingWhitespace) => &[],
+            Self::Lint(LintRule::BigDecimalNew) => &[],
+            Self::Lint(LintRule::BooleanSymbol) => &[],
             Self::Lint(LintRule::Debugger) => &[],
+            Self::Lint(LintRule::ElseLayout) => &[],
+            Self::Lint(LintRule::EmptyBlock) => &[],
+            Self::Lint(LintRule::EmptyInterpolation) => &[],
+            Self::Lint(LintRule::ImplicitStringConcatenation) => &[],
+            Self::Lint(LintRule::LiteralAsCondition) => &[],
+            Self::Lint(LintRule::PercentStringArray) => &[],
+            Self::Lint(LintRule::PercentSymbolArray) => &[],
+            Self::Lint(LintRule::RedundantWithIndex) => &[],
+            Self::Lint(LintRule::RedundantWithObject) => &[],
+            Self::Lint(LintRule::ScriptPermission) => &[],
+            Self::Lint(LintRule::ToEnumArguments) => &[],
+            Self::Style(StyleRule::ClassAndModuleChildren) => &[],
+            Self::Style(StyleRule::ConditionalAssignment) => &[],
+            Self::Style(StyleRule::EmptyElse) => &[],
+            Self::Style(StyleRule::ExpandPathArguments) => &[],
+            Self::Style(StyleRule::NegatedWhile) => &[],
+            Self::Style(StyleRule::Next) => &[],
+            Self::Style(StyleRule::RedundantDoubleSplatHashBraces) => &[],
+            Self::Style(StyleRule::RedundantRegexpEscape) => &[],
+            Self::Style(StyleRule::RedundantReturn) => &[],
+            Self::Style(StyleRule::RedundantSortBy) => &[],
+            Self::Style(StyleRule::SafeNavigation) => &[],
+            Self::Style(StyleRule::StderrPuts) => &[],
         }
     }
 
@@ -57,13 +129,47 @@ impl RuleId {
     pub fn has_conflict_with(&self, other: RuleId) -> bool {
         self.conflicts_with().contains(&other)
     }
+
+    /// Get the base configuration of the rule (`Enabled`, `Severity`, `AutoCorrect`, ...) in `config`.
+    pub fn base_config(self, config: &crate::config::Config) -> &crate::config::BaseCopConfig {
+        __reuko_rule_base_config(config, self)
+    }
+
+    /// Get the documentation of the rule, extracted from its sources at build time.
+    pub fn doc(&self) -> Option<&'static RuleDoc> {
+        __REUKO_RULE_DOCS.iter().find(|(rule, _)| rule == self).map(|(_, doc)| doc)
+    }
+}
+
+/// Documentation of a rule, as shown by `--explain`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RuleDoc {
+    /// What the rule checks.
+    pub description: &'static str,
+    /// Ruby code showing bad and good cases.
+    pub example: &'static str,
+    /// The rule's own options, besides `Enabled`, `Severity`, `Include` and `Exclude`.
+    pub options: &'static [RuleOption],
+}
+
+/// A configuration option of a rule.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RuleOption {
+    /// The option name in `.rubocop.yml` (e.g. `EnforcedStyle`).
+    pub name: &'static str,
+    /// What the option controls.
+    pub description: &'static str,
 }
 
+include!(concat!(env!("OUT_DIR"), "/rule_docs.rs"));
+include!(concat!(env!("OUT_DIR"), "/rule_list.rs"));
+
 /// Category of a rule.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
 pub enum Category {
     Layout,
     Lint,
+    Style,
 }
 impl Category {
     /// Get the category name as a string.
@@ -71,21 +177,80 @@ impl Category {
         match self {
             Self::Layout => "Layout",
             Self::Lint => "Lint",
+            Self::Style => "Style",
         }
     }
+    /// Get the bit of this category in a `CategorySet`.
+    const fn bit(self) -> u8 {
+        1 << self as u8
+    }
+}
+
+/// A set of rule categories, e.g. to run only the Layout rules.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct CategorySet(u8);
+impl CategorySet {
+    /// Every category.
+    pub const ALL: Self = Self(Category::Layout.bit() | Category::Lint.bit() | Category::Style.bit());
+    /// No category.
+    pub const NONE: Self = Self(0);
+
+    /// Create a set holding only `category`.
+    pub const fn only(category: Category) -> Self {
+        Self(category.bit())
+    }
+    /// Add `category` to the set.
+    pub const fn with(self, category: Category) -> Self {
+        Self(self.0 | category.bit())
+    }
+    /// Check if the set holds `category`.
+    #[inline]
+    pub const fn contains(self, category: Category) -> bool {
+        self.0 & category.bit() != 0
+    }
+}
+impl Default for CategorySet {
+    fn default() -> Self {
+        Self::ALL
+    }
+}
+impl From<Category> for CategorySet {
+    fn from(category: Category) -> Self {
+        Self::only(category)
+    }
 }
 
 /// Layout rules.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
 pub enum LayoutRule {
     BeginEndAlignment,
+    BlockAlignment,
+    BlockEndNewline,
+    ClosingHeredocIndentation,
+    ClosingParenthesisIndentation,
     DefEndAlignment,
+    EmptyLineAfterMagicComment,
+    EmptyLineBetweenDefs,
     EmptyLines,
+    EmptyLinesAroundMethodBody,
     EndAlignment,
+    EndOfLine,
+    ExtraSpacing,
     IndentationConsistency,
     IndentationStyle,
     IndentationWidth,
+    LeadingCommentSpace,
     LeadingEmptyLines,
+    MultilineMethodDefinitionBraceLayout,
+    RedundantLineBreak,
+    SpaceAfterColon,
+    SpaceAfterComma,
+    SpaceAfterMethodName,
+    SpaceBeforeBlockBraces,
+    SpaceBeforeColon,
+    SpaceBeforeComma,
+    SpaceInsideArrayLiteralBrackets,
+    SpaceInsideStringInterpolation,
     TrailingEmptyLines,
     TrailingWhitespace,
 }
@@ -94,13 +259,33 @@ impl LayoutRule {
     pub const fn name(&self) -> &'static str {
         match self {
             Self::BeginEndAlignment => "BeginEndAlignment",
+            Self::BlockAlignment => "BlockAlignment",
+            Self::BlockEndNewline => "BlockEndNewline",
+            Self::ClosingHeredocIndentation => "ClosingHeredocIndentation",
+            Self::ClosingParenthesisIndentation => "ClosingParenthesisIndentation",
             Self::DefEndAlignment => "DefEndAlignment",
+            Self::EmptyLineAfterMagicComment => "EmptyLineAfterMagicComment",
+            Self::EmptyLineBetweenDefs => "EmptyLineBetweenDefs",
             Self::EmptyLines => "EmptyLines",
+            Self::EmptyLinesAroundMethodBody => "EmptyLinesAroundMethodBody",
             Self::EndAlignment => "EndAlignment",
+            Self::EndOfLine => "EndOfLine",
+            Self::ExtraSpacing => "ExtraSpacing",
             Self::IndentationConsistency => "IndentationConsistency",
             Self::IndentationStyle => "IndentationStyle",
             Self::IndentationWidth => "IndentationWidth",
+            Self::LeadingCommentSpace => "LeadingCommentSpace",
             Self::LeadingEmptyLines => "LeadingEmptyLines",
+            Self::MultilineMethodDefinitionBraceLayout => "MultilineMethodDefinitionBraceLayout",
+            Self::RedundantLineBreak => "RedundantLineBreak",
+            Self::SpaceAfterColon => "SpaceAfterColon",
+            Self::SpaceAfterComma => "SpaceAfterComma",
+            Self::SpaceAfterMethodName => "SpaceAfterMethodName",
+            Self::SpaceBeforeBlockBraces => "SpaceBeforeBlockBraces",
+            Self::SpaceBeforeColon => "SpaceBeforeColon",
+            Self::SpaceBeforeComma => "SpaceBeforeComma",
+            Self::SpaceInsideArrayLiteralBrackets => "SpaceInsideArrayLiteralBrackets",
+            Self::SpaceInsideStringInterpolation => "SpaceInsideStringInterpolation",
             Self::TrailingEmptyLines => "TrailingEmptyLines",
             Self::TrailingWhitespace => "TrailingWhitespace",
         }
@@ -110,13 +295,75 @@ impl LayoutRule {
 /// Lint rules.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
 pub enum LintRule {
+    BigDecimalNew,
+    BooleanSymbol,
     Debugger,
+    ElseLayout,
+    EmptyBlock,
+    EmptyInterpolation,
+    ImplicitStringConcatenation,
+    LiteralAsCondition,
+    PercentStringArray,
+    PercentSymbolArray,
+    RedundantWithIndex,
+    RedundantWithObject,
+    ScriptPermission,
+    ToEnumArguments,
 }
 impl LintRule {
     /// Get the rule name.
     pub const fn name(&self) -> &'static str {
         match self {
+            Self::BigDecimalNew => "BigDecimalNew",
+            Self::BooleanSymbol => "BooleanSymbol",
             Self::Debugger => "Debugger",
+            Self::ElseLayout => "ElseLayout",
+            Self::EmptyBlock => "EmptyBlock",
+            Self::EmptyInterpolation => "EmptyInterpolation",
+            Self::ImplicitStringConcatenation => "ImplicitStringConcatenation",
+            Self::LiteralAsCondition => "LiteralAsCondition",
+            Self::PercentStringArray => "PercentStringArray",
+            Self::PercentSymbolArray => "PercentSymbolArray",
+            Self::RedundantWithIndex => "RedundantWithIndex",
+            Self::RedundantWithObject => "RedundantWithObject",
+            Self::ScriptPermission => "ScriptPermission",
+            Self::ToEnumArguments => "ToEnumArguments",
+        }
+    }
+}
+
+/// Style rules.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum StyleRule {
+    ClassAndModuleChildren,
+    ConditionalAssignment,
+    EmptyElse,
+    ExpandPathArguments,
+    NegatedWhile,
+    Next,
+    RedundantDoubleSplatHashBraces,
+    RedundantRegexpEscape,
+    RedundantReturn,
+    RedundantSortBy,
+    SafeNavigation,
+    StderrPuts,
+}
+impl StyleRule {
+    /// Get the rule name.
+    pub const fn name(&self) -> &'static str {
+        match self {
+            Self::ClassAndModuleChildren => "ClassAndModuleChildren",
+            Self::ConditionalAssignment => "ConditionalAssignment",
+            Self::EmptyElse => "EmptyElse",
+            Self::ExpandPathArguments => "ExpandPathArguments",
+            Self::NegatedWhile => "NegatedWhile",
+            Self::Next => "Next",
+            Self::RedundantDoubleSplatHashBraces => "RedundantDoubleSplatHashBraces",
+            Self::RedundantRegexpEscape => "RedundantRegexpEscape",
+            Self::RedundantReturn => "RedundantReturn",
+            Self::RedundantSortBy => "RedundantSortBy",
+            Self::SafeNavigation => "SafeNavigation",
+            Self::StderrPuts => "StderrPuts",
         }
     }
 }
@@ -184,6 +431,34 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_rule_id_from_name() {
+        let trailing_whitespace = Some(RuleId::Layout(LayoutRule::TrailingWhitespace));
+        assert_eq!(RuleId::from_name("Layout/TrailingWhitespace"), trailing_whitespace);
+        assert_eq!(RuleId::from_name("TrailingWhitespace"), trailing_whitespace);
+        assert_eq!(RuleId::from_name("Lint/Debugger"), Some(RuleId::Lint(LintRule::Debugger)));
+
+        assert_eq!(RuleId::from_name("Layout/TrailingWhitespaces"), None);
+        assert_eq!(RuleId::from_name("Lint/TrailingWhitespace"), None);
+        assert_eq!(RuleId::from_name("layout/trailing_whitespace"), None);
+        assert_eq!(RuleId::from_name(""), None);
+    }
+
+    #[test]
+    fn test_all_rules() {
+        let all = RuleId::all();
+        assert!(!all.is_empty());
+        assert!(all.contains(&RuleId::Lint(LintRule::Debugger)));
+        // Line-based rules are listed as well as AST-based ones.
+        assert!(all.contains(&RuleId::Layout(LayoutRule::EndOfLine)));
+        assert!(all.windows(2).all(|pair| pair[0] < pair[1]), "sorted without duplicates");
+        let config = crate::Config::default();
+        for rule in all {
+            assert_eq!(RuleId::from_name(&rule.to_string()), Some(*rule));
+            assert!(std::ptr::eq(rule.base_config(&config), config.base_config(&rule.to_string()).unwrap()));
+        }
+    }
+
     #[test]
     fn test_no_conflict() {
         let rule = RuleId::Layout(LayoutRule::TrailingWhitespace);
@@ -16,6 +16,22 @@ fn config<'a>(checker: &'a Checker<'_>) -> &'a crate::config::layout::begin_end_
 }
 
 /// Layout/BeginEndAlignment rule.
+///
+/// Checks that the `end` of a `begin` block is aligned with the start of the
+/// line where `begin` appears (`start_of_line`, default) or with the `begin`
+/// keyword itself (`begin`).
+///
+/// ```ruby
+/// # bad (EnforcedStyleAlignWith: start_of_line)
+/// foo ||= begin
+///           bar
+///         end
+///
+/// # good (EnforcedStyleAlignWith: start_of_line)
+/// foo ||= begin
+///   bar
+/// end
+/// ```
 pub struct BeginEndAlignment;
 impl Rule for BeginEndAlignment {
     const ID: RuleId = RuleId::Layout(LayoutRule::BeginEndAlignment);
@@ -0,0 +1,198 @@
+use crate::checker::Checker;
+use crate::config::layout::block_alignment::EnforcedStyleAlignWith;
+use crate::rule::{Check, LayoutRule, Rule, RuleId};
+use crate::utility::alignment::indentation_fix;
+use reukocyte_macros::check;
+use ruby_prism::*;
+
+/// Get the config for this rule
+#[inline]
+fn config<'a>(checker: &'a Checker<'_>) -> &'a crate::config::layout::block_alignment::BlockAlignment {
+    &checker.config().layout.block_alignment
+}
+
+/// Layout/BlockAlignment rule.
+///
+/// Checks that the closing `end`/`}` of a multiline block is aligned with the
+/// start of the line where the block opens (`start_of_block`), with the start
+/// of the line where the whole expression starts (`start_of_line`), or with
+/// either of them (`either`, default). Closings that are not first on their
+/// line are left alone.
+///
+/// ```ruby
+/// # bad
+/// foo.bar
+///   .each do
+///     baz
+///       end
+///
+/// # good (EnforcedStyleAlignWith: either)
+/// foo.bar
+///   .each do
+///     baz
+///   end
+///
+/// foo.bar
+///   .each do
+///     baz
+/// end
+/// ```
+pub struct BlockAlignment;
+impl Rule for BlockAlignment {
+    const ID: RuleId = RuleId::Layout(LayoutRule::BlockAlignment);
+}
+#[check(BlockNode)]
+impl Check<BlockNode<'_>> for BlockAlignment {
+    fn check(node: &BlockNode, checker: &mut Checker) {
+        let opening = node.opening_loc();
+        let closing = node.closing_loc();
+        let line_index = checker.line_index();
+        if line_index.are_on_same_line(opening.start_offset(), closing.start_offset()) || !line_index.is_first_on_line(closing.start_offset()) {
+            return;
+        }
+
+        let block_start = AlignmentTarget::new(checker, line_index.line_start_offset(opening.start_offset()), opening.end_offset());
+        let expression_start = expression_start(checker, node.location().start_offset());
+        let line_start = AlignmentTarget::new(checker, expression_start, line_index.line_end_offset(expression_start));
+        let targets = match config(checker).enforced_style_align_with {
+            EnforcedStyleAlignWith::Either if block_start.column == line_start.column => vec![line_start],
+            EnforcedStyleAlignWith::Either => vec![line_start, block_start],
+            EnforcedStyleAlignWith::StartOfBlock => vec![block_start],
+            EnforcedStyleAlignWith::StartOfLine => vec![line_start],
+        };
+        let column = line_index.indentation(closing.start_offset());
+        if targets.iter().any(|target| target.column == column) {
+            return;
+        }
+
+        let (line, _) = line_index.line_column(closing.start_offset());
+        let message = format!(
+            "`{}` at {}, {} is not aligned with {}.",
+            String::from_utf8_lossy(closing.as_slice()),
+            line,
+            column,
+            targets.iter().map(AlignmentTarget::describe).collect::<Vec<_>>().join(" or ")
+        );
+        let fix = indentation_fix(line_index, closing.start_offset(), targets[0].column as isize - column as isize);
+        let severity = config(checker).base.severity;
+        checker.report(BlockAlignment::ID, message, severity, closing.start_offset(), closing.end_offset(), Some(fix));
+    }
+}
+
+/// A source line the closing of a block may be aligned with.
+struct AlignmentTarget {
+    /// Source shown in the message.
+    source: String,
+    /// Line number (1-indexed).
+    line: usize,
+    /// Indentation of the line.
+    column: usize,
+}
+impl AlignmentTarget {
+    /// Describe the source between `start` and `end`, which must be on the same line.
+    fn new(checker: &Checker, start: usize, end: usize) -> Self {
+        let line_index = checker.line_index();
+        let source = String::from_utf8_lossy(&checker.source()[start..end]).trim().to_string();
+        Self {
+            source,
+            line: line_index.line_number(start),
+            column: line_index.indentation(start),
+        }
+    }
+
+    fn describe(&self) -> String {
+        format!("`{}` at {}, {}", self.source, self.line, self.column)
+    }
+}
+
+/// Get the start offset of the outermost expression containing the block
+/// (a method chain, an assignment, an enclosing call, ...).
+fn expression_start(checker: &Checker, block_start: usize) -> usize {
+    let mut start = block_start;
+    for ancestor in checker.semantic().ancestors() {
+        match ancestor {
+            Node::StatementsNode { .. } | Node::ProgramNode { .. } | Node::BeginNode { .. } | Node::ParenthesesNode { .. } => break,
+            _ => start = start.min(ancestor.location().start_offset()),
+        }
+    }
+    start
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::Config;
+    use crate::corrector::Corrector;
+    use crate::{check, check_with_config};
+
+    fn block_alignment_diagnostics(source: &[u8], config: &Config) -> Vec<crate::Diagnostic> {
+        check_with_config(source, config)
+            .into_iter()
+            .filter(|d| d.rule_id == BlockAlignment::ID)
+            .collect()
+    }
+
+    fn style_config(style: EnforcedStyleAlignWith) -> Config {
+        let mut config = Config::default();
+        config.layout.block_alignment.enforced_style_align_with = style;
+        config
+    }
+
+    fn fixed(source: &[u8], config: &Config) -> String {
+        let mut corrector = Corrector::new();
+        for diagnostic in block_alignment_diagnostics(source, config) {
+            corrector.merge(diagnostic.fix.as_ref().unwrap()).unwrap();
+        }
+        String::from_utf8(corrector.apply(source)).unwrap()
+    }
+
+    #[test]
+    fn test_misaligned_do_end() {
+        let source = b"foo.each do |x|\n  bar(x)\n  end\n";
+        let diagnostics = block_alignment_diagnostics(source, &Config::default());
+        assert_eq!(diagnostics.len(), 1);
+        assert_eq!(diagnostics[0].message, "`end` at 3, 2 is not aligned with `foo.each do |x|` at 1, 0.");
+        assert_eq!(fixed(source, &Config::default()), "foo.each do |x|\n  bar(x)\nend\n");
+    }
+
+    #[test]
+    fn test_misaligned_braces() {
+        let source = b"  foo.map { |x|\n    x\n}\n";
+        assert_eq!(fixed(source, &Config::default()), "  foo.map { |x|\n    x\n  }\n");
+    }
+
+    #[test]
+    fn test_single_line_and_aligned_blocks() {
+        let config = Config::default();
+        assert!(block_alignment_diagnostics(b"foo.each { |x| bar(x) }\n", &config).is_empty());
+        assert!(block_alignment_diagnostics(b"foo.each do |x|\n  bar(x)\nend\n", &config).is_empty());
+        assert!(block_alignment_diagnostics(b"foo.each { |x|\n  bar(x) }\n", &config).is_empty());
+        assert!(check(b"def a\n  foo do\n    b\n  end\nend\n").iter().all(|d| d.rule_id != BlockAlignment::ID));
+    }
+
+    #[test]
+    fn test_block_assigned_to_variable() {
+        let source = b"x =\n  foo.map do |y|\n    y\n  end\n";
+        assert!(block_alignment_diagnostics(source, &Config::default()).is_empty());
+        assert!(block_alignment_diagnostics(source, &style_config(EnforcedStyleAlignWith::StartOfBlock)).is_empty());
+        let config = style_config(EnforcedStyleAlignWith::StartOfLine);
+        let diagnostics = block_alignment_diagnostics(source, &config);
+        assert_eq!(diagnostics.len(), 1);
+        assert_eq!(diagnostics[0].message, "`end` at 4, 2 is not aligned with `x =` at 1, 0.");
+        assert_eq!(fixed(source, &config), "x =\n  foo.map do |y|\n    y\nend\n");
+    }
+
+    #[test]
+    fn test_either_style_and_method_chains() {
+        let source = b"foo\n  .bar do\n    baz\nend\n";
+        assert!(block_alignment_diagnostics(source, &Config::default()).is_empty());
+        assert!(block_alignment_diagnostics(source, &style_config(EnforcedStyleAlignWith::StartOfLine)).is_empty());
+        let config = style_config(EnforcedStyleAlignWith::StartOfBlock);
+        assert_eq!(fixed(source, &config), "foo\n  .bar do\n    baz\n  end\n");
+
+        let source = b"foo\n  .bar do\n    baz\n    end\n";
+        let diagnostics = block_alignment_diagnostics(source, &Config::default());
+        assert_eq!(diagnostics.len(), 1);
+        assert_eq!(diagnostics[0].message, "`end` at 4, 4 is not aligned with `foo` at 1, 0 or `.bar do` at 2, 2.");
+    }
+}
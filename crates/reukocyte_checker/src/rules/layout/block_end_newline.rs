@@ -0,0 +1,153 @@
+use crate::checker::Checker;
+use crate::diagnostic::{Edit, Fix};
+use crate::rule::{Check, LayoutRule, Rule, RuleId};
+use reukocyte_macros::check;
+use ruby_prism::*;
+
+/// Get the config for this rule
+#[inline]
+fn config<'a>(checker: &'a Checker<'_>) -> &'a crate::config::layout::block_end_newline::BlockEndNewline {
+    &checker.config().layout.block_end_newline
+}
+
+/// Layout/BlockEndNewline rule.
+///
+/// Checks that the `end` or `}` of a multiline block is on its own line
+/// rather than after the block's last expression. Single-line blocks are not
+/// checked, nor is a closing that follows a `;`.
+///
+/// ```ruby
+/// # bad
+/// blah do |i|
+///   foo(i) end
+///
+/// # good
+/// blah do |i|
+///   foo(i)
+/// end
+/// ```
+pub struct BlockEndNewline;
+impl Rule for BlockEndNewline {
+    const ID: RuleId = RuleId::Layout(LayoutRule::BlockEndNewline);
+}
+#[check(BlockNode)]
+impl Check<BlockNode<'_>> for BlockEndNewline {
+    fn check(node: &BlockNode, checker: &mut Checker) {
+        let opening = node.opening_loc();
+        let closing = node.closing_loc();
+        let line_index = checker.line_index();
+        if line_index.are_on_same_line(opening.start_offset(), closing.start_offset()) || line_index.is_first_on_line(closing.start_offset()) {
+            return;
+        }
+        // What comes before the closing: the body, the parameters or the opening of an empty block.
+        let last_end = match node.body() {
+            Some(body) => match body.as_begin_node() {
+                Some(begin) => last_clause_end(&begin),
+                None => body.location().end_offset(),
+            },
+            None => node.parameters().map_or(opening.end_offset(), |parameters| parameters.location().end_offset()),
+        };
+        if last_end > closing.start_offset() {
+            return;
+        }
+        let between = &checker.source()[last_end..closing.start_offset()];
+        if between.trim_ascii_start().starts_with(b";") {
+            return;
+        }
+        let (line, column) = line_index.line_column(closing.start_offset());
+        let message = format!("Expression at {}, {} should be on its own line.", line, column);
+        let fix = Fix::safe(vec![Edit::replacement(last_end, closing.start_offset(), "\n".to_string())]);
+        let severity = config(checker).base.severity;
+        checker.report(BlockEndNewline::ID, message, severity, closing.start_offset(), closing.end_offset(), Some(fix));
+    }
+}
+
+/// Get where the last clause of an implicit `begin` body ends.
+///
+/// The location of the body itself runs to the block's closing, and so do
+/// those of its `else` and `ensure` clauses.
+fn last_clause_end(begin: &BeginNode) -> usize {
+    let statements_end =
+        |statements: Option<StatementsNode>, keyword: Location| statements.map_or(keyword.end_offset(), |statements| statements.location().end_offset());
+    if let Some(ensure) = begin.ensure_clause() {
+        return statements_end(ensure.statements(), ensure.ensure_keyword_loc());
+    }
+    if let Some(else_clause) = begin.else_clause() {
+        return statements_end(else_clause.statements(), else_clause.else_keyword_loc());
+    }
+    if let Some(mut rescue) = begin.rescue_clause() {
+        while let Some(subsequent) = rescue.subsequent() {
+            rescue = subsequent;
+        }
+        if let Some(statements) = rescue.statements() {
+            return statements.location().end_offset();
+        }
+        let last_exception = rescue.exceptions().iter().last().map(|exception| exception.location());
+        return rescue
+            .reference()
+            .map(|reference| reference.location())
+            .or(last_exception)
+            .unwrap_or(rescue.keyword_loc())
+            .end_offset();
+    }
+    begin
+        .statements()
+        .map_or(begin.location().start_offset(), |statements| statements.location().end_offset())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::check;
+
+    fn block_end_diagnostics(source: &[u8]) -> Vec<crate::Diagnostic> {
+        check(source).into_iter().filter(|d| d.rule_id == BlockEndNewline::ID).collect()
+    }
+
+    fn fixed(source: &[u8]) -> Vec<u8> {
+        crate::apply_fixes(None, source, &block_end_diagnostics(source), false).0
+    }
+
+    #[test]
+    fn test_closing_after_last_expression() {
+        let source = b"foo do\n  bar end\n";
+        let diagnostics = block_end_diagnostics(source);
+        assert_eq!(diagnostics.len(), 1);
+        assert_eq!(diagnostics[0].message, "Expression at 2, 7 should be on its own line.");
+        assert_eq!((diagnostics[0].line_start, diagnostics[0].column_start, diagnostics[0].column_end), (2, 7, 10));
+        assert_eq!(fixed(source), b"foo do\n  bar\nend\n");
+
+        let source = b"foo.each { |x|\n  x }\n";
+        assert_eq!(fixed(source), b"foo.each { |x|\n  x\n}\n");
+    }
+
+    #[test]
+    fn test_empty_multiline_block() {
+        assert_eq!(
+            fixed(b"foo do |x|\n  x\nend\nbar do |x,\n       y| end\n"),
+            b"foo do |x|\n  x\nend\nbar do |x,\n       y|\nend\n"
+        );
+        assert!(block_end_diagnostics(b"foo do\nend\n").is_empty());
+    }
+
+    #[test]
+    fn test_rescue_and_ensure_clauses() {
+        assert_eq!(fixed(b"foo do\n  bar\nrescue\n  baz end\n"), b"foo do\n  bar\nrescue\n  baz\nend\n");
+        assert_eq!(fixed(b"foo do\n  bar\nensure\n  baz end\n"), b"foo do\n  bar\nensure\n  baz\nend\n");
+        assert_eq!(
+            fixed(b"foo do\n  bar\nrescue A\n  baz\nelse\n  qux end\n"),
+            b"foo do\n  bar\nrescue A\n  baz\nelse\n  qux\nend\n"
+        );
+        assert_eq!(fixed(b"foo do\n  bar\nrescue A => e end\n"), b"foo do\n  bar\nrescue A => e\nend\n");
+        assert!(block_end_diagnostics(b"foo do\n  bar\nrescue\n  baz\nend\n").is_empty());
+    }
+
+    #[test]
+    fn test_accepted_blocks() {
+        assert!(block_end_diagnostics(b"foo { bar }\n").is_empty());
+        assert!(block_end_diagnostics(b"foo do bar end\n").is_empty());
+        assert!(block_end_diagnostics(b"foo do\n  bar # comment\nend\n").is_empty());
+        assert!(block_end_diagnostics(b"foo do\n  bar\n  # comment\n  end\n").is_empty());
+        assert!(block_end_diagnostics(b"foo do\n  bar; end\n").is_empty());
+    }
+}
@@ -0,0 +1,190 @@
+use crate::checker::Checker;
+use crate::diagnostic::{Edit, Fix};
+use crate::rule::{Check, LayoutRule, Rule, RuleId};
+use reukocyte_macros::check;
+use ruby_prism::*;
+
+/// Get the config for this rule
+#[inline]
+fn config<'a>(checker: &'a Checker<'_>) -> &'a crate::config::layout::closing_heredoc_indentation::ClosingHeredocIndentation {
+    &checker.config().layout.closing_heredoc_indentation
+}
+
+/// Layout/ClosingHeredocIndentation rule.
+///
+/// Checks that the closing identifier of a `<<~` or `<<-` heredoc is indented
+/// like the line where the heredoc begins. A heredoc passed to a method call
+/// may instead close at the indentation of the line the call starts on. Plain
+/// `<<` heredocs always close at column 0 and are not checked.
+///
+/// ```ruby
+/// # bad
+/// class Foo
+///   def bar
+///     <<~SQL
+///       'Hi'
+///   SQL
+///   end
+/// end
+///
+/// # good
+/// class Foo
+///   def bar
+///     <<~SQL
+///       'Hi'
+///     SQL
+///   end
+/// end
+///
+/// # good
+/// include_some_module(<<~CODE,
+///                       text
+///                     CODE
+///                     :else)
+/// ```
+pub struct ClosingHeredocIndentation;
+impl Rule for ClosingHeredocIndentation {
+    const ID: RuleId = RuleId::Layout(LayoutRule::ClosingHeredocIndentation);
+}
+#[check(StringNode)]
+impl Check<StringNode<'_>> for ClosingHeredocIndentation {
+    fn check(node: &StringNode, checker: &mut Checker) {
+        if let (Some(opening), Some(closing)) = (node.opening_loc(), node.closing_loc()) {
+            check_heredoc(&opening, &closing, checker);
+        }
+    }
+}
+#[check(InterpolatedStringNode)]
+impl Check<InterpolatedStringNode<'_>> for ClosingHeredocIndentation {
+    fn check(node: &InterpolatedStringNode, checker: &mut Checker) {
+        if let (Some(opening), Some(closing)) = (node.opening_loc(), node.closing_loc()) {
+            check_heredoc(&opening, &closing, checker);
+        }
+    }
+}
+#[check(XStringNode)]
+impl Check<XStringNode<'_>> for ClosingHeredocIndentation {
+    fn check(node: &XStringNode, checker: &mut Checker) {
+        check_heredoc(&node.opening_loc(), &node.closing_loc(), checker);
+    }
+}
+#[check(InterpolatedXStringNode)]
+impl Check<InterpolatedXStringNode<'_>> for ClosingHeredocIndentation {
+    fn check(node: &InterpolatedXStringNode, checker: &mut Checker) {
+        check_heredoc(&node.opening_loc(), &node.closing_loc(), checker);
+    }
+}
+
+fn check_heredoc(opening: &Location, closing: &Location, checker: &mut Checker) {
+    let heredoc_opening = opening.as_slice();
+    if !heredoc_opening.starts_with(b"<<~") && !heredoc_opening.starts_with(b"<<-") {
+        return;
+    }
+    let line_index = checker.line_index();
+    let opening_indentation = line_index.indentation(opening.start_offset());
+    let closing_line_start = line_index.line_start_offset(closing.start_offset());
+    let closing_indentation = line_index.indentation(closing.start_offset());
+    if opening_indentation == closing_indentation {
+        return;
+    }
+    let call_indentation = outermost_call_start(checker).map(|start| line_index.indentation(start));
+    if call_indentation == Some(closing_indentation) {
+        return;
+    }
+
+    let identifier_start = closing_line_start + closing_indentation;
+    let identifier = line_index.line_at(closing.start_offset())[closing_indentation..].trim_ascii_end();
+    if identifier.is_empty() {
+        return;
+    }
+    let identifier_end = identifier_start + identifier.len();
+    let opening_line = line_index.line_at(opening.start_offset()).trim_ascii();
+    let message = format!(
+        "`{}` is not aligned with `{}`{}.",
+        String::from_utf8_lossy(identifier),
+        String::from_utf8_lossy(opening_line),
+        if call_indentation.is_some() {
+            " or beginning of method definition"
+        } else {
+            ""
+        }
+    );
+    let fix = Fix::safe(vec![Edit::replacement(closing_line_start, identifier_start, " ".repeat(opening_indentation))]);
+    let severity = config(checker).base.severity;
+    checker.report(ClosingHeredocIndentation::ID, message, severity, closing_line_start, identifier_end, Some(fix));
+}
+
+/// Find where the outermost call of a chain the heredoc is an argument or a
+/// receiver of starts.
+fn outermost_call_start(checker: &Checker) -> Option<usize> {
+    checker
+        .semantic()
+        .ancestors()
+        .take_while(|ancestor| ancestor.as_call_node().is_some() || ancestor.as_arguments_node().is_some())
+        .filter_map(|ancestor| ancestor.as_call_node())
+        .last()
+        .map(|call| call.location().start_offset())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::check;
+
+    fn heredoc_diagnostics(source: &[u8]) -> Vec<crate::Diagnostic> {
+        check(source).into_iter().filter(|d| d.rule_id == ClosingHeredocIndentation::ID).collect()
+    }
+
+    fn fixed(source: &[u8]) -> Vec<u8> {
+        crate::apply_fixes(None, source, &heredoc_diagnostics(source), false).0
+    }
+
+    #[test]
+    fn test_misaligned_closing() {
+        let source = b"def foo\n  x = <<~SQL\n    'Hi'\nSQL\nend\n";
+        let diagnostics = heredoc_diagnostics(source);
+        assert_eq!(diagnostics.len(), 1);
+        assert_eq!(diagnostics[0].message, "`SQL` is not aligned with `x = <<~SQL`.");
+        assert_eq!((diagnostics[0].line_start, diagnostics[0].column_start, diagnostics[0].column_end), (4, 1, 4));
+        assert_eq!(fixed(source), b"def foo\n  x = <<~SQL\n    'Hi'\n  SQL\nend\n");
+
+        let source = b"def foo\n  <<-EOS\n    text\n      EOS\nend\n";
+        assert_eq!(fixed(source), b"def foo\n  <<-EOS\n    text\n  EOS\nend\n");
+    }
+
+    #[test]
+    fn test_aligned_closing() {
+        assert!(heredoc_diagnostics(b"def foo\n  <<~SQL\n    'Hi'\n  SQL\nend\n").is_empty());
+        assert!(heredoc_diagnostics(b"def foo\n  x = <<-EOS.strip\n    #{bar}\n  EOS\nend\n").is_empty());
+        assert!(heredoc_diagnostics(b"def foo\n  x = <<~`CMD`\n    ls\n  CMD\nend\n").is_empty());
+    }
+
+    #[test]
+    fn test_plain_heredoc_is_ignored() {
+        assert!(heredoc_diagnostics(b"def foo\n  <<SQL\n    'Hi'\nSQL\nend\n").is_empty());
+    }
+
+    #[test]
+    fn test_multiple_heredocs_on_one_line() {
+        let source = b"  foo(<<~A, <<~B)\n    a\n  A\n    b\nB\n";
+        let diagnostics = heredoc_diagnostics(source);
+        assert_eq!(diagnostics.len(), 1);
+        assert_eq!(diagnostics[0].line_start, 5);
+        assert_eq!(
+            diagnostics[0].message,
+            "`B` is not aligned with `foo(<<~A, <<~B)` or beginning of method definition."
+        );
+        assert_eq!(fixed(source), b"  foo(<<~A, <<~B)\n    a\n  A\n    b\n  B\n");
+    }
+
+    #[test]
+    fn test_heredoc_argument() {
+        // Aligned with the start of the call rather than the opening line
+        let source = b"include_module(:a,\n               <<~CODE)\n  text\nCODE\n";
+        assert!(heredoc_diagnostics(source).is_empty());
+        let source = b"foo.bar(:a,\n        <<~CODE)\n  text\n    CODE\n";
+        let diagnostics = heredoc_diagnostics(source);
+        assert_eq!(diagnostics.len(), 1);
+        assert_eq!(fixed(source), b"foo.bar(:a,\n        <<~CODE)\n  text\n        CODE\n");
+    }
+}
@@ -0,0 +1,253 @@
+use crate::checker::Checker;
+use crate::diagnostic::{Edit, Fix};
+use crate::rule::{Check, LayoutRule, Rule, RuleId};
+use reukocyte_macros::check;
+use ruby_prism::*;
+
+const MSG_ALIGN: &str = "Align `)` with `(`.";
+
+/// Get the config for this rule
+#[inline]
+fn config<'a>(checker: &'a Checker<'_>) -> &'a crate::config::layout::closing_parenthesis_indentation::ClosingParenthesisIndentation {
+    &checker.config().layout.closing_parenthesis_indentation
+}
+
+/// Layout/ClosingParenthesisIndentation rule.
+///
+/// Checks the indentation of a `)` that begins its line, in method calls,
+/// method definitions and grouping parentheses. When a line break follows the
+/// `(`, the `)` is dedented one level from the first element; otherwise it
+/// aligns with the `(` if the elements are aligned, or with the first element's
+/// line. A call that is an argument of a call starting on the same line closes
+/// at the start of that line instead.
+///
+/// ```ruby
+/// # bad
+/// some_method(
+///   a
+///   )
+///
+/// # good
+/// some_method(
+///   a
+/// )
+///
+/// # good
+/// foo(bar(
+///   baz
+/// ))
+/// ```
+pub struct ClosingParenthesisIndentation;
+impl Rule for ClosingParenthesisIndentation {
+    const ID: RuleId = RuleId::Layout(LayoutRule::ClosingParenthesisIndentation);
+}
+#[check(CallNode)]
+impl Check<CallNode<'_>> for ClosingParenthesisIndentation {
+    fn check(node: &CallNode, checker: &mut Checker) {
+        let (Some(opening), Some(closing)) = (node.opening_loc(), node.closing_loc()) else {
+            return;
+        };
+        if opening.as_slice() != b"(" {
+            return;
+        }
+        let mut elements: Vec<Node> = node.arguments().map(|arguments| arguments.arguments().iter().collect()).unwrap_or_default();
+        elements.extend(node.block().filter(|block| block.as_block_argument_node().is_some()));
+        let nested = is_nested_argument(&opening, checker);
+        check_parentheses(&opening, &closing, &node.location(), &elements, nested, checker);
+    }
+}
+#[check(DefNode)]
+impl Check<DefNode<'_>> for ClosingParenthesisIndentation {
+    fn check(node: &DefNode, checker: &mut Checker) {
+        let (Some(opening), Some(closing)) = (node.lparen_loc(), node.rparen_loc()) else {
+            return;
+        };
+        let mut elements = Vec::new();
+        if let Some(parameters) = node.parameters() {
+            elements.extend(parameters.requireds().iter());
+            elements.extend(parameters.optionals().iter());
+            elements.extend(parameters.rest());
+            elements.extend(parameters.posts().iter());
+            elements.extend(parameters.keywords().iter());
+            elements.extend(parameters.keyword_rest());
+            elements.extend(parameters.block().map(|block| block.as_node()));
+            elements.sort_by_key(|element| element.location().start_offset());
+        }
+        check_parentheses(&opening, &closing, &opening, &elements, false, checker);
+    }
+}
+#[check(ParenthesesNode)]
+impl Check<ParenthesesNode<'_>> for ClosingParenthesisIndentation {
+    fn check(node: &ParenthesesNode, checker: &mut Checker) {
+        let elements: Vec<Node> = match node.body() {
+            Some(body) => match body.as_statements_node() {
+                Some(statements) => statements.body().iter().collect(),
+                None => vec![body],
+            },
+            None => Vec::new(),
+        };
+        check_parentheses(&node.opening_loc(), &node.closing_loc(), &node.location(), &elements, false, checker);
+    }
+}
+
+/// Check if the call is an argument of a call that starts on the line of its `(`,
+/// as in `foo(bar(`.
+fn is_nested_argument(opening: &Location, checker: &Checker) -> bool {
+    let mut ancestors = checker.semantic().ancestors();
+    let (Some(arguments), Some(outer)) = (ancestors.next(), ancestors.next()) else {
+        return false;
+    };
+    arguments.as_arguments_node().is_some()
+        && outer.as_call_node().is_some()
+        && checker.line_index().are_on_same_line(outer.location().start_offset(), opening.start_offset())
+}
+
+/// Report a `)` beginning its line that is not at the expected column.
+///
+/// `node` is where the parenthesized construct starts, one of the accepted
+/// columns when there are no elements.
+fn check_parentheses(opening: &Location, closing: &Location, node: &Location, elements: &[Node], nested: bool, checker: &mut Checker) {
+    let line_index = checker.line_index();
+    let closing_start = closing.start_offset();
+    if !line_index.is_first_on_line(closing_start) {
+        return;
+    }
+    let actual = line_index.column(closing_start);
+    let opening_column = line_index.column(opening.start_offset());
+    let expected = match elements.first() {
+        None => {
+            // Any of the line start, the `(` or the start of the node is accepted.
+            let candidates = [
+                line_index.indentation(opening.start_offset()),
+                opening_column,
+                line_index.column(node.start_offset()),
+            ];
+            if candidates.contains(&actual) {
+                return;
+            }
+            candidates[0]
+        }
+        Some(first) => {
+            let first_start = first.location().start_offset();
+            if line_index.are_on_same_line(opening.start_offset(), first_start) {
+                if all_elements_aligned(elements, checker) {
+                    opening_column
+                } else {
+                    line_index.indentation(first_start)
+                }
+            } else if nested {
+                line_index.indentation(opening.start_offset())
+            } else {
+                let width = checker.config().layout.indentation_width.width.max(0) as usize;
+                line_index.indentation(first_start).saturating_sub(width)
+            }
+        }
+    };
+    if actual == expected {
+        return;
+    }
+    let message = if expected == opening_column {
+        MSG_ALIGN.to_string()
+    } else {
+        format!("Indent `)` to column {} (not {})", expected, actual)
+    };
+    let edit = if expected > actual {
+        Edit::insertion(closing_start, " ".repeat(expected - actual))
+    } else {
+        Edit::deletion(closing_start - (actual - expected), closing_start)
+    };
+    let severity = config(checker).base.severity;
+    checker.report(
+        ClosingParenthesisIndentation::ID,
+        message,
+        severity,
+        closing_start,
+        closing.end_offset(),
+        Some(Fix::safe(vec![edit])),
+    );
+}
+
+/// Check if every element (and every pair of a braceless hash) starts at the same column.
+fn all_elements_aligned(elements: &[Node], checker: &Checker) -> bool {
+    let line_index = checker.line_index();
+    let mut columns = elements.iter().flat_map(|element| match element.as_keyword_hash_node() {
+        Some(hash) => hash.elements().iter().map(|pair| line_index.column(pair.location().start_offset())).collect(),
+        None => vec![line_index.column(element.location().start_offset())],
+    });
+    let first = columns.next();
+    columns.all(|column| Some(column) == first)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::check;
+    use crate::corrector::Corrector;
+
+    fn closing_parenthesis_diagnostics(source: &[u8]) -> Vec<crate::Diagnostic> {
+        check(source).into_iter().filter(|d| d.rule_id == ClosingParenthesisIndentation::ID).collect()
+    }
+
+    fn fixed(source: &[u8]) -> String {
+        let mut corrector = Corrector::new();
+        for diagnostic in closing_parenthesis_diagnostics(source) {
+            corrector.merge(diagnostic.fix.as_ref().unwrap()).unwrap();
+        }
+        String::from_utf8(corrector.apply(source)).unwrap()
+    }
+
+    #[test]
+    fn test_line_break_after_opening_paren() {
+        let source = b"some_method(\n  a\n  )\n";
+        let diagnostics = closing_parenthesis_diagnostics(source);
+        assert_eq!(diagnostics.len(), 1);
+        assert_eq!(diagnostics[0].message, "Indent `)` to column 0 (not 2)");
+        assert_eq!(fixed(source), "some_method(\n  a\n)\n");
+        assert_eq!(fixed(b"def foo(\n  a,\n  b\n    )\nend\n"), "def foo(\n  a,\n  b\n)\nend\n");
+        assert!(closing_parenthesis_diagnostics(b"  foo(\n    a\n  )\n").is_empty());
+    }
+
+    #[test]
+    fn test_elements_on_opening_line() {
+        let source = b"some_method(a,\n            b\n)\n";
+        let diagnostics = closing_parenthesis_diagnostics(source);
+        assert_eq!(diagnostics[0].message, MSG_ALIGN);
+        assert_eq!(fixed(source), "some_method(a,\n            b\n           )\n");
+        // Unaligned elements close at the first element's line.
+        assert_eq!(fixed(b"some_method(a,\n  b\n  )\n"), "some_method(a,\n  b\n)\n");
+        assert!(closing_parenthesis_diagnostics(b"some_method(a,\n            b: 1,\n            c: 2\n           )\n").is_empty());
+    }
+
+    #[test]
+    fn test_nested_call() {
+        // Indenting one level from `baz` would be right too, but the column of
+        // `bar` (the method name) is not.
+        assert!(closing_parenthesis_diagnostics(b"foo(bar(\n  baz\n))\n").is_empty());
+        let source = b"foo(bar(\n  baz\n    ))\n";
+        let diagnostics = closing_parenthesis_diagnostics(source);
+        assert_eq!(diagnostics.len(), 1);
+        assert_eq!(diagnostics[0].message, "Indent `)` to column 0 (not 4)");
+        assert_eq!(fixed(source), "foo(bar(\n  baz\n))\n");
+        // Arguments indented past the line start still close at the statement's line start.
+        assert_eq!(
+            fixed(b"  expect(foo).to eq(bar(\n        baz\n      ))\n"),
+            "  expect(foo).to eq(bar(\n        baz\n  ))\n"
+        );
+        // The outer call on an earlier line does not count.
+        assert!(closing_parenthesis_diagnostics(b"foo(\n  bar(\n    baz\n  )\n)\n").is_empty());
+    }
+
+    #[test]
+    fn test_no_elements() {
+        assert!(closing_parenthesis_diagnostics(b"some_method(\n)\n").is_empty());
+        assert!(closing_parenthesis_diagnostics(b"x = some_method(\n               )\n").is_empty());
+        assert!(closing_parenthesis_diagnostics(b"x = some_method(\n    )\n").is_empty());
+        assert_eq!(fixed(b"x = some_method(\n      )\n"), "x = some_method(\n)\n");
+    }
+
+    #[test]
+    fn test_grouping_parentheses() {
+        assert_eq!(fixed(b"x = (\n  1 + 2\n  )\n"), "x = (\n  1 + 2\n)\n");
+        assert!(closing_parenthesis_diagnostics(b"foo(a,\n    b)\n").is_empty());
+    }
+}
@@ -1,12 +1,11 @@
 use crate::checker::Checker;
 use crate::config::layout::def_end_alignment::EnforcedStyleAlignWith;
-use crate::diagnostic::Edit;
-use crate::diagnostic::Fix;
 use crate::rule::Check;
 use crate::rule::LayoutRule;
 use crate::rule::Rule;
 use crate::rule::RuleId;
 use crate::utility::access_modifier::is_access_modifier;
+use crate::utility::alignment::indentation_fix;
 use reukocyte_macros::check;
 use ruby_prism::*;
 
@@ -17,6 +16,21 @@ fn config<'a>(checker: &'a Checker<'_>) -> &'a crate::config::layout::def_end_al
 }
 
 /// Layout/DefEndAlignment rule.
+///
+/// Checks that the `end` of a method definition is aligned with the start of
+/// the line where `def` appears (`start_of_line`, default) or with the `def`
+/// keyword (`def`), which only differ when the `def` follows a method call
+/// such as `private`.
+///
+/// ```ruby
+/// # bad (EnforcedStyleAlignWith: start_of_line)
+/// private def foo
+///         end
+///
+/// # good (EnforcedStyleAlignWith: start_of_line)
+/// private def foo
+/// end
+/// ```
 pub struct DefEndAlignment;
 impl Rule for DefEndAlignment {
     const ID: RuleId = RuleId::Layout(LayoutRule::DefEndAlignment);
@@ -70,13 +84,11 @@ fn check_end_kw_alignment(node: &DefNode, def: usize, start_of_line: usize, chec
             }
         };
 
-        let fix = if column_delta > 0 {
-            let end = end_keyword_loc.start_offset();
-            let start = end as i32 - column_delta;
-            Fix::safe(vec![Edit::deletion(start as usize, end)])
-        } else {
-            Fix::safe(vec![Edit::insertion(end_keyword_loc.start_offset(), " ".repeat(column_delta.abs() as usize))])
-        };
+        // The `end` can only be re-indented when nothing precedes it on its line.
+        let end_offset = end_keyword_loc.start_offset();
+        let fix = line_index
+            .is_first_on_line(end_offset)
+            .then(|| indentation_fix(line_index, end_offset, -(column_delta as isize)));
 
         checker.report(
             DefEndAlignment::ID,
@@ -84,7 +96,66 @@ fn check_end_kw_alignment(node: &DefNode, def: usize, start_of_line: usize, chec
             cfg.base.severity,
             end_keyword_loc.start_offset(),
             end_keyword_loc.end_offset(),
-            Some(fix),
+            fix,
         );
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::Config;
+    use crate::config::layout::def_end_alignment::EnforcedStyleAlignWith;
+    use crate::corrector::Corrector;
+    use crate::{check, check_with_config};
+
+    fn def_end_diagnostics(diagnostics: Vec<crate::Diagnostic>) -> Vec<crate::Diagnostic> {
+        diagnostics.into_iter().filter(|d| d.rule_id == DefEndAlignment::ID).collect()
+    }
+
+    fn fixed(source: &[u8], diagnostics: &[crate::Diagnostic]) -> String {
+        let mut corrector = Corrector::new();
+        for fix in diagnostics.iter().filter_map(|d| d.fix.as_ref()) {
+            corrector.merge(fix).unwrap();
+        }
+        String::from_utf8(corrector.apply(source)).unwrap()
+    }
+
+    #[test]
+    fn test_over_indented_end() {
+        let source = b"def foo\n  bar\n  end\n";
+        let diagnostics = def_end_diagnostics(check(source));
+        assert_eq!(diagnostics.len(), 1);
+        assert_eq!(diagnostics[0].line_start, 3);
+        assert_eq!(fixed(source, &diagnostics), "def foo\n  bar\nend\n");
+    }
+
+    #[test]
+    fn test_under_indented_end() {
+        let source = b"class A\n  def foo\n    bar\n end\nend\n";
+        let diagnostics = def_end_diagnostics(check(source));
+        assert_eq!(diagnostics.len(), 1);
+        assert_eq!(fixed(source, &diagnostics), "class A\n  def foo\n    bar\n  end\nend\n");
+    }
+
+    #[test]
+    fn test_end_after_access_modifier() {
+        let source = b"private def foo\n  bar\n        end\n";
+        let diagnostics = def_end_diagnostics(check(source));
+        assert_eq!(diagnostics.len(), 1);
+        assert_eq!(fixed(source, &diagnostics), "private def foo\n  bar\nend\n");
+
+        let mut config = Config::default();
+        config.layout.def_end_alignment.enforced_style_align_with = EnforcedStyleAlignWith::Def;
+        let diagnostics = def_end_diagnostics(check_with_config(source, &config));
+        assert_eq!(fixed(source, &diagnostics), "private def foo\n  bar\n        end\n");
+    }
+
+    #[test]
+    fn test_no_fix_when_end_follows_code() {
+        let source = b"def foo\n  bar; end\n";
+        let diagnostics = def_end_diagnostics(check(source));
+        assert_eq!(diagnostics.len(), 1);
+        assert!(diagnostics[0].fix.is_none());
+    }
+}
@@ -0,0 +1,154 @@
+//! Layout/EmptyLineAfterMagicComment
+//!
+//! Checks for a blank line after the magic comments at the top of a file
+//! (`frozen_string_literal`, `encoding`, `warn_indent`, `shareable_constant_value`).
+//!
+//! # Examples
+//!
+//! ```ruby
+//! # bad
+//! # frozen_string_literal: true
+//! class Foo
+//! end
+//!
+//! # good
+//! # frozen_string_literal: true
+//!
+//! class Foo
+//! end
+//! ```
+
+use crate::Checker;
+use crate::Edit;
+use crate::Fix;
+use crate::rule::{LayoutRule, RuleId};
+
+/// Rule identifier for Layout/EmptyLineAfterMagicComment.
+pub const RULE_ID: RuleId = RuleId::Layout(LayoutRule::EmptyLineAfterMagicComment);
+
+const MSG: &str = "Add an empty line after magic comments.";
+
+/// Magic comment keys, with `-` normalized to `_`.
+const MAGIC_COMMENT_KEYS: &[&str] = &["frozen_string_literal", "encoding", "coding", "warn_indent", "shareable_constant_value"];
+
+/// Check for a missing blank line after the leading magic comments.
+pub fn check(checker: &mut Checker) {
+    let config = &checker.config().layout.empty_line_after_magic_comment;
+    if !config.base.enabled {
+        return;
+    }
+    // Check cop-specific include/exclude
+    if !checker.should_run_cop(&config.base) {
+        return;
+    }
+    let severity = config.base.severity;
+
+    if let Some((start, end)) = analyze(checker) {
+        let fix = Fix::safe(vec![Edit::insertion(start, "\n".to_string())]);
+        checker.report(RULE_ID, MSG.to_string(), severity, start, end, Some(fix));
+    }
+}
+
+/// Find the line following the last leading magic comment when it is not blank.
+/// Returns the (start, end) offsets of that line.
+fn analyze(checker: &Checker) -> Option<(usize, usize)> {
+    let line_index = checker.line_index();
+    // Comments (including a shebang) and blank lines before the first line of code.
+    let mut last_magic_comment = None;
+    for i in 0..line_index.line_count() {
+        let line = line_index.line(i)?.trim_ascii();
+        if line.is_empty() {
+            continue;
+        }
+        if !line.starts_with(b"#") {
+            break;
+        }
+        if is_magic_comment(line) {
+            last_magic_comment = Some(i);
+        }
+    }
+
+    let next = last_magic_comment? + 1;
+    let next_line = line_index.line(next)?;
+    if next_line.trim_ascii().is_empty() {
+        return None;
+    }
+    let start = line_index.line_start(next)?;
+    Some((start, start + next_line.len()))
+}
+
+/// Check if a comment is a magic comment, in either the `# key: value` form or
+/// the Emacs `# -*- key: value; key: value -*-` form.
+fn is_magic_comment(comment: &[u8]) -> bool {
+    let Ok(text) = std::str::from_utf8(comment) else {
+        return false;
+    };
+    let text = text.trim_start_matches('#').trim();
+    let text = match text.strip_prefix("-*-").and_then(|rest| rest.strip_suffix("-*-")) {
+        Some(inner) => inner,
+        None => text,
+    };
+    text.split(';').any(|directive| {
+        let Some((key, value)) = directive.split_once(':') else {
+            return false;
+        };
+        let key = key.trim().to_ascii_lowercase().replace('-', "_");
+        MAGIC_COMMENT_KEYS.contains(&key.as_str()) && !value.trim().is_empty()
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{apply_fixes, check};
+
+    fn offenses(source: &[u8]) -> Vec<crate::Diagnostic> {
+        check(source).into_iter().filter(|d| d.rule_id == RULE_ID).collect()
+    }
+
+    #[test]
+    fn test_missing_blank_line() {
+        let source = b"# frozen_string_literal: true\nclass Foo\nend\n";
+        let diagnostics = offenses(source);
+        assert_eq!(diagnostics.len(), 1);
+        assert_eq!(diagnostics[0].message, MSG);
+        assert_eq!(diagnostics[0].line_start, 2);
+        let (fixed, _) = apply_fixes(None, source, &diagnostics, false);
+        assert_eq!(fixed, b"# frozen_string_literal: true\n\nclass Foo\nend\n");
+    }
+
+    #[test]
+    fn test_blank_line_present() {
+        assert!(offenses(b"# frozen_string_literal: true\n\nclass Foo\nend\n").is_empty());
+    }
+
+    #[test]
+    fn test_only_magic_comments() {
+        assert!(offenses(b"# frozen_string_literal: true\n").is_empty());
+        assert!(offenses(b"# frozen_string_literal: true").is_empty());
+    }
+
+    #[test]
+    fn test_after_shebang_and_consecutive_magic_comments() {
+        let source = b"#!/usr/bin/env ruby\n# encoding: utf-8\n# frozen_string_literal: true\nputs 1\n";
+        let diagnostics = offenses(source);
+        assert_eq!(diagnostics.len(), 1);
+        assert_eq!(diagnostics[0].line_start, 4);
+        let (fixed, _) = apply_fixes(None, source, &diagnostics, false);
+        assert_eq!(fixed, b"#!/usr/bin/env ruby\n# encoding: utf-8\n# frozen_string_literal: true\n\nputs 1\n");
+    }
+
+    #[test]
+    fn test_regular_comment_after_magic_comment() {
+        let diagnostics = offenses(b"# frozen_string_literal: true\n# The Foo class.\nclass Foo\nend\n");
+        assert_eq!(diagnostics.len(), 1);
+        assert_eq!(diagnostics[0].line_start, 2);
+    }
+
+    #[test]
+    fn test_emacs_style_and_non_magic_comments() {
+        assert_eq!(offenses(b"# -*- coding: utf-8 -*-\nputs 1\n").len(), 1);
+        assert!(offenses(b"# Just a comment\nputs 1\n").is_empty());
+        assert!(offenses(b"puts 1\n# frozen_string_literal: true\nputs 2\n").is_empty());
+    }
+}
@@ -0,0 +1,237 @@
+use crate::checker::Checker;
+use crate::diagnostic::{Edit, Fix};
+use crate::rule::{Check, LayoutRule, Rule, RuleId};
+use reukocyte_macros::check;
+use ruby_prism::*;
+
+/// Get the config for this rule
+#[inline]
+fn config<'a>(checker: &'a Checker<'_>) -> &'a crate::config::layout::empty_line_between_defs::EmptyLineBetweenDefs {
+    &checker.config().layout.empty_line_between_defs
+}
+
+/// Layout/EmptyLineBetweenDefs rule.
+///
+/// Checks that consecutive method (including `def self.foo`), class and module
+/// definitions are separated by `NumberOfEmptyLines` empty lines. One-line
+/// definitions on adjacent lines are allowed with `AllowAdjacentOneLineDefs`,
+/// and empty lines split up by comments are left alone.
+///
+/// ```ruby
+/// # bad
+/// def a
+/// end
+/// def b
+/// end
+///
+/// # good
+/// def a
+/// end
+///
+/// def b
+/// end
+///
+/// # good (AllowAdjacentOneLineDefs: true)
+/// def a; end
+/// def b; end
+/// ```
+pub struct EmptyLineBetweenDefs;
+impl Rule for EmptyLineBetweenDefs {
+    const ID: RuleId = RuleId::Layout(LayoutRule::EmptyLineBetweenDefs);
+}
+#[check(StatementsNode)]
+impl Check<StatementsNode<'_>> for EmptyLineBetweenDefs {
+    fn check(node: &StatementsNode, checker: &mut Checker) {
+        let body: Vec<Node> = node.body().iter().collect();
+        for pair in body.windows(2) {
+            let (Some(_), Some(definition)) = (Definition::new(&pair[0], checker), Definition::new(&pair[1], checker)) else {
+                continue;
+            };
+            check_defs(&pair[0], &pair[1], &definition, checker);
+        }
+    }
+}
+
+/// Check the empty lines between two consecutive definitions.
+fn check_defs(previous: &Node, node: &Node, definition: &Definition, checker: &mut Checker) {
+    let config = config(checker);
+    let line_index = checker.line_index();
+    let previous_location = previous.location();
+    let location = node.location();
+    // 1-indexed line numbers of the end of the previous definition and the start of this one.
+    let end_line = line_index.line_number(previous_location.end_offset().saturating_sub(1));
+    let start_line = line_index.line_number(location.start_offset());
+    // Whether each line in between is blank.
+    let blanks: Vec<bool> = (end_line..start_line.saturating_sub(1))
+        .filter_map(|i| line_index.line(i))
+        .map(|line| line.trim_ascii().is_empty())
+        .collect();
+    let count = blanks.iter().filter(|&&blank| blank).count();
+    let range = config.number_of_empty_lines.clone();
+    if range.contains(&count) {
+        return;
+    }
+    // Empty lines after a comment, e.g. two groups of empty lines with a comment in between.
+    if let (Some(last_blank), Some(first_content)) = (blanks.iter().rposition(|&blank| blank), blanks.iter().position(|&blank| !blank)) {
+        if last_blank > first_content {
+            return;
+        }
+    }
+    let single_line = |location: &Location| line_index.are_on_same_line(location.start_offset(), location.end_offset());
+    if config.allow_adjacent_one_line_defs && single_line(&previous_location) && single_line(&location) {
+        return;
+    }
+
+    let (min, max) = (*range.start(), *range.end());
+    let expected = if min != max {
+        format!("{min}..{max} empty lines")
+    } else if max == 1 {
+        "1 empty line".to_string()
+    } else {
+        format!("{max} empty lines")
+    };
+    let message = format!("Expected {} between {} definitions; found {}.", expected, definition.kind, count);
+    // The first line after the previous definition.
+    let fix = if end_line < start_line {
+        line_index.line_start(end_line).and_then(|first| {
+            if count < min {
+                Some(Fix::safe(vec![Edit::insertion(first, "\n".repeat(min - count))]))
+            } else {
+                let last = line_index.line_start(end_line + count - max)?;
+                Some(Fix::safe(vec![Edit::deletion(first, last)]))
+            }
+        })
+    } else {
+        None
+    };
+    let severity = config.base.severity;
+    checker.report(EmptyLineBetweenDefs::ID, message, severity, definition.start, definition.end, fix);
+}
+
+/// A method, class or module definition checked by the rule.
+struct Definition {
+    /// `method`, `class` or `module`.
+    kind: &'static str,
+    /// Offsets of the keyword and name, where the offense is reported.
+    start: usize,
+    end: usize,
+}
+impl Definition {
+    /// Get the definition if the node is one the config asks to check.
+    fn new(node: &Node, checker: &Checker) -> Option<Self> {
+        let config = config(checker);
+        let (kind, start, end) = match node {
+            Node::DefNode { .. } if config.empty_line_between_method_defs => {
+                let def = node.as_def_node().unwrap();
+                ("method", def.def_keyword_loc().start_offset(), def.name_loc().end_offset())
+            }
+            Node::ClassNode { .. } if config.empty_line_between_class_defs => {
+                let class = node.as_class_node().unwrap();
+                ("class", class.class_keyword_loc().start_offset(), class.constant_path().location().end_offset())
+            }
+            Node::SingletonClassNode { .. } if config.empty_line_between_class_defs => {
+                let class = node.as_singleton_class_node().unwrap();
+                ("class", class.class_keyword_loc().start_offset(), class.expression().location().end_offset())
+            }
+            Node::ModuleNode { .. } if config.empty_line_between_module_defs => {
+                let module = node.as_module_node().unwrap();
+                (
+                    "module",
+                    module.module_keyword_loc().start_offset(),
+                    module.constant_path().location().end_offset(),
+                )
+            }
+            _ => return None,
+        };
+        Some(Self { kind, start, end })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::Config;
+    use crate::corrector::Corrector;
+    use crate::{apply_fixes, check, check_with_config};
+
+    fn empty_line_between_defs_diagnostics(source: &[u8], config: &Config) -> Vec<crate::Diagnostic> {
+        check_with_config(source, config)
+            .into_iter()
+            .filter(|d| d.rule_id == EmptyLineBetweenDefs::ID)
+            .collect()
+    }
+
+    fn fixed(source: &[u8], config: &Config) -> String {
+        let mut corrector = Corrector::new();
+        for diagnostic in empty_line_between_defs_diagnostics(source, config) {
+            corrector.merge(diagnostic.fix.as_ref().unwrap()).unwrap();
+        }
+        String::from_utf8(corrector.apply(source)).unwrap()
+    }
+
+    #[test]
+    fn test_missing_empty_line() {
+        let source = b"def a\nend\ndef self.b\nend\n";
+        let diagnostics = empty_line_between_defs_diagnostics(source, &Config::default());
+        assert_eq!(diagnostics.len(), 1);
+        assert_eq!(diagnostics[0].message, "Expected 1 empty line between method definitions; found 0.");
+        assert_eq!(diagnostics[0].line_start, 3);
+        assert_eq!(diagnostics[0].column_end - diagnostics[0].column_start, "def self.b".len());
+        let (fixed, _) = apply_fixes(None, source, &diagnostics, false);
+        assert_eq!(fixed, b"def a\nend\n\ndef self.b\nend\n");
+    }
+
+    #[test]
+    fn test_classes_modules_and_comments() {
+        let config = Config::default();
+        let diagnostics = empty_line_between_defs_diagnostics(b"class A\nend\n# Docs for B.\nmodule B\nend\n", &config);
+        assert_eq!(diagnostics.len(), 1);
+        assert_eq!(diagnostics[0].message, "Expected 1 empty line between module definitions; found 0.");
+        assert_eq!(
+            fixed(b"class A\nend\n# Docs for B.\nmodule B\nend\n", &config),
+            "class A\nend\n\n# Docs for B.\nmodule B\nend\n"
+        );
+        assert!(empty_line_between_defs_diagnostics(b"def a\nend\n\n# Docs.\ndef b\nend\n", &config).is_empty());
+        assert!(empty_line_between_defs_diagnostics(b"def a\nend\nfoo\ndef b\nend\n", &config).is_empty());
+    }
+
+    #[test]
+    fn test_adjacent_one_line_defs() {
+        let source = b"def a; end\ndef b; end\ndef self.c = 1\n";
+        assert!(check(source).iter().all(|d| d.rule_id != EmptyLineBetweenDefs::ID));
+        // A multi-line definition next to a one-line one is still checked.
+        assert_eq!(empty_line_between_defs_diagnostics(b"def a; end\ndef b\nend\n", &Config::default()).len(), 1);
+
+        let mut config = Config::default();
+        config.layout.empty_line_between_defs.allow_adjacent_one_line_defs = false;
+        assert_eq!(empty_line_between_defs_diagnostics(source, &config).len(), 2);
+        assert_eq!(fixed(source, &config), "def a; end\n\ndef b; end\n\ndef self.c = 1\n");
+    }
+
+    #[test]
+    fn test_number_of_empty_lines_range() {
+        let mut config = Config::default();
+        config.layout.empty_line_between_defs.number_of_empty_lines = 1..=2;
+        assert!(empty_line_between_defs_diagnostics(b"def a\nend\n\n\ndef b\nend\n", &config).is_empty());
+        assert!(empty_line_between_defs_diagnostics(b"def a\nend\n\ndef b\nend\n", &config).is_empty());
+
+        let source = b"def a\nend\n\n\n\n\ndef b\nend\n";
+        let diagnostics = empty_line_between_defs_diagnostics(source, &config);
+        assert_eq!(diagnostics.len(), 1);
+        assert_eq!(diagnostics[0].message, "Expected 1..2 empty lines between method definitions; found 4.");
+        assert_eq!(fixed(source, &config), "def a\nend\n\n\ndef b\nend\n");
+
+        config.layout.empty_line_between_defs.number_of_empty_lines = 2..=2;
+        let diagnostics = empty_line_between_defs_diagnostics(b"def a\nend\n\ndef b\nend\n", &config);
+        assert_eq!(diagnostics[0].message, "Expected 2 empty lines between method definitions; found 1.");
+        assert_eq!(fixed(b"def a\nend\n\ndef b\nend\n", &config), "def a\nend\n\n\ndef b\nend\n");
+    }
+
+    #[test]
+    fn test_disabled_definition_kinds() {
+        let mut config = Config::default();
+        config.layout.empty_line_between_defs.empty_line_between_class_defs = false;
+        assert!(empty_line_between_defs_diagnostics(b"class A\nend\nclass B\nend\n", &config).is_empty());
+        assert_eq!(empty_line_between_defs_diagnostics(b"def a\nend\ndef b\nend\n", &config).len(), 1);
+    }
+}
@@ -36,7 +36,7 @@ pub fn check(checker: &mut Checker) {
         return;
     }
     // Check cop-specific include/exclude
-    if !checker.should_run_cop(&config.base.include, &config.base.exclude) {
+    if !checker.should_run_cop(&config.base) {
         return;
     }
     let severity = config.base.severity;
@@ -45,7 +45,7 @@ pub fn check(checker: &mut Checker) {
     for (start, end, message) in edit_ranges {
         // Fix: remove extra blank lines, keeping just one
         let fix = Fix::safe(vec![Edit::deletion(start, end)]);
-        checker.report(RULE_ID, message, severity, start, end, Some(fix));
+        checker.report_with_fix_description(RULE_ID, message, severity, start, end, fix, "remove extra blank lines");
     }
 }
 
@@ -0,0 +1,137 @@
+use crate::checker::Checker;
+use crate::diagnostic::{Edit, Fix};
+use crate::rule::{Check, LayoutRule, Rule, RuleId};
+use reukocyte_macros::check;
+use ruby_prism::*;
+
+const MSG_BEGINNING: &str = "Extra empty line detected at method body beginning.";
+const MSG_END: &str = "Extra empty line detected at method body end.";
+
+/// Get the config for this rule
+#[inline]
+fn config<'a>(checker: &'a Checker<'_>) -> &'a crate::config::layout::empty_lines_around_method_body::EmptyLinesAroundMethodBody {
+    &checker.config().layout.empty_lines_around_method_body
+}
+
+/// Layout/EmptyLinesAroundMethodBody rule.
+///
+/// Checks that method bodies do not start or end with empty lines. The body
+/// starts on the line after the method's parameters, so a parameter list
+/// spanning several lines is not part of it. One-line and endless methods are
+/// not checked.
+///
+/// ```ruby
+/// # bad
+/// def foo
+///
+///   bar
+///
+/// end
+///
+/// # good
+/// def foo
+///   bar
+/// end
+/// ```
+pub struct EmptyLinesAroundMethodBody;
+impl Rule for EmptyLinesAroundMethodBody {
+    const ID: RuleId = RuleId::Layout(LayoutRule::EmptyLinesAroundMethodBody);
+}
+#[check(DefNode)]
+impl Check<DefNode<'_>> for EmptyLinesAroundMethodBody {
+    fn check(node: &DefNode, checker: &mut Checker) {
+        let Some(end_keyword) = node.end_keyword_loc() else {
+            return;
+        };
+        let header_end = match (node.rparen_loc(), node.parameters()) {
+            (Some(rparen), _) => rparen.end_offset(),
+            (None, Some(parameters)) => parameters.location().end_offset(),
+            (None, None) => node.name_loc().end_offset(),
+        };
+        let line_index = checker.line_index();
+        // 0-indexed lines of the end of the header and of `end`.
+        let header_line = line_index.line_index(header_end.saturating_sub(1));
+        let end_line = line_index.line_index(end_keyword.start_offset());
+        let is_blank = |line: usize| line_index.line(line).is_some_and(|content| content.trim_ascii().is_empty());
+
+        let first_content = (header_line + 1..end_line).find(|&line| !is_blank(line)).unwrap_or(end_line);
+        let last_content = (first_content..end_line).rev().find(|&line| !is_blank(line));
+        if first_content > header_line + 1 {
+            report(header_line + 1, first_content, MSG_BEGINNING, checker);
+        }
+        // A body of only empty lines is reported once, at its beginning.
+        if let Some(last_content) = last_content
+            && last_content + 1 < end_line
+        {
+            report(last_content + 1, end_line, MSG_END, checker);
+        }
+    }
+}
+
+/// Report the empty lines `first..last` (0-indexed, exclusive), deleting them.
+fn report(first: usize, last: usize, message: &str, checker: &mut Checker) {
+    let line_index = checker.line_index();
+    let (Some(start), Some(end)) = (line_index.line_start(first), line_index.line_start(last)) else {
+        return;
+    };
+    let fix = Fix::safe(vec![Edit::deletion(start, end)]);
+    let severity = config(checker).base.severity;
+    checker.report(EmptyLinesAroundMethodBody::ID, message.to_string(), severity, start, start, Some(fix));
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::check;
+    use crate::corrector::Corrector;
+
+    fn method_body_diagnostics(source: &[u8]) -> Vec<crate::Diagnostic> {
+        check(source).into_iter().filter(|d| d.rule_id == EmptyLinesAroundMethodBody::ID).collect()
+    }
+
+    fn fixed(source: &[u8]) -> String {
+        let mut corrector = Corrector::new();
+        for diagnostic in method_body_diagnostics(source) {
+            corrector.merge(diagnostic.fix.as_ref().unwrap()).unwrap();
+        }
+        String::from_utf8(corrector.apply(source)).unwrap()
+    }
+
+    #[test]
+    fn test_empty_line_after_def() {
+        let source = b"def foo\n\n  bar\nend\n";
+        let diagnostics = method_body_diagnostics(source);
+        assert_eq!(diagnostics.len(), 1);
+        assert_eq!(diagnostics[0].message, MSG_BEGINNING);
+        assert_eq!(diagnostics[0].line_start, 2);
+        assert_eq!(fixed(source), "def foo\n  bar\nend\n");
+        assert_eq!(fixed(b"def foo(a,\n        b)\n\n\n  bar\nend\n"), "def foo(a,\n        b)\n  bar\nend\n");
+    }
+
+    #[test]
+    fn test_empty_line_before_end() {
+        let source = b"class A\n  def foo\n    bar\n\n  end\nend\n";
+        let diagnostics = method_body_diagnostics(source);
+        assert_eq!(diagnostics.len(), 1);
+        assert_eq!(diagnostics[0].message, MSG_END);
+        assert_eq!(fixed(source), "class A\n  def foo\n    bar\n  end\nend\n");
+        assert_eq!(fixed(b"def self.foo a\n\n  bar\n\nend\n"), "def self.foo a\n  bar\nend\n");
+    }
+
+    #[test]
+    fn test_rescue_and_empty_bodies() {
+        assert_eq!(fixed(b"def foo\n\nrescue\n  bar\nend\n"), "def foo\nrescue\n  bar\nend\n");
+        assert!(method_body_diagnostics(b"def foo\n  bar\n\nrescue\n  baz\nend\n").is_empty());
+        let diagnostics = method_body_diagnostics(b"def foo\n\n\nend\n");
+        assert_eq!(diagnostics.len(), 1);
+        assert_eq!(diagnostics[0].message, MSG_BEGINNING);
+        assert_eq!(fixed(b"def foo\n\n\nend\n"), "def foo\nend\n");
+        assert!(method_body_diagnostics(b"def foo\nend\n").is_empty());
+    }
+
+    #[test]
+    fn test_one_line_and_endless_methods() {
+        assert!(method_body_diagnostics(b"def foo = bar\n\ndef baz; end\n\ndef qux(a) = a\n").is_empty());
+        assert!(method_body_diagnostics(b"def foo\n  bar\nend\n").is_empty());
+    }
+}
@@ -18,6 +18,21 @@ fn config<'a>(checker: &'a Checker<'_>) -> &'a crate::config::layout::end_alignm
 }
 
 /// Layout/EndAlignment rule.
+///
+/// Checks that the `end` of `class`, `module`, `if`, `while`, `case` and similar
+/// keywords is aligned with the keyword (`keyword`, default), with the start of
+/// the variable it is assigned to (`variable`), or with the start of the line
+/// (`start_of_line`).
+///
+/// ```ruby
+/// # bad (EnforcedStyleAlignWith: keyword)
+/// variable = if true
+/// end
+///
+/// # good (EnforcedStyleAlignWith: keyword)
+/// variable = if true
+///            end
+/// ```
 pub struct EndAlignment;
 impl Rule for EndAlignment {
     const ID: RuleId = RuleId::Layout(LayoutRule::EndAlignment);
@@ -49,8 +64,9 @@ impl Check<ModuleNode<'_>> for EndAlignment {
 #[check(IfNode)]
 impl Check<IfNode<'_>> for EndAlignment {
     fn check(node: &IfNode, checker: &mut Checker) {
-        // If not ternary, check alignment of 'if' keyword
+        // If not ternary, check alignment of 'if' keyword. An `elsif` shares the `end` of its `if`.
         if let Some(if_keyword_loc) = node.if_keyword_loc()
+            && if_keyword_loc.as_slice() == b"if"
             && let Some(end_keyword_loc) = node.end_keyword_loc()
         {
             check_other_alignment(node.location(), if_keyword_loc, end_keyword_loc, checker);
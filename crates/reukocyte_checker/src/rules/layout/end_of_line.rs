@@ -0,0 +1,135 @@
+//! Layout/EndOfLine
+//!
+//! Checks that every line ends with the configured terminator: `\n` for `lf`,
+//! `\r\n` for `crlf`, or the platform's for `native` (the default). A last line
+//! without a terminator is not checked.
+//!
+//! # Examples
+//!
+//! ```ruby
+//! # bad (with EnforcedStyle: lf, each ␍ is a carriage return)
+//! x = 0␍
+//!
+//! # good
+//! x = 0
+//! ```
+
+use crate::Checker;
+use crate::Edit;
+use crate::Fix;
+use crate::rule::{LayoutRule, RuleId};
+
+/// Rule identifier for Layout/EndOfLine.
+pub const RULE_ID: RuleId = RuleId::Layout(LayoutRule::EndOfLine);
+
+/// Check the terminator of every line.
+pub fn check(checker: &mut Checker) {
+    let config = &checker.config().layout.end_of_line;
+    if !config.base.enabled {
+        return;
+    }
+    // Check cop-specific include/exclude
+    if !checker.should_run_cop(&config.base) {
+        return;
+    }
+    let severity = config.base.severity;
+    let crlf = config.enforced_style.uses_crlf();
+
+    for newline in collect_wrong_terminators(checker.source(), crlf) {
+        if crlf {
+            let fix = Fix::safe(vec![Edit::insertion(newline, "\r".to_string())]);
+            checker.report_with_fix_description(
+                RULE_ID,
+                "Carriage return character missing.".to_string(),
+                severity,
+                newline,
+                newline + 1,
+                fix,
+                "add carriage return",
+            );
+        } else {
+            let fix = Fix::safe(vec![Edit::deletion(newline - 1, newline)]);
+            checker.report_with_fix_description(
+                RULE_ID,
+                "Carriage return character detected.".to_string(),
+                severity,
+                newline - 1,
+                newline,
+                fix,
+                "remove carriage return",
+            );
+        }
+    }
+}
+
+/// Collect the offsets of the `\n`s whose line has the wrong terminator: `\r\n`
+/// when `crlf` is false, a bare `\n` when it is true.
+fn collect_wrong_terminators(source: &[u8], crlf: bool) -> Vec<usize> {
+    source
+        .iter()
+        .enumerate()
+        .filter(|&(offset, &byte)| byte == b'\n' && (offset > 0 && source[offset - 1] == b'\r') != crlf)
+        .map(|(offset, _)| offset)
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::layout::end_of_line::EnforcedStyle;
+
+    fn config(style: EnforcedStyle) -> crate::Config {
+        let mut config = crate::Config::default();
+        config.layout.end_of_line.enforced_style = style;
+        config
+    }
+
+    fn end_of_line_diagnostics(source: &[u8], style: EnforcedStyle) -> Vec<crate::Diagnostic> {
+        crate::check_with_config(source, &config(style))
+            .into_iter()
+            .filter(|d| d.rule_id == RULE_ID)
+            .collect()
+    }
+
+    fn fixed(source: &[u8], style: EnforcedStyle) -> Vec<u8> {
+        let config = config(style);
+        let diagnostics = end_of_line_diagnostics(source, style);
+        crate::apply_fixes_filtered_with_corrected(None, source, &diagnostics, &config, false, crate::FixMode::Merged, |d| d.rule_id == RULE_ID).0
+    }
+
+    #[test]
+    fn test_crlf_under_lf_style() {
+        let source = b"x = 1\r\ny = 2\n";
+        let diagnostics = end_of_line_diagnostics(source, EnforcedStyle::Lf);
+        assert_eq!(diagnostics.len(), 1);
+        assert_eq!(diagnostics[0].message, "Carriage return character detected.");
+        assert_eq!((diagnostics[0].line_start, diagnostics[0].column_start), (1, 6));
+        assert_eq!(fixed(source, EnforcedStyle::Lf), b"x = 1\ny = 2\n");
+    }
+
+    #[test]
+    fn test_lf_under_crlf_style() {
+        // Mixed endings; the unterminated last line is not checked
+        let source = b"x = 1\ny = 2\r\nz = 3\nw";
+        let diagnostics = end_of_line_diagnostics(source, EnforcedStyle::Crlf);
+        assert_eq!(diagnostics.len(), 2);
+        assert_eq!(diagnostics[0].message, "Carriage return character missing.");
+        assert_eq!(diagnostics[1].line_start, 3);
+        assert_eq!(fixed(source, EnforcedStyle::Crlf), b"x = 1\r\ny = 2\r\nz = 3\r\nw");
+    }
+
+    #[test]
+    fn test_consistent_endings() {
+        assert!(end_of_line_diagnostics(b"x = 1\ny = 2", EnforcedStyle::Lf).is_empty());
+        assert!(end_of_line_diagnostics(b"x = 1\r\ny = 2\r\n", EnforcedStyle::Crlf).is_empty());
+        let native = if cfg!(windows) { &b"x = 1\r\n"[..] } else { &b"x = 1\n"[..] };
+        assert!(end_of_line_diagnostics(native, EnforcedStyle::Native).is_empty());
+    }
+
+    #[test]
+    fn test_style_from_yaml() {
+        let yaml = crate::config::parse_rubocop_yaml("Layout/EndOfLine:\n  EnforcedStyle: crlf\n").unwrap();
+        let config = crate::Config::from_rubocop_yaml(&yaml);
+        assert_eq!(config.layout.end_of_line.enforced_style, EnforcedStyle::Crlf);
+    }
+}
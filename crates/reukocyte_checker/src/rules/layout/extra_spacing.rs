@@ -0,0 +1,194 @@
+//! Layout/ExtraSpacing
+//!
+//! Checks for runs of more than one space between tokens. Leading indentation is
+//! left to the indentation cops, trailing whitespace to Layout/TrailingWhitespace,
+//! and spacing after commas to Layout/SpaceAfterComma.
+//!
+//! With `AllowForAlignment` (default), extra spaces are accepted when they align a
+//! token (such as `=`, `=>` or a trailing comment) with the same column on the
+//! nearest non-blank line above or below.
+//!
+//! ## Examples
+//!
+//! ```ruby
+//! # bad
+//! name  = "foo"
+//! puts  name
+//!
+//! # good
+//! name = "foo"
+//!
+//! # good (AllowForAlignment: true)
+//! name    = "foo"
+//! surname = "bar"
+//! ```
+
+use crate::checker::Checker;
+use crate::diagnostic::{Edit, Fix};
+use crate::rule::{LayoutRule, RuleId};
+
+/// Rule identifier for Layout/ExtraSpacing.
+pub const RULE_ID: RuleId = RuleId::Layout(LayoutRule::ExtraSpacing);
+
+const MSG: &str = "Unnecessary spacing detected.";
+
+/// Check for extra spacing between tokens.
+pub fn check(checker: &mut Checker) {
+    let config = &checker.config().layout.extra_spacing;
+    if !config.base.enabled {
+        return;
+    }
+    // Check cop-specific include/exclude
+    if !checker.should_run_cop(&config.base) {
+        return;
+    }
+    let severity = config.base.severity;
+    let allow_for_alignment = config.allow_for_alignment;
+
+    let edit_ranges = collect_edit_ranges(checker, allow_for_alignment);
+    for (start, end) in edit_ranges {
+        // Fix: collapse the run to a single space
+        let fix = Fix::safe(vec![Edit::deletion(start, end)]);
+        checker.report(RULE_ID, MSG.to_string(), severity, start, end, Some(fix));
+    }
+}
+
+/// Collect (start, end) byte offsets of the extra spaces in each run (all but the last space).
+fn collect_edit_ranges(checker: &Checker, allow_for_alignment: bool) -> Vec<(usize, usize)> {
+    let mut lines = Vec::new();
+    let mut offset = 0;
+    for line in checker.source().split(|&b| b == b'\n') {
+        lines.push((offset, line.strip_suffix(b"\r").unwrap_or(line)));
+        offset += line.len() + 1;
+    }
+
+    let mut ranges = Vec::new();
+    let mut in_embedded_document = false;
+    for (index, &(line_offset, line)) in lines.iter().enumerate() {
+        if in_embedded_document {
+            in_embedded_document = !line.starts_with(b"=end");
+            continue;
+        }
+        if !checker.is_in_literal(line_offset) {
+            if line.starts_with(b"=begin") {
+                in_embedded_document = true;
+                continue;
+            }
+            if line == b"__END__" {
+                break;
+            }
+        }
+        let Some(first) = line.iter().position(|&b| b != b' ' && b != b'\t') else {
+            continue;
+        };
+
+        let mut column = first;
+        while column < line.len() {
+            match line[column] {
+                b'#' if !checker.is_in_literal(line_offset + column) => break,
+                b' ' => {
+                    let run_end = line[column..].iter().position(|&b| b != b' ').map_or(line.len(), |len| column + len);
+                    let is_extra = run_end - column >= 2 && run_end < line.len() && !checker.is_in_literal(line_offset + column);
+                    // Spacing around commas is handled by Layout/SpaceBeforeComma and Layout/SpaceAfterComma.
+                    let is_comma = line[column - 1] == b',' || line.get(run_end) == Some(&b',');
+                    if is_extra && !is_comma && !(allow_for_alignment && is_aligned(&lines, index, run_end)) {
+                        ranges.push((line_offset + column, line_offset + run_end - 1));
+                    }
+                    column = run_end;
+                }
+                _ => column += 1,
+            }
+        }
+    }
+    ranges
+}
+
+/// Check if the token at `column` of line `index` is aligned with the nearest non-blank line above or below.
+fn is_aligned(lines: &[(usize, &[u8])], index: usize, column: usize) -> bool {
+    let is_blank = |line: &[u8]| line.iter().all(|&b| b == b' ' || b == b'\t');
+    let token = &lines[index].1[column..];
+    let above = lines[..index].iter().rev().find(|(_, line)| !is_blank(line));
+    let below = lines[index + 1..].iter().find(|(_, line)| !is_blank(line));
+    [above, below].into_iter().flatten().any(|&(_, line)| is_aligned_with(line, column, token))
+}
+
+/// Check if `line` has a token starting at `column` after a space, or an assignment
+/// whose `=` is in the same column as the `=` of `token` (`+=` aligned with `=`).
+fn is_aligned_with(line: &[u8], column: usize, token: &[u8]) -> bool {
+    if column > 0 && line.get(column - 1) == Some(&b' ') && line.get(column).is_some_and(|&b| b != b' ') {
+        return true;
+    }
+    let operator_len = token.iter().take_while(|b| b"+-*/%|&^<>!=~".contains(b)).count();
+    let operator = &token[..operator_len];
+    let is_assignment = operator.ends_with(b"=") && !matches!(operator, b"==" | b"===" | b"!=" | b"<=" | b">=");
+    if !is_assignment {
+        return false;
+    }
+    let equal_column = column + operator_len - 1;
+    line.get(equal_column) == Some(&b'=')
+        && line.get(equal_column - 1).is_some_and(|b| b" +-*/%|&^<>".contains(b))
+        && !line.get(equal_column + 1).is_some_and(|b| b"=~>".contains(b))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::config::Config;
+    use crate::{check, check_with_config};
+
+    fn extra_spacing_count(source: &[u8]) -> usize {
+        check(source).iter().filter(|d| d.rule() == "Layout/ExtraSpacing").count()
+    }
+
+    #[test]
+    fn test_extra_space_before_operator() {
+        let source = b"x  = 1\n";
+        let diagnostics: Vec<_> = check(source).into_iter().filter(|d| d.rule() == "Layout/ExtraSpacing").collect();
+        assert_eq!(diagnostics.len(), 1);
+        assert_eq!(diagnostics[0].column_start, 2);
+        assert_eq!(diagnostics[0].column_end, 3);
+        let (fixed, _) = crate::apply_fixes(None, source, &diagnostics, false);
+        assert_eq!(fixed, b"x = 1\n");
+    }
+
+    #[test]
+    fn test_ignores_indentation_and_trailing_whitespace() {
+        assert_eq!(extra_spacing_count(b"def foo\n    bar\nend\n"), 0);
+        assert_eq!(extra_spacing_count(b"x = 1   \n"), 0);
+    }
+
+    #[test]
+    fn test_ignores_string_contents() {
+        assert_eq!(extra_spacing_count(b"x = \"a  b\"\n"), 0);
+        assert_eq!(extra_spacing_count(b"x = %w[a  b]\n"), 0);
+        assert_eq!(extra_spacing_count(b"x = <<~EOS\n  a  b\nEOS\n"), 0);
+        assert_eq!(extra_spacing_count(b"x = /a  b/\n"), 0);
+    }
+
+    #[test]
+    fn test_ignores_comment_contents() {
+        assert_eq!(extra_spacing_count(b"# a  b\nx = 1 # a  b\n"), 0);
+        assert_eq!(extra_spacing_count(b"=begin\na  b\n=end\n"), 0);
+    }
+
+    #[test]
+    fn test_ignores_spacing_after_comma() {
+        assert_eq!(extra_spacing_count(b"foo(a,  b)\n"), 0);
+    }
+
+    #[test]
+    fn test_allows_alignment() {
+        assert_eq!(extra_spacing_count(b"name    = 1\nsurname = 2\n"), 0);
+        assert_eq!(extra_spacing_count(b"a  += 1\nbb  = 2\n"), 0);
+        assert_eq!(extra_spacing_count(b"{\n  a   => 1,\n  bcd => 2\n}\n"), 0);
+        assert_eq!(extra_spacing_count(b"x = 1   # one\nyyy = 2 # two\n"), 0);
+    }
+
+    #[test]
+    fn test_unaligned_spacing_is_reported_without_allow_for_alignment() {
+        let mut config = Config::default();
+        config.layout.extra_spacing.allow_for_alignment = false;
+        let source = b"name    = 1\nsurname = 2\n";
+        let count = check_with_config(source, &config).iter().filter(|d| d.rule() == "Layout/ExtraSpacing").count();
+        assert_eq!(count, 1);
+    }
+}
@@ -9,6 +9,8 @@ use crate::utility::alignment::check_alignment;
 use reukocyte_macros::check;
 use ruby_prism::*;
 
+const MSG: &str = "Inconsistent indentation detected.";
+
 /// Get the config for this rule
 #[inline]
 fn config<'a>(checker: &'a Checker<'_>) -> &'a crate::config::layout::indentation_consistency::IndentationConsistency {
@@ -16,6 +18,24 @@ fn config<'a>(checker: &'a Checker<'_>) -> &'a crate::config::layout::indentatio
 }
 
 /// Layout/IndentationConsistency rule.
+///
+/// Checks that the statements of a body are indented consistently. With
+/// `indented_internal_methods`, the methods after a bare `private` or
+/// `protected` are expected one level deeper than the ones before it.
+///
+/// ```ruby
+/// # bad
+/// def foo
+///   bar
+///     baz
+/// end
+///
+/// # good
+/// def foo
+///   bar
+///   baz
+/// end
+/// ```
 pub struct IndentationConsistency;
 impl Rule for IndentationConsistency {
     const ID: RuleId = RuleId::Layout(LayoutRule::IndentationConsistency);
@@ -44,26 +64,42 @@ fn check_normal_style(node: &StatementsNode, checker: &mut Checker) {
             }
         })
         .collect::<Vec<_>>();
-    check_alignment(targets, base_column_for_normal_style(node, checker), IndentationConsistency::ID, checker);
+    let base_column = base_column_for_normal_style(node, checker);
+    let severity = config(checker).base.severity;
+    check_alignment(targets, base_column, IndentationConsistency::ID, MSG, severity, checker);
 }
 
 /// Check indentation consistency in indented internal methods style.
+///
+/// Bare access modifiers (`private`, `protected`, `public`) split the body into groups.
+/// Members after a modifier are expected one indentation level deeper than the modifier,
+/// while members before the first modifier only need to be consistent with each other.
+/// Modifiers with arguments (`private :foo`) do not start a new group.
 fn check_indented_internal_methods_style(node: &StatementsNode, checker: &mut Checker) {
-    let mut children_to_check = Vec::new();
+    let indentation_width = checker.config().layout.indentation_width.width;
+    let mut groups: Vec<(Option<usize>, Vec<Location>)> = vec![(None, Vec::new())];
     for statement in node.body().iter() {
         let Some(node_id) = checker.semantic().node_id_for(&statement) else {
             continue;
         };
         if is_bare_access_modifier(&node_id, checker) {
-            children_to_check.push(Vec::new());
-        } else {
-            if let Some(last_group) = children_to_check.last_mut() {
-                last_group.push(statement.location());
-            }
+            let modifier_start = statement.location().start_offset();
+            let base_column = if checker.line_index().is_first_on_line(modifier_start) {
+                checker
+                    .line_index()
+                    .column_number(modifier_start)
+                    .checked_add_signed(indentation_width as isize)
+            } else {
+                None
+            };
+            groups.push((base_column, Vec::new()));
+        } else if let Some((_, last_group)) = groups.last_mut() {
+            last_group.push(statement.location());
         }
     }
-    for group in children_to_check {
-        check_alignment(group, None, IndentationConsistency::ID, checker);
+    let severity = config(checker).base.severity;
+    for (base_column, group) in groups {
+        check_alignment(group, base_column, IndentationConsistency::ID, MSG, severity, checker);
     }
 }
 
@@ -87,3 +123,96 @@ fn base_column_for_normal_style(node: &StatementsNode, checker: &mut Checker) ->
     }
     None
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::Config;
+    use crate::corrector::Corrector;
+    use crate::{check, check_with_config};
+
+    fn indented_internal_methods_config() -> Config {
+        let mut config = Config::default();
+        config.layout.indentation_consistency.enforced_style = EnforcedStyle::IndentedInternalMethods;
+        config
+    }
+
+    fn consistency_diagnostics(diagnostics: Vec<crate::Diagnostic>) -> Vec<crate::Diagnostic> {
+        diagnostics.into_iter().filter(|d| d.rule_id == IndentationConsistency::ID).collect()
+    }
+
+    fn fixed(source: &[u8], diagnostics: &[crate::Diagnostic]) -> String {
+        let mut corrector = Corrector::new();
+        for fix in diagnostics.iter().filter_map(|d| d.fix.as_ref()) {
+            corrector.merge(fix).unwrap();
+        }
+        String::from_utf8(corrector.apply(source)).unwrap()
+    }
+
+    #[test]
+    fn test_normal_style_inconsistent_member() {
+        let source = b"class Foo\n  def a; end\n\n   def b; end\nend\n";
+        let diagnostics = consistency_diagnostics(check(source));
+        assert_eq!(diagnostics.len(), 1);
+        assert_eq!(diagnostics[0].message, MSG);
+        assert_eq!(diagnostics[0].line_start, 4);
+        assert_eq!(fixed(source, &diagnostics), "class Foo\n  def a; end\n\n  def b; end\nend\n");
+    }
+
+    #[test]
+    fn test_normal_style_flags_indented_internal_methods() {
+        let source = b"class Foo\n  def a; end\n\n  private\n\n    def b; end\nend\n";
+        let diagnostics = consistency_diagnostics(check(source));
+        assert_eq!(diagnostics.len(), 1);
+        assert_eq!(fixed(source, &diagnostics), "class Foo\n  def a; end\n\n  private\n\n  def b; end\nend\n");
+    }
+
+    #[test]
+    fn test_indented_internal_methods_accepts_extra_indent() {
+        let source = b"class Foo\n  def a; end\n\n  private\n\n    def b; end\n\n    def c; end\nend\n";
+        let diagnostics = consistency_diagnostics(check_with_config(source, &indented_internal_methods_config()));
+        assert!(diagnostics.is_empty());
+    }
+
+    #[test]
+    fn test_indented_internal_methods_indents_after_modifier() {
+        let source = b"class Foo\n  def a; end\n\n  protected\n\n  def b; end\n\n  def c; end\nend\n";
+        let diagnostics = consistency_diagnostics(check_with_config(source, &indented_internal_methods_config()));
+        assert_eq!(diagnostics.len(), 2);
+        assert_eq!(
+            fixed(source, &diagnostics),
+            "class Foo\n  def a; end\n\n  protected\n\n    def b; end\n\n    def c; end\nend\n"
+        );
+    }
+
+    #[test]
+    fn test_indented_internal_methods_checks_members_before_modifier() {
+        let source = b"class Foo\n  def a; end\n   def b; end\n\n  private\n\n    def c; end\nend\n";
+        let diagnostics = consistency_diagnostics(check_with_config(source, &indented_internal_methods_config()));
+        assert_eq!(diagnostics.len(), 1);
+        assert_eq!(diagnostics[0].line_start, 3);
+        assert_eq!(
+            fixed(source, &diagnostics),
+            "class Foo\n  def a; end\n  def b; end\n\n  private\n\n    def c; end\nend\n"
+        );
+    }
+
+    #[test]
+    fn test_indented_internal_methods_ignores_modifier_with_arguments() {
+        let source = b"class Foo\n  def a; end\n  private :a\n  def b; end\nend\n";
+        let diagnostics = consistency_diagnostics(check_with_config(source, &indented_internal_methods_config()));
+        assert!(diagnostics.is_empty());
+    }
+
+    #[test]
+    fn test_indented_internal_methods_nested_class_resets_baseline() {
+        let source = b"class Foo\n  private\n\n    class Bar\n      def a; end\n\n      private\n\n      def b; end\n    end\nend\n";
+        let diagnostics = consistency_diagnostics(check_with_config(source, &indented_internal_methods_config()));
+        assert_eq!(diagnostics.len(), 1);
+        assert_eq!(diagnostics[0].line_start, 9);
+        assert_eq!(
+            fixed(source, &diagnostics),
+            "class Foo\n  private\n\n    class Bar\n      def a; end\n\n      private\n\n        def b; end\n    end\nend\n"
+        );
+    }
+}
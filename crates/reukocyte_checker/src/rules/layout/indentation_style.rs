@@ -31,7 +31,7 @@ pub fn check(checker: &mut Checker) {
         return;
     }
     // Check cop-specific include/exclude
-    if !checker.should_run_cop(&config.base.include, &config.base.exclude) {
+    if !checker.should_run_cop(&config.base) {
         return;
     }
     let severity = config.base.severity;
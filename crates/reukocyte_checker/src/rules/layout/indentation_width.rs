@@ -19,6 +19,26 @@ fn config<'a>(checker: &'a Checker<'_>) -> &'a crate::config::layout::indentatio
 }
 
 /// Layout/IndentationWidth rule.
+///
+/// Checks the indentation of the first statement of a body relative to the
+/// construct that opens it. Besides `def`/`class`/`module` and block bodies,
+/// this covers `when`/`in` bodies relative to their keyword, `rescue`/`ensure`
+/// and `else` bodies, and each `if`/`elsif`/`else` branch. Bodies on the
+/// keyword's line (`when 1 then foo`) and empty branches are not checked.
+///
+/// ```ruby
+/// # bad
+/// case x
+/// when 1
+///     foo
+/// end
+///
+/// # good
+/// case x
+/// when 1
+///   foo
+/// end
+/// ```
 pub struct IndentationWidth;
 impl Rule for IndentationWidth {
     const ID: RuleId = RuleId::Layout(LayoutRule::IndentationWidth);
@@ -375,6 +395,57 @@ mod tests {
         check(source.as_bytes())
     }
 
+    fn indentation_diagnostics(source: &str) -> Vec<crate::Diagnostic> {
+        check_source(source).into_iter().filter(|d| d.rule_id == IndentationWidth::ID).collect()
+    }
+
+    fn fixed(source: &str) -> String {
+        let diagnostics = indentation_diagnostics(source);
+        String::from_utf8(crate::apply_fixes(None, source.as_bytes(), &diagnostics, false).0).unwrap()
+    }
+
+    #[test]
+    fn test_when_body() {
+        let source = "case x\nwhen 1\n    foo\nwhen 2\nbar\nelse\n      baz\nend\n";
+        let diagnostics = indentation_diagnostics(source);
+        let lines: Vec<_> = diagnostics.iter().map(|d| (d.line_start, d.message.as_str())).collect();
+        assert_eq!(
+            lines,
+            [
+                (3, "Use 2 (not 4) spaces for normal indentation."),
+                (5, "Use 2 (not 0) spaces for normal indentation."),
+                (7, "Use 2 (not 6) spaces for normal indentation.")
+            ]
+        );
+        assert_eq!(fixed(source), "case x\nwhen 1\n  foo\nwhen 2\n  bar\nelse\n  baz\nend\n");
+        // One-line `when`, empty branches and nested structures.
+        assert!(indentation_diagnostics("case x\nwhen 1 then foo\nwhen 2\nwhen 3\n  if y\n    bar\n  end\nend\n").is_empty());
+    }
+
+    #[test]
+    fn test_begin_rescue_bodies() {
+        let source = "begin\n    foo\nrescue StandardError => e\n bar\nelse\n  baz\nensure\n    qux\nend\n";
+        let lines: Vec<_> = indentation_diagnostics(source).iter().map(|d| d.line_start).collect();
+        assert_eq!(lines, [2, 4, 8]);
+        assert_eq!(
+            fixed(source),
+            "begin\n  foo\nrescue StandardError => e\n  bar\nelse\n  baz\nensure\n  qux\nend\n"
+        );
+        let source = "def foo\n  bar\nrescue\n    baz\nend\n";
+        assert_eq!(fixed(source), "def foo\n  bar\nrescue\n  baz\nend\n");
+        assert_eq!(fixed("foo do\n  bar\nrescue\nbaz\nend\n"), "foo do\n  bar\nrescue\n  baz\nend\n");
+        assert!(indentation_diagnostics("begin\n  foo\nrescue\nend\nfoo rescue bar\n").is_empty());
+    }
+
+    #[test]
+    fn test_if_branch_bodies() {
+        let source = "if a\n  foo\nelsif b\n    bar\nelse\nbaz\nend\n";
+        let lines: Vec<_> = indentation_diagnostics(source).iter().map(|d| d.line_start).collect();
+        assert_eq!(lines, [4, 6]);
+        assert_eq!(fixed(source), "if a\n  foo\nelsif b\n  bar\nelse\n  baz\nend\n");
+        assert!(indentation_diagnostics("if a\nelsif b\nelse\nend\nx = a ? b : c\n").is_empty());
+    }
+
     #[test]
     fn test_correct_indentation_in_def() {
         let source = r#"
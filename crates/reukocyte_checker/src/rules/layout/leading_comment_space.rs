@@ -0,0 +1,131 @@
+//! Layout/LeadingCommentSpace
+//!
+//! Checks that the `#` of a comment is followed by a space. Comments are taken
+//! from the parse result, so `#{}` interpolation is never mistaken for one, and
+//! `=begin`/`=end` documents are not checked.
+//!
+//! # Examples
+//!
+//! ```ruby
+//! # bad
+//! #Some comment
+//!
+//! # good
+//! # Some comment
+//! ```
+
+use crate::Checker;
+use crate::Edit;
+use crate::Fix;
+use crate::config::layout::leading_comment_space::LeadingCommentSpace;
+use crate::rule::{LayoutRule, RuleId};
+
+/// Rule identifier for Layout/LeadingCommentSpace.
+pub const RULE_ID: RuleId = RuleId::Layout(LayoutRule::LeadingCommentSpace);
+
+const MSG: &str = "Missing space after `#`.";
+
+/// Check for comments without a space after `#`.
+pub fn check(checker: &mut Checker) {
+    let config = &checker.config().layout.leading_comment_space;
+    if !config.base.enabled {
+        return;
+    }
+    // Check cop-specific include/exclude
+    if !checker.should_run_cop(&config.base) {
+        return;
+    }
+    let severity = config.base.severity;
+
+    let mut offenses = Vec::new();
+    for &(start, end) in checker.comments() {
+        let text = &checker.source()[start..end];
+        if let Some(hashes) = missing_space(text, start == 0, config) {
+            offenses.push((start, end, start + hashes));
+        }
+    }
+    for (start, end, insert_at) in offenses {
+        let fix = Fix::safe(vec![Edit::insertion(insert_at, " ".to_string())]);
+        checker.report(RULE_ID, MSG.to_string(), severity, start, end, Some(fix));
+    }
+}
+
+/// Check if a comment lacks the space after its leading `#`s.
+/// Returns the number of leading `#`s.
+fn missing_space(text: &[u8], is_first_line: bool, config: &LeadingCommentSpace) -> Option<usize> {
+    // `=begin`/`=end` documents.
+    if !text.starts_with(b"#") {
+        return None;
+    }
+    let hashes = text.iter().take_while(|&&b| b == b'#').count();
+    let allowed = match text.get(hashes) {
+        None | Some(b' ' | b'\t' | b'\r') => true,
+        // rdoc `#++`/`#--` toggles.
+        Some(b'+') => text.starts_with(b"#++"),
+        Some(b'-') => text.starts_with(b"#--"),
+        Some(b'!') => hashes == 1 && is_first_line && config.allow_shebang,
+        Some(b':') => hashes == 1 && config.allow_rbs_inline_annotation,
+        Some(b'=') => config.allow_equal_sign,
+        Some(_) => false,
+    };
+    if allowed { None } else { Some(hashes) }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::Config;
+    use crate::corrector::Corrector;
+    use crate::{apply_fixes, check, check_with_config};
+
+    fn offenses(source: &[u8]) -> Vec<crate::Diagnostic> {
+        check(source).into_iter().filter(|d| d.rule_id == RULE_ID).collect()
+    }
+
+    #[test]
+    fn test_missing_space() {
+        let source = b"#comment\nfoo #bar\n##baz\n";
+        let diagnostics = offenses(source);
+        assert_eq!(diagnostics.len(), 3);
+        assert_eq!(diagnostics[0].message, MSG);
+        assert_eq!(diagnostics[1].column_start, 5);
+        let (fixed, _) = apply_fixes(None, source, &diagnostics, false);
+        assert_eq!(fixed, b"# comment\nfoo # bar\n## baz\n");
+    }
+
+    #[test]
+    fn test_comments_with_space() {
+        assert!(offenses(b"# comment\n#\n##\n## doc\n#\tTab\nfoo # bar\n").is_empty());
+        assert!(offenses(b"#++\n#--\n").is_empty());
+    }
+
+    #[test]
+    fn test_interpolation_and_documents_are_not_comments() {
+        assert!(offenses(b"x = \"#{foo}\"\ny = /#{bar}/\nz = %w[#a]\n").is_empty());
+        assert!(offenses(b"=begin\n#not checked\n=end\nfoo\n").is_empty());
+    }
+
+    #[test]
+    fn test_default_exemptions() {
+        assert!(offenses(b"#!/usr/bin/env ruby\nfoo #=> 1\n#=begin\n").is_empty());
+        // Only a shebang on the first line is allowed.
+        assert_eq!(offenses(b"foo\n#!not a shebang\n").len(), 1);
+        assert_eq!(offenses(b"def foo #: () -> void\nend\n").len(), 1);
+    }
+
+    #[test]
+    fn test_configured_exemptions() {
+        let mut config = Config::default();
+        config.layout.leading_comment_space.allow_shebang = false;
+        config.layout.leading_comment_space.allow_rbs_inline_annotation = true;
+        config.layout.leading_comment_space.allow_equal_sign = false;
+        let source = b"#!/usr/bin/env ruby\ndef foo #: () -> void\nend\nfoo #=> 1\n";
+        let diagnostics: Vec<_> = check_with_config(source, &config).into_iter().filter(|d| d.rule_id == RULE_ID).collect();
+        assert_eq!(diagnostics.iter().map(|d| d.line_start).collect::<Vec<_>>(), vec![1, 4]);
+        let mut corrector = Corrector::new();
+        for diagnostic in &diagnostics {
+            corrector.merge(diagnostic.fix.as_ref().unwrap()).unwrap();
+        }
+        assert_eq!(corrector.apply(source), b"# !/usr/bin/env ruby\ndef foo #: () -> void\nend\nfoo # => 1\n");
+    }
+}
@@ -30,14 +30,14 @@ pub fn check(checker: &mut Checker) {
         return;
     }
     // Check cop-specific include/exclude
-    if !checker.should_run_cop(&config.base.include, &config.base.exclude) {
+    if !checker.should_run_cop(&config.base) {
         return;
     }
     let severity = config.base.severity;
 
     if let Some((end, message)) = analyze(checker.source()) {
         let fix = Fix::safe(vec![Edit::deletion(0, end)]);
-        checker.report(RULE_ID, message, severity, 0, end, Some(fix));
+        checker.report_with_fix_description(RULE_ID, message, severity, 0, end, fix, "remove leading blank lines");
     }
 }
 
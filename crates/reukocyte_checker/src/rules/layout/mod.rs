@@ -1,10 +1,30 @@
 pub mod begin_end_alignment;
+pub mod block_alignment;
+pub mod block_end_newline;
+pub mod closing_heredoc_indentation;
+pub mod closing_parenthesis_indentation;
 pub mod def_end_alignment;
+pub mod empty_line_after_magic_comment;
+pub mod empty_line_between_defs;
 pub mod empty_lines;
+pub mod empty_lines_around_method_body;
 pub mod end_alignment;
+pub mod end_of_line;
+pub mod extra_spacing;
 pub mod indentation_consistency;
 pub mod indentation_style;
 pub mod indentation_width;
+pub mod leading_comment_space;
 pub mod leading_empty_lines;
+pub mod multiline_method_definition_brace_layout;
+pub mod redundant_line_break;
+pub mod space_after_colon;
+pub mod space_after_comma;
+pub mod space_after_method_name;
+pub mod space_before_block_braces;
+pub mod space_before_colon;
+pub mod space_before_comma;
+pub mod space_inside_array_literal_brackets;
+pub mod space_inside_string_interpolation;
 pub mod trailing_empty_lines;
 pub mod trailing_whitespace;
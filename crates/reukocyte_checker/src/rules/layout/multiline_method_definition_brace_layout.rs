@@ -0,0 +1,155 @@
+use crate::checker::Checker;
+use crate::rule::{Check, LayoutRule, Rule, RuleId};
+use crate::utility::multiline_brace_layout::brace_layout_offense;
+use reukocyte_macros::check;
+use ruby_prism::*;
+
+/// Get the config for this rule
+#[inline]
+fn config<'a>(checker: &'a Checker<'_>) -> &'a crate::config::layout::multiline_method_definition_brace_layout::MultilineMethodDefinitionBraceLayout {
+    &checker.config().layout.multiline_method_definition_brace_layout
+}
+
+/// Layout/MultilineMethodDefinitionBraceLayout rule.
+///
+/// Checks that the closing parenthesis of a multiline parameter list is on the
+/// same line as the last parameter (`same_line`), on the line after it
+/// (`new_line`), or either one depending on whether the opening parenthesis
+/// is on the same line as the first parameter (`symmetrical`, default).
+///
+/// ```ruby
+/// # bad (EnforcedStyle: symmetrical)
+/// def foo(a,
+///   b
+/// )
+/// end
+///
+/// # good (EnforcedStyle: symmetrical)
+/// def foo(a,
+///   b)
+/// end
+/// ```
+pub struct MultilineMethodDefinitionBraceLayout;
+impl Rule for MultilineMethodDefinitionBraceLayout {
+    const ID: RuleId = RuleId::Layout(LayoutRule::MultilineMethodDefinitionBraceLayout);
+}
+#[check(DefNode)]
+impl Check<DefNode<'_>> for MultilineMethodDefinitionBraceLayout {
+    fn check(node: &DefNode, checker: &mut Checker) {
+        let (Some(opening), Some(closing), Some(parameters)) = (node.lparen_loc(), node.rparen_loc(), node.parameters()) else {
+            return;
+        };
+        let elements = parameters.location().start_offset()..parameters.location().end_offset();
+        let config = config(checker);
+        let Some((message, fix)) = brace_layout_offense(
+            &opening,
+            &closing,
+            elements,
+            ("method definition brace", "parameter"),
+            config.enforced_style,
+            checker,
+        ) else {
+            return;
+        };
+        let severity = config.base.severity;
+        checker.report(
+            MultilineMethodDefinitionBraceLayout::ID,
+            message,
+            severity,
+            closing.start_offset(),
+            closing.end_offset(),
+            fix,
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::check_with_config;
+    use crate::config::Config;
+    use crate::config::layout::multiline_method_definition_brace_layout::EnforcedStyle;
+    use crate::corrector::Corrector;
+
+    fn brace_layout_diagnostics(source: &[u8], config: &Config) -> Vec<crate::Diagnostic> {
+        check_with_config(source, config)
+            .into_iter()
+            .filter(|d| d.rule_id == MultilineMethodDefinitionBraceLayout::ID)
+            .collect()
+    }
+
+    fn style_config(style: EnforcedStyle) -> Config {
+        let mut config = Config::default();
+        config.layout.multiline_method_definition_brace_layout.enforced_style = style;
+        config
+    }
+
+    fn fixed(source: &[u8], config: &Config) -> String {
+        let mut corrector = Corrector::new();
+        for diagnostic in brace_layout_diagnostics(source, config) {
+            corrector.merge(diagnostic.fix.as_ref().unwrap()).unwrap();
+        }
+        String::from_utf8(corrector.apply(source)).unwrap()
+    }
+
+    #[test]
+    fn test_symmetrical_style() {
+        let config = Config::default();
+        let source = b"def foo(a,\n        b\n)\nend\n";
+        let diagnostics = brace_layout_diagnostics(source, &config);
+        assert_eq!(diagnostics.len(), 1);
+        assert_eq!(
+            diagnostics[0].message,
+            "Closing method definition brace must be on the same line as the last parameter when opening brace is on the same line as the first parameter."
+        );
+        assert_eq!(fixed(source, &config), "def foo(a,\n        b)\nend\n");
+
+        let source = b"def foo(\n  a,\n  b)\nend\n";
+        let diagnostics = brace_layout_diagnostics(source, &config);
+        assert_eq!(diagnostics.len(), 1);
+        assert_eq!(fixed(source, &config), "def foo(\n  a,\n  b\n)\nend\n");
+
+        assert!(brace_layout_diagnostics(b"def foo(a,\n        b)\nend\n", &config).is_empty());
+        assert!(brace_layout_diagnostics(b"def foo(\n  a,\n  b\n)\nend\n", &config).is_empty());
+    }
+
+    #[test]
+    fn test_same_line_style() {
+        let config = style_config(EnforcedStyle::SameLine);
+        let source = b"  def foo(\n    a,\n    b\n  )\n  end\n";
+        let diagnostics = brace_layout_diagnostics(source, &config);
+        assert_eq!(diagnostics.len(), 1);
+        assert_eq!(
+            diagnostics[0].message,
+            "Closing method definition brace must be on the same line as the last parameter."
+        );
+        assert_eq!(fixed(source, &config), "  def foo(\n    a,\n    b)\n  end\n");
+        assert!(brace_layout_diagnostics(b"def foo(a,\n        b)\nend\n", &config).is_empty());
+    }
+
+    #[test]
+    fn test_new_line_style() {
+        let config = style_config(EnforcedStyle::NewLine);
+        let source = b"  def foo(a,\n          b)\n  end\n";
+        let diagnostics = brace_layout_diagnostics(source, &config);
+        assert_eq!(diagnostics.len(), 1);
+        assert_eq!(
+            diagnostics[0].message,
+            "Closing method definition brace must be on the line after the last parameter."
+        );
+        assert_eq!(fixed(source, &config), "  def foo(a,\n          b\n  )\n  end\n");
+        assert!(brace_layout_diagnostics(b"def foo(a,\n        b\n)\nend\n", &config).is_empty());
+    }
+
+    #[test]
+    fn test_ignored_definitions() {
+        let config = style_config(EnforcedStyle::SameLine);
+        assert!(brace_layout_diagnostics(b"def foo(a, b)\nend\n", &config).is_empty());
+        assert!(brace_layout_diagnostics(b"def foo a,\n  b\nend\n", &config).is_empty());
+        assert!(brace_layout_diagnostics(b"def foo(\n)\nend\n", &config).is_empty());
+        // A comment after the last parameter is reported but not fixed.
+        let diagnostics = brace_layout_diagnostics(b"def foo(a,\n        b # comment\n)\nend\n", &config);
+        assert_eq!(diagnostics.len(), 1);
+        assert!(diagnostics[0].fix.is_none());
+    }
+}
@@ -0,0 +1,238 @@
+use crate::checker::Checker;
+use crate::diagnostic::{Edit, Fix};
+use crate::rule::{Check, LayoutRule, Rule, RuleId};
+use reukocyte_macros::check;
+use ruby_prism::*;
+
+const MSG: &str = "Redundant line break detected.";
+
+/// Get the config for this rule
+#[inline]
+fn config<'a>(checker: &'a Checker<'_>) -> &'a crate::config::layout::redundant_line_break::RedundantLineBreak {
+    &checker.config().layout.redundant_line_break
+}
+
+/// Layout/RedundantLineBreak rule.
+///
+/// Checks for method calls, arrays and hashes split across lines although the
+/// whole expression would fit on one line within `Layout/LineLength` `Max`.
+/// Only the outermost expression is checked, and expressions containing
+/// comments, heredocs, multi-line strings or statements (blocks, `if`, ...)
+/// are never joined. The fix is unsafe: rebuilding the expression from its
+/// source can still change how an unusual line break is parsed.
+///
+/// ```ruby
+/// # bad
+/// foo(a,
+///     b)
+///
+/// # good
+/// foo(a, b)
+/// ```
+pub struct RedundantLineBreak;
+impl Rule for RedundantLineBreak {
+    const ID: RuleId = RuleId::Layout(LayoutRule::RedundantLineBreak);
+}
+#[check(CallNode)]
+impl Check<CallNode<'_>> for RedundantLineBreak {
+    fn check(node: &CallNode, checker: &mut Checker) {
+        check_expression(&node.as_node(), checker);
+    }
+}
+#[check(ArrayNode)]
+impl Check<ArrayNode<'_>> for RedundantLineBreak {
+    fn check(node: &ArrayNode, checker: &mut Checker) {
+        check_expression(&node.as_node(), checker);
+    }
+}
+#[check(HashNode)]
+impl Check<HashNode<'_>> for RedundantLineBreak {
+    fn check(node: &HashNode, checker: &mut Checker) {
+        check_expression(&node.as_node(), checker);
+    }
+}
+
+/// Report the expression if it spans several lines but would fit on one.
+fn check_expression(node: &Node, checker: &mut Checker) {
+    let location = node.location();
+    let (start, end) = (location.start_offset(), location.end_offset());
+    if checker.line_index().are_on_same_line(start, end) || is_part_of_larger_expression(checker) || !is_joinable(node, checker) {
+        return;
+    }
+    let source = checker.source();
+    let single_line = to_single_line(&source[start..end]);
+    let line_start = checker.line_index().line_start_offset(start);
+    let line_end = checker.line_index().line_end_offset(end);
+    let width = char_count(&source[line_start..start]) + char_count(single_line.as_bytes()) + char_count(&source[end..line_end]);
+    if width > checker.config().layout.line_length.max {
+        return;
+    }
+    let fix = Fix::r#unsafe(vec![Edit::replacement(start, end, single_line)]);
+    let severity = config(checker).base.severity;
+    checker.report(RedundantLineBreak::ID, MSG.to_string(), severity, start, end, Some(fix));
+}
+
+/// Check if an enclosing call, array or hash (within the same statement) is checked instead.
+fn is_part_of_larger_expression(checker: &Checker) -> bool {
+    for ancestor in checker.semantic().ancestors() {
+        match ancestor {
+            Node::StatementsNode { .. } => return false,
+            Node::CallNode { .. } | Node::ArrayNode { .. } | Node::HashNode { .. } => return true,
+            _ => {}
+        }
+    }
+    false
+}
+
+/// Check if joining the lines of the expression keeps its meaning.
+fn is_joinable(node: &Node, checker: &Checker) -> bool {
+    if node
+        .as_call_node()
+        .is_some_and(|call| call.block().is_some_and(|block| block.as_block_node().is_some()))
+    {
+        return false;
+    }
+    let location = node.location();
+    let source = checker.source();
+    for (offset, &byte) in source.iter().enumerate().take(location.end_offset()).skip(location.start_offset()) {
+        match byte {
+            // A line break inside a string literal or heredoc body.
+            b'\n' if checker.is_in_literal(offset) => return false,
+            // A comment.
+            b'#' if !checker.is_in_literal(offset) => return false,
+            _ => {}
+        }
+    }
+    let mut finder = UnjoinableNodeFinder { found: false };
+    finder.visit(node);
+    !finder.found
+}
+
+/// Finds nodes whose line breaks are significant: statement lists and heredocs.
+struct UnjoinableNodeFinder {
+    found: bool,
+}
+impl<'pr> Visit<'pr> for UnjoinableNodeFinder {
+    fn visit_statements_node(&mut self, _node: &StatementsNode<'pr>) {
+        self.found = true;
+    }
+    fn visit_string_node(&mut self, node: &StringNode<'pr>) {
+        self.found |= node.opening_loc().is_some_and(|loc| loc.as_slice().starts_with(b"<<"));
+    }
+    fn visit_interpolated_string_node(&mut self, node: &InterpolatedStringNode<'pr>) {
+        if node.opening_loc().is_some_and(|loc| loc.as_slice().starts_with(b"<<")) {
+            self.found = true;
+        } else {
+            visit_interpolated_string_node(self, node);
+        }
+    }
+}
+
+/// Join the lines of an expression, collapsing each line break (with its
+/// surrounding whitespace and any `\` continuation) to a single space.
+///
+/// No space is left after an opening `(`, `[` or `.`, nor before a closing
+/// `)`, `]` or a leading `.`/`&.` of a chained call.
+fn to_single_line(source: &[u8]) -> String {
+    let mut result: Vec<u8> = Vec::with_capacity(source.len());
+    let mut offset = 0;
+    while offset < source.len() {
+        if source[offset] != b'\n' {
+            result.push(source[offset]);
+            offset += 1;
+            continue;
+        }
+        while result.last().is_some_and(|&b| matches!(b, b' ' | b'\t' | b'\r' | b'\\')) {
+            result.pop();
+        }
+        while offset < source.len() && matches!(source[offset], b' ' | b'\t' | b'\r' | b'\n') {
+            offset += 1;
+        }
+        let rest = &source[offset..];
+        let no_space_after = result.last().is_some_and(|&b| matches!(b, b'(' | b'[' | b'.'));
+        let no_space_before = rest.starts_with(b")") || rest.starts_with(b"]") || rest.starts_with(b".") || rest.starts_with(b"&.");
+        if !no_space_after && !no_space_before {
+            result.push(b' ');
+        }
+    }
+    String::from_utf8_lossy(&result).into_owned()
+}
+
+/// Count the characters in a byte slice.
+fn char_count(bytes: &[u8]) -> usize {
+    String::from_utf8_lossy(bytes).chars().count()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::check_with_config;
+    use crate::config::Config;
+    use crate::corrector::Corrector;
+
+    fn enabled_config() -> Config {
+        let mut config = Config::default();
+        config.layout.redundant_line_break.base.enabled = true;
+        config
+    }
+
+    fn redundant_line_break_diagnostics(source: &[u8], config: &Config) -> Vec<crate::Diagnostic> {
+        check_with_config(source, config)
+            .into_iter()
+            .filter(|d| d.rule_id == RedundantLineBreak::ID)
+            .collect()
+    }
+
+    fn fixed(source: &[u8]) -> String {
+        let mut corrector = Corrector::new();
+        for diagnostic in redundant_line_break_diagnostics(source, &enabled_config()) {
+            corrector.merge(diagnostic.fix.as_ref().unwrap()).unwrap();
+        }
+        String::from_utf8(corrector.apply(source)).unwrap()
+    }
+
+    #[test]
+    fn test_disabled_by_default() {
+        assert!(redundant_line_break_diagnostics(b"foo(a,\n    b)\n", &Config::default()).is_empty());
+    }
+
+    #[test]
+    fn test_short_call_split_across_lines() {
+        let source = b"x = foo(a,\n        b)\n";
+        let diagnostics = redundant_line_break_diagnostics(source, &enabled_config());
+        assert_eq!(diagnostics.len(), 1);
+        assert_eq!(diagnostics[0].message, MSG);
+        assert_eq!(diagnostics[0].column_start, 5);
+        assert_eq!(diagnostics[0].fix.as_ref().unwrap().applicability, crate::Applicability::Unsafe);
+        assert_eq!(fixed(source), "x = foo(a, b)\n");
+    }
+
+    #[test]
+    fn test_joins_parentheses_chains_and_collections() {
+        assert_eq!(fixed(b"foo(\n  a,\n  b\n)\n"), "foo(a, b)\n");
+        assert_eq!(fixed(b"foo\n  .bar\n  &.baz\n"), "foo.bar&.baz\n");
+        assert_eq!(fixed(b"x = [\n  1,\n  2,\n]\n"), "x = [1, 2,]\n");
+        assert_eq!(fixed(b"x = { a: 1,\n      b: foo(2,\n             3) }\n"), "x = { a: 1, b: foo(2, 3) }\n");
+        assert_eq!(fixed(b"foo a, \\\n    b\n"), "foo a, b\n");
+    }
+
+    #[test]
+    fn test_too_long_for_one_line() {
+        let mut config = enabled_config();
+        config.layout.line_length.max = 14;
+        assert!(redundant_line_break_diagnostics(b"foo(aaaa,\n    bbbb)\n", &config).is_empty());
+        config.layout.line_length.max = 15;
+        assert_eq!(redundant_line_break_diagnostics(b"foo(aaaa,\n    bbbb)\n", &config).len(), 1);
+    }
+
+    #[test]
+    fn test_unjoinable_expressions() {
+        let config = enabled_config();
+        assert!(redundant_line_break_diagnostics(b"foo(a, # comment\n    b)\n", &config).is_empty());
+        assert!(redundant_line_break_diagnostics(b"foo(<<~EOS)\n  text\nEOS\n", &config).is_empty());
+        assert!(redundant_line_break_diagnostics(b"foo(\"a\n  b\")\n", &config).is_empty());
+        assert!(redundant_line_break_diagnostics(b"foo.each do |x|\n  bar(x)\nend\n", &config).is_empty());
+        assert!(redundant_line_break_diagnostics(b"foo(if a\n      b\n    end)\n", &config).is_empty());
+        assert!(redundant_line_break_diagnostics(b"foo(a, b)\n", &config).is_empty());
+    }
+}
@@ -0,0 +1,122 @@
+use crate::checker::Checker;
+use crate::diagnostic::{Edit, Fix};
+use crate::rule::{Check, LayoutRule, Rule, RuleId};
+use reukocyte_macros::check;
+use ruby_prism::*;
+
+const MSG: &str = "Space missing after colon.";
+
+/// Get the config for this rule
+#[inline]
+fn config<'a>(checker: &'a Checker<'_>) -> &'a crate::config::layout::space_after_colon::SpaceAfterColon {
+    &checker.config().layout.space_after_colon
+}
+
+/// Layout/SpaceAfterColon rule.
+///
+/// Checks for a missing space after the colon of `key: value` pairs and optional
+/// keyword parameters, the only colons that introduce a value. Extra spaces
+/// are left to Layout/ExtraSpacing, which allows them when they align values.
+///
+/// ```ruby
+/// # bad
+/// { a:1 }
+/// def foo(bar:1); end
+///
+/// # good
+/// { a: 1 }
+/// def foo(bar: 1); end
+/// ```
+pub struct SpaceAfterColon;
+impl Rule for SpaceAfterColon {
+    const ID: RuleId = RuleId::Layout(LayoutRule::SpaceAfterColon);
+}
+#[check(AssocNode)]
+impl Check<AssocNode<'_>> for SpaceAfterColon {
+    fn check(node: &AssocNode, checker: &mut Checker) {
+        // Only the `key: value` form; `key => value` has an operator, `{ a: }` an implicit value.
+        if node.operator_loc().is_some() || node.value().as_implicit_node().is_some() {
+            return;
+        }
+        let Some(colon) = node.key().as_symbol_node().and_then(|key| key.closing_loc()) else {
+            return;
+        };
+        if colon.as_slice().ends_with(b":") {
+            check_colon(colon.end_offset(), checker);
+        }
+    }
+}
+#[check(OptionalKeywordParameterNode)]
+impl Check<OptionalKeywordParameterNode<'_>> for SpaceAfterColon {
+    fn check(node: &OptionalKeywordParameterNode, checker: &mut Checker) {
+        check_colon(node.name_loc().end_offset(), checker);
+    }
+}
+
+/// Report a colon (ending at `colon_end`) that is directly followed by its value.
+///
+/// Only label colons reach here: ternary `:`, symbol literals and `::` scope
+/// operators are never hash keys or keyword parameters, which the guard below
+/// makes sure of for the source bytes too.
+fn check_colon(colon_end: usize, checker: &mut Checker) {
+    let source = checker.source();
+    let is_label_colon = colon_end >= 2 && source[colon_end - 1] == b':' && source[colon_end - 2] != b':';
+    if !is_label_colon || source.get(colon_end).is_none_or(|b| b.is_ascii_whitespace()) {
+        return;
+    }
+    let fix = Fix::safe(vec![Edit::insertion(colon_end, " ".to_string())]);
+    let severity = config(checker).base.severity;
+    checker.report(SpaceAfterColon::ID, MSG.to_string(), severity, colon_end - 1, colon_end, Some(fix));
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::check;
+
+    fn colon_diagnostics(source: &[u8]) -> Vec<crate::Diagnostic> {
+        check(source).into_iter().filter(|d| d.rule_id == SpaceAfterColon::ID).collect()
+    }
+
+    fn fixed(source: &[u8]) -> Vec<u8> {
+        crate::apply_fixes(None, source, &colon_diagnostics(source), false).0
+    }
+
+    #[test]
+    fn test_missing_space_in_hash() {
+        let diagnostics = colon_diagnostics(b"{a:1}\n");
+        assert_eq!(diagnostics.len(), 1);
+        assert_eq!(diagnostics[0].message, MSG);
+        assert_eq!(diagnostics[0].column_start, 3);
+        assert_eq!(fixed(b"{a:1}\n"), b"{a: 1}\n");
+        assert_eq!(fixed(b"{\"a\":1}\n"), b"{\"a\": 1}\n");
+    }
+
+    #[test]
+    fn test_missing_space_in_keywords() {
+        assert_eq!(fixed(b"foo(a:1, b:2)\n"), b"foo(a: 1, b: 2)\n");
+        assert_eq!(fixed(b"def foo(a:1); end\n"), b"def foo(a: 1); end\n");
+    }
+
+    #[test]
+    fn test_accepts_other_colons() {
+        assert!(colon_diagnostics(b"{ a: 1, b:\n  2 }\n").is_empty());
+        assert!(colon_diagnostics(b"x = a ? b :c\n").is_empty());
+        assert!(colon_diagnostics(b"x = { :a => :b }\n").is_empty());
+        assert!(colon_diagnostics(b"foo(a:, b:)\n").is_empty());
+        assert!(colon_diagnostics(b"def foo(a:); end\n").is_empty());
+    }
+
+    #[test]
+    fn test_ternary_symbol_and_scope_colons() {
+        assert!(colon_diagnostics(b"x = a ? b : c\n").is_empty());
+        assert!(colon_diagnostics(b"x = a ? b:c\n").is_empty());
+        assert!(colon_diagnostics(b"x = a ?b :c\n").is_empty());
+        assert!(colon_diagnostics(b"foo :sym, :\"quoted\"\n").is_empty());
+        assert!(colon_diagnostics(b"Foo::Bar\n::Baz::Qux.new\n").is_empty());
+        assert!(colon_diagnostics(b"x = %i[a: b:]\n").is_empty());
+        assert_eq!(fixed(b"{x:1}\n"), b"{x: 1}\n");
+        assert_eq!(fixed(b"{x:Foo::Bar, y: :sym}\n"), b"{x: Foo::Bar, y: :sym}\n");
+        assert_eq!(fixed(b"x = a ? {x:1} : {y:2}\n"), b"x = a ? {x: 1} : {y: 2}\n");
+    }
+}
@@ -0,0 +1,175 @@
+use crate::checker::Checker;
+use crate::diagnostic::{Edit, Fix};
+use crate::rule::{Check, LayoutRule, Rule, RuleId};
+use crate::utility::comma::commas;
+use reukocyte_macros::check;
+use ruby_prism::*;
+
+const MSG: &str = "Space missing after comma.";
+
+/// Get the config for this rule
+#[inline]
+fn config<'a>(checker: &'a Checker<'_>) -> &'a crate::config::layout::space_after_comma::SpaceAfterComma {
+    &checker.config().layout.space_after_comma
+}
+
+/// Layout/SpaceAfterComma rule.
+///
+/// Requires a space after a comma, unless the comma is a trailing comma or is
+/// followed by a line break or a comment. Extra spaces are left to
+/// Layout/ExtraSpacing, which allows them for alignment.
+///
+/// ```ruby
+/// # bad
+/// foo(a,b)
+///
+/// # good
+/// foo(a, b)
+/// [1,  2]
+/// ```
+pub struct SpaceAfterComma;
+impl Rule for SpaceAfterComma {
+    const ID: RuleId = RuleId::Layout(LayoutRule::SpaceAfterComma);
+}
+#[check(ArgumentsNode)]
+impl Check<ArgumentsNode<'_>> for SpaceAfterComma {
+    fn check(node: &ArgumentsNode, checker: &mut Checker) {
+        check_commas(&node.as_node(), checker);
+    }
+}
+#[check(ArrayNode)]
+impl Check<ArrayNode<'_>> for SpaceAfterComma {
+    fn check(node: &ArrayNode, checker: &mut Checker) {
+        check_commas(&node.as_node(), checker);
+    }
+}
+#[check(CallNode)]
+impl Check<CallNode<'_>> for SpaceAfterComma {
+    fn check(node: &CallNode, checker: &mut Checker) {
+        check_commas(&node.as_node(), checker);
+    }
+}
+#[check(HashNode)]
+impl Check<HashNode<'_>> for SpaceAfterComma {
+    fn check(node: &HashNode, checker: &mut Checker) {
+        check_commas(&node.as_node(), checker);
+    }
+}
+#[check(KeywordHashNode)]
+impl Check<KeywordHashNode<'_>> for SpaceAfterComma {
+    fn check(node: &KeywordHashNode, checker: &mut Checker) {
+        check_commas(&node.as_node(), checker);
+    }
+}
+#[check(MultiTargetNode)]
+impl Check<MultiTargetNode<'_>> for SpaceAfterComma {
+    fn check(node: &MultiTargetNode, checker: &mut Checker) {
+        check_commas(&node.as_node(), checker);
+    }
+}
+#[check(MultiWriteNode)]
+impl Check<MultiWriteNode<'_>> for SpaceAfterComma {
+    fn check(node: &MultiWriteNode, checker: &mut Checker) {
+        check_commas(&node.as_node(), checker);
+    }
+}
+#[check(ParametersNode)]
+impl Check<ParametersNode<'_>> for SpaceAfterComma {
+    fn check(node: &ParametersNode, checker: &mut Checker) {
+        check_commas(&node.as_node(), checker);
+    }
+}
+#[check(RescueNode)]
+impl Check<RescueNode<'_>> for SpaceAfterComma {
+    fn check(node: &RescueNode, checker: &mut Checker) {
+        check_commas(&node.as_node(), checker);
+    }
+}
+#[check(WhenNode)]
+impl Check<WhenNode<'_>> for SpaceAfterComma {
+    fn check(node: &WhenNode, checker: &mut Checker) {
+        check_commas(&node.as_node(), checker);
+    }
+}
+
+fn check_commas(node: &Node, checker: &mut Checker) {
+    let severity = config(checker).base.severity;
+    for comma in commas(node, checker.source()) {
+        if comma.next_start.is_none() {
+            continue;
+        }
+        let after = comma.offset + 1;
+        if checker
+            .source()
+            .get(after)
+            .is_none_or(|b| matches!(b, b' ' | b'\t' | b'\n' | b'\r' | b'#' | b'\\'))
+        {
+            continue;
+        }
+        let fix = Fix::safe(vec![Edit::insertion(after, " ".to_string())]);
+        checker.report(SpaceAfterComma::ID, MSG.to_string(), severity, comma.offset, after, Some(fix));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::check;
+    use crate::fix::apply_fixes;
+
+    fn space_after_comma_diagnostics(source: &[u8]) -> Vec<crate::Diagnostic> {
+        check(source).into_iter().filter(|d| d.rule_id == SpaceAfterComma::ID).collect()
+    }
+
+    fn fixed(source: &[u8]) -> String {
+        let diagnostics = space_after_comma_diagnostics(source);
+        let (fixed, _) = apply_fixes(None, source, &diagnostics, false);
+        String::from_utf8(fixed).unwrap()
+    }
+
+    #[test]
+    fn test_space_missing_after_comma() {
+        let source = b"foo(a,b)\n";
+        let diagnostics = space_after_comma_diagnostics(source);
+        assert_eq!(diagnostics.len(), 1);
+        assert_eq!(diagnostics[0].message, MSG);
+        assert_eq!(diagnostics[0].column_start, 6);
+        assert_eq!(fixed(source), "foo(a, b)\n");
+    }
+
+    #[test]
+    fn test_no_offense_for_extra_space_after_comma() {
+        let source = b"[1,   2]\nfoo(a,\tb)\n";
+        assert!(space_after_comma_diagnostics(source).is_empty());
+    }
+
+    #[test]
+    fn test_space_after_comma_in_other_nodes() {
+        assert_eq!(fixed(b"{a: 1,b: 2}\n"), "{a: 1, b: 2}\n");
+        assert_eq!(fixed(b"foo(a: 1,b: 2)\n"), "foo(a: 1, b: 2)\n");
+        assert_eq!(fixed(b"def foo(a,*b,&c); end\n"), "def foo(a, *b, &c); end\n");
+        assert_eq!(fixed(b"foo(a,&b)\n"), "foo(a, &b)\n");
+        assert_eq!(fixed(b"a,b = 1,2\n"), "a, b = 1, 2\n");
+        assert_eq!(fixed(b"each { |a,b| a }\n"), "each { |a, b| a }\n");
+        assert_eq!(fixed(b"case x\nwhen a,b\n  1\nend\n"), "case x\nwhen a, b\n  1\nend\n");
+        assert_eq!(fixed(b"begin\n  foo\nrescue A,B\n  bar\nend\n"), "begin\n  foo\nrescue A, B\n  bar\nend\n");
+    }
+
+    #[test]
+    fn test_no_offense_for_trailing_comma() {
+        let source = b"[1, 2,]\nfoo(a,)\n{ a: 1,}\n";
+        assert!(space_after_comma_diagnostics(source).is_empty());
+    }
+
+    #[test]
+    fn test_no_offense_for_line_break_after_comma() {
+        let source = b"foo(a,\n    b)\n[1, # one\n 2]\n";
+        assert!(space_after_comma_diagnostics(source).is_empty());
+    }
+
+    #[test]
+    fn test_no_offense_for_comma_in_string() {
+        let source = b"foo('a,b', \"c,d\", %w[e,f])\n";
+        assert!(space_after_comma_diagnostics(source).is_empty());
+    }
+}
@@ -0,0 +1,92 @@
+use crate::checker::Checker;
+use crate::diagnostic::{Edit, Fix};
+use crate::rule::{Check, LayoutRule, Rule, RuleId};
+use reukocyte_macros::check;
+use ruby_prism::*;
+
+const MSG: &str = "Do not put a space between a method name and the opening parenthesis.";
+
+/// Get the config for this rule
+#[inline]
+fn config<'a>(checker: &'a Checker<'_>) -> &'a crate::config::layout::space_after_method_name::SpaceAfterMethodName {
+    &checker.config().layout.space_after_method_name
+}
+
+/// Layout/SpaceAfterMethodName rule.
+///
+/// Checks for a space between the name of a method definition and the opening
+/// parenthesis of its parameters, including operator and setter methods.
+///
+/// ```ruby
+/// # bad
+/// def func (x) end
+/// def method= (y) end
+///
+/// # good
+/// def func(x) end
+/// def method=(y) end
+/// ```
+pub struct SpaceAfterMethodName;
+impl Rule for SpaceAfterMethodName {
+    const ID: RuleId = RuleId::Layout(LayoutRule::SpaceAfterMethodName);
+}
+#[check(DefNode)]
+impl Check<DefNode<'_>> for SpaceAfterMethodName {
+    fn check(node: &DefNode, checker: &mut Checker) {
+        let Some(lparen) = node.lparen_loc() else {
+            return;
+        };
+        let start = node.name_loc().end_offset();
+        let end = lparen.start_offset();
+        if start == end || !checker.source()[start..end].iter().all(|&b| b == b' ' || b == b'\t') {
+            return;
+        }
+        let severity = config(checker).base.severity;
+        checker.report(
+            SpaceAfterMethodName::ID,
+            MSG.to_string(),
+            severity,
+            start,
+            end,
+            Some(Fix::safe(vec![Edit::deletion(start, end)])),
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::check;
+
+    fn space_diagnostics(source: &[u8]) -> Vec<crate::Diagnostic> {
+        check(source).into_iter().filter(|d| d.rule_id == SpaceAfterMethodName::ID).collect()
+    }
+
+    #[test]
+    fn test_space_before_parameters() {
+        let source = b"def foo (a)\n  a\nend\n";
+        let diagnostics = space_diagnostics(source);
+        assert_eq!(diagnostics.len(), 1);
+        assert_eq!(diagnostics[0].message, MSG);
+        assert_eq!((diagnostics[0].line_start, diagnostics[0].column_start, diagnostics[0].column_end), (1, 8, 9));
+        assert_eq!(crate::apply_fixes(None, source, &diagnostics, false).0, b"def foo(a)\n  a\nend\n");
+    }
+
+    #[test]
+    fn test_operator_setter_and_singleton_methods() {
+        assert_eq!(space_diagnostics(b"def foo ()\nend\n").len(), 1);
+        assert_eq!(space_diagnostics(b"def + (other)\nend\n").len(), 1);
+        assert_eq!(space_diagnostics(b"def foo= (val)\nend\n").len(), 1);
+        assert_eq!(space_diagnostics(b"def self.foo  (a)\nend\n").len(), 1);
+    }
+
+    #[test]
+    fn test_accepted_definitions() {
+        assert!(space_diagnostics(b"def foo(a)\nend\n").is_empty());
+        assert!(space_diagnostics(b"def foo a\nend\n").is_empty());
+        assert!(space_diagnostics(b"def foo\nend\n").is_empty());
+        assert!(space_diagnostics(b"def +(other)\nend\n").is_empty());
+        assert!(space_diagnostics(b"def foo=(val)\nend\n").is_empty());
+        assert!(space_diagnostics(b"def foo = (1)\n").is_empty());
+    }
+}
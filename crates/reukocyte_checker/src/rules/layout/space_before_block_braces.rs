@@ -0,0 +1,159 @@
+use crate::checker::Checker;
+use crate::config::layout::space_before_block_braces::EnforcedStyle;
+use crate::diagnostic::{Edit, Fix};
+use crate::rule::{Check, LayoutRule, Rule, RuleId};
+use reukocyte_macros::check;
+use ruby_prism::*;
+
+const MSG_MISSING: &str = "Space missing to the left of {.";
+const MSG_DETECTED: &str = "Space detected to the left of {.";
+
+/// Get the config for this rule
+#[inline]
+fn config<'a>(checker: &'a Checker<'_>) -> &'a crate::config::layout::space_before_block_braces::SpaceBeforeBlockBraces {
+    &checker.config().layout.space_before_block_braces
+}
+
+/// Layout/SpaceBeforeBlockBraces rule.
+///
+/// Checks that the `{` of a brace block is preceded by a space (`space`,
+/// default) or not (`no_space`). Empty blocks (`{}`) follow
+/// `EnforcedStyleForEmptyBraces` instead. Hash literals are not checked.
+///
+/// ```ruby
+/// # bad (EnforcedStyle: space)
+/// foo.map{ |x| x * 2 }
+///
+/// # good (EnforcedStyle: space)
+/// foo.map { |x| x * 2 }
+/// ```
+pub struct SpaceBeforeBlockBraces;
+impl Rule for SpaceBeforeBlockBraces {
+    const ID: RuleId = RuleId::Layout(LayoutRule::SpaceBeforeBlockBraces);
+}
+#[check(BlockNode)]
+impl Check<BlockNode<'_>> for SpaceBeforeBlockBraces {
+    fn check(node: &BlockNode, checker: &mut Checker) {
+        let opening = node.opening_loc();
+        if opening.as_slice() != b"{" {
+            return;
+        }
+        let brace = opening.start_offset();
+        // Nothing precedes a `{` at the start of a line.
+        if checker.line_index().is_first_on_line(brace) {
+            return;
+        }
+        let config = config(checker);
+        let style = if node.closing_loc().start_offset() == opening.end_offset() {
+            config.enforced_style_for_empty_braces
+        } else {
+            config.enforced_style
+        };
+        let source = checker.source();
+        let space_start = brace - source[..brace].iter().rev().take_while(|&&b| b == b' ' || b == b'\t').count();
+        let severity = config.base.severity;
+        match style {
+            EnforcedStyle::Space if space_start == brace => {
+                let fix = Fix::safe(vec![Edit::insertion(brace, " ".to_string())]);
+                checker.report(
+                    SpaceBeforeBlockBraces::ID,
+                    MSG_MISSING.to_string(),
+                    severity,
+                    brace,
+                    opening.end_offset(),
+                    Some(fix),
+                );
+            }
+            EnforcedStyle::NoSpace if space_start < brace => {
+                let fix = Fix::safe(vec![Edit::deletion(space_start, brace)]);
+                checker.report(SpaceBeforeBlockBraces::ID, MSG_DETECTED.to_string(), severity, space_start, brace, Some(fix));
+            }
+            _ => {}
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::Config;
+    use crate::corrector::Corrector;
+    use crate::{apply_fixes, check, check_with_config};
+
+    fn space_before_block_braces_diagnostics(source: &[u8], config: &Config) -> Vec<crate::Diagnostic> {
+        check_with_config(source, config)
+            .into_iter()
+            .filter(|d| d.rule_id == SpaceBeforeBlockBraces::ID)
+            .collect()
+    }
+
+    fn style_config(style: EnforcedStyle, empty_braces_style: EnforcedStyle) -> Config {
+        let mut config = Config::default();
+        config.layout.space_before_block_braces.enforced_style = style;
+        config.layout.space_before_block_braces.enforced_style_for_empty_braces = empty_braces_style;
+        config
+    }
+
+    fn fixed(source: &[u8], config: &Config) -> String {
+        let mut corrector = Corrector::new();
+        for diagnostic in space_before_block_braces_diagnostics(source, config) {
+            corrector.merge(diagnostic.fix.as_ref().unwrap()).unwrap();
+        }
+        String::from_utf8(corrector.apply(source)).unwrap()
+    }
+
+    #[test]
+    fn test_space_missing() {
+        let source = b"foo.each{ |x| puts x }\n";
+        let diagnostics = space_before_block_braces_diagnostics(source, &Config::default());
+        assert_eq!(diagnostics.len(), 1);
+        assert_eq!(diagnostics[0].message, MSG_MISSING);
+        assert_eq!(diagnostics[0].column_start, 9);
+        let (fixed, _) = apply_fixes(None, source, &diagnostics, false);
+        assert_eq!(fixed, b"foo.each { |x| puts x }\n");
+    }
+
+    #[test]
+    fn test_space_present_and_other_braces() {
+        assert!(check(b"foo.each { |x| puts x }\n").iter().all(|d| d.rule_id != SpaceBeforeBlockBraces::ID));
+        // Hash literals and `do`/`end` blocks are not checked.
+        assert!(check(b"foo({a: 1})\nx ={ a: 1 }\n").iter().all(|d| d.rule_id != SpaceBeforeBlockBraces::ID));
+        assert!(check(b"foo.each do |x|\n  x\nend\n").iter().all(|d| d.rule_id != SpaceBeforeBlockBraces::ID));
+    }
+
+    #[test]
+    fn test_no_space_style() {
+        let config = style_config(EnforcedStyle::NoSpace, EnforcedStyle::NoSpace);
+        let source = b"foo.each  { |x| puts x }\n";
+        let diagnostics = space_before_block_braces_diagnostics(source, &config);
+        assert_eq!(diagnostics.len(), 1);
+        assert_eq!(diagnostics[0].message, MSG_DETECTED);
+        assert_eq!(fixed(source, &config), "foo.each{ |x| puts x }\n");
+        assert!(space_before_block_braces_diagnostics(b"foo.each{ |x| puts x }\n", &config).is_empty());
+    }
+
+    #[test]
+    fn test_empty_braces_style() {
+        // Empty blocks follow `EnforcedStyleForEmptyBraces`, other blocks `EnforcedStyle`.
+        let config = style_config(EnforcedStyle::Space, EnforcedStyle::NoSpace);
+        let source = b"foo.each {}\nbar.each{ x }\n";
+        let diagnostics = space_before_block_braces_diagnostics(source, &config);
+        assert_eq!(diagnostics.len(), 2);
+        assert_eq!(diagnostics[0].message, MSG_DETECTED);
+        assert_eq!(diagnostics[1].message, MSG_MISSING);
+        assert_eq!(fixed(source, &config), "foo.each{}\nbar.each { x }\n");
+
+        let config = style_config(EnforcedStyle::NoSpace, EnforcedStyle::Space);
+        assert_eq!(fixed(b"foo.each{}\nbar.each { x }\n", &config), "foo.each {}\nbar.each{ x }\n");
+        // `{ }` is not an empty-braces block.
+        assert!(space_before_block_braces_diagnostics(b"foo.each{ }\n", &config).is_empty());
+    }
+
+    #[test]
+    fn test_brace_at_start_of_line() {
+        let config = style_config(EnforcedStyle::NoSpace, EnforcedStyle::NoSpace);
+        let source = b"foo.each \\\n  { |x| x }\n";
+        assert!(ruby_prism::parse(source).errors().next().is_none());
+        assert!(space_before_block_braces_diagnostics(source, &config).is_empty());
+    }
+}
@@ -0,0 +1,93 @@
+use crate::checker::Checker;
+use crate::diagnostic::{Edit, Fix};
+use crate::rule::{Check, LayoutRule, Rule, RuleId};
+use reukocyte_macros::check;
+use ruby_prism::*;
+
+const MSG: &str = "Space found before colon.";
+
+/// Get the config for this rule
+#[inline]
+fn config<'a>(checker: &'a Checker<'_>) -> &'a crate::config::layout::space_before_colon::SpaceBeforeColon {
+    &checker.config().layout.space_before_colon
+}
+
+/// Layout/SpaceBeforeColon rule.
+///
+/// Checks for whitespace between a hash key and the colon of the `key: value` form.
+///
+/// Ruby does not allow whitespace inside a label, so `{ a :1 }` is a syntax error.
+/// Prism recovers it as a pair `a => :` with a missing `=>` and an empty symbol
+/// right after the whitespace; that shape is what this rule detects and removes.
+///
+/// ```ruby
+/// # bad
+/// { a :1 }
+///
+/// # good
+/// { a:1 }
+/// ```
+pub struct SpaceBeforeColon;
+impl Rule for SpaceBeforeColon {
+    const ID: RuleId = RuleId::Layout(LayoutRule::SpaceBeforeColon);
+}
+#[check(AssocNode)]
+impl Check<AssocNode<'_>> for SpaceBeforeColon {
+    fn check(node: &AssocNode, checker: &mut Checker) {
+        // A recovered pair has a zero-width (missing) `=>`.
+        if node.operator_loc().is_none_or(|operator| !operator.as_slice().is_empty()) {
+            return;
+        }
+        let key = node.key();
+        let Some(key_name) = key.as_call_node().filter(|call| call.is_variable_call()).map(|call| call.location()) else {
+            return;
+        };
+        let Some(symbol) = node.value().as_symbol_node() else {
+            return;
+        };
+        let is_empty_symbol =
+            symbol.opening_loc().is_some_and(|opening| opening.as_slice() == b":") && symbol.value_loc().is_none_or(|value| value.as_slice().is_empty());
+        if !is_empty_symbol {
+            return;
+        }
+        let (start, end) = (key_name.end_offset(), symbol.location().start_offset());
+        let gap = &checker.source()[start..end];
+        if gap.is_empty() || !gap.iter().all(|&b| b == b' ' || b == b'\t') {
+            return;
+        }
+        let fix = Fix::safe(vec![Edit::deletion(start, end)]);
+        let severity = config(checker).base.severity;
+        checker.report(SpaceBeforeColon::ID, MSG.to_string(), severity, start, end, Some(fix));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::check;
+    use crate::corrector::Corrector;
+
+    fn colon_diagnostics(source: &[u8]) -> Vec<crate::Diagnostic> {
+        check(source).into_iter().filter(|d| d.rule_id == SpaceBeforeColon::ID).collect()
+    }
+
+    #[test]
+    fn test_space_before_colon() {
+        let source = b"{a :1}\n";
+        let diagnostics = colon_diagnostics(source);
+        assert_eq!(diagnostics.len(), 1);
+        assert_eq!(diagnostics[0].message, MSG);
+        let mut corrector = Corrector::new();
+        corrector.merge(diagnostics[0].fix.as_ref().unwrap()).unwrap();
+        assert_eq!(corrector.apply(source), b"{a:1}\n");
+    }
+
+    #[test]
+    fn test_accepts_valid_pairs_and_symbols() {
+        assert!(colon_diagnostics(b"{a:1}\n").is_empty());
+        assert!(colon_diagnostics(b"{ a: :b }\n").is_empty());
+        assert!(colon_diagnostics(b"{ a => :b }\n").is_empty());
+        assert!(colon_diagnostics(b"x = a ? b : c\n").is_empty());
+        assert!(colon_diagnostics(b"foo :bar\n").is_empty());
+    }
+}
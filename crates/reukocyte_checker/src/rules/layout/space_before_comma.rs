@@ -0,0 +1,142 @@
+use crate::checker::Checker;
+use crate::diagnostic::{Edit, Fix};
+use crate::rule::{Check, LayoutRule, Rule, RuleId};
+use crate::utility::comma::commas;
+use reukocyte_macros::check;
+use ruby_prism::*;
+
+const MSG: &str = "Space found before comma.";
+
+/// Get the config for this rule
+#[inline]
+fn config<'a>(checker: &'a Checker<'_>) -> &'a crate::config::layout::space_before_comma::SpaceBeforeComma {
+    &checker.config().layout.space_before_comma
+}
+
+/// Layout/SpaceBeforeComma rule.
+///
+/// Checks for spaces or tabs between an element and the comma that follows it.
+///
+/// ```ruby
+/// # bad
+/// foo(a , b)
+///
+/// # good
+/// foo(a, b)
+/// ```
+pub struct SpaceBeforeComma;
+impl Rule for SpaceBeforeComma {
+    const ID: RuleId = RuleId::Layout(LayoutRule::SpaceBeforeComma);
+}
+#[check(ArgumentsNode)]
+impl Check<ArgumentsNode<'_>> for SpaceBeforeComma {
+    fn check(node: &ArgumentsNode, checker: &mut Checker) {
+        check_commas(&node.as_node(), checker);
+    }
+}
+#[check(ArrayNode)]
+impl Check<ArrayNode<'_>> for SpaceBeforeComma {
+    fn check(node: &ArrayNode, checker: &mut Checker) {
+        check_commas(&node.as_node(), checker);
+    }
+}
+#[check(CallNode)]
+impl Check<CallNode<'_>> for SpaceBeforeComma {
+    fn check(node: &CallNode, checker: &mut Checker) {
+        check_commas(&node.as_node(), checker);
+    }
+}
+#[check(HashNode)]
+impl Check<HashNode<'_>> for SpaceBeforeComma {
+    fn check(node: &HashNode, checker: &mut Checker) {
+        check_commas(&node.as_node(), checker);
+    }
+}
+#[check(KeywordHashNode)]
+impl Check<KeywordHashNode<'_>> for SpaceBeforeComma {
+    fn check(node: &KeywordHashNode, checker: &mut Checker) {
+        check_commas(&node.as_node(), checker);
+    }
+}
+#[check(MultiTargetNode)]
+impl Check<MultiTargetNode<'_>> for SpaceBeforeComma {
+    fn check(node: &MultiTargetNode, checker: &mut Checker) {
+        check_commas(&node.as_node(), checker);
+    }
+}
+#[check(MultiWriteNode)]
+impl Check<MultiWriteNode<'_>> for SpaceBeforeComma {
+    fn check(node: &MultiWriteNode, checker: &mut Checker) {
+        check_commas(&node.as_node(), checker);
+    }
+}
+#[check(ParametersNode)]
+impl Check<ParametersNode<'_>> for SpaceBeforeComma {
+    fn check(node: &ParametersNode, checker: &mut Checker) {
+        check_commas(&node.as_node(), checker);
+    }
+}
+
+fn check_commas(node: &Node, checker: &mut Checker) {
+    let severity = config(checker).base.severity;
+    for comma in commas(node, checker.source()) {
+        let space = &checker.source()[comma.previous_end..comma.offset];
+        if space.is_empty() || !space.iter().all(|b| matches!(b, b' ' | b'\t')) {
+            continue;
+        }
+        let fix = Fix::safe(vec![Edit::deletion(comma.previous_end, comma.offset)]);
+        checker.report(SpaceBeforeComma::ID, MSG.to_string(), severity, comma.previous_end, comma.offset, Some(fix));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::check;
+    use crate::fix::apply_fixes;
+
+    fn space_before_comma_diagnostics(source: &[u8]) -> Vec<crate::Diagnostic> {
+        check(source).into_iter().filter(|d| d.rule_id == SpaceBeforeComma::ID).collect()
+    }
+
+    fn fixed(source: &[u8]) -> String {
+        let diagnostics = space_before_comma_diagnostics(source);
+        let (fixed, _) = apply_fixes(None, source, &diagnostics, false);
+        String::from_utf8(fixed).unwrap()
+    }
+
+    #[test]
+    fn test_space_before_comma_in_arguments() {
+        let source = b"foo(a , b)\n";
+        let diagnostics = space_before_comma_diagnostics(source);
+        assert_eq!(diagnostics.len(), 1);
+        assert_eq!(diagnostics[0].message, MSG);
+        assert_eq!(diagnostics[0].column_start, 6);
+        assert_eq!(fixed(source), "foo(a, b)\n");
+    }
+
+    #[test]
+    fn test_space_before_comma_in_collections() {
+        assert_eq!(fixed(b"[1 , 2 , 3]\n"), "[1, 2, 3]\n");
+        assert_eq!(fixed(b"{ a: 1 , b: 2 }\n"), "{ a: 1, b: 2 }\n");
+        assert_eq!(fixed(b"def foo(a , *b , &c); end\n"), "def foo(a, *b, &c); end\n");
+        assert_eq!(fixed(b"a , b = 1 , 2\n"), "a, b = 1, 2\n");
+    }
+
+    #[test]
+    fn test_space_before_trailing_comma() {
+        assert_eq!(fixed(b"[\n  1 ,\n]\n"), "[\n  1,\n]\n");
+    }
+
+    #[test]
+    fn test_no_offense_for_comma_on_next_line() {
+        let source = b"foo(a\n    , b)\n";
+        assert!(space_before_comma_diagnostics(source).is_empty());
+    }
+
+    #[test]
+    fn test_no_offense_for_comma_in_string() {
+        let source = b"foo('a , b', \"c , d\")\n";
+        assert!(space_before_comma_diagnostics(source).is_empty());
+    }
+}
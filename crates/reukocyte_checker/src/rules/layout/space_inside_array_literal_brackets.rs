@@ -0,0 +1,222 @@
+use crate::checker::Checker;
+use crate::config::layout::space_inside_array_literal_brackets::{EnforcedStyle, EnforcedStyleForEmptyBrackets};
+use crate::diagnostic::{Edit, Fix};
+use crate::rule::{Check, LayoutRule, Rule, RuleId};
+use reukocyte_macros::check;
+use ruby_prism::*;
+
+const MSG_NO_SPACE: &str = "Do not use space inside array brackets.";
+const MSG_SPACE: &str = "Use space inside array brackets.";
+const MSG_EMPTY_NO_SPACE: &str = "Do not use space inside empty array brackets.";
+const MSG_EMPTY_SPACE: &str = "Use one space inside empty array brackets.";
+
+/// Get the config for this rule
+#[inline]
+fn config<'a>(checker: &'a Checker<'_>) -> &'a crate::config::layout::space_inside_array_literal_brackets::SpaceInsideArrayLiteralBrackets {
+    &checker.config().layout.space_inside_array_literal_brackets
+}
+
+/// Layout/SpaceInsideArrayLiteralBrackets rule.
+///
+/// Checks the spacing just inside the `[` and `]` of array literals. Percent
+/// literals are not checked, and neither is a side of the brackets that a
+/// line break follows (or precedes). With `compact`, brackets of nested arrays
+/// are kept together: `[[ 1 ], [ 2 ]]`.
+///
+/// ```ruby
+/// # bad (EnforcedStyle: no_space)
+/// [ 1, 2 ]
+///
+/// # good (EnforcedStyle: no_space)
+/// [1, 2]
+///
+/// # good (EnforcedStyle: space)
+/// [ 1, 2 ]
+///
+/// # good (EnforcedStyle: compact)
+/// [[ 1, 2 ], [ 3 ]]
+/// ```
+pub struct SpaceInsideArrayLiteralBrackets;
+impl Rule for SpaceInsideArrayLiteralBrackets {
+    const ID: RuleId = RuleId::Layout(LayoutRule::SpaceInsideArrayLiteralBrackets);
+}
+#[check(ArrayNode)]
+impl Check<ArrayNode<'_>> for SpaceInsideArrayLiteralBrackets {
+    fn check(node: &ArrayNode, checker: &mut Checker) {
+        let (Some(opening), Some(closing)) = (node.opening_loc(), node.closing_loc()) else {
+            return;
+        };
+        if opening.as_slice() != b"[" {
+            return;
+        }
+        if node.elements().iter().next().is_none() {
+            check_empty(&opening, &closing, checker);
+            return;
+        }
+        let opening_end = opening.end_offset();
+        let closing_start = closing.start_offset();
+        let inner = &checker.source()[opening_end..closing_start];
+
+        let leading = inner.iter().take_while(|&&b| is_space(b)).count();
+        let trailing = inner.iter().rev().take_while(|&&b| is_space(b)).count();
+        let after_leading = inner[leading];
+        let before_trailing = inner[inner.len() - trailing - 1];
+        let style = config(checker).enforced_style;
+        // A line break (or a comment running to one) exempts that side.
+        if !matches!(after_leading, b'\n' | b'\r' | b'#') {
+            let space = match style {
+                EnforcedStyle::NoSpace => false,
+                EnforcedStyle::Space => true,
+                EnforcedStyle::Compact => after_leading != b'[',
+            };
+            check_side(space, leading, &opening, opening_end, opening_end + leading, checker);
+        }
+        if before_trailing != b'\n' {
+            let space = match style {
+                EnforcedStyle::NoSpace => false,
+                EnforcedStyle::Space => true,
+                EnforcedStyle::Compact => before_trailing != b']',
+            };
+            check_side(space, trailing, &closing, closing_start - trailing, closing_start, checker);
+        }
+    }
+}
+
+/// Report the spaces (`start..end`, `count` of them) next to `bracket` if they do not match the style.
+fn check_side(space: bool, count: usize, bracket: &Location, start: usize, end: usize, checker: &mut Checker) {
+    let severity = config(checker).base.severity;
+    if space && count == 0 {
+        let fix = Fix::safe(vec![Edit::insertion(start, " ".to_string())]);
+        checker.report(
+            SpaceInsideArrayLiteralBrackets::ID,
+            MSG_SPACE.to_string(),
+            severity,
+            bracket.start_offset(),
+            bracket.end_offset(),
+            Some(fix),
+        );
+    } else if !space && count > 0 {
+        let fix = Fix::safe(vec![Edit::deletion(start, end)]);
+        checker.report(SpaceInsideArrayLiteralBrackets::ID, MSG_NO_SPACE.to_string(), severity, start, end, Some(fix));
+    }
+}
+
+/// Check the spacing inside `[]`, left alone when it spans lines or holds a comment.
+fn check_empty(opening: &Location, closing: &Location, checker: &mut Checker) {
+    let inner = &checker.source()[opening.end_offset()..closing.start_offset()];
+    let (message, expected) = match config(checker).enforced_style_for_empty_brackets {
+        EnforcedStyleForEmptyBrackets::NoSpace => (MSG_EMPTY_NO_SPACE, ""),
+        EnforcedStyleForEmptyBrackets::Space => (MSG_EMPTY_SPACE, " "),
+    };
+    if !inner.iter().all(|&b| is_space(b)) || inner == expected.as_bytes() {
+        return;
+    }
+    let fix = Fix::safe(vec![Edit::replacement(opening.end_offset(), closing.start_offset(), expected.to_string())]);
+    let severity = config(checker).base.severity;
+    checker.report(
+        SpaceInsideArrayLiteralBrackets::ID,
+        message.to_string(),
+        severity,
+        opening.start_offset(),
+        closing.end_offset(),
+        Some(fix),
+    );
+}
+
+#[inline]
+fn is_space(byte: u8) -> bool {
+    byte == b' ' || byte == b'\t'
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::check_with_config;
+    use crate::config::Config;
+    use crate::corrector::Corrector;
+
+    fn config(style: EnforcedStyle, empty: EnforcedStyleForEmptyBrackets) -> Config {
+        let mut config = Config::default();
+        config.layout.space_inside_array_literal_brackets.enforced_style = style;
+        config.layout.space_inside_array_literal_brackets.enforced_style_for_empty_brackets = empty;
+        config
+    }
+
+    fn bracket_diagnostics(source: &[u8], config: &Config) -> Vec<crate::Diagnostic> {
+        check_with_config(source, config)
+            .into_iter()
+            .filter(|d| d.rule_id == SpaceInsideArrayLiteralBrackets::ID)
+            .collect()
+    }
+
+    fn fixed(source: &[u8], config: &Config) -> String {
+        let mut corrector = Corrector::new();
+        for diagnostic in bracket_diagnostics(source, config) {
+            corrector.merge(diagnostic.fix.as_ref().unwrap()).unwrap();
+        }
+        String::from_utf8(corrector.apply(source)).unwrap()
+    }
+
+    #[test]
+    fn test_no_space() {
+        let config = config(EnforcedStyle::NoSpace, EnforcedStyleForEmptyBrackets::NoSpace);
+        let source = b"x = [ 1, 2  ]\n";
+        let diagnostics = bracket_diagnostics(source, &config);
+        assert_eq!(diagnostics.len(), 2);
+        assert_eq!(diagnostics[0].message, MSG_NO_SPACE);
+        assert_eq!((diagnostics[1].column_start, diagnostics[1].column_end), (11, 13));
+        assert_eq!(fixed(source, &config), "x = [1, 2]\n");
+        assert_eq!(fixed(b"x = [ [ 1 ] ]\n", &config), "x = [[1]]\n");
+        assert!(bracket_diagnostics(b"x = [1, 2]\ny = %w[ a b ]\nz = a[ 0 ]\n", &config).is_empty());
+    }
+
+    #[test]
+    fn test_space() {
+        let config = config(EnforcedStyle::Space, EnforcedStyleForEmptyBrackets::NoSpace);
+        let source = b"x = [1, 2]\n";
+        let diagnostics = bracket_diagnostics(source, &config);
+        assert_eq!(diagnostics.len(), 2);
+        assert_eq!(diagnostics[0].message, MSG_SPACE);
+        assert_eq!((diagnostics[0].column_start, diagnostics[0].column_end), (5, 6));
+        assert_eq!(fixed(source, &config), "x = [ 1, 2 ]\n");
+        assert_eq!(fixed(b"x = [[1]]\n", &config), "x = [ [ 1 ] ]\n");
+        assert!(bracket_diagnostics(b"x = [ 1, 2 ]\n", &config).is_empty());
+    }
+
+    #[test]
+    fn test_compact() {
+        let config = config(EnforcedStyle::Compact, EnforcedStyleForEmptyBrackets::NoSpace);
+        assert_eq!(fixed(b"x = [ [ 1, 2 ], [ 3 ] ]\n", &config), "x = [[ 1, 2 ], [ 3 ]]\n");
+        assert_eq!(fixed(b"x = [[1], 2]\n", &config), "x = [[ 1 ], 2 ]\n");
+        assert_eq!(fixed(b"x = [1]\n", &config), "x = [ 1 ]\n");
+        assert!(bracket_diagnostics(b"x = [[[ 1 ]]]\n", &config).is_empty());
+    }
+
+    #[test]
+    fn test_empty_brackets() {
+        let no_space = config(EnforcedStyle::Space, EnforcedStyleForEmptyBrackets::NoSpace);
+        let diagnostics = bracket_diagnostics(b"x = [  ]\n", &no_space);
+        assert_eq!(diagnostics.len(), 1);
+        assert_eq!(diagnostics[0].message, MSG_EMPTY_NO_SPACE);
+        assert_eq!(fixed(b"x = [  ]\n", &no_space), "x = []\n");
+        assert!(bracket_diagnostics(b"x = []\n", &no_space).is_empty());
+
+        let space = config(EnforcedStyle::NoSpace, EnforcedStyleForEmptyBrackets::Space);
+        assert_eq!(bracket_diagnostics(b"x = []\n", &space)[0].message, MSG_EMPTY_SPACE);
+        assert_eq!(fixed(b"x = []\n", &space), "x = [ ]\n");
+        assert_eq!(fixed(b"x = [   ]\n", &space), "x = [ ]\n");
+        assert!(bracket_diagnostics(b"x = [ ]\n", &space).is_empty());
+        // Multiline or commented empty arrays are left alone.
+        assert!(bracket_diagnostics(b"x = [\n]\ny = [ # none\n]\n", &space).is_empty());
+    }
+
+    #[test]
+    fn test_multiline_arrays() {
+        let no_space = config(EnforcedStyle::NoSpace, EnforcedStyleForEmptyBrackets::NoSpace);
+        assert!(bracket_diagnostics(b"x = [\n  1,\n  2\n]\ny = [ # items\n  1\n]\n", &no_space).is_empty());
+        assert_eq!(fixed(b"x = [ 1,\n  2 ]\n", &no_space), "x = [1,\n  2]\n");
+        let space = config(EnforcedStyle::Space, EnforcedStyleForEmptyBrackets::NoSpace);
+        assert!(bracket_diagnostics(b"x = [\n  1,\n  2\n]\ny = [ 1, # last\n]\n", &space).is_empty());
+        assert_eq!(fixed(b"x = [1,\n  2]\n", &space), "x = [ 1,\n  2 ]\n");
+    }
+}
@@ -0,0 +1,144 @@
+use crate::checker::Checker;
+use crate::config::layout::space_inside_string_interpolation::EnforcedStyle;
+use crate::diagnostic::{Edit, Fix};
+use crate::rule::{Check, LayoutRule, Rule, RuleId};
+use reukocyte_macros::check;
+use ruby_prism::*;
+
+const MSG_NO_SPACE: &str = "Do not use spaces inside string interpolation.";
+const MSG_SPACE: &str = "Use spaces inside string interpolation.";
+
+/// Get the config for this rule
+#[inline]
+fn config<'a>(checker: &'a Checker<'_>) -> &'a crate::config::layout::space_inside_string_interpolation::SpaceInsideStringInterpolation {
+    &checker.config().layout.space_inside_string_interpolation
+}
+
+/// Layout/SpaceInsideStringInterpolation rule.
+///
+/// Checks the spacing just inside `#{` and `}` of string interpolations.
+///
+/// ```ruby
+/// # bad (EnforcedStyle: no_space)
+/// "#{ name }"
+///
+/// # good (EnforcedStyle: no_space)
+/// "#{name}"
+///
+/// # good (EnforcedStyle: space)
+/// "#{ name }"
+/// ```
+pub struct SpaceInsideStringInterpolation;
+impl Rule for SpaceInsideStringInterpolation {
+    const ID: RuleId = RuleId::Layout(LayoutRule::SpaceInsideStringInterpolation);
+}
+#[check(EmbeddedStatementsNode)]
+impl Check<EmbeddedStatementsNode<'_>> for SpaceInsideStringInterpolation {
+    fn check(node: &EmbeddedStatementsNode, checker: &mut Checker) {
+        let source = checker.source();
+        let opening_end = node.opening_loc().end_offset();
+        let closing_start = node.closing_loc().start_offset();
+        let inner = &source[opening_end..closing_start];
+        // Empty interpolations are left to Lint/EmptyInterpolation, multiline ones are not checked.
+        if inner.iter().all(|&b| is_space(b)) || inner.contains(&b'\n') {
+            return;
+        }
+        let leading = inner.iter().take_while(|&&b| is_space(b)).count();
+        let trailing = inner.iter().rev().take_while(|&&b| is_space(b)).count();
+        let severity = config(checker).base.severity;
+
+        match config(checker).enforced_style {
+            EnforcedStyle::NoSpace => {
+                let spaces = [(opening_end, opening_end + leading), (closing_start - trailing, closing_start)];
+                for (start, end) in spaces.into_iter().filter(|(start, end)| start < end) {
+                    let fix = Fix::safe(vec![Edit::deletion(start, end)]);
+                    checker.report(SpaceInsideStringInterpolation::ID, MSG_NO_SPACE.to_string(), severity, start, end, Some(fix));
+                }
+            }
+            EnforcedStyle::Space => {
+                let delimiters = [(node.opening_loc(), leading, opening_end), (node.closing_loc(), trailing, closing_start)];
+                for (delimiter, space_count, insert_at) in delimiters {
+                    if space_count == 0 {
+                        let fix = Fix::safe(vec![Edit::insertion(insert_at, " ".to_string())]);
+                        checker.report(
+                            SpaceInsideStringInterpolation::ID,
+                            MSG_SPACE.to_string(),
+                            severity,
+                            delimiter.start_offset(),
+                            delimiter.end_offset(),
+                            Some(fix),
+                        );
+                    }
+                }
+            }
+        }
+    }
+}
+
+#[inline]
+fn is_space(byte: u8) -> bool {
+    byte == b' ' || byte == b'\t'
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::Config;
+    use crate::corrector::Corrector;
+    use crate::{check, check_with_config};
+
+    fn interpolation_diagnostics(diagnostics: Vec<crate::Diagnostic>) -> Vec<crate::Diagnostic> {
+        diagnostics.into_iter().filter(|d| d.rule_id == SpaceInsideStringInterpolation::ID).collect()
+    }
+
+    fn space_config() -> Config {
+        let mut config = Config::default();
+        config.layout.space_inside_string_interpolation.enforced_style = EnforcedStyle::Space;
+        config
+    }
+
+    fn fixed(source: &[u8], diagnostics: &[crate::Diagnostic]) -> String {
+        let mut corrector = Corrector::new();
+        for fix in diagnostics.iter().filter_map(|d| d.fix.as_ref()) {
+            corrector.merge(fix).unwrap();
+        }
+        String::from_utf8(corrector.apply(source)).unwrap()
+    }
+
+    #[test]
+    fn test_no_space_reports_spaces() {
+        let source = b"\"#{ x }\"\n";
+        let diagnostics = interpolation_diagnostics(check(source));
+        assert_eq!(diagnostics.len(), 2);
+        assert_eq!(diagnostics[0].message, MSG_NO_SPACE);
+        assert_eq!(fixed(source, &diagnostics), "\"#{x}\"\n");
+    }
+
+    #[test]
+    fn test_no_space_accepts_tight_interpolation() {
+        assert!(interpolation_diagnostics(check(b"\"a #{x} b #{y.z(1)}\"\n")).is_empty());
+        assert!(interpolation_diagnostics(check(b"\"#{ }\"\n")).is_empty());
+    }
+
+    #[test]
+    fn test_no_space_one_side() {
+        let source = b"\"#{x  }\"\n";
+        let diagnostics = interpolation_diagnostics(check(source));
+        assert_eq!(diagnostics.len(), 1);
+        assert_eq!(fixed(source, &diagnostics), "\"#{x}\"\n");
+    }
+
+    #[test]
+    fn test_space_reports_missing_spaces() {
+        let source = b"\"#{x}\"\n";
+        let diagnostics = interpolation_diagnostics(check_with_config(source, &space_config()));
+        assert_eq!(diagnostics.len(), 2);
+        assert_eq!(diagnostics[0].message, MSG_SPACE);
+        assert_eq!(fixed(source, &diagnostics), "\"#{ x }\"\n");
+    }
+
+    #[test]
+    fn test_space_accepts_spaced_interpolation() {
+        assert!(interpolation_diagnostics(check_with_config(b"\"#{ x }\"\n", &space_config())).is_empty());
+    }
+}
@@ -44,7 +44,7 @@ pub fn check(checker: &mut Checker) {
         return;
     }
     // Check cop-specific include/exclude
-    if !checker.should_run_cop(&config.base.include, &config.base.exclude) {
+    if !checker.should_run_cop(&config.base) {
         return;
     }
     let severity = config.base.severity;
@@ -55,15 +55,18 @@ pub fn check(checker: &mut Checker) {
         crate::config::layout::trailing_empty_lines::EnforcedStyle::FinalBlankLine => EnforcedStyle::FinalBlankLine,
     };
 
-    if let Some((start, end, replacement, message)) = analyze(checker.source(), style) {
-        let fix = Fix::safe(vec![Edit::replacement(start, end, replacement)]);
-        checker.report(RULE_ID, message, severity, start, end, Some(fix));
+    if let Some((start, end, edit, message)) = analyze(checker.source(), style) {
+        let fix = Fix::safe(vec![edit]);
+        checker.report_with_fix_description(RULE_ID, message, severity, start, end, fix, "normalize the newlines at the end of the file");
     }
 }
 
 /// Analyze source for trailing empty lines issues.
-/// Returns (start, end, replacement, message) if there's an issue.
-fn analyze(source: &[u8], style: EnforcedStyle) -> Option<(usize, usize, String, String)> {
+/// Returns (start, end, edit, message) if there's an issue.
+///
+/// The edit replaces everything after the last content with the wanted newlines,
+/// so a single pass both adds a missing blank line and trims extra ones.
+fn analyze(source: &[u8], style: EnforcedStyle) -> Option<(usize, usize, Edit, String)> {
     if source.is_empty() {
         return None;
     }
@@ -71,8 +74,13 @@ fn analyze(source: &[u8], style: EnforcedStyle) -> Option<(usize, usize, String,
     // Count trailing newlines
     let trailing_newlines = source.iter().rev().take_while(|&&b| b == b'\n').count();
 
-    // Find where trailing whitespace starts (including blank lines)
-    let trailing_start = find_trailing_whitespace_start(source);
+    // Find where the content ends and where the trailing blank lines start
+    let content_end = find_content_end(source);
+    let trailing_start = if content_end > 0 && source.get(content_end) == Some(&b'\n') {
+        content_end + 1
+    } else {
+        content_end
+    };
 
     let wanted_newlines = match style {
         EnforcedStyle::FinalNewline => 1,
@@ -82,8 +90,8 @@ fn analyze(source: &[u8], style: EnforcedStyle) -> Option<(usize, usize, String,
     // Check for missing final newline
     if trailing_newlines == 0 {
         let message = "Final newline missing.".to_string();
-        let replacement = "\n".repeat(wanted_newlines);
-        return Some((source.len(), source.len(), replacement, message));
+        let edit = Edit::insertion(source.len(), "\n".repeat(wanted_newlines));
+        return Some((source.len(), source.len(), edit, message));
     }
 
     // Count blank lines (trailing_newlines - 1 because last \n is the final newline)
@@ -103,36 +111,40 @@ fn analyze(source: &[u8], style: EnforcedStyle) -> Option<(usize, usize, String,
             format!("{} trailing blank lines instead of {} detected.", blank_lines, wanted_blank_lines)
         };
 
-        let replacement = "\n".repeat(wanted_newlines);
-        return Some((trailing_start, source.len(), replacement, message));
+        let edit = Edit::replacement(content_end, source.len(), "\n".repeat(wanted_newlines));
+        return Some((trailing_start, source.len(), edit, message));
     }
 
     None
 }
 
-/// Find the start position of trailing whitespace/blank lines.
-fn find_trailing_whitespace_start(source: &[u8]) -> usize {
-    let mut pos = source.len();
-
-    for &byte in source.iter().rev() {
-        if byte == b'\n' || byte == b' ' || byte == b'\t' || byte == b'\r' {
-            pos -= 1;
-        } else {
-            break;
-        }
-    }
-
-    // Include the final newline of the last content line
-    if pos < source.len() && source.get(pos) == Some(&b'\n') {
-        pos += 1;
-    }
-
-    pos
+/// Find the end of the content, before any trailing whitespace and blank lines.
+///
+/// In a file of only whitespace this is the start of the file, so the whole file is replaced.
+fn find_content_end(source: &[u8]) -> usize {
+    let trailing = source.iter().rev().take_while(|&&b| matches!(b, b'\n' | b' ' | b'\t' | b'\r')).count();
+    source.len() - trailing
 }
 
 #[cfg(test)]
 mod tests {
-    use crate::check;
+    use crate::config::Config;
+    use crate::config::layout::trailing_empty_lines::EnforcedStyle;
+    use crate::corrector::Corrector;
+    use crate::{check, check_with_config};
+
+    /// Apply this rule's fix once with the given style.
+    fn fixed(source: &[u8], style: EnforcedStyle) -> String {
+        let mut config = Config::default();
+        config.layout.trailing_empty_lines.enforced_style = style;
+        let mut corrector = Corrector::new();
+        for diagnostic in check_with_config(source, &config) {
+            if diagnostic.rule() == "Layout/TrailingEmptyLines" {
+                corrector.merge(diagnostic.fix.as_ref().unwrap()).unwrap();
+            }
+        }
+        String::from_utf8(corrector.apply(source)).unwrap()
+    }
 
     #[test]
     fn test_final_newline_ok() {
@@ -176,4 +188,40 @@ mod tests {
         let trailing = diagnostics.iter().filter(|d| d.rule() == "Layout/TrailingEmptyLines").count();
         assert_eq!(trailing, 0);
     }
+
+    #[test]
+    fn test_final_newline_autocorrect() {
+        let style = EnforcedStyle::FinalNewline;
+        assert_eq!(fixed(b"x = 1", style), "x = 1\n");
+        assert_eq!(fixed(b"x = 1\n", style), "x = 1\n");
+        assert_eq!(fixed(b"x = 1\n\n\n", style), "x = 1\n");
+        assert_eq!(fixed(b"x = 1\n  \n\n", style), "x = 1\n");
+        assert_eq!(fixed(b"\n\n\n", style), "\n");
+        assert_eq!(fixed(b"", style), "");
+    }
+
+    #[test]
+    fn test_final_blank_line_autocorrect() {
+        let style = EnforcedStyle::FinalBlankLine;
+        assert_eq!(fixed(b"x = 1", style), "x = 1\n\n");
+        assert_eq!(fixed(b"x = 1\n", style), "x = 1\n\n");
+        assert_eq!(fixed(b"x = 1\n\n", style), "x = 1\n\n");
+        assert_eq!(fixed(b"x = 1\n\n\n\n", style), "x = 1\n\n");
+        assert_eq!(fixed(b"\n", style), "\n\n");
+        assert_eq!(fixed(b"", style), "");
+    }
+
+    #[test]
+    fn test_final_blank_line_messages() {
+        let mut config = Config::default();
+        config.layout.trailing_empty_lines.enforced_style = EnforcedStyle::FinalBlankLine;
+        let message = |source: &[u8]| {
+            let diagnostics = check_with_config(source, &config);
+            diagnostics.into_iter().find(|d| d.rule() == "Layout/TrailingEmptyLines").map(|d| d.message)
+        };
+        assert_eq!(message(b"x = 1\n").as_deref(), Some("Trailing blank line missing."));
+        assert_eq!(message(b"x = 1\n\n\n").as_deref(), Some("2 trailing blank lines instead of 1 detected."));
+        assert_eq!(message(b"x = 1").as_deref(), Some("Final newline missing."));
+        assert_eq!(message(b"x = 1\n\n"), None);
+    }
 }
@@ -1,3 +1,17 @@
+//! Layout/TrailingWhitespace
+//!
+//! Checks for whitespace (spaces, tabs and fullwidth spaces) at the end of lines.
+//!
+//! # Examples
+//!
+//! ```ruby
+//! # bad (each ␣ is a space)
+//! x = 0␣␣
+//!
+//! # good
+//! x = 0
+//! ```
+
 use crate::Checker;
 use crate::Edit;
 use crate::Fix;
@@ -16,28 +30,47 @@ pub fn check(checker: &mut Checker) {
         return;
     }
     // Check cop-specific include/exclude
-    if !checker.should_run_cop(&config.base.include, &config.base.exclude) {
+    if !checker.should_run_cop(&config.base) {
         return;
     }
     let severity = config.base.severity;
 
     // Collect edit ranges first, then report them
     let edit_ranges = collect_edit_ranges(checker.source());
-    for (start, end) in edit_ranges {
+    for (start, end, kind) in edit_ranges {
         let fix = Fix::safe(vec![Edit::deletion(start, end)]);
-        checker.report(RULE_ID, "Trailing whitespace detected.".to_string(), severity, start, end, Some(fix));
+        let description = match kind {
+            LineKind::Content => "remove trailing whitespace",
+            LineKind::Blank => "remove whitespace from blank line",
+        };
+        checker.report_with_fix_description(RULE_ID, "Trailing whitespace detected.".to_string(), severity, start, end, fix, description);
     }
 }
 
-/// Collect (start, end) byte offsets of trailing whitespace.
-fn collect_edit_ranges(source: &[u8]) -> Vec<(usize, usize)> {
+/// The kind of line the trailing whitespace is on.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum LineKind {
+    /// The whitespace follows other content.
+    Content,
+    /// The line is whitespace only.
+    Blank,
+}
+
+/// Collect (start, end, kind) byte offsets of trailing whitespace.
+///
+/// On a whitespace-only line the range is the whole line, without its newline,
+/// so the fix is a pure deletion. It then merges with the deletion of
+/// `Layout/EmptyLines` when that removes the same line, rather than conflicting
+/// with it and waiting for another iteration.
+fn collect_edit_ranges(source: &[u8]) -> Vec<(usize, usize, LineKind)> {
     let mut ranges = Vec::new();
     let mut offset = 0;
     for line in source.split(|&b| b == b'\n') {
         if let Some(trailing_start) = find_trailing_whitespace_fast(line) {
             let start = offset + trailing_start;
             let end = offset + line.len();
-            ranges.push((start, end));
+            let kind = if trailing_start == 0 { LineKind::Blank } else { LineKind::Content };
+            ranges.push((start, end, kind));
         }
         offset += line.len() + 1;
     }
@@ -81,6 +114,7 @@ fn find_trailing_whitespace_fast(line: &[u8]) -> Option<usize> {
 
 #[cfg(test)]
 mod tests {
+    use super::*;
     use crate::check;
 
     #[test]
@@ -116,6 +150,14 @@ mod tests {
         assert_eq!(diagnostics[1].line_start, 2);
     }
 
+    #[test]
+    fn test_fix_description() {
+        let diagnostics = check(b"binding.pry  \n");
+        let description = |rule: &str| diagnostics.iter().find(|d| d.rule() == rule).unwrap().fix_description;
+        assert_eq!(description("Layout/TrailingWhitespace"), Some("remove trailing whitespace"));
+        assert_eq!(description("Lint/Debugger"), None);
+    }
+
     #[test]
     fn test_empty_file() {
         let source = b"";
@@ -126,7 +168,7 @@ mod tests {
     #[test]
     fn test_whitespace_only_line() {
         let source = b"def foo\n   \nend\n";
-        let diagnostics = check(source);
+        let diagnostics: Vec<_> = check(source).into_iter().filter(|d| d.rule() == "Layout/TrailingWhitespace").collect();
         assert_eq!(diagnostics.len(), 1);
         assert_eq!(diagnostics[0].line_start, 2);
     }
@@ -145,7 +187,36 @@ mod tests {
     fn test_cr_not_trailing_whitespace() {
         // CR should NOT be detected as trailing whitespace (RuboCop behavior)
         let source = b"def foo\r\n  bar\nend\n";
-        let diagnostics = check(source);
+        let diagnostics: Vec<_> = check(source).into_iter().filter(|d| d.rule() == "Layout/TrailingWhitespace").collect();
         assert!(diagnostics.is_empty());
     }
+
+    #[test]
+    fn test_blank_and_content_lines() {
+        let source = b"foo  \n   \nbar\n";
+        assert_eq!(collect_edit_ranges(source), vec![(3, 5, LineKind::Content), (6, 9, LineKind::Blank)]);
+        let diagnostics = check(source);
+        let descriptions: Vec<_> = diagnostics.iter().map(|d| d.fix_description).collect();
+        assert_eq!(
+            descriptions,
+            vec![Some("remove trailing whitespace"), Some("remove whitespace from blank line")]
+        );
+    }
+
+    #[test]
+    fn test_mixed_blank_lines_autocorrect() {
+        let source = b"x = 1  \n  \n\n \t\n\ny = 2 \n  \n\n";
+        let diagnostics = check(source);
+        let config = crate::Config::default();
+        let (fixed, corrected) = crate::apply_fixes_filtered_with_corrected(None, source, &diagnostics, &config, false, crate::FixMode::Merged, |_| true);
+        assert_eq!(fixed, b"x = 1\n\ny = 2\n");
+        assert!(check(&fixed).is_empty());
+        // The blank lines `Layout/EmptyLines` removes are corrected by both cops at once.
+        let on_line = |rule: &str, line: usize| corrected.iter().any(|d| d.rule() == rule && d.line_start == line);
+        assert!(on_line("Layout/EmptyLines", 3));
+        assert!(on_line("Layout/TrailingWhitespace", 4));
+
+        let (again, _) = crate::apply_fixes_filtered_with_corrected(None, &fixed, &check(&fixed), &config, false, crate::FixMode::Merged, |_| true);
+        assert_eq!(again, fixed);
+    }
 }
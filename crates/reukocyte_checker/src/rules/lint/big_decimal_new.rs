@@ -0,0 +1,75 @@
+use crate::checker::Checker;
+use crate::diagnostic::Applicability;
+use crate::rule::{Check, LintRule, Rule, RuleId};
+use crate::utility::deprecated_call::{DeprecatedCall, deprecated_call};
+use reukocyte_macros::check;
+use ruby_prism::*;
+
+/// The deprecated calls checked by the rule.
+const DEPRECATED_CALLS: &[DeprecatedCall] = &[DeprecatedCall {
+    receiver: "BigDecimal",
+    method: "new",
+    replacement: "",
+    applicability: Applicability::Safe,
+}];
+
+/// Get the config for this rule
+#[inline]
+fn config<'a>(checker: &'a Checker<'_>) -> &'a crate::config::lint::big_decimal_new::BigDecimalNew {
+    &checker.config().lint.big_decimal_new
+}
+
+/// Lint/BigDecimalNew rule.
+///
+/// `BigDecimal.new` was removed in BigDecimal 2.0; the `BigDecimal()` method is
+/// used instead.
+///
+/// ```ruby
+/// # bad
+/// BigDecimal.new(123.456, 3)
+///
+/// # good
+/// BigDecimal(123.456, 3)
+/// ```
+pub struct BigDecimalNew;
+impl Rule for BigDecimalNew {
+    const ID: RuleId = RuleId::Lint(LintRule::BigDecimalNew);
+}
+#[check(CallNode)]
+impl Check<CallNode<'_>> for BigDecimalNew {
+    fn check(node: &CallNode, checker: &mut Checker) {
+        let Some(offense) = deprecated_call(DEPRECATED_CALLS, node) else {
+            return;
+        };
+        let severity = config(checker).base.severity;
+        checker.report(Self::ID, offense.message, severity, offense.start, offense.end, Some(offense.fix));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{apply_fixes, check};
+
+    fn big_decimal_diagnostics(source: &[u8]) -> Vec<crate::Diagnostic> {
+        check(source).into_iter().filter(|d| d.rule_id == BigDecimalNew::ID).collect()
+    }
+
+    #[test]
+    fn test_big_decimal_new() {
+        let source = b"x = BigDecimal.new(123.456, 3)\n";
+        let diagnostics = big_decimal_diagnostics(source);
+        assert_eq!(diagnostics.len(), 1);
+        assert_eq!(diagnostics[0].message, "`BigDecimal.new()` is deprecated. Use `BigDecimal()` instead.");
+        assert_eq!((diagnostics[0].column_start, diagnostics[0].column_end), (16, 19));
+        let (fixed, _) = apply_fixes(None, source, &diagnostics, false);
+        assert_eq!(fixed, b"x = BigDecimal(123.456, 3)\n");
+    }
+
+    #[test]
+    fn test_cbase_and_other_calls() {
+        let (fixed, _) = apply_fixes(None, b"::BigDecimal.new('1')\n", &big_decimal_diagnostics(b"::BigDecimal.new('1')\n"), false);
+        assert_eq!(fixed, b"::BigDecimal('1')\n");
+        assert!(big_decimal_diagnostics(b"BigDecimal('1')\nBigDecimal.save_rounding_mode { x }\nFoo.new(1)\n").is_empty());
+    }
+}
@@ -0,0 +1,111 @@
+use crate::checker::Checker;
+use crate::diagnostic::{Edit, Fix};
+use crate::rule::{Check, LintRule, Rule, RuleId};
+use reukocyte_macros::check;
+use ruby_prism::*;
+
+/// Symbol names that are almost always mistaken for the literals of the same name.
+const LITERAL_NAMES: &[&[u8]] = &[b"true", b"false", b"nil"];
+
+/// Get the config for this rule
+#[inline]
+fn config<'a>(checker: &'a Checker<'_>) -> &'a crate::config::lint::boolean_symbol::BooleanSymbol {
+    &checker.config().lint.boolean_symbol
+}
+
+/// Lint/BooleanSymbol rule - detects `:true`, `:false` and `:nil` symbols.
+///
+/// The autocorrect is unsafe because code may really compare against the symbol.
+///
+/// ```ruby
+/// # bad
+/// foo(:true)
+/// { enabled: :false }
+///
+/// # good
+/// foo(true)
+/// { enabled: false }
+/// ```
+pub struct BooleanSymbol;
+impl Rule for BooleanSymbol {
+    const ID: RuleId = RuleId::Lint(LintRule::BooleanSymbol);
+}
+#[check(SymbolNode)]
+impl Check<SymbolNode<'_>> for BooleanSymbol {
+    fn check(node: &SymbolNode, checker: &mut Checker) {
+        // Hash keys (`true: 1`) and `%i[]` elements have no `:` opening, and
+        // quoted hash keys (`"true": 1`) close with `":`.
+        if node.opening_loc().is_none() || node.closing_loc().is_some_and(|closing| closing.as_slice().ends_with(b":")) {
+            return;
+        }
+        let name = node.unescaped();
+        if !LITERAL_NAMES.contains(&name) {
+            return;
+        }
+        let location = node.location();
+        let literal = String::from_utf8_lossy(name).into_owned();
+        let message = format!(
+            "Symbol `{}` found; use the literal `{}` instead.",
+            String::from_utf8_lossy(location.as_slice()),
+            literal
+        );
+        let fix = Fix::r#unsafe(vec![Edit::replacement(location.start_offset(), location.end_offset(), literal)]);
+        let severity = config(checker).base.severity;
+        checker.report(BooleanSymbol::ID, message, severity, location.start_offset(), location.end_offset(), Some(fix));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::check;
+
+    fn boolean_symbol_diagnostics(source: &[u8]) -> Vec<crate::Diagnostic> {
+        check(source).into_iter().filter(|d| d.rule_id == BooleanSymbol::ID).collect()
+    }
+
+    fn fixed(source: &[u8]) -> Vec<u8> {
+        crate::apply_fixes(None, source, &boolean_symbol_diagnostics(source), true).0
+    }
+
+    #[test]
+    fn test_boolean_and_nil_symbols() {
+        let diagnostics = boolean_symbol_diagnostics(b"foo(:true, :false, :nil)\n");
+        assert_eq!(diagnostics.len(), 3);
+        assert_eq!(diagnostics[0].message, "Symbol `:true` found; use the literal `true` instead.");
+        assert_eq!(diagnostics[0].fix_applicability(), Some(crate::Applicability::Unsafe));
+        assert_eq!(fixed(b"foo(:true, :false, :nil)\n"), b"foo(true, false, nil)\n");
+    }
+
+    #[test]
+    fn test_hash_value_is_flagged() {
+        assert_eq!(fixed(b"{ foo: :true }\n"), b"{ foo: true }\n");
+        assert!(boolean_symbol_diagnostics(b"{ true: 1 }\n").is_empty());
+    }
+
+    #[test]
+    fn test_quoted_hash_key_is_skipped() {
+        assert!(boolean_symbol_diagnostics(b"x = { \"true\": 1, 'nil': 2 }\n").is_empty());
+        assert_eq!(fixed(b"x = { \"true\": 1 }\n"), b"x = { \"true\": 1 }\n");
+    }
+
+    #[test]
+    fn test_quoted_symbol() {
+        assert_eq!(fixed(b"x = :\"false\"\n"), b"x = false\n");
+    }
+
+    #[test]
+    fn test_skips_other_symbols() {
+        assert!(boolean_symbol_diagnostics(b"x = :truthy\n").is_empty());
+        assert!(boolean_symbol_diagnostics(b"x = :\"#{value}\"\n").is_empty());
+        assert!(boolean_symbol_diagnostics(b"x = %i[true false]\n").is_empty());
+    }
+
+    #[test]
+    fn test_disabled_from_yaml() {
+        let yaml = crate::config::parse_rubocop_yaml("Lint/BooleanSymbol:\n  Enabled: false\n").unwrap();
+        let config = crate::Config::from_rubocop_yaml(&yaml);
+        assert!(!config.lint.boolean_symbol.base.enabled);
+        assert!(crate::check_with_config(b"foo(:true)\n", &config).is_empty());
+    }
+}
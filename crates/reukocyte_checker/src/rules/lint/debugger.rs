@@ -1,25 +1,29 @@
 use crate::Checker;
 use crate::rule::{Check, LintRule, Rule, RuleId};
 use reukocyte_macros::check;
-use ruby_prism::CallNode;
-
-/// Standalone debugger method names to detect (no receiver)
-const STANDALONE_DEBUGGERS: &[&[u8]] = &[b"debugger", b"byebug", b"remote_byebug"];
-
-/// Debugger receiver/method combinations
-const DEBUGGER_RECEIVERS: &[(&[u8], &[u8])] = &[
-    (b"binding", b"pry"),
-    (b"binding", b"remote_pry"),
-    (b"binding", b"pry_remote"),
-    (b"binding", b"irb"),
-    (b"binding", b"console"),
-    (b"Pry", b"rescue"),
-];
+use ruby_prism::{CallNode, Node};
 
 /// Lint/Debugger rule - detects debugger statements left in code.
 ///
+/// The entry points come from `DebuggerMethods`: bare names (`byebug`) match only
+/// receiverless calls, and dotted names (`binding.irb`, `Kernel.binding.pry`) match
+/// calls with exactly that receiver chain.
+///
 /// Note: No fix is provided because removing debugger statements
 /// may have side effects (e.g., debugging in production).
+///
+/// ```ruby
+/// # bad
+/// def foo
+///   binding.irb
+///   bar
+/// end
+///
+/// # good
+/// def foo
+///   bar
+/// end
+/// ```
 pub struct Debugger;
 
 impl Rule for Debugger {
@@ -41,63 +45,71 @@ impl Check<CallNode<'_>> for Debugger {
         }
         let severity = cfg.base.severity;
 
+        // Cheap filter on the method name before building the receiver chain.
         let method_name = node.name().as_slice();
+        if !cfg.debugger_methods.iter().any(|m| last_segment(m) == method_name) {
+            return;
+        }
+        let Some(chain) = call_chain(node) else {
+            return;
+        };
+        if !cfg.debugger_methods.iter().any(|m| m.as_bytes() == chain.as_slice()) {
+            return;
+        }
+
         let location = node.location();
+        checker.report(
+            Self::ID,
+            format!("Debugger statement `{}` detected.", String::from_utf8_lossy(&chain)),
+            severity,
+            location.start_offset(),
+            location.end_offset(),
+            None,
+        );
+    }
+}
+
+/// The method name of a debugger entry (`irb` for `binding.irb`).
+fn last_segment(method: &str) -> &[u8] {
+    method.rsplit('.').next().unwrap_or(method).as_bytes()
+}
 
-        // Check for standalone debugger calls (e.g., `debugger`, `byebug`)
-        if node.receiver().is_none() {
-            for &debugger_method in STANDALONE_DEBUGGERS {
-                if method_name == debugger_method {
-                    checker.report(
-                        Self::ID,
-                        format!("Debugger statement `{}` detected.", String::from_utf8_lossy(debugger_method)),
-                        severity,
-                        location.start_offset(),
-                        location.end_offset(),
-                        None,
-                    );
-                    return;
-                }
-            }
+/// The dotted receiver chain of a call (`Kernel.binding.irb`), or `None` when a
+/// receiver is anything other than constants and plain, argument-less calls.
+fn call_chain(node: &CallNode) -> Option<Vec<u8>> {
+    let mut chain = match node.receiver() {
+        None => Vec::new(),
+        Some(receiver) => {
+            let mut chain = receiver_chain(&receiver)?;
+            chain.push(b'.');
+            chain
         }
+    };
+    chain.extend_from_slice(node.name().as_slice());
+    Some(chain)
+}
 
-        // Check for receiver.method calls (e.g., `binding.pry`)
-        if let Some(receiver) = node.receiver() {
-            let receiver_name: Option<&[u8]> = if let Some(call) = receiver.as_call_node() {
-                Some(call.name().as_slice())
-            } else if let Some(const_node) = receiver.as_constant_read_node() {
-                Some(const_node.name().as_slice())
-            } else {
-                None
-            };
-
-            if let Some(recv_name) = receiver_name {
-                for &(expected_recv, expected_method) in DEBUGGER_RECEIVERS {
-                    if recv_name == expected_recv && method_name == expected_method {
-                        checker.report(
-                            Self::ID,
-                            format!(
-                                "Debugger statement `{}.{}` detected.",
-                                String::from_utf8_lossy(expected_recv),
-                                String::from_utf8_lossy(expected_method)
-                            ),
-                            severity,
-                            location.start_offset(),
-                            location.end_offset(),
-                            None,
-                        );
-                        return;
-                    }
-                }
-            }
+fn receiver_chain(receiver: &Node) -> Option<Vec<u8>> {
+    if let Some(call) = receiver.as_call_node() {
+        if call.arguments().is_some() || call.block().is_some() {
+            return None;
         }
+        call_chain(&call)
+    } else if let Some(constant) = receiver.as_constant_read_node() {
+        Some(constant.name().as_slice().to_vec())
+    } else if let Some(path) = receiver.as_constant_path_node() {
+        let source = path.location().as_slice();
+        Some(source.strip_prefix(b"::").unwrap_or(source).to_vec())
+    } else {
+        None
     }
 }
 
 #[cfg(test)]
 mod tests {
-    use crate::check;
+    use crate::config::{Config, parse_rubocop_yaml};
     use crate::rule::{LintRule, RuleId};
+    use crate::{check, check_with_config};
 
     #[test]
     fn test_no_debugger() {
@@ -155,4 +167,23 @@ mod tests {
         assert_eq!(debugger_diagnostics.len(), 1);
         assert!(debugger_diagnostics[0].message.contains("Pry.rescue"));
     }
+
+    #[test]
+    fn test_receiver_chains() {
+        let source = b"binding.break\nKernel.binding.irb\nbinding.remote_pry\n";
+        assert_eq!(check(source).len(), 3);
+        // `irb` alone is not `binding.irb`, and `foo.byebug` is not the bare `byebug`.
+        let source = b"irb\nfoo.byebug\nbinding.pry_foo\nbinding(1).irb\n";
+        assert!(check(source).iter().all(|d| d.rule_id != RuleId::Lint(LintRule::Debugger)));
+    }
+
+    #[test]
+    fn test_custom_debugger_methods() {
+        let yaml = "Lint/Debugger:\n  DebuggerMethods:\n    Custom:\n      - my_debugger\n";
+        let config = Config::from_rubocop_yaml(&parse_rubocop_yaml(yaml).unwrap());
+        let source = b"my_debugger\nbinding.irb\nbyebug\n";
+        let diagnostics = check_with_config(source, &config);
+        assert_eq!(diagnostics.len(), 3);
+        assert!(diagnostics[0].message.contains("my_debugger"));
+    }
 }
@@ -0,0 +1,100 @@
+use crate::checker::Checker;
+use crate::rule::{Check, LintRule, Rule, RuleId};
+use reukocyte_macros::check;
+use ruby_prism::*;
+
+/// Get the config for this rule
+#[inline]
+fn config<'a>(checker: &'a Checker<'_>) -> &'a crate::config::lint::else_layout::ElseLayout {
+    &checker.config().lint.else_layout
+}
+
+/// Lint/ElseLayout rule.
+///
+/// Detects an expression on the same line as `else`, which usually was meant to go
+/// on the next line or to be an `elsif` condition. An `else;` is taken as deliberate.
+///
+/// ```ruby
+/// # bad
+/// if something
+///   foo
+/// else bar
+///   baz
+/// end
+///
+/// # good
+/// if something
+///   foo
+/// else
+///   bar
+///   baz
+/// end
+/// ```
+pub struct ElseLayout;
+impl Rule for ElseLayout {
+    const ID: RuleId = RuleId::Lint(LintRule::ElseLayout);
+}
+#[check(ElseNode)]
+impl Check<ElseNode<'_>> for ElseLayout {
+    fn check(node: &ElseNode, checker: &mut Checker) {
+        let keyword = node.else_keyword_loc();
+        // The `:` of a ternary is also an `ElseNode`.
+        if keyword.as_slice() != b"else" {
+            return;
+        }
+        let Some(first) = node.statements().and_then(|statements| statements.body().iter().next()) else {
+            return;
+        };
+        let location = first.location();
+        if !checker.line_index().are_on_same_line(keyword.end_offset(), location.start_offset()) {
+            return;
+        }
+        if checker.source()[keyword.end_offset()..location.start_offset()].contains(&b';') {
+            return;
+        }
+        let severity = config(checker).base.severity;
+        checker.report(
+            ElseLayout::ID,
+            "Odd `else` layout detected. Did you mean to use `elsif`?".to_string(),
+            severity,
+            location.start_offset(),
+            location.end_offset(),
+            None,
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::check;
+
+    fn else_layout_diagnostics(source: &[u8]) -> Vec<crate::Diagnostic> {
+        check(source).into_iter().filter(|d| d.rule_id == ElseLayout::ID).collect()
+    }
+
+    #[test]
+    fn test_expression_on_else_line() {
+        let diagnostics = else_layout_diagnostics(b"if something\n  foo\nelse do_thing\n  bar\nend\n");
+        assert_eq!(diagnostics.len(), 1);
+        assert_eq!(diagnostics[0].message, "Odd `else` layout detected. Did you mean to use `elsif`?");
+        assert_eq!((diagnostics[0].line_start, diagnostics[0].column_start, diagnostics[0].column_end), (3, 6, 14));
+        assert!(diagnostics[0].fix.is_none());
+    }
+
+    #[test]
+    fn test_case_and_one_liners() {
+        assert_eq!(else_layout_diagnostics(b"case x\nwhen 1 then foo\nelse bar\nend\n").len(), 1);
+        assert_eq!(else_layout_diagnostics(b"unless x\n  foo\nelse bar\nend\n").len(), 1);
+        assert_eq!(else_layout_diagnostics(b"if x then foo else bar end\n").len(), 1);
+    }
+
+    #[test]
+    fn test_accepted_layouts() {
+        assert!(else_layout_diagnostics(b"if x\n  foo\nelse\n  bar\nend\n").is_empty());
+        assert!(else_layout_diagnostics(b"if x\n  foo\nelse # comment\n  bar\nend\n").is_empty());
+        assert!(else_layout_diagnostics(b"if x\n  foo\nelse; bar\nend\n").is_empty());
+        assert!(else_layout_diagnostics(b"y = x ? foo : bar\n").is_empty());
+        assert!(else_layout_diagnostics(b"if x\n  foo\nelse\nend\n").is_empty());
+    }
+}
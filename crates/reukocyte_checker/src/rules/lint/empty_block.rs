@@ -0,0 +1,132 @@
+use crate::checker::Checker;
+use crate::rule::{Check, LintRule, Rule, RuleId};
+use reukocyte_macros::check;
+use ruby_prism::*;
+
+const MSG: &str = "Empty block detected.";
+
+/// Get the config for this rule
+#[inline]
+fn config<'a>(checker: &'a Checker<'_>) -> &'a crate::config::lint::empty_block::EmptyBlock {
+    &checker.config().lint.empty_block
+}
+
+/// Lint/EmptyBlock rule.
+///
+/// Detects blocks without a body, which are usually unfinished. A block
+/// with a comment on its lines is allowed with `AllowComments`, and empty lambdas
+/// and procs with `AllowEmptyLambdas`.
+///
+/// ```ruby
+/// # bad
+/// items.each { |item| }
+///
+/// # good
+/// items.each { |item| puts item }
+/// items.each do |item|
+///   # TODO: process items
+/// end
+/// noop = -> {}
+/// ```
+pub struct EmptyBlock;
+impl Rule for EmptyBlock {
+    const ID: RuleId = RuleId::Lint(LintRule::EmptyBlock);
+}
+#[check(BlockNode)]
+impl Check<BlockNode<'_>> for EmptyBlock {
+    fn check(node: &BlockNode, checker: &mut Checker) {
+        if node.body().is_some() {
+            return;
+        }
+        let call = checker.semantic().parent().and_then(|parent| parent.as_call_node());
+        if config(checker).allow_empty_lambdas && call.as_ref().is_some_and(is_lambda_or_proc) {
+            return;
+        }
+        let start = call.map_or(node.location().start_offset(), |call| call.location().start_offset());
+        check_empty(start, &node.closing_loc(), checker);
+    }
+}
+#[check(LambdaNode)]
+impl Check<LambdaNode<'_>> for EmptyBlock {
+    fn check(node: &LambdaNode, checker: &mut Checker) {
+        if node.body().is_some() || config(checker).allow_empty_lambdas {
+            return;
+        }
+        check_empty(node.location().start_offset(), &node.closing_loc(), checker);
+    }
+}
+
+/// Report an empty block from `start` unless a comment on its lines is allowed.
+///
+/// Like RuboCop, any comment on the lines of the block counts, including one
+/// after it on its last line.
+fn check_empty(start: usize, closing: &Location, checker: &mut Checker) {
+    let config = config(checker);
+    let line_index = checker.line_index();
+    let (lines_start, lines_end) = (line_index.line_start_offset(start), line_index.line_end_offset(closing.start_offset()));
+    if config.allow_comments && checker.comments().iter().any(|&(comment, _)| lines_start <= comment && comment < lines_end) {
+        return;
+    }
+    let severity = config.base.severity;
+    checker.report(EmptyBlock::ID, MSG.to_string(), severity, start, closing.end_offset(), None);
+}
+
+/// Check if a call builds a lambda or a proc: `lambda`, `proc` or `Proc.new`.
+fn is_lambda_or_proc(call: &CallNode) -> bool {
+    match call.receiver() {
+        None => matches!(call.name().as_slice(), b"lambda" | b"proc"),
+        Some(receiver) => call.name().as_slice() == b"new" && receiver.as_constant_read_node().is_some_and(|constant| constant.name().as_slice() == b"Proc"),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::check;
+
+    fn empty_block_diagnostics(source: &[u8]) -> Vec<crate::Diagnostic> {
+        check(source).into_iter().filter(|d| d.rule_id == EmptyBlock::ID).collect()
+    }
+
+    fn empty_block_diagnostics_with(source: &[u8], allow_comments: bool, allow_empty_lambdas: bool) -> Vec<crate::Diagnostic> {
+        let mut config = crate::Config::default();
+        config.lint.empty_block.allow_comments = allow_comments;
+        config.lint.empty_block.allow_empty_lambdas = allow_empty_lambdas;
+        crate::check_with_config(source, &config)
+            .into_iter()
+            .filter(|d| d.rule_id == EmptyBlock::ID)
+            .collect()
+    }
+
+    #[test]
+    fn test_empty_blocks() {
+        let diagnostics = empty_block_diagnostics(b"x = 1\nfoo {}\n");
+        assert_eq!(diagnostics.len(), 1);
+        assert_eq!(diagnostics[0].message, MSG);
+        assert_eq!(diagnostics[0].severity, crate::Severity::Warning);
+        assert_eq!((diagnostics[0].line_start, diagnostics[0].column_start, diagnostics[0].column_end), (2, 1, 7));
+        assert!(diagnostics[0].fix.is_none());
+        assert_eq!(empty_block_diagnostics(b"items.each do |item|\nend\n").len(), 1);
+        assert_eq!(empty_block_diagnostics(b"it 'does nothing' do\nend\n").len(), 1);
+        assert!(empty_block_diagnostics(b"foo { _1 }\n").is_empty());
+        assert!(empty_block_diagnostics(b"foo { bar }\n").is_empty());
+    }
+
+    #[test]
+    fn test_allow_comments() {
+        let source = b"foo do # noop\nend\nbar {\n  # noop\n}\n";
+        assert!(empty_block_diagnostics(source).is_empty());
+        assert_eq!(empty_block_diagnostics_with(source, false, true).len(), 2);
+        // A comment after the block on its line counts, one on the next line does not.
+        assert!(empty_block_diagnostics(b"items.each { |x| } # comment\n").is_empty());
+        assert_eq!(empty_block_diagnostics_with(b"items.each { |x| } # comment\n", false, true).len(), 1);
+        assert_eq!(empty_block_diagnostics(b"foo {}\n# noop\n").len(), 1);
+    }
+
+    #[test]
+    fn test_allow_empty_lambdas() {
+        let source = b"a = -> {}\nb = lambda {}\nc = proc {}\nd = Proc.new {}\n";
+        assert!(empty_block_diagnostics(source).is_empty());
+        assert_eq!(empty_block_diagnostics_with(source, true, false).len(), 4);
+    }
+}
@@ -0,0 +1,81 @@
+use crate::checker::Checker;
+use crate::diagnostic::{Edit, Fix};
+use crate::rule::{Check, LintRule, Rule, RuleId};
+use reukocyte_macros::check;
+use ruby_prism::*;
+
+const MSG: &str = "Empty interpolation detected.";
+
+/// Get the config for this rule
+#[inline]
+fn config<'a>(checker: &'a Checker<'_>) -> &'a crate::config::lint::empty_interpolation::EmptyInterpolation {
+    &checker.config().lint.empty_interpolation
+}
+
+/// Lint/EmptyInterpolation rule - detects interpolations without any statements.
+///
+/// ```ruby
+/// # bad
+/// "result is #{}"
+///
+/// # good
+/// "result is #{some_result}"
+/// ```
+pub struct EmptyInterpolation;
+impl Rule for EmptyInterpolation {
+    const ID: RuleId = RuleId::Lint(LintRule::EmptyInterpolation);
+}
+#[check(EmbeddedStatementsNode)]
+impl Check<EmbeddedStatementsNode<'_>> for EmptyInterpolation {
+    fn check(node: &EmbeddedStatementsNode, checker: &mut Checker) {
+        if node.statements().is_some_and(|statements| statements.body().iter().next().is_some()) {
+            return;
+        }
+        let location = node.location();
+        let fix = Fix::safe(vec![Edit::deletion(location.start_offset(), location.end_offset())]);
+        let severity = config(checker).base.severity;
+        checker.report(
+            EmptyInterpolation::ID,
+            MSG.to_string(),
+            severity,
+            location.start_offset(),
+            location.end_offset(),
+            Some(fix),
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::check;
+
+    fn empty_interpolation_diagnostics(source: &[u8]) -> Vec<crate::Diagnostic> {
+        check(source).into_iter().filter(|d| d.rule_id == EmptyInterpolation::ID).collect()
+    }
+
+    fn fixed(source: &[u8]) -> Vec<u8> {
+        crate::apply_fixes(None, source, &empty_interpolation_diagnostics(source), false).0
+    }
+
+    #[test]
+    fn test_empty_interpolation() {
+        let diagnostics = empty_interpolation_diagnostics(b"x = \"a#{}b\"\n");
+        assert_eq!(diagnostics.len(), 1);
+        assert_eq!(diagnostics[0].message, MSG);
+        assert_eq!(diagnostics[0].column_start, 7);
+        assert_eq!(fixed(b"x = \"a#{}b\"\n"), b"x = \"ab\"\n");
+    }
+
+    #[test]
+    fn test_whitespace_only_interpolation() {
+        assert_eq!(fixed(b"x = \"a#{ }b\"\n"), b"x = \"ab\"\n");
+        assert_eq!(fixed(b"x = :\"a#{}\"\n"), b"x = :\"a\"\n");
+    }
+
+    #[test]
+    fn test_non_empty_interpolation() {
+        assert!(empty_interpolation_diagnostics(b"x = \"a#{b}c\"\n").is_empty());
+        assert!(empty_interpolation_diagnostics(b"x = 'a#{}b'\n").is_empty());
+    }
+}
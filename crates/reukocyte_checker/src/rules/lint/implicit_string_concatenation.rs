@@ -0,0 +1,126 @@
+use crate::checker::Checker;
+use crate::rule::{Check, LintRule, Rule, RuleId};
+use reukocyte_macros::check;
+use ruby_prism::*;
+
+const FOR_ARRAY: &str = " Or, if they were intended to be separate array elements, separate them with a comma.";
+const FOR_METHOD: &str = " Or, if they were intended to be separate method arguments, separate them with a comma.";
+
+/// Get the config for this rule
+#[inline]
+fn config<'a>(checker: &'a Checker<'_>) -> &'a crate::config::lint::implicit_string_concatenation::ImplicitStringConcatenation {
+    &checker.config().lint.implicit_string_concatenation
+}
+
+/// Lint/ImplicitStringConcatenation rule.
+///
+/// Detects string literals next to each other on one line, which Ruby joins
+/// into one string. In an array or an argument list this is usually a missing
+/// comma. Literals continued on the next line with `\` are taken as deliberate.
+///
+/// ```ruby
+/// # bad
+/// array = ['Item 1' 'Item 2']
+///
+/// # good
+/// array = ['Item 1', 'Item 2']
+/// text = 'Item 1' \
+///   'Item 2'
+/// ```
+pub struct ImplicitStringConcatenation;
+impl Rule for ImplicitStringConcatenation {
+    const ID: RuleId = RuleId::Lint(LintRule::ImplicitStringConcatenation);
+}
+#[check(InterpolatedStringNode)]
+impl Check<InterpolatedStringNode<'_>> for ImplicitStringConcatenation {
+    fn check(node: &InterpolatedStringNode, checker: &mut Checker) {
+        // Implicit concatenation has no delimiters of its own, only its parts do.
+        if node.opening_loc().is_some() {
+            return;
+        }
+        let parts: Vec<Node> = node.parts().iter().collect();
+        let suffix = match checker.semantic().parent() {
+            Some(Node::ArrayNode { .. }) => FOR_ARRAY,
+            Some(Node::ArgumentsNode { .. }) => FOR_METHOD,
+            _ => "",
+        };
+        for pair in parts.windows(2) {
+            let (lhs, rhs) = (pair[0].location(), pair[1].location());
+            if !is_string_literal(&pair[0]) || !is_string_literal(&pair[1]) {
+                continue;
+            }
+            if !checker.line_index().are_on_same_line(lhs.end_offset(), rhs.start_offset()) {
+                continue;
+            }
+            let message = format!(
+                "Combine {} and {} into a single string literal, rather than using implicit string concatenation.{}",
+                display_string(&lhs),
+                display_string(&rhs),
+                suffix
+            );
+            let severity = config(checker).base.severity;
+            checker.report(ImplicitStringConcatenation::ID, message, severity, rhs.start_offset(), rhs.end_offset(), None);
+        }
+    }
+}
+
+/// Check if a part of a concatenation is a string literal of its own, not a heredoc.
+fn is_string_literal(node: &Node) -> bool {
+    let opening = match node {
+        Node::StringNode { .. } => node.as_string_node().unwrap().opening_loc(),
+        Node::InterpolatedStringNode { .. } => node.as_interpolated_string_node().unwrap().opening_loc(),
+        _ => None,
+    };
+    opening.is_some_and(|opening| !opening.as_slice().starts_with(b"<<"))
+}
+
+/// Show a literal as written, or escaped when it spans lines.
+fn display_string(location: &Location) -> String {
+    let source = String::from_utf8_lossy(location.as_slice());
+    if source.contains('\n') {
+        format!("{:?}", source)
+    } else {
+        source.into_owned()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::check;
+
+    fn concatenation_diagnostics(source: &[u8]) -> Vec<crate::Diagnostic> {
+        check(source).into_iter().filter(|d| d.rule_id == ImplicitStringConcatenation::ID).collect()
+    }
+
+    #[test]
+    fn test_array_elements() {
+        let diagnostics = concatenation_diagnostics(b"x = [\"a\" \"b\"]\n");
+        assert_eq!(diagnostics.len(), 1);
+        assert_eq!(
+            diagnostics[0].message,
+            "Combine \"a\" and \"b\" into a single string literal, rather than using implicit string concatenation. \
+             Or, if they were intended to be separate array elements, separate them with a comma."
+        );
+        assert_eq!((diagnostics[0].line_start, diagnostics[0].column_start, diagnostics[0].column_end), (1, 10, 13));
+        assert!(diagnostics[0].fix.is_none());
+    }
+
+    #[test]
+    fn test_arguments_and_other_positions() {
+        let diagnostics = concatenation_diagnostics(b"foo(\"a#{1}\" 'b')\n");
+        assert_eq!(diagnostics.len(), 1);
+        assert!(diagnostics[0].message.ends_with("separate method arguments, separate them with a comma."));
+        let diagnostics = concatenation_diagnostics(b"x = 'a' 'b' 'c'\n");
+        assert_eq!(diagnostics.len(), 2);
+        assert!(diagnostics[0].message.ends_with("implicit string concatenation."));
+    }
+
+    #[test]
+    fn test_accepted_strings() {
+        assert!(concatenation_diagnostics(b"x = 'a' \\\n  'b'\n").is_empty());
+        assert!(concatenation_diagnostics(b"x = ['a', 'b']\n").is_empty());
+        assert!(concatenation_diagnostics(b"x = \"a#{b}c\"\n").is_empty());
+        assert!(concatenation_diagnostics(b"x = <<~A\n  text\nA\n").is_empty());
+    }
+}
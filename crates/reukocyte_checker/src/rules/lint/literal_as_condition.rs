@@ -0,0 +1,156 @@
+use crate::checker::Checker;
+use crate::rule::{Check, LintRule, Rule, RuleId};
+use reukocyte_macros::check;
+use ruby_prism::*;
+
+/// Get the config for this rule
+#[inline]
+fn config<'a>(checker: &'a Checker<'_>) -> &'a crate::config::lint::literal_as_condition::LiteralAsCondition {
+    &checker.config().lint.literal_as_condition
+}
+
+/// Lint/LiteralAsCondition rule.
+///
+/// Detects a literal used as the condition of `if`, `unless`, `while`, `until`,
+/// `case` or a ternary, which makes the branch taken constant. `while true` and
+/// `until false` are allowed by default (`AllowInfiniteLoops`).
+///
+/// ```ruby
+/// # bad
+/// if true
+///   foo
+/// end
+/// bar while 1
+///
+/// # good
+/// if enabled?
+///   foo
+/// end
+/// while true
+///   break if done?
+/// end
+/// ```
+pub struct LiteralAsCondition;
+impl Rule for LiteralAsCondition {
+    const ID: RuleId = RuleId::Lint(LintRule::LiteralAsCondition);
+}
+#[check(IfNode)]
+impl Check<IfNode<'_>> for LiteralAsCondition {
+    fn check(node: &IfNode, checker: &mut Checker) {
+        check_condition(&node.predicate(), checker);
+    }
+}
+#[check(UnlessNode)]
+impl Check<UnlessNode<'_>> for LiteralAsCondition {
+    fn check(node: &UnlessNode, checker: &mut Checker) {
+        check_condition(&node.predicate(), checker);
+    }
+}
+#[check(WhileNode)]
+impl Check<WhileNode<'_>> for LiteralAsCondition {
+    fn check(node: &WhileNode, checker: &mut Checker) {
+        let predicate = node.predicate();
+        if predicate.as_true_node().is_some() && config(checker).allow_infinite_loops {
+            return;
+        }
+        check_condition(&predicate, checker);
+    }
+}
+#[check(UntilNode)]
+impl Check<UntilNode<'_>> for LiteralAsCondition {
+    fn check(node: &UntilNode, checker: &mut Checker) {
+        let predicate = node.predicate();
+        if predicate.as_false_node().is_some() && config(checker).allow_infinite_loops {
+            return;
+        }
+        check_condition(&predicate, checker);
+    }
+}
+#[check(CaseNode)]
+impl Check<CaseNode<'_>> for LiteralAsCondition {
+    fn check(node: &CaseNode, checker: &mut Checker) {
+        if let Some(predicate) = node.predicate() {
+            check_condition(&predicate, checker);
+        }
+    }
+}
+
+fn check_condition(condition: &Node, checker: &mut Checker) {
+    if !is_literal(condition) {
+        return;
+    }
+    let location = condition.location();
+    let message = format!("Literal `{}` appeared as a condition.", String::from_utf8_lossy(location.as_slice()));
+    let severity = config(checker).base.severity;
+    checker.report(LiteralAsCondition::ID, message, severity, location.start_offset(), location.end_offset(), None);
+}
+
+/// Check if a node is a literal whose truthiness is known without running the code.
+///
+/// Interpolated strings and regular expressions are not: a bare regexp condition
+/// matches against `$_`.
+fn is_literal(node: &Node) -> bool {
+    matches!(
+        node,
+        Node::TrueNode { .. }
+            | Node::FalseNode { .. }
+            | Node::NilNode { .. }
+            | Node::IntegerNode { .. }
+            | Node::FloatNode { .. }
+            | Node::RationalNode { .. }
+            | Node::ImaginaryNode { .. }
+            | Node::StringNode { .. }
+            | Node::SymbolNode { .. }
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::check;
+
+    fn literal_diagnostics(source: &[u8]) -> Vec<crate::Diagnostic> {
+        check(source).into_iter().filter(|d| d.rule_id == LiteralAsCondition::ID).collect()
+    }
+
+    #[test]
+    fn test_if_true_is_flagged() {
+        let diagnostics = literal_diagnostics(b"if true\n  foo\nend\n");
+        assert_eq!(diagnostics.len(), 1);
+        assert_eq!(diagnostics[0].message, "Literal `true` appeared as a condition.");
+        assert_eq!((diagnostics[0].line_start, diagnostics[0].column_start, diagnostics[0].column_end), (1, 4, 8));
+        assert!(diagnostics[0].fix.is_none());
+    }
+
+    #[test]
+    fn test_other_conditions() {
+        assert_eq!(literal_diagnostics(b"foo unless \"string\"\n").len(), 1);
+        assert_eq!(literal_diagnostics(b"bar while 1\n").len(), 1);
+        assert_eq!(literal_diagnostics(b"x = nil ? 1 : 2\n").len(), 1);
+        assert_eq!(literal_diagnostics(b"case :a\nwhen :a then foo\nend\n").len(), 1);
+        assert_eq!(literal_diagnostics(b"if foo\nelsif 2.5\nend\n").len(), 1);
+    }
+
+    #[test]
+    fn test_non_literal_conditions() {
+        assert!(literal_diagnostics(b"if foo?\n  bar\nend\n").is_empty());
+        assert!(literal_diagnostics(b"if \"#{foo}\"\n  bar\nend\n").is_empty());
+        assert!(literal_diagnostics(b"case\nwhen foo then bar\nend\n").is_empty());
+        assert!(literal_diagnostics(b"x = [1].empty? ? 1 : 2\n").is_empty());
+    }
+
+    #[test]
+    fn test_infinite_loops() {
+        let source = b"while true\n  break if done?\nend\nuntil false\n  break\nend\n";
+        assert!(literal_diagnostics(source).is_empty());
+        assert_eq!(literal_diagnostics(b"until true\n  foo\nend\n").len(), 1);
+
+        let yaml = crate::config::parse_rubocop_yaml("Lint/LiteralAsCondition:\n  AllowInfiniteLoops: false\n").unwrap();
+        let config = crate::Config::from_rubocop_yaml(&yaml);
+        let diagnostics: Vec<_> = crate::check_with_config(source, &config)
+            .into_iter()
+            .filter(|d| d.rule_id == LiteralAsCondition::ID)
+            .collect();
+        assert_eq!(diagnostics.len(), 2);
+    }
+}
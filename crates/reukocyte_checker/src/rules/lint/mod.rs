@@ -1 +1,14 @@
+pub mod big_decimal_new;
+pub mod boolean_symbol;
 pub mod debugger;
+pub mod else_layout;
+pub mod empty_block;
+pub mod empty_interpolation;
+pub mod implicit_string_concatenation;
+pub mod literal_as_condition;
+pub mod percent_string_array;
+pub mod percent_symbol_array;
+pub mod redundant_with_index;
+pub mod redundant_with_object;
+pub mod script_permission;
+pub mod to_enum_arguments;
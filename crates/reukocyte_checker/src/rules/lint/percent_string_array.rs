@@ -0,0 +1,123 @@
+use crate::checker::Checker;
+use crate::diagnostic::{Edit, Fix};
+use crate::rule::{Check, LintRule, Rule, RuleId};
+use reukocyte_macros::check;
+use ruby_prism::*;
+
+const MSG: &str = "Within `%w`/`%W`, quotes and ',' are unnecessary and may be unwanted in the resulting strings.";
+
+/// Get the config for this rule
+#[inline]
+fn config<'a>(checker: &'a Checker<'_>) -> &'a crate::config::lint::percent_string_array::PercentStringArray {
+    &checker.config().lint.percent_string_array
+}
+
+/// Lint/PercentStringArray rule - detects quotes and commas inside `%w`/`%W` literals.
+///
+/// Elements of a percent literal are separated by whitespace only, so quotes and
+/// commas end up in the strings themselves. Elements without any alphanumeric
+/// character (`%w(' ")`) and escaped quotes are assumed to be intentional.
+/// The autocorrect is unsafe because the characters may really be wanted.
+///
+/// ```ruby
+/// # bad
+/// %w('foo', "bar")
+///
+/// # good
+/// %w(foo bar)
+/// ```
+pub struct PercentStringArray;
+impl Rule for PercentStringArray {
+    const ID: RuleId = RuleId::Lint(LintRule::PercentStringArray);
+}
+#[check(ArrayNode)]
+impl Check<ArrayNode<'_>> for PercentStringArray {
+    fn check(node: &ArrayNode, checker: &mut Checker) {
+        let Some(opening) = node.opening_loc() else {
+            return;
+        };
+        if !matches!(opening.as_slice().get(..2), Some(b"%w" | b"%W")) {
+            return;
+        }
+        let severity = config(checker).base.severity;
+        for element in node.elements().iter() {
+            let location = element.location();
+            let Some(edits) = punctuation_edits(location.as_slice(), location.start_offset()) else {
+                continue;
+            };
+            let fix = Fix::r#unsafe(edits);
+            checker.report(
+                PercentStringArray::ID,
+                MSG.to_string(),
+                severity,
+                location.start_offset(),
+                location.end_offset(),
+                Some(fix),
+            );
+        }
+    }
+}
+
+/// Get the deletions removing a trailing comma and surrounding quotes from an
+/// element, or `None` if it has neither.
+fn punctuation_edits(source: &[u8], start: usize) -> Option<Vec<Edit>> {
+    // To avoid likely false positives (e.g. a lone `'` or `,`).
+    if !source.iter().any(|b| b.is_ascii_alphanumeric()) {
+        return None;
+    }
+    let mut edits = Vec::new();
+    let mut content = source;
+    if let Some(rest) = content.strip_suffix(b",") {
+        edits.push(Edit::deletion(start + rest.len(), start + source.len()));
+        content = rest;
+    }
+    for quote in [b'\'', b'"'] {
+        if content.len() >= 2 && content[0] == quote && content[content.len() - 1] == quote {
+            edits.push(Edit::deletion(start, start + 1));
+            edits.push(Edit::deletion(start + content.len() - 1, start + content.len()));
+            break;
+        }
+    }
+    if edits.is_empty() { None } else { Some(edits) }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::check;
+
+    fn percent_string_array_diagnostics(source: &[u8]) -> Vec<crate::Diagnostic> {
+        check(source).into_iter().filter(|d| d.rule_id == PercentStringArray::ID).collect()
+    }
+
+    fn fixed(source: &[u8]) -> Vec<u8> {
+        crate::apply_fixes(None, source, &percent_string_array_diagnostics(source), true).0
+    }
+
+    #[test]
+    fn test_quotes_and_commas() {
+        let diagnostics = percent_string_array_diagnostics(b"x = %w['a', 'b']\n");
+        assert_eq!(diagnostics.len(), 2);
+        assert_eq!(diagnostics[0].message, MSG);
+        assert_eq!(diagnostics[0].fix_applicability(), Some(crate::Applicability::Unsafe));
+        assert_eq!(fixed(b"x = %w['a', 'b']\n"), b"x = %w[a b]\n");
+    }
+
+    #[test]
+    fn test_double_quotes_and_bare_commas() {
+        assert_eq!(fixed(b"x = %W(\"foo\" bar, baz)\n"), b"x = %W(foo bar baz)\n");
+    }
+
+    #[test]
+    fn test_intentional_punctuation_is_allowed() {
+        assert!(percent_string_array_diagnostics(b"x = %w(' \" ,)\n").is_empty());
+        assert!(percent_string_array_diagnostics(b"x = %w(\\'a\\')\n").is_empty());
+        assert!(percent_string_array_diagnostics(b"x = %w(it's a,b)\n").is_empty());
+    }
+
+    #[test]
+    fn test_other_arrays_are_ignored() {
+        assert!(percent_string_array_diagnostics(b"x = ['a', 'b']\n").is_empty());
+        assert!(percent_string_array_diagnostics(b"x = %w[a b]\n").is_empty());
+    }
+}
@@ -0,0 +1,104 @@
+use crate::checker::Checker;
+use crate::rule::{Check, LintRule, Rule, RuleId};
+use reukocyte_macros::check;
+use ruby_prism::*;
+
+const MSG: &str = "Within `%i`/`%I`, ':' and ',' are unnecessary and may be unwanted in the resulting symbols.";
+
+/// Get the config for this rule
+#[inline]
+fn config<'a>(checker: &'a Checker<'_>) -> &'a crate::config::lint::percent_symbol_array::PercentSymbolArray {
+    &checker.config().lint.percent_symbol_array
+}
+
+/// Lint/PercentSymbolArray rule - detects colons and commas inside `%i`/`%I` literals.
+///
+/// Elements of a percent literal are separated by whitespace only, so a leading
+/// `:` or a trailing `,` ends up in the symbols themselves. Elements without any
+/// alphanumeric character (`%i(: ,)`) are assumed to be intentional.
+///
+/// ```ruby
+/// # bad
+/// %i(:foo, :bar)
+///
+/// # good
+/// %i(foo bar)
+/// ```
+pub struct PercentSymbolArray;
+impl Rule for PercentSymbolArray {
+    const ID: RuleId = RuleId::Lint(LintRule::PercentSymbolArray);
+}
+#[check(ArrayNode)]
+impl Check<ArrayNode<'_>> for PercentSymbolArray {
+    fn check(node: &ArrayNode, checker: &mut Checker) {
+        let Some(opening) = node.opening_loc() else {
+            return;
+        };
+        if !matches!(opening.as_slice().get(..2), Some(b"%i" | b"%I")) {
+            return;
+        }
+        let severity = config(checker).base.severity;
+        for element in node.elements().iter() {
+            let location = element.location();
+            if !has_symbol_punctuation(location.as_slice()) {
+                continue;
+            }
+            checker.report(
+                PercentSymbolArray::ID,
+                MSG.to_string(),
+                severity,
+                location.start_offset(),
+                location.end_offset(),
+                None,
+            );
+        }
+    }
+}
+
+/// Check if an element starts with `:` or ends with `,`.
+fn has_symbol_punctuation(source: &[u8]) -> bool {
+    // To avoid likely false positives (e.g. a lone `:` or `,`).
+    if !source.iter().any(|b| b.is_ascii_alphanumeric()) {
+        return false;
+    }
+    source.starts_with(b":") || source.ends_with(b",")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::check;
+
+    fn percent_symbol_array_diagnostics(source: &[u8]) -> Vec<crate::Diagnostic> {
+        check(source).into_iter().filter(|d| d.rule_id == PercentSymbolArray::ID).collect()
+    }
+
+    #[test]
+    fn test_colons() {
+        let diagnostics = percent_symbol_array_diagnostics(b"x = %i[:a :b]\n");
+        assert_eq!(diagnostics.len(), 2);
+        assert_eq!(diagnostics[0].message, MSG);
+        assert_eq!(diagnostics[0].severity, crate::Severity::Warning);
+        assert_eq!(diagnostics[1].column_start, 11);
+        assert!(diagnostics[0].fix.is_none());
+    }
+
+    #[test]
+    fn test_commas() {
+        assert_eq!(percent_symbol_array_diagnostics(b"x = %i(:a, :b)\n").len(), 2);
+        assert_eq!(percent_symbol_array_diagnostics(b"x = %I(a, b c)\n").len(), 1);
+    }
+
+    #[test]
+    fn test_intentional_punctuation_is_allowed() {
+        assert!(percent_symbol_array_diagnostics(b"x = %i(: , ::)\n").is_empty());
+        assert!(percent_symbol_array_diagnostics(b"x = %i(a:b)\n").is_empty());
+    }
+
+    #[test]
+    fn test_other_arrays_are_ignored() {
+        assert!(percent_symbol_array_diagnostics(b"x = [:a, :b]\n").is_empty());
+        assert!(percent_symbol_array_diagnostics(b"x = %i[a b]\n").is_empty());
+        assert!(percent_symbol_array_diagnostics(b"x = %w[:a b,]\n").is_empty());
+    }
+}
@@ -0,0 +1,138 @@
+use crate::checker::Checker;
+use crate::diagnostic::{Edit, Fix};
+use crate::rule::{Check, LintRule, Rule, RuleId};
+use crate::utility::block_parameter::{SecondParameter, is_local_variable_used, second_parameter};
+use reukocyte_macros::check;
+use ruby_prism::*;
+
+const MSG_EACH_WITH_INDEX: &str = "Use `each` instead of `each_with_index`.";
+const MSG_WITH_INDEX: &str = "Remove redundant `with_index`.";
+
+/// Get the config for this rule
+#[inline]
+fn config<'a>(checker: &'a Checker<'_>) -> &'a crate::config::lint::redundant_with_index::RedundantWithIndex {
+    &checker.config().lint.redundant_with_index
+}
+
+/// Lint/RedundantWithIndex rule.
+///
+/// Detects `each_with_index` and `with_index` whose block never uses the
+/// index, either by not declaring it or by leaving it unused. The fix is
+/// unsafe: the receiver may define `each` and `each_with_index` differently.
+///
+/// ```ruby
+/// # bad
+/// ary.each_with_index { |v| v }
+/// ary.each.with_index(1) { |v, _i| v }
+///
+/// # good
+/// ary.each { |v| v }
+/// ary.each_with_index { |v, i| foo(v, i) }
+/// ```
+pub struct RedundantWithIndex;
+impl Rule for RedundantWithIndex {
+    const ID: RuleId = RuleId::Lint(LintRule::RedundantWithIndex);
+}
+#[check(BlockNode)]
+impl Check<BlockNode<'_>> for RedundantWithIndex {
+    fn check(node: &BlockNode, checker: &mut Checker) {
+        let Some(call) = checker.semantic().parent().and_then(|parent| parent.as_call_node()) else {
+            return;
+        };
+        let argument_count = call.arguments().map_or(0, |arguments| arguments.arguments().iter().count());
+        let each_with_index = match call.name().as_slice() {
+            b"each_with_index" if argument_count == 0 => true,
+            // `with_index` takes an optional offset.
+            b"with_index" if argument_count <= 1 && call.receiver().is_some_and(|receiver| receiver.as_call_node().is_some()) => false,
+            _ => return,
+        };
+        let Some(selector) = call.message_loc() else {
+            return;
+        };
+        let Some(index) = second_parameter(node) else {
+            return;
+        };
+        if let SecondParameter::Declared { second: index, .. } = &index
+            && is_local_variable_used(node, index.name().as_slice())
+        {
+            return;
+        }
+
+        let end = call
+            .closing_loc()
+            .or_else(|| call.arguments().map(|arguments| arguments.location()))
+            .map_or(selector.end_offset(), |location| location.end_offset());
+        let (message, call_edit) = if each_with_index {
+            (MSG_EACH_WITH_INDEX, Edit::replacement(selector.start_offset(), end, "each".to_string()))
+        } else {
+            let Some(operator) = call.call_operator_loc() else {
+                return;
+            };
+            (MSG_WITH_INDEX, Edit::deletion(operator.start_offset(), end))
+        };
+        let mut edits = vec![call_edit];
+        if let SecondParameter::Declared { element, second: index } = index {
+            edits.push(Edit::deletion(element.location().end_offset(), index.location().end_offset()));
+        }
+        let severity = config(checker).base.severity;
+        checker.report(
+            RedundantWithIndex::ID,
+            message.to_string(),
+            severity,
+            selector.start_offset(),
+            end,
+            Some(Fix::r#unsafe(edits)),
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::check;
+    use crate::corrector::Corrector;
+
+    fn redundant_with_index_diagnostics(source: &[u8]) -> Vec<crate::Diagnostic> {
+        check(source).into_iter().filter(|d| d.rule_id == RedundantWithIndex::ID).collect()
+    }
+
+    fn fixed(source: &[u8]) -> String {
+        let mut corrector = Corrector::new();
+        for diagnostic in redundant_with_index_diagnostics(source) {
+            corrector.merge(diagnostic.fix.as_ref().unwrap()).unwrap();
+        }
+        String::from_utf8(corrector.apply(source)).unwrap()
+    }
+
+    #[test]
+    fn test_unused_index_parameter() {
+        let source = b"arr.each.with_index { |x, _i| x }\n";
+        let diagnostics = redundant_with_index_diagnostics(source);
+        assert_eq!(diagnostics.len(), 1);
+        assert_eq!(diagnostics[0].message, MSG_WITH_INDEX);
+        assert_eq!(diagnostics[0].severity, crate::Severity::Warning);
+        assert_eq!((diagnostics[0].column_start, diagnostics[0].column_end), (10, 20));
+        assert_eq!(diagnostics[0].fix.as_ref().unwrap().applicability, crate::Applicability::Unsafe);
+        assert_eq!(fixed(source), "arr.each { |x| x }\n");
+        assert_eq!(fixed(b"arr.map.with_index(1) { |x, _| x }\n"), "arr.map { |x| x }\n");
+    }
+
+    #[test]
+    fn test_each_with_index() {
+        let source = b"arr.each_with_index { |x| puts x }\n";
+        let diagnostics = redundant_with_index_diagnostics(source);
+        assert_eq!(diagnostics[0].message, MSG_EACH_WITH_INDEX);
+        assert_eq!(fixed(source), "arr.each { |x| puts x }\n");
+        assert_eq!(fixed(b"arr.each_with_index do |x, i|\n  puts x\nend\n"), "arr.each do |x|\n  puts x\nend\n");
+        assert_eq!(fixed(b"arr.each_with_index { puts _1 }\n"), "arr.each { puts _1 }\n");
+    }
+
+    #[test]
+    fn test_used_index_is_ignored() {
+        assert!(redundant_with_index_diagnostics(b"arr.each_with_index { |x, i| puts x, i }\n").is_empty());
+        assert!(redundant_with_index_diagnostics(b"arr.each.with_index(1) { |x, i| x * i }\n").is_empty());
+        assert!(redundant_with_index_diagnostics(b"arr.each_with_index { puts _1, _2 }\n").is_empty());
+        assert!(redundant_with_index_diagnostics(b"arr.each_with_index { puts 1 }\n").is_empty());
+        assert!(redundant_with_index_diagnostics(b"with_index { |x| x }\n").is_empty());
+    }
+}
@@ -0,0 +1,149 @@
+use crate::checker::Checker;
+use crate::diagnostic::{Edit, Fix};
+use crate::rule::{Check, LintRule, Rule, RuleId};
+use crate::utility::block_parameter::{SecondParameter, is_local_variable_used, second_parameter};
+use reukocyte_macros::check;
+use ruby_prism::*;
+
+const MSG_EACH_WITH_OBJECT: &str = "Use `each` instead of `each_with_object`.";
+const MSG_WITH_OBJECT: &str = "Remove redundant `with_object`.";
+
+/// Get the config for this rule
+#[inline]
+fn config<'a>(checker: &'a Checker<'_>) -> &'a crate::config::lint::redundant_with_object::RedundantWithObject {
+    &checker.config().lint.redundant_with_object
+}
+
+/// Lint/RedundantWithObject rule.
+///
+/// Detects `each_with_object` and `with_object` whose block never uses the
+/// memo object, either by not declaring it or by leaving it unused. The fix
+/// is unsafe: `each` returns the receiver instead of the memo object.
+///
+/// ```ruby
+/// # bad
+/// ary.each_with_object([]) { |v, memo| v }
+/// ary.each.with_object([]) { |v| v }
+///
+/// # good
+/// ary.each { |v| v }
+/// ```
+pub struct RedundantWithObject;
+impl Rule for RedundantWithObject {
+    const ID: RuleId = RuleId::Lint(LintRule::RedundantWithObject);
+}
+#[check(BlockNode)]
+impl Check<BlockNode<'_>> for RedundantWithObject {
+    fn check(node: &BlockNode, checker: &mut Checker) {
+        let Some(call) = checker.semantic().parent().and_then(|parent| parent.as_call_node()) else {
+            return;
+        };
+        let each_with_object = match call.name().as_slice() {
+            b"each_with_object" => true,
+            b"with_object" if call.receiver().is_some() => false,
+            _ => return,
+        };
+        let (Some(selector), Some(arguments)) = (call.message_loc(), call.arguments()) else {
+            return;
+        };
+        if arguments.arguments().iter().count() != 1 {
+            return;
+        }
+        let Some(memo) = second_parameter(node) else {
+            return;
+        };
+        if let SecondParameter::Declared { second: memo, .. } = &memo
+            && is_local_variable_used(node, memo.name().as_slice())
+        {
+            return;
+        }
+
+        let end = call.closing_loc().map_or(arguments.location().end_offset(), |closing| closing.end_offset());
+        let (message, call_edit) = if each_with_object {
+            (MSG_EACH_WITH_OBJECT, Edit::replacement(selector.start_offset(), end, "each".to_string()))
+        } else {
+            let Some(operator) = call.call_operator_loc() else {
+                return;
+            };
+            (MSG_WITH_OBJECT, Edit::deletion(operator.start_offset(), end))
+        };
+        let mut edits = vec![call_edit];
+        if let SecondParameter::Declared { element, second: memo } = memo {
+            edits.push(Edit::deletion(element.location().end_offset(), memo.location().end_offset()));
+        }
+        let severity = config(checker).base.severity;
+        checker.report(
+            RedundantWithObject::ID,
+            message.to_string(),
+            severity,
+            selector.start_offset(),
+            end,
+            Some(Fix::r#unsafe(edits)),
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::check;
+    use crate::corrector::Corrector;
+
+    fn redundant_with_object_diagnostics(source: &[u8]) -> Vec<crate::Diagnostic> {
+        check(source).into_iter().filter(|d| d.rule_id == RedundantWithObject::ID).collect()
+    }
+
+    fn fixed(source: &[u8]) -> String {
+        let mut corrector = Corrector::new();
+        for diagnostic in redundant_with_object_diagnostics(source) {
+            corrector.merge(diagnostic.fix.as_ref().unwrap()).unwrap();
+        }
+        String::from_utf8(corrector.apply(source)).unwrap()
+    }
+
+    #[test]
+    fn test_unused_memo_parameter() {
+        let source = b"arr.each_with_object([]) { |x, memo| puts x }\n";
+        let diagnostics = redundant_with_object_diagnostics(source);
+        assert_eq!(diagnostics.len(), 1);
+        assert_eq!(diagnostics[0].message, MSG_EACH_WITH_OBJECT);
+        assert_eq!(diagnostics[0].severity, crate::Severity::Warning);
+        assert_eq!((diagnostics[0].column_start, diagnostics[0].column_end), (5, 25));
+        assert_eq!(diagnostics[0].fix.as_ref().unwrap().applicability, crate::Applicability::Unsafe);
+        assert_eq!(fixed(source), "arr.each { |x| puts x }\n");
+    }
+
+    #[test]
+    fn test_omitted_memo_parameter() {
+        assert_eq!(fixed(b"arr.each_with_object({}) { |x| puts x }\n"), "arr.each { |x| puts x }\n");
+        assert_eq!(fixed(b"arr.each_with_object [] do\n  puts _1\nend\n"), "arr.each do\n  puts _1\nend\n");
+        assert_eq!(fixed(b"arr.each_with_object([]) { puts it }\n"), "arr.each { puts it }\n");
+    }
+
+    #[test]
+    fn test_with_object() {
+        let source = b"arr.each.with_object([]) { |(a, b), memo| puts a }\n";
+        let diagnostics = redundant_with_object_diagnostics(source);
+        assert_eq!(diagnostics[0].message, MSG_WITH_OBJECT);
+        assert_eq!(fixed(source), "arr.each { |(a, b)| puts a }\n");
+    }
+
+    #[test]
+    fn test_used_memo_is_ignored() {
+        assert!(redundant_with_object_diagnostics(b"arr.each_with_object([]) { |x, memo| memo << x }\n").is_empty());
+        assert!(redundant_with_object_diagnostics(b"arr.each_with_object([]) { |x, memo| x.each { memo << _1 } }\n").is_empty());
+        assert!(redundant_with_object_diagnostics(b"arr.each_with_object([]) { |x, memo| memo = x }\n").is_empty());
+        assert!(redundant_with_object_diagnostics(b"arr.each_with_object([]) { _2 << _1 }\n").is_empty());
+        // A shadowing block parameter or method body is a different variable.
+        assert_eq!(
+            redundant_with_object_diagnostics(b"arr.each_with_object([]) { |x, memo| x.each { |memo| memo } }\n").len(),
+            1
+        );
+        assert_eq!(
+            redundant_with_object_diagnostics(b"arr.each_with_object([]) { |x, memo| def foo(memo) = memo }\n").len(),
+            1
+        );
+        assert!(redundant_with_object_diagnostics(b"arr.each_with_object([]) { puts 1 }\n").is_empty());
+        assert!(redundant_with_object_diagnostics(b"arr.each_with_index { |x, i| puts x }\n").is_empty());
+    }
+}
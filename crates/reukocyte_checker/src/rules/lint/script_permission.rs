@@ -0,0 +1,91 @@
+//! Lint/ScriptPermission
+//!
+//! Checks that a file starting with a `#!` shebang is executable.
+//!
+//! The file's permission bits come from the caller (the CLI reads them from the
+//! file's metadata); without them, e.g. for stdin, nothing is checked. Fixing
+//! needs a `chmod`, so offenses are reported without a fix.
+//!
+//! # Examples
+//!
+//! ```ruby
+//! # bad (mode 0644)
+//! #!/usr/bin/env ruby
+//! puts "hello"
+//!
+//! # good (mode 0755)
+//! #!/usr/bin/env ruby
+//! puts "hello"
+//! ```
+
+use crate::Checker;
+use crate::rule::{LintRule, RuleId};
+
+/// Rule identifier for Lint/ScriptPermission.
+pub const RULE_ID: RuleId = RuleId::Lint(LintRule::ScriptPermission);
+
+/// Any of the owner, group or other execute bits.
+const EXECUTE_BITS: u32 = 0o111;
+
+/// Check that a script with a shebang has an execute permission bit.
+pub fn check(checker: &mut Checker) {
+    let config = &checker.config().lint.script_permission;
+    if !config.base.enabled {
+        return;
+    }
+    // Check cop-specific include/exclude
+    if !checker.should_run_cop(&config.base) {
+        return;
+    }
+    let severity = config.base.severity;
+    let (Some(file_path), Some(file_mode)) = (checker.file_path(), checker.file_mode()) else {
+        return;
+    };
+    let source = checker.source();
+    if !source.starts_with(b"#!") || file_mode & EXECUTE_BITS != 0 {
+        return;
+    }
+    let end = source.iter().position(|&b| b == b'\n' || b == b'\r').unwrap_or(source.len());
+    let file_name = std::path::Path::new(file_path)
+        .file_name()
+        .map_or(file_path.into(), |name| name.to_string_lossy());
+    let message = format!("Script file {} doesn't have execute permission.", file_name);
+    checker.report(RULE_ID, message, severity, 0, end, None);
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::check_with_config_and_path;
+    use crate::config::Config;
+
+    fn messages(source: &[u8], file_mode: Option<u32>) -> Vec<String> {
+        check_with_config_and_path(source, &Config::default(), Some("bin/run"), file_mode)
+            .into_iter()
+            .filter(|d| d.rule() == "Lint/ScriptPermission")
+            .map(|d| d.message)
+            .collect()
+    }
+
+    #[test]
+    fn test_shebang_without_execute_bit() {
+        let source = b"#!/usr/bin/env ruby\nputs 1\n";
+        let diagnostics = check_with_config_and_path(source, &Config::default(), Some("bin/run"), Some(0o100644));
+        let diagnostic = diagnostics.iter().find(|d| d.rule() == "Lint/ScriptPermission").unwrap();
+        assert_eq!(diagnostic.message, "Script file run doesn't have execute permission.");
+        assert_eq!((diagnostic.line_start, diagnostic.column_start, diagnostic.column_end), (1, 1, 20));
+        assert!(diagnostic.fix.is_none());
+    }
+
+    #[test]
+    fn test_executable_or_no_shebang() {
+        assert!(messages(b"#!/usr/bin/env ruby\nputs 1\n", Some(0o100755)).is_empty());
+        assert!(messages(b"#!/usr/bin/env ruby\nputs 1\n", Some(0o100744)).is_empty());
+        assert!(messages(b"puts 1\n", Some(0o100644)).is_empty());
+        assert!(messages(b"# !/usr/bin/env ruby\n", Some(0o100644)).is_empty());
+    }
+
+    #[test]
+    fn test_unknown_file_mode() {
+        assert!(messages(b"#!/usr/bin/env ruby\nputs 1\n", None).is_empty());
+    }
+}
@@ -0,0 +1,189 @@
+use crate::checker::Checker;
+use crate::rule::{Check, LintRule, Rule, RuleId};
+use reukocyte_macros::check;
+use ruby_prism::*;
+
+const MSG: &str = "Ensure you correctly provided all the arguments.";
+
+/// Get the config for this rule
+#[inline]
+fn config<'a>(checker: &'a Checker<'_>) -> &'a crate::config::lint::to_enum_arguments::ToEnumArguments {
+    &checker.config().lint.to_enum_arguments
+}
+
+/// Lint/ToEnumArguments rule - detects `to_enum`/`enum_for` calls that do not
+/// pass along all the arguments of the enclosing method.
+///
+/// ```ruby
+/// # bad
+/// def foo(x, y = 1)
+///   return to_enum(__method__, x) # `y` is missing
+/// end
+///
+/// # good
+/// def foo(x, y = 1)
+///   return to_enum(__method__, x, y)
+/// end
+/// ```
+pub struct ToEnumArguments;
+impl Rule for ToEnumArguments {
+    const ID: RuleId = RuleId::Lint(LintRule::ToEnumArguments);
+}
+#[check(CallNode)]
+impl Check<CallNode<'_>> for ToEnumArguments {
+    fn check(node: &CallNode, checker: &mut Checker) {
+        if !matches!(node.name().as_slice(), b"to_enum" | b"enum_for") || node.receiver().is_some_and(|receiver| receiver.as_self_node().is_none()) {
+            return;
+        }
+        let Some(def_node) = checker.semantic().ancestors().find_map(|ancestor| ancestor.as_def_node()) else {
+            return;
+        };
+        let arguments: Vec<Node> = node.arguments().map(|arguments| arguments.arguments().iter().collect()).unwrap_or_default();
+        let Some((method, arguments)) = arguments.split_first() else {
+            return;
+        };
+        if !is_method_name(method, def_node.name().as_slice()) || arguments_match(arguments, def_node.parameters()) {
+            return;
+        }
+        let location = node.location();
+        let severity = config(checker).base.severity;
+        checker.report(
+            ToEnumArguments::ID,
+            MSG.to_string(),
+            severity,
+            location.start_offset(),
+            location.end_offset(),
+            None,
+        );
+    }
+}
+
+/// Check if the node is `__method__` or a symbol naming the method.
+fn is_method_name(node: &Node, method_name: &[u8]) -> bool {
+    if let Some(call) = node.as_call_node() {
+        return call.receiver().is_none() && call.arguments().is_none() && call.name().as_slice() == b"__method__";
+    }
+    node.as_symbol_node().is_some_and(|symbol| symbol.unescaped() == method_name)
+}
+
+/// Check if the call arguments pass along every parameter (except the block parameter).
+///
+/// Positional parameters must be passed in order under the same name; keyword
+/// parameters (`k: k`) and a keyword splat (`**opts`) must all appear in the
+/// hash argument that follows them.
+fn arguments_match(arguments: &[Node], parameters: Option<ParametersNode>) -> bool {
+    let Some(parameters) = parameters else {
+        return true;
+    };
+    let mut positionals: Vec<Node> = parameters.requireds().iter().collect();
+    positionals.extend(parameters.optionals().iter());
+    positionals.extend(parameters.rest());
+    positionals.extend(parameters.posts().iter());
+    let mut keywords: Vec<Node> = parameters.keywords().iter().collect();
+    keywords.extend(parameters.keyword_rest());
+
+    for (index, parameter) in positionals.iter().enumerate() {
+        let Some(argument) = arguments.get(index) else {
+            return false;
+        };
+        let expected = match parameter.as_optional_parameter_node() {
+            Some(optional) => optional.name().as_slice(),
+            None => parameter.location().as_slice(),
+        };
+        if argument.location().as_slice() != expected {
+            return false;
+        }
+    }
+    if keywords.is_empty() {
+        return true;
+    }
+    let Some(argument) = arguments.get(positionals.len()) else {
+        return false;
+    };
+    keywords.iter().all(|parameter| keyword_argument_match(argument, parameter))
+}
+
+/// Check if a call argument passes along a keyword, keyword splat or `...` parameter.
+fn keyword_argument_match(argument: &Node, parameter: &Node) -> bool {
+    if parameter.as_forwarding_parameter_node().is_some() {
+        return argument.as_forwarding_arguments_node().is_some();
+    }
+    let elements: Vec<Node> = if let Some(hash) = argument.as_keyword_hash_node() {
+        hash.elements().iter().collect()
+    } else if let Some(hash) = argument.as_hash_node() {
+        hash.elements().iter().collect()
+    } else {
+        return false;
+    };
+    if parameter.as_keyword_rest_parameter_node().is_some() {
+        let source = parameter.location().as_slice();
+        return elements
+            .iter()
+            .any(|element| element.as_assoc_splat_node().is_some() && element.location().as_slice() == source);
+    }
+    let name = if let Some(required) = parameter.as_required_keyword_parameter_node() {
+        required.name()
+    } else if let Some(optional) = parameter.as_optional_keyword_parameter_node() {
+        optional.name()
+    } else {
+        // `**nil`
+        return true;
+    };
+    elements.iter().filter_map(|element| element.as_assoc_node()).any(|pair| {
+        let key_matches = pair.key().as_symbol_node().is_some_and(|key| key.unescaped() == name.as_slice());
+        // `k: k`, or the shorthand `k:` whose value is implicit.
+        let value = pair.value();
+        let value = value.as_implicit_node().map_or(value, |implicit| implicit.value());
+        key_matches
+            && value
+                .as_local_variable_read_node()
+                .is_some_and(|read| read.name().as_slice() == name.as_slice())
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::check;
+
+    fn to_enum_arguments_diagnostics(source: &[u8]) -> Vec<crate::Diagnostic> {
+        check(source).into_iter().filter(|d| d.rule_id == ToEnumArguments::ID).collect()
+    }
+
+    #[test]
+    fn test_missing_argument() {
+        let diagnostics = to_enum_arguments_diagnostics(b"def foo(x, y = 1)\n  return to_enum(__method__, x) unless block_given?\nend\n");
+        assert_eq!(diagnostics.len(), 1);
+        assert_eq!(diagnostics[0].message, MSG);
+        assert_eq!(diagnostics[0].severity, crate::Severity::Warning);
+        assert!(diagnostics[0].fix.is_none());
+    }
+
+    #[test]
+    fn test_mismatched_arguments() {
+        assert_eq!(to_enum_arguments_diagnostics(b"def foo(x, y)\n  enum_for(:foo, y, x)\nend\n").len(), 1);
+        assert_eq!(to_enum_arguments_diagnostics(b"def foo(*args)\n  self.to_enum(:foo, args)\nend\n").len(), 1);
+        assert_eq!(to_enum_arguments_diagnostics(b"def foo(a:, b: 1)\n  to_enum(:foo, a: a)\nend\n").len(), 1);
+        assert_eq!(to_enum_arguments_diagnostics(b"def foo(x, **opts)\n  to_enum(:foo, x)\nend\n").len(), 1);
+    }
+
+    #[test]
+    fn test_matching_arguments() {
+        assert!(
+            to_enum_arguments_diagnostics(
+                b"def foo(x, y = 1, *rest, z, k:, o: 2, **opts, &block)\n  to_enum(__method__, x, y, *rest, z, k: k, o:, **opts)\nend\n"
+            )
+            .is_empty()
+        );
+        assert!(to_enum_arguments_diagnostics(b"def foo(...)\n  to_enum(:foo, ...)\nend\n").is_empty());
+        assert!(to_enum_arguments_diagnostics(b"def foo\n  to_enum(:foo)\nend\n").is_empty());
+    }
+
+    #[test]
+    fn test_other_calls_are_ignored() {
+        // A different method, an explicit receiver, or no enclosing method.
+        assert!(to_enum_arguments_diagnostics(b"def foo(x)\n  to_enum(:bar)\nend\n").is_empty());
+        assert!(to_enum_arguments_diagnostics(b"def foo(x)\n  other.to_enum(:foo)\nend\n").is_empty());
+        assert!(to_enum_arguments_diagnostics(b"to_enum(:foo, 1)\n").is_empty());
+    }
+}
@@ -0,0 +1,236 @@
+use crate::checker::Checker;
+use crate::config::style::class_and_module_children::EnforcedStyle;
+use crate::diagnostic::{Edit, Fix};
+use crate::rule::{Check, Rule, RuleId, StyleRule};
+use reukocyte_macros::check;
+use ruby_prism::*;
+
+const MSG_NESTED: &str = "Use nested module/class definitions instead of compact style.";
+const MSG_COMPACT: &str = "Use compact module/class style.";
+
+/// Get the config for this rule
+#[inline]
+fn config<'a>(checker: &'a Checker<'_>) -> &'a crate::config::style::class_and_module_children::ClassAndModuleChildren {
+    &checker.config().style.class_and_module_children
+}
+
+/// Style/ClassAndModuleChildren rule.
+///
+/// Checks the style of children definitions in classes and modules.
+///
+/// ```ruby
+/// # bad (EnforcedStyle: nested)
+/// class Foo::Bar
+/// end
+///
+/// # good (EnforcedStyle: nested)
+/// module Foo
+///   class Bar
+///   end
+/// end
+/// ```
+///
+/// The nested autocorrect is unsafe: the outer namespaces are always opened as
+/// modules, and constant lookup changes once they are nested.
+pub struct ClassAndModuleChildren;
+impl Rule for ClassAndModuleChildren {
+    const ID: RuleId = RuleId::Style(StyleRule::ClassAndModuleChildren);
+}
+#[check(ClassNode)]
+impl Check<ClassNode<'_>> for ClassAndModuleChildren {
+    fn check(node: &ClassNode, checker: &mut Checker) {
+        match config(checker).enforced_style {
+            EnforcedStyle::Nested => check_nested_style(&node.location(), &node.constant_path(), &node.end_keyword_loc(), checker),
+            // A class with a superclass cannot be merged into its child's compact name.
+            EnforcedStyle::Compact if node.superclass().is_none() => check_compact_style(&node.constant_path(), node.body(), checker),
+            EnforcedStyle::Compact => {}
+        }
+    }
+}
+#[check(ModuleNode)]
+impl Check<ModuleNode<'_>> for ClassAndModuleChildren {
+    fn check(node: &ModuleNode, checker: &mut Checker) {
+        match config(checker).enforced_style {
+            EnforcedStyle::Nested => check_nested_style(&node.location(), &node.constant_path(), &node.end_keyword_loc(), checker),
+            EnforcedStyle::Compact => check_compact_style(&node.constant_path(), node.body(), checker),
+        }
+    }
+}
+
+/// Report a compact definition (`class Foo::Bar`) and rewrite it into nested definitions.
+fn check_nested_style(location: &Location, constant_path: &Node, end_keyword: &Location, checker: &mut Checker) {
+    // Plain names need no nesting; rooted (`::Foo::Bar`) or dynamic (`foo::Bar`) paths cannot be nested.
+    let Some(segments) = namespace_segments(constant_path) else {
+        return;
+    };
+    let path_location = constant_path.location();
+    let fix = nested_fix(location, &path_location, end_keyword, &segments, checker);
+    let severity = config(checker).base.severity;
+    checker.report(
+        ClassAndModuleChildren::ID,
+        MSG_NESTED.to_string(),
+        severity,
+        path_location.start_offset(),
+        path_location.end_offset(),
+        fix,
+    );
+}
+
+/// Report a namespace whose only child is another class or module definition.
+fn check_compact_style(constant_path: &Node, body: Option<Node>, checker: &mut Checker) {
+    let Some(statements) = body.and_then(|body| body.as_statements_node()) else {
+        return;
+    };
+    let children = statements.body();
+    let is_single_definition = children.iter().count() == 1
+        && children
+            .iter()
+            .next()
+            .is_some_and(|child| child.as_class_node().is_some() || child.as_module_node().is_some());
+    if !is_single_definition {
+        return;
+    }
+    let path_location = constant_path.location();
+    let severity = config(checker).base.severity;
+    checker.report(
+        ClassAndModuleChildren::ID,
+        MSG_COMPACT.to_string(),
+        severity,
+        path_location.start_offset(),
+        path_location.end_offset(),
+        None,
+    );
+}
+
+/// Split `A::B::C` into its segments, or `None` if it is not a path of plain constants.
+fn namespace_segments(constant_path: &Node) -> Option<Vec<String>> {
+    let name_of = |id: ConstantId| String::from_utf8_lossy(id.as_slice()).into_owned();
+    let path = constant_path.as_constant_path_node()?;
+    let mut segments = vec![name_of(path.name()?)];
+    let mut parent = path.parent()?;
+    loop {
+        if let Some(path) = parent.as_constant_path_node() {
+            segments.push(name_of(path.name()?));
+            parent = path.parent()?;
+        } else if let Some(read) = parent.as_constant_read_node() {
+            segments.push(name_of(read.name()));
+            break;
+        } else {
+            return None;
+        }
+    }
+    segments.reverse();
+    Some(segments)
+}
+
+/// Build the fix that opens each namespace as a module, indents the body and closes the modules.
+///
+/// Only applied when the definition starts its line, so the indentation can be derived from it.
+fn nested_fix(location: &Location, path_location: &Location, end_keyword: &Location, segments: &[String], checker: &Checker) -> Option<Fix> {
+    let line_index = checker.line_index();
+    let start = location.start_offset();
+    if !line_index.is_first_on_line(start) {
+        return None;
+    }
+    let indent = line_index.column(start);
+    let width = checker.config().layout.indentation_width.width.max(0) as usize;
+    let (namespaces, name) = segments.split_at(segments.len() - 1);
+    let depth = namespaces.len();
+
+    let mut opening = String::new();
+    for (level, namespace) in namespaces.iter().enumerate() {
+        opening.push_str(&format!("module {}\n{}", namespace, " ".repeat(indent + (level + 1) * width)));
+    }
+    let mut edits = vec![
+        Edit::insertion(start, opening),
+        Edit::replacement(path_location.start_offset(), path_location.end_offset(), name[0].clone()),
+    ];
+
+    // Indent every following line up to and including `end`, leaving blank lines and heredoc bodies alone.
+    let first_line = line_index.line_index(start);
+    let last_line = line_index.line_index(end_keyword.start_offset());
+    for line in first_line + 1..=last_line {
+        let (Some(line_start), Some(content)) = (line_index.line_start(line), line_index.line(line)) else {
+            continue;
+        };
+        if content.iter().all(|b| b.is_ascii_whitespace()) || checker.is_in_literal(line_start) {
+            continue;
+        }
+        edits.push(Edit::insertion(line_start, " ".repeat(depth * width)));
+    }
+
+    let mut closing = String::new();
+    for level in (0..depth).rev() {
+        closing.push_str(&format!("\n{}end", " ".repeat(indent + level * width)));
+    }
+    edits.push(Edit::insertion(end_keyword.end_offset(), closing));
+    Some(Fix::r#unsafe(edits))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::Config;
+    use crate::corrector::Corrector;
+    use crate::{check, check_with_config};
+
+    fn children_diagnostics(diagnostics: Vec<crate::Diagnostic>) -> Vec<crate::Diagnostic> {
+        diagnostics.into_iter().filter(|d| d.rule_id == ClassAndModuleChildren::ID).collect()
+    }
+
+    /// Apply the fixes in a single pass to look at this rule's rewrite alone.
+    fn fixed(source: &[u8], diagnostics: &[crate::Diagnostic]) -> String {
+        let mut corrector = Corrector::new();
+        for fix in diagnostics.iter().filter_map(|d| d.fix.as_ref()) {
+            corrector.merge(fix).unwrap();
+        }
+        String::from_utf8(corrector.apply(source)).unwrap()
+    }
+
+    #[test]
+    fn test_nested_style_reports_compact_class() {
+        let source = b"class Foo::Bar < Base\n  def baz\n    1\n  end\nend\n";
+        let diagnostics = children_diagnostics(check(source));
+        assert_eq!(diagnostics.len(), 1);
+        assert_eq!(diagnostics[0].message, MSG_NESTED);
+        assert_eq!(diagnostics[0].fix_applicability(), Some(crate::Applicability::Unsafe));
+        assert_eq!(
+            fixed(source, &diagnostics),
+            "module Foo\n  class Bar < Base\n    def baz\n      1\n    end\n  end\nend\n"
+        );
+    }
+
+    #[test]
+    fn test_nested_style_multiple_namespaces() {
+        let source = b"module A::B::C\n  X = 1\n\n  Y = 2\nend\n";
+        let diagnostics = children_diagnostics(check(source));
+        assert_eq!(
+            fixed(source, &diagnostics),
+            "module A\n  module B\n    module C\n      X = 1\n\n      Y = 2\n    end\n  end\nend\n"
+        );
+    }
+
+    #[test]
+    fn test_nested_style_single_line() {
+        let source = b"class Foo::Bar; end\n";
+        let diagnostics = children_diagnostics(check(source));
+        assert_eq!(fixed(source, &diagnostics), "module Foo\n  class Bar; end\nend\n");
+    }
+
+    #[test]
+    fn test_nested_style_accepts_nested_and_rooted() {
+        assert!(children_diagnostics(check(b"module Foo\n  class Bar\n  end\nend\n")).is_empty());
+        assert!(children_diagnostics(check(b"class ::Foo::Bar\nend\n")).is_empty());
+    }
+
+    #[test]
+    fn test_compact_style() {
+        let mut config = Config::default();
+        config.style.class_and_module_children.enforced_style = EnforcedStyle::Compact;
+        let diagnostics = children_diagnostics(check_with_config(b"module Foo\n  class Bar\n  end\nend\n", &config));
+        assert_eq!(diagnostics.len(), 1);
+        assert_eq!(diagnostics[0].message, MSG_COMPACT);
+        assert!(children_diagnostics(check_with_config(b"class Foo::Bar\nend\n", &config)).is_empty());
+        assert!(children_diagnostics(check_with_config(b"module Foo\n  X = 1\n  class Bar\n  end\nend\n", &config)).is_empty());
+    }
+}
@@ -0,0 +1,294 @@
+use crate::checker::Checker;
+use crate::config::style::conditional_assignment::EnforcedStyle;
+use crate::diagnostic::{Edit, Fix};
+use crate::rule::{Check, Rule, RuleId, StyleRule};
+use reukocyte_macros::check;
+use ruby_prism::*;
+
+const MSG_ASSIGN_TO_CONDITION: &str = "Use the return of the conditional for variable assignment and comparison.";
+const MSG_ASSIGN_INSIDE_CONDITION: &str = "Assign variables inside of conditionals.";
+
+/// Get the config for this rule
+#[inline]
+fn config<'a>(checker: &'a Checker<'_>) -> &'a crate::config::style::conditional_assignment::ConditionalAssignment {
+    &checker.config().style.conditional_assignment
+}
+
+/// Style/ConditionalAssignment rule.
+///
+/// With `assign_to_condition` (default), checks for `if`/`case` expressions whose
+/// branches each consist of a single assignment to the same variable. With
+/// `assign_inside_condition`, checks for the result of an `if`/`case` being
+/// assigned to a variable. Only conditionals with an `else` branch are checked,
+/// and the autocorrect is unsafe since it leaves the re-indentation to the
+/// layout cops.
+///
+/// ```ruby
+/// # bad (EnforcedStyle: assign_to_condition)
+/// if foo
+///   bar = 1
+/// else
+///   bar = 2
+/// end
+///
+/// # good (EnforcedStyle: assign_to_condition)
+/// bar = if foo
+///         1
+///       else
+///         2
+///       end
+/// ```
+pub struct ConditionalAssignment;
+impl Rule for ConditionalAssignment {
+    const ID: RuleId = RuleId::Style(StyleRule::ConditionalAssignment);
+}
+#[check(IfNode)]
+impl Check<IfNode<'_>> for ConditionalAssignment {
+    fn check(node: &IfNode, checker: &mut Checker) {
+        // Ternaries and `elsif` branches (checked with their `if`) are skipped.
+        if node.if_keyword_loc().is_none_or(|keyword| keyword.as_slice() != b"if") {
+            return;
+        }
+        if let Some(branches) = if_branches(node) {
+            check_conditional(&node.as_node(), branches, checker);
+        }
+    }
+}
+#[check(CaseNode)]
+impl Check<CaseNode<'_>> for ConditionalAssignment {
+    fn check(node: &CaseNode, checker: &mut Checker) {
+        let Some(else_clause) = node.else_clause() else {
+            return;
+        };
+        let mut branches: Vec<_> = node
+            .conditions()
+            .iter()
+            .filter_map(|when| when.as_when_node())
+            .map(|when| when.statements())
+            .collect();
+        branches.push(else_clause.statements());
+        check_conditional(&node.as_node(), branches, checker);
+    }
+}
+
+/// Check a conditional given the statements of each of its branches.
+fn check_conditional<'pr>(node: &Node<'pr>, branches: Vec<Option<StatementsNode<'pr>>>, checker: &mut Checker) {
+    match config(checker).enforced_style {
+        EnforcedStyle::AssignToCondition => check_assign_to_condition(node, &branches, checker),
+        EnforcedStyle::AssignInsideCondition => check_assign_inside_condition(node, &branches, checker),
+    }
+}
+
+/// Report branches that all assign the same variable.
+fn check_assign_to_condition(node: &Node, branches: &[Option<StatementsNode>], checker: &mut Checker) {
+    let mut assignments = Vec::with_capacity(branches.len());
+    for statements in branches {
+        let Some(statements) = statements else {
+            return;
+        };
+        let body = statements.body();
+        if body.iter().count() != 1 {
+            return;
+        }
+        let Some(assignment) = body.iter().next().as_ref().and_then(Assignment::from_node) else {
+            return;
+        };
+        assignments.push(assignment);
+    }
+    let Some(first) = assignments.first() else {
+        return;
+    };
+    if assignments.iter().any(|assignment| assignment.target != first.target) {
+        return;
+    }
+
+    let location = node.location();
+    let source = checker.source();
+    let prefix = String::from_utf8_lossy(&source[first.start..first.value_start]).into_owned();
+    let mut edits = vec![Edit::insertion(location.start_offset(), prefix)];
+    edits.extend(assignments.iter().map(|assignment| Edit::deletion(assignment.start, assignment.value_start)));
+    let severity = config(checker).base.severity;
+    checker.report(
+        ConditionalAssignment::ID,
+        MSG_ASSIGN_TO_CONDITION.to_string(),
+        severity,
+        location.start_offset(),
+        location.end_offset(),
+        Some(Fix::r#unsafe(edits)),
+    );
+}
+
+/// Report the conditional when its result is assigned to a variable.
+fn check_assign_inside_condition(node: &Node, branches: &[Option<StatementsNode>], checker: &mut Checker) {
+    let location = node.location();
+    let Some(assignment) = checker.semantic().parent().and_then(Assignment::from_node) else {
+        return;
+    };
+    if assignment.value_start != location.start_offset() {
+        return;
+    }
+    let mut last_statements = Vec::with_capacity(branches.len());
+    for statements in branches {
+        let Some(last) = statements.as_ref().and_then(|statements| statements.body().iter().last()) else {
+            return;
+        };
+        last_statements.push(last.location().start_offset());
+    }
+
+    let source = checker.source();
+    let prefix = String::from_utf8_lossy(&source[assignment.start..assignment.value_start]).into_owned();
+    let mut edits = vec![Edit::deletion(assignment.start, assignment.value_start)];
+    edits.extend(last_statements.into_iter().map(|start| Edit::insertion(start, prefix.clone())));
+    let severity = config(checker).base.severity;
+    checker.report(
+        ConditionalAssignment::ID,
+        MSG_ASSIGN_INSIDE_CONDITION.to_string(),
+        severity,
+        assignment.start,
+        location.end_offset(),
+        Some(Fix::r#unsafe(edits)),
+    );
+}
+
+/// Collect the statements of each branch of an `if`/`elsif`/`else` chain, or
+/// `None` if there is no final `else`.
+fn if_branches<'pr>(node: &IfNode<'pr>) -> Option<Vec<Option<StatementsNode<'pr>>>> {
+    let mut branches = vec![node.statements()];
+    let mut subsequent = node.subsequent();
+    while let Some(next) = subsequent {
+        if let Some(elsif) = next.as_if_node() {
+            branches.push(elsif.statements());
+            subsequent = elsif.subsequent();
+        } else {
+            branches.push(next.as_else_node()?.statements());
+            return Some(branches);
+        }
+    }
+    None
+}
+
+/// A plain variable or constant assignment (`x = value`).
+struct Assignment<'pr> {
+    /// The kind of variable and its name.
+    target: (&'static str, &'pr [u8]),
+    /// Start offset of the assignment (the variable name).
+    start: usize,
+    /// Start offset of the assigned value.
+    value_start: usize,
+}
+impl<'pr> Assignment<'pr> {
+    fn from_node(node: &Node<'pr>) -> Option<Self> {
+        let (kind, name, value) = match node {
+            Node::LocalVariableWriteNode { .. } => {
+                let write = node.as_local_variable_write_node().unwrap();
+                ("local", write.name().as_slice(), write.value())
+            }
+            Node::InstanceVariableWriteNode { .. } => {
+                let write = node.as_instance_variable_write_node().unwrap();
+                ("instance", write.name().as_slice(), write.value())
+            }
+            Node::ClassVariableWriteNode { .. } => {
+                let write = node.as_class_variable_write_node().unwrap();
+                ("class", write.name().as_slice(), write.value())
+            }
+            Node::GlobalVariableWriteNode { .. } => {
+                let write = node.as_global_variable_write_node().unwrap();
+                ("global", write.name().as_slice(), write.value())
+            }
+            Node::ConstantWriteNode { .. } => {
+                let write = node.as_constant_write_node().unwrap();
+                ("constant", write.name().as_slice(), write.value())
+            }
+            _ => return None,
+        };
+        Some(Self {
+            target: (kind, name),
+            start: node.location().start_offset(),
+            value_start: value.location().start_offset(),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::Config;
+    use crate::corrector::Corrector;
+    use crate::{check, check_with_config};
+
+    fn conditional_assignment_diagnostics(source: &[u8], config: &Config) -> Vec<crate::Diagnostic> {
+        check_with_config(source, config)
+            .into_iter()
+            .filter(|d| d.rule_id == ConditionalAssignment::ID)
+            .collect()
+    }
+
+    fn assign_inside_condition_config() -> Config {
+        let mut config = Config::default();
+        config.style.conditional_assignment.enforced_style = EnforcedStyle::AssignInsideCondition;
+        config
+    }
+
+    fn fixed(source: &[u8], config: &Config) -> String {
+        let mut corrector = Corrector::new();
+        for diagnostic in conditional_assignment_diagnostics(source, config) {
+            corrector.merge(diagnostic.fix.as_ref().unwrap()).unwrap();
+        }
+        String::from_utf8(corrector.apply(source)).unwrap()
+    }
+
+    #[test]
+    fn test_if_else_assigning_same_variable() {
+        let source = b"if c\n  x = 1\nelse\n  x = 2\nend\n";
+        let diagnostics = conditional_assignment_diagnostics(source, &Config::default());
+        assert_eq!(diagnostics.len(), 1);
+        assert_eq!(diagnostics[0].message, MSG_ASSIGN_TO_CONDITION);
+        assert_eq!(diagnostics[0].fix_applicability(), Some(crate::Applicability::Unsafe));
+        assert_eq!(fixed(source, &Config::default()), "x = if c\n  1\nelse\n  2\nend\n");
+    }
+
+    #[test]
+    fn test_elsif_and_case_forms() {
+        let config = Config::default();
+        assert_eq!(
+            fixed(b"if a\n  @x = 1\nelsif b\n  @x = 2\nelse\n  @x = 3\nend\n", &config),
+            "@x = if a\n  1\nelsif b\n  2\nelse\n  3\nend\n"
+        );
+        assert_eq!(
+            fixed(b"case y\nwhen 1\n  x = :a\nelse\n  x = :b\nend\n", &config),
+            "x = case y\nwhen 1\n  :a\nelse\n  :b\nend\n"
+        );
+    }
+
+    #[test]
+    fn test_assign_to_condition_skips_mismatched_branches() {
+        let config = Config::default();
+        assert!(conditional_assignment_diagnostics(b"if c\n  x = 1\nelse\n  y = 2\nend\n", &config).is_empty());
+        assert!(conditional_assignment_diagnostics(b"if c\n  x = 1\nend\n", &config).is_empty());
+        assert!(conditional_assignment_diagnostics(b"if c\n  foo\n  x = 1\nelse\n  x = 2\nend\n", &config).is_empty());
+        assert!(conditional_assignment_diagnostics(b"case y\nwhen 1\n  x = :a\nend\n", &config).is_empty());
+        assert!(conditional_assignment_diagnostics(b"x = if c\n  1\nelse\n  2\nend\n", &config).is_empty());
+    }
+
+    #[test]
+    fn test_assign_inside_condition() {
+        let config = assign_inside_condition_config();
+        let source = b"x = if c\n  1\nelse\n  foo\n  2\nend\n";
+        let diagnostics = conditional_assignment_diagnostics(source, &config);
+        assert_eq!(diagnostics.len(), 1);
+        assert_eq!(diagnostics[0].message, MSG_ASSIGN_INSIDE_CONDITION);
+        assert_eq!(fixed(source, &config), "if c\n  x = 1\nelse\n  foo\n  x = 2\nend\n");
+        assert_eq!(
+            fixed(b"x = case y\nwhen 1 then :a\nelse :b\nend\n", &config),
+            "case y\nwhen 1 then x = :a\nelse x = :b\nend\n"
+        );
+    }
+
+    #[test]
+    fn test_assign_inside_condition_skips_conditionals_without_else() {
+        let config = assign_inside_condition_config();
+        assert!(conditional_assignment_diagnostics(b"x = if c\n  1\nend\n", &config).is_empty());
+        assert!(conditional_assignment_diagnostics(b"if c\n  x = 1\nelse\n  x = 2\nend\n", &config).is_empty());
+        assert!(check(b"x = if c\n  1\nelse\n  2\nend\n").iter().all(|d| d.rule_id != ConditionalAssignment::ID));
+    }
+}
@@ -0,0 +1,178 @@
+use crate::checker::Checker;
+use crate::config::style::empty_else::EnforcedStyle;
+use crate::diagnostic::{Edit, Fix};
+use crate::rule::{Check, Rule, RuleId, StyleRule};
+use reukocyte_macros::check;
+use ruby_prism::*;
+
+const MSG: &str = "Redundant `else`-clause.";
+
+/// Get the config for this rule
+#[inline]
+fn config<'a>(checker: &'a Checker<'_>) -> &'a crate::config::style::empty_else::EmptyElse {
+    &checker.config().style.empty_else
+}
+
+/// Style/EmptyElse rule.
+///
+/// Checks for `else` branches that are empty or only contain `nil`; both give
+/// the same result as having no `else` at all.
+///
+/// ```ruby
+/// # bad (EnforcedStyle: both)
+/// if condition
+///   statement
+/// else
+/// end
+///
+/// if condition
+///   statement
+/// else
+///   nil
+/// end
+///
+/// # good
+/// if condition
+///   statement
+/// end
+/// ```
+pub struct EmptyElse;
+impl Rule for EmptyElse {
+    const ID: RuleId = RuleId::Style(StyleRule::EmptyElse);
+}
+#[check(IfNode)]
+impl Check<IfNode<'_>> for EmptyElse {
+    fn check(node: &IfNode, checker: &mut Checker) {
+        // Ternaries (`a ? b : nil`) have no `if` keyword.
+        if node.if_keyword_loc().is_none() {
+            return;
+        }
+        if let Some(else_node) = node.subsequent().and_then(|subsequent| subsequent.as_else_node()) {
+            check_else(&else_node, else_node.end_keyword_loc(), checker);
+        }
+    }
+}
+#[check(CaseNode)]
+impl Check<CaseNode<'_>> for EmptyElse {
+    fn check(node: &CaseNode, checker: &mut Checker) {
+        if let Some(else_node) = node.else_clause() {
+            check_else(&else_node, Some(node.end_keyword_loc()), checker);
+        }
+    }
+}
+
+fn check_else(else_node: &ElseNode, end_keyword: Option<Location>, checker: &mut Checker) {
+    let Some(end_keyword) = end_keyword else {
+        return;
+    };
+    let body = else_node.statements().map(|statements| statements.body());
+    let is_empty = body.as_ref().is_none_or(|body| body.iter().next().is_none());
+    let is_nil = body
+        .as_ref()
+        .is_some_and(|body| body.iter().count() == 1 && body.iter().next().is_some_and(|node| node.as_nil_node().is_some()));
+    let is_redundant = match config(checker).enforced_style {
+        EnforcedStyle::Both => is_empty || is_nil,
+        EnforcedStyle::Empty => is_empty,
+        EnforcedStyle::Nil => is_nil,
+    };
+    if !is_redundant {
+        return;
+    }
+    let else_keyword = else_node.else_keyword_loc();
+    let fix = removal_fix(&else_keyword, &end_keyword, checker);
+    let severity = config(checker).base.severity;
+    checker.report(
+        EmptyElse::ID,
+        MSG.to_string(),
+        severity,
+        else_keyword.start_offset(),
+        else_keyword.end_offset(),
+        fix,
+    );
+}
+
+/// Delete the `else` branch up to the `end` keyword, whole lines when both keywords start their lines.
+///
+/// No fix is offered when the branch contains a comment, which would be lost.
+fn removal_fix(else_keyword: &Location, end_keyword: &Location, checker: &Checker) -> Option<Fix> {
+    let line_index = checker.line_index();
+    let (mut start, mut end) = (else_keyword.start_offset(), end_keyword.start_offset());
+    if line_index.is_first_on_line(start) && line_index.is_first_on_line(end) {
+        start = line_index.line_start_offset(start);
+        end = line_index.line_start_offset(end);
+    }
+    let removed = &checker.source()[start..end];
+    if removed.contains(&b'#') {
+        return None;
+    }
+    Some(Fix::safe(vec![Edit::deletion(start, end)]))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::Config;
+    use crate::{check, check_with_config};
+
+    fn empty_else_diagnostics(diagnostics: Vec<crate::Diagnostic>) -> Vec<crate::Diagnostic> {
+        diagnostics.into_iter().filter(|d| d.rule_id == EmptyElse::ID).collect()
+    }
+
+    fn config_with_style(style: EnforcedStyle) -> Config {
+        let mut config = Config::default();
+        config.style.empty_else.enforced_style = style;
+        config
+    }
+
+    fn fixed(source: &[u8]) -> Vec<u8> {
+        let diagnostics = empty_else_diagnostics(check(source));
+        crate::apply_fixes(None, source, &diagnostics, false).0
+    }
+
+    #[test]
+    fn test_empty_else() {
+        let source = b"if a\n  b\nelse\nend\n";
+        let diagnostics = empty_else_diagnostics(check(source));
+        assert_eq!(diagnostics.len(), 1);
+        assert_eq!(diagnostics[0].message, MSG);
+        assert_eq!(diagnostics[0].line_start, 3);
+        assert_eq!(fixed(source), b"if a\n  b\nend\n");
+    }
+
+    #[test]
+    fn test_nil_else() {
+        assert_eq!(fixed(b"if a\n  b\nelse\n  nil\nend\n"), b"if a\n  b\nend\n");
+        assert_eq!(fixed(b"case x\nwhen 1 then :one\nelse nil\nend\n"), b"case x\nwhen 1 then :one\nend\n");
+        assert_eq!(fixed(b"if a then b else nil end\n"), b"if a then b end\n");
+    }
+
+    #[test]
+    fn test_elsif_chain() {
+        assert_eq!(fixed(b"if a\n  b\nelsif c\n  d\nelse\nend\n"), b"if a\n  b\nelsif c\n  d\nend\n");
+    }
+
+    #[test]
+    fn test_accepts_meaningful_else_and_ternary() {
+        assert!(empty_else_diagnostics(check(b"if a\n  b\nelse\n  c\nend\n")).is_empty());
+        assert!(empty_else_diagnostics(check(b"x = a ? b : nil\n")).is_empty());
+    }
+
+    #[test]
+    fn test_comment_prevents_fix() {
+        let diagnostics = empty_else_diagnostics(check(b"if a\n  b\nelse\n  # nothing to do\nend\n"));
+        assert_eq!(diagnostics.len(), 1);
+        assert!(diagnostics[0].fix.is_none());
+    }
+
+    #[test]
+    fn test_styles() {
+        let empty = b"if a\n  b\nelse\nend\n";
+        let nil = b"if a\n  b\nelse\n  nil\nend\n";
+        let empty_style = config_with_style(EnforcedStyle::Empty);
+        assert_eq!(empty_else_diagnostics(check_with_config(empty, &empty_style)).len(), 1);
+        assert!(empty_else_diagnostics(check_with_config(nil, &empty_style)).is_empty());
+        let nil_style = config_with_style(EnforcedStyle::Nil);
+        assert!(empty_else_diagnostics(check_with_config(empty, &nil_style)).is_empty());
+        assert_eq!(empty_else_diagnostics(check_with_config(nil, &nil_style)).len(), 1);
+    }
+}
@@ -0,0 +1,177 @@
+use crate::checker::Checker;
+use crate::diagnostic::{Edit, Fix};
+use crate::rule::{Check, Rule, RuleId, StyleRule};
+use reukocyte_macros::check;
+use ruby_prism::*;
+
+/// Get the config for this rule
+#[inline]
+fn config<'a>(checker: &'a Checker<'_>) -> &'a crate::config::style::expand_path_arguments::ExpandPathArguments {
+    &checker.config().style.expand_path_arguments
+}
+
+/// Style/ExpandPathArguments rule.
+///
+/// Prefers `__dir__` over `__FILE__` as the base directory of `File.expand_path`,
+/// dropping one `..` from the relative path.
+///
+/// ```ruby
+/// # bad
+/// File.expand_path('../../lib', __FILE__)
+/// File.expand_path('..', __FILE__)
+///
+/// # good
+/// File.expand_path('../lib', __dir__)
+/// File.expand_path(__dir__)
+/// ```
+pub struct ExpandPathArguments;
+impl Rule for ExpandPathArguments {
+    const ID: RuleId = RuleId::Style(StyleRule::ExpandPathArguments);
+}
+#[check(CallNode)]
+impl Check<CallNode<'_>> for ExpandPathArguments {
+    fn check(node: &CallNode, checker: &mut Checker) {
+        if node.name().as_slice() != b"expand_path" || !node.receiver().is_some_and(|r| is_file_constant(&r)) {
+            return;
+        }
+        let Some(arguments) = node.arguments() else {
+            return;
+        };
+        let args: Vec<Node> = arguments.arguments().iter().collect();
+        let [path, default_dir] = args.as_slice() else {
+            return;
+        };
+        let (Some(path), true) = (path.as_string_node(), default_dir.as_source_file_node().is_some()) else {
+            return;
+        };
+        let (Some(opening), Some(closing)) = (path.opening_loc(), path.closing_loc()) else {
+            return;
+        };
+        let Ok(current_path) = std::str::from_utf8(path.content_loc().as_slice()) else {
+            return;
+        };
+        let Some(new_arguments) = corrected_arguments(current_path, opening.as_slice(), closing.as_slice()) else {
+            return;
+        };
+
+        let quote = String::from_utf8_lossy(opening.as_slice());
+        let message = format!(
+            "Use `expand_path({})` instead of `expand_path({}{}{}, __FILE__)`.",
+            new_arguments, quote, current_path, quote
+        );
+        let start = path.location().start_offset();
+        let end = default_dir.location().end_offset();
+        let fix = Fix::safe(vec![Edit::replacement(start, end, new_arguments)]);
+        let severity = config(checker).base.severity;
+        let location = node.location();
+        checker.report(Self::ID, message, severity, location.start_offset(), location.end_offset(), Some(fix));
+    }
+}
+
+/// Check if the node is `File` or `::File`.
+fn is_file_constant(node: &Node) -> bool {
+    if let Some(constant) = node.as_constant_read_node() {
+        return constant.name().as_slice() == b"File";
+    }
+    if let Some(constant_path) = node.as_constant_path_node() {
+        return constant_path.parent().is_none() && constant_path.name().is_some_and(|name| name.as_slice() == b"File");
+    }
+    false
+}
+
+/// Build the replacement arguments for `expand_path(current_path, __FILE__)`.
+///
+/// Returns `None` when the path does not climb out of the file itself
+/// (e.g. `'lib'`), since switching to `__dir__` would change its meaning.
+fn corrected_arguments(current_path: &str, opening: &[u8], closing: &[u8]) -> Option<String> {
+    let mut segments: Vec<&str> = current_path.split('/').filter(|segment| *segment != ".").collect();
+    if segments.is_empty() {
+        // `'.'` refers to the file itself.
+        return Some("__FILE__".to_string());
+    }
+    if segments[0] != ".." {
+        return None;
+    }
+    segments.remove(0);
+    if segments.is_empty() {
+        return Some("__dir__".to_string());
+    }
+    Some(format!(
+        "{}{}{}, __dir__",
+        String::from_utf8_lossy(opening),
+        segments.join("/"),
+        String::from_utf8_lossy(closing)
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::check;
+    use crate::fix::apply_fixes;
+
+    fn expand_path_diagnostics(source: &[u8]) -> Vec<crate::Diagnostic> {
+        check(source).into_iter().filter(|d| d.rule_id == ExpandPathArguments::ID).collect()
+    }
+
+    fn fixed(source: &[u8]) -> String {
+        let diagnostics = expand_path_diagnostics(source);
+        let (fixed, _) = apply_fixes(None, source, &diagnostics, false);
+        String::from_utf8(fixed).unwrap()
+    }
+
+    #[test]
+    fn test_rails_config_application() {
+        let source = b"require File.expand_path('../boot', __FILE__)\n";
+        let diagnostics = expand_path_diagnostics(source);
+        assert_eq!(diagnostics.len(), 1);
+        assert_eq!(diagnostics[0].rule(), "Style/ExpandPathArguments");
+        assert_eq!(
+            diagnostics[0].message,
+            "Use `expand_path('boot', __dir__)` instead of `expand_path('../boot', __FILE__)`."
+        );
+        assert_eq!(fixed(source), "require File.expand_path('boot', __dir__)\n");
+    }
+
+    #[test]
+    fn test_rails_environment() {
+        let source = b"require File.expand_path('../../config/environment', __FILE__)\n";
+        assert_eq!(fixed(source), "require File.expand_path('../config/environment', __dir__)\n");
+    }
+
+    #[test]
+    fn test_parent_directory() {
+        let source = b"File.expand_path('..', __FILE__)\n";
+        assert_eq!(fixed(source), "File.expand_path(__dir__)\n");
+    }
+
+    #[test]
+    fn test_current_file() {
+        let source = b"File.expand_path('.', __FILE__)\n";
+        assert_eq!(fixed(source), "File.expand_path(__FILE__)\n");
+    }
+
+    #[test]
+    fn test_double_quotes_are_preserved() {
+        let source = b"::File.expand_path(\"../lib\", __FILE__)\n";
+        assert_eq!(fixed(source), "::File.expand_path(\"lib\", __dir__)\n");
+    }
+
+    #[test]
+    fn test_no_offense_for_dir() {
+        let source = b"File.expand_path('../lib', __dir__)\nFile.expand_path(__FILE__)\n";
+        assert!(expand_path_diagnostics(source).is_empty());
+    }
+
+    #[test]
+    fn test_no_offense_for_path_inside_file() {
+        let source = b"File.expand_path('lib', __FILE__)\n";
+        assert!(expand_path_diagnostics(source).is_empty());
+    }
+
+    #[test]
+    fn test_no_offense_for_other_receiver() {
+        let source = b"Foo.expand_path('../lib', __FILE__)\n";
+        assert!(expand_path_diagnostics(source).is_empty());
+    }
+}
@@ -0,0 +1,12 @@
+pub mod class_and_module_children;
+pub mod conditional_assignment;
+pub mod empty_else;
+pub mod expand_path_arguments;
+pub mod negated_while;
+pub mod next;
+pub mod redundant_double_splat_hash_braces;
+pub mod redundant_regexp_escape;
+pub mod redundant_return;
+pub mod redundant_sort_by;
+pub mod safe_navigation;
+pub mod stderr_puts;
@@ -0,0 +1,131 @@
+use crate::checker::Checker;
+use crate::diagnostic::{Edit, Fix};
+use crate::rule::{Check, Rule, RuleId, StyleRule};
+use reukocyte_macros::check;
+use ruby_prism::*;
+
+/// Get the config for this rule
+#[inline]
+fn config<'a>(checker: &'a Checker<'_>) -> &'a crate::config::style::negated_while::NegatedWhile {
+    &checker.config().style.negated_while
+}
+
+/// Style/NegatedWhile rule.
+///
+/// Prefers `until` over `while` (and vice versa) when the condition is negated.
+///
+/// ```ruby
+/// # bad
+/// while !foo
+///   bar
+/// end
+/// bar until !foo
+///
+/// # good
+/// until foo
+///   bar
+/// end
+/// bar while foo
+/// ```
+pub struct NegatedWhile;
+impl Rule for NegatedWhile {
+    const ID: RuleId = RuleId::Style(StyleRule::NegatedWhile);
+}
+#[check(WhileNode)]
+impl Check<WhileNode<'_>> for NegatedWhile {
+    fn check(node: &WhileNode, checker: &mut Checker) {
+        check_loop(&node.keyword_loc(), &node.predicate(), "until", checker);
+    }
+}
+#[check(UntilNode)]
+impl Check<UntilNode<'_>> for NegatedWhile {
+    fn check(node: &UntilNode, checker: &mut Checker) {
+        check_loop(&node.keyword_loc(), &node.predicate(), "while", checker);
+    }
+}
+
+fn check_loop(keyword: &Location, predicate: &Node, inverse_keyword: &str, checker: &mut Checker) {
+    let Some(condition) = negated_condition(predicate) else {
+        return;
+    };
+    let current_keyword = String::from_utf8_lossy(keyword.as_slice());
+    let message = format!("Favor `{}` over `{}` for negative conditions.", inverse_keyword, current_keyword);
+    let predicate_location = predicate.location();
+    let condition_location = condition.location();
+    let fix = Fix::safe(vec![
+        Edit::replacement(keyword.start_offset(), keyword.end_offset(), inverse_keyword.to_string()),
+        Edit::deletion(predicate_location.start_offset(), condition_location.start_offset()),
+        Edit::deletion(condition_location.end_offset(), predicate_location.end_offset()),
+    ]);
+    let severity = config(checker).base.severity;
+    checker.report(
+        NegatedWhile::ID,
+        message,
+        severity,
+        keyword.start_offset(),
+        predicate_location.end_offset(),
+        Some(fix),
+    );
+}
+
+/// Get the condition negated by `!cond` or `not cond`, if the predicate is a single negation.
+fn negated_condition<'pr>(predicate: &Node<'pr>) -> Option<Node<'pr>> {
+    let call = predicate.as_call_node()?;
+    if call.name().as_slice() != b"!" || call.arguments().is_some() {
+        return None;
+    }
+    let receiver = call.receiver()?;
+    // `!!cond` is a boolean cast rather than a negation.
+    if receiver.as_call_node().is_some_and(|inner| inner.name().as_slice() == b"!") {
+        return None;
+    }
+    Some(receiver)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::check;
+    use crate::fix::apply_fixes;
+
+    fn negated_while_diagnostics(source: &[u8]) -> Vec<crate::Diagnostic> {
+        check(source).into_iter().filter(|d| d.rule_id == NegatedWhile::ID).collect()
+    }
+
+    fn fixed(source: &[u8]) -> String {
+        let diagnostics = negated_while_diagnostics(source);
+        let (fixed, _) = apply_fixes(None, source, &diagnostics, false);
+        String::from_utf8(fixed).unwrap()
+    }
+
+    #[test]
+    fn test_negated_while() {
+        let source = b"while !foo\n  bar\nend\n";
+        let diagnostics = negated_while_diagnostics(source);
+        assert_eq!(diagnostics.len(), 1);
+        assert_eq!(diagnostics[0].message, "Favor `until` over `while` for negative conditions.");
+        assert_eq!(fixed(source), "until foo\n  bar\nend\n");
+    }
+
+    #[test]
+    fn test_negated_until() {
+        let source = b"until !foo.empty?\n  bar\nend\n";
+        let diagnostics = negated_while_diagnostics(source);
+        assert_eq!(diagnostics.len(), 1);
+        assert_eq!(diagnostics[0].message, "Favor `while` over `until` for negative conditions.");
+        assert_eq!(fixed(source), "while foo.empty?\n  bar\nend\n");
+    }
+
+    #[test]
+    fn test_modifier_forms() {
+        assert_eq!(fixed(b"bar while !foo\n"), "bar until foo\n");
+        assert_eq!(fixed(b"bar until not foo\n"), "bar while foo\n");
+        assert_eq!(fixed(b"begin\n  bar\nend while !(a && b)\n"), "begin\n  bar\nend until (a && b)\n");
+    }
+
+    #[test]
+    fn test_no_offense() {
+        let source = b"while foo\n  bar\nend\nbar until foo\nwhile !a && b\n  bar\nend\nwhile !!foo\n  bar\nend\n";
+        assert!(negated_while_diagnostics(source).is_empty());
+    }
+}
@@ -0,0 +1,297 @@
+use crate::checker::Checker;
+use crate::config::style::next::EnforcedStyle;
+use crate::diagnostic::{Edit, Fix};
+use crate::rule::{Check, Rule, RuleId, StyleRule};
+use reukocyte_macros::check;
+use ruby_prism::*;
+
+const MSG: &str = "Use `next` to skip iteration.";
+
+/// Iterator methods whose blocks are checked, besides any `each_*` method.
+const ENUMERATOR_METHODS: &[&[u8]] = &[
+    b"collect",
+    b"collect_concat",
+    b"detect",
+    b"downto",
+    b"each",
+    b"find",
+    b"find_all",
+    b"find_index",
+    b"inject",
+    b"loop",
+    b"map",
+    b"map!",
+    b"reduce",
+    b"reject",
+    b"reject!",
+    b"reverse_each",
+    b"select",
+    b"select!",
+    b"times",
+    b"upto",
+];
+
+/// Get the config for this rule
+#[inline]
+fn config<'a>(checker: &'a Checker<'_>) -> &'a crate::config::style::next::Next {
+    &checker.config().style.next
+}
+
+/// Style/Next rule.
+///
+/// Detects an iterator or `for` loop body that ends in an `if`/`unless`
+/// wrapping the rest of the iteration, which reads flatter with `next`.
+/// Conditionals must span more than `MinBodyLength` lines; modifier forms are
+/// only reported with `EnforcedStyle: always`. The fix is unsafe, since it
+/// reindents the body and rewrites the condition.
+///
+/// ```ruby
+/// # bad
+/// [1, 2].each do |a|
+///   if a == 1
+///     puts a
+///   end
+/// end
+///
+/// # good
+/// [1, 2].each do |a|
+///   next unless a == 1
+///   puts a
+/// end
+/// ```
+pub struct Next;
+impl Rule for Next {
+    const ID: RuleId = RuleId::Style(StyleRule::Next);
+}
+#[check(BlockNode)]
+impl Check<BlockNode<'_>> for Next {
+    fn check(node: &BlockNode, checker: &mut Checker) {
+        let Some(call) = checker.semantic().parent().and_then(|parent| parent.as_call_node()) else {
+            return;
+        };
+        let name = call.name().as_slice();
+        if !ENUMERATOR_METHODS.contains(&name) && !name.starts_with(b"each_") {
+            return;
+        }
+        if let Some(body) = node.body().and_then(|body| body.as_statements_node()) {
+            check_body(&body, checker);
+        }
+    }
+}
+#[check(ForNode)]
+impl Check<ForNode<'_>> for Next {
+    fn check(node: &ForNode, checker: &mut Checker) {
+        if let Some(body) = node.statements() {
+            check_body(&body, checker);
+        }
+    }
+}
+
+/// An `if`/`unless` without an `else` branch.
+struct Conditional<'pr> {
+    location: Location<'pr>,
+    keyword: Location<'pr>,
+    /// The keyword that negates the condition in the `next` modifier.
+    inverse: &'static str,
+    predicate: Node<'pr>,
+    then_keyword: Option<Location<'pr>>,
+    statements: StatementsNode<'pr>,
+    /// `None` for modifier forms.
+    end_keyword: Option<Location<'pr>>,
+}
+impl<'pr> Conditional<'pr> {
+    fn from_node(node: &Node<'pr>) -> Option<Self> {
+        if let Some(if_node) = node.as_if_node() {
+            // Ternaries have no keyword; `elsif`/`else` have a subsequent branch.
+            let keyword = if_node.if_keyword_loc()?;
+            if if_node.subsequent().is_some() {
+                return None;
+            }
+            return Some(Self {
+                location: if_node.location(),
+                keyword,
+                inverse: "unless",
+                predicate: if_node.predicate(),
+                then_keyword: if_node.then_keyword_loc(),
+                statements: if_node.statements()?,
+                end_keyword: if_node.end_keyword_loc(),
+            });
+        }
+        let unless_node = node.as_unless_node()?;
+        if unless_node.else_clause().is_some() {
+            return None;
+        }
+        Some(Self {
+            location: unless_node.location(),
+            keyword: unless_node.keyword_loc(),
+            inverse: "if",
+            predicate: unless_node.predicate(),
+            then_keyword: unless_node.then_keyword_loc(),
+            statements: unless_node.statements()?,
+            end_keyword: unless_node.end_keyword_loc(),
+        })
+    }
+}
+
+/// Report the conditional ending the loop body, if `next` could replace it.
+fn check_body(body: &StatementsNode, checker: &mut Checker) {
+    let Some(last) = body.body().iter().last() else {
+        return;
+    };
+    let Some(conditional) = Conditional::from_node(&last) else {
+        return;
+    };
+    let config = config(checker);
+    let line_index = checker.line_index();
+    match conditional.end_keyword {
+        None if config.enforced_style == EnforcedStyle::SkipModifierIfs => return,
+        Some(end_keyword)
+            if line_index.line_number(end_keyword.start_offset()) - line_index.line_number(conditional.keyword.start_offset()) <= config.min_body_length =>
+        {
+            return;
+        }
+        _ => {}
+    }
+    let mut statements = conditional.statements.body().iter();
+    if let (Some(statement), None) = (statements.next(), statements.next()) {
+        // An exit is already as flat as `next`, and a nested `if`/`else` is left alone.
+        let nested_else = Conditional::from_node(&statement).is_none() && (statement.as_if_node().is_some() || statement.as_unless_node().is_some());
+        if statement.as_break_node().is_some() || statement.as_return_node().is_some() || nested_else {
+            return;
+        }
+    }
+
+    let start = conditional.keyword.start_offset();
+    let end = conditional.predicate.location().end_offset();
+    let fix = fix(&conditional, checker);
+    let severity = config.base.severity;
+    checker.report(Next::ID, MSG.to_string(), severity, start, end, fix);
+}
+
+/// Replace the conditional with a `next` modifier followed by its body.
+fn fix(conditional: &Conditional, checker: &Checker) -> Option<Fix> {
+    let line_index = checker.line_index();
+    let start = conditional.location.start_offset();
+    if !line_index.is_first_on_line(start) {
+        return None;
+    }
+    let source = checker.source();
+    let predicate = String::from_utf8_lossy(conditional.predicate.location().as_slice());
+    let next = format!("next {} {}", conditional.inverse, predicate);
+    let body = conditional.statements.location();
+
+    let Some(end_keyword) = &conditional.end_keyword else {
+        // `body if cond` -> `next unless cond` and `body` on the next line.
+        let indentation = " ".repeat(line_index.column(start));
+        let body = String::from_utf8_lossy(&source[body.start_offset()..body.end_offset()]);
+        let replacement = format!("{next}\n{indentation}{body}");
+        return Some(Fix::r#unsafe(vec![Edit::replacement(start, conditional.location.end_offset(), replacement)]));
+    };
+    if line_index.are_on_same_line(start, body.start_offset())
+        || line_index.are_on_same_line(body.end_offset(), end_keyword.start_offset())
+        || !line_index.is_first_on_line(end_keyword.start_offset())
+    {
+        return None;
+    }
+
+    let header_end = conditional
+        .then_keyword
+        .as_ref()
+        .map_or(conditional.predicate.location().end_offset(), |then| then.end_offset());
+    let mut edits = vec![Edit::replacement(start, header_end, next)];
+    // Shift the body left by one level, leaving literal contents alone.
+    let shift = line_index.column(body.start_offset()).saturating_sub(line_index.column(start));
+    for line in line_index.line_index(body.start_offset())..=line_index.line_index(body.end_offset()) {
+        let Some(line_start) = line_index.line_start(line) else {
+            continue;
+        };
+        let content = line_index.line(line).unwrap_or_default();
+        if content.len() > shift && content[..shift].iter().all(|&b| b == b' ') && !checker.is_in_literal(line_start) {
+            edits.push(Edit::deletion(line_start, line_start + shift));
+        }
+    }
+    // Remove the `end` line, including the newline before it.
+    edits.push(Edit::deletion(
+        line_index.line_start_offset(end_keyword.start_offset()) - 1,
+        end_keyword.end_offset(),
+    ));
+    Some(Fix::r#unsafe(edits))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::Config;
+    use crate::corrector::Corrector;
+    use crate::{Applicability, check_with_config};
+
+    fn next_diagnostics(source: &[u8], config: &Config) -> Vec<crate::Diagnostic> {
+        check_with_config(source, config).into_iter().filter(|d| d.rule_id == Next::ID).collect()
+    }
+
+    fn fixed(source: &[u8], config: &Config) -> String {
+        let mut corrector = Corrector::new();
+        for diagnostic in next_diagnostics(source, config) {
+            corrector.merge(diagnostic.fix.as_ref().unwrap()).unwrap();
+        }
+        String::from_utf8(corrector.apply(source)).unwrap()
+    }
+
+    #[test]
+    fn test_iterator_wrapped_in_if() {
+        let config = Config::default();
+        let source = b"[1, 2].each do |a|\n  if a == 1\n    puts a\n    puts a\n    puts a\n  end\nend\n";
+        let diagnostics = next_diagnostics(source, &config);
+        assert_eq!(diagnostics.len(), 1);
+        assert_eq!(diagnostics[0].message, MSG);
+        assert_eq!((diagnostics[0].line_start, diagnostics[0].column_start, diagnostics[0].column_end), (2, 3, 12));
+        assert_eq!(diagnostics[0].fix.as_ref().unwrap().applicability, Applicability::Unsafe);
+        assert_eq!(
+            fixed(source, &config),
+            "[1, 2].each do |a|\n  next unless a == 1\n  puts a\n  puts a\n  puts a\nend\n"
+        );
+
+        // Heredoc contents keep their indentation.
+        let source = b"items.map { |a|\n  foo\n  unless a then\n    x = <<~TEXT\n      text\n    TEXT\n    bar(x)\n  end\n}\n";
+        assert_eq!(
+            fixed(source, &config),
+            "items.map { |a|\n  foo\n  next if a\n  x = <<~TEXT\n      text\n  TEXT\n  bar(x)\n}\n"
+        );
+    }
+
+    #[test]
+    fn test_for_loop() {
+        let source = b"for a in list\n  if a\n    foo\n    bar\n    baz\n  end\nend\n";
+        assert_eq!(fixed(source, &Config::default()), "for a in list\n  next unless a\n  foo\n  bar\n  baz\nend\n");
+    }
+
+    #[test]
+    fn test_min_body_length() {
+        let mut config = Config::default();
+        let source = b"list.each do |a|\n  if a\n    foo\n  end\nend\n";
+        assert!(next_diagnostics(source, &config).is_empty());
+        config.style.next.min_body_length = 1;
+        assert_eq!(next_diagnostics(source, &config).len(), 1);
+        assert_eq!(fixed(source, &config), "list.each do |a|\n  next unless a\n  foo\nend\n");
+    }
+
+    #[test]
+    fn test_modifier_form() {
+        let mut config = Config::default();
+        let source = b"list.each do |a|\n  foo(a) if a\nend\n";
+        assert!(next_diagnostics(source, &config).is_empty());
+        config.style.next.enforced_style = EnforcedStyle::Always;
+        assert_eq!(fixed(source, &config), "list.each do |a|\n  next unless a\n  foo(a)\nend\n");
+    }
+
+    #[test]
+    fn test_ignored_conditionals() {
+        let config = Config::default();
+        // `else` branches, exits, non-iterator blocks, and conditionals before the end of the body.
+        assert!(next_diagnostics(b"list.each do |a|\n  if a\n    foo\n    bar\n  else\n    baz\n  end\nend\n", &config).is_empty());
+        assert!(next_diagnostics(b"list.each do |a|\n  if a\n\n\n    break\n  end\nend\n", &config).is_empty());
+        assert!(next_diagnostics(b"foo do |a|\n  if a\n    foo\n    bar\n    baz\n  end\nend\n", &config).is_empty());
+        assert!(next_diagnostics(b"list.each do |a|\n  if a\n    foo\n    bar\n    baz\n  end\n  qux\nend\n", &config).is_empty());
+        assert!(next_diagnostics(b"list.each do |a|\n  a ? foo : bar\nend\n", &config).is_empty());
+    }
+}
@@ -0,0 +1,118 @@
+use crate::checker::Checker;
+use crate::diagnostic::{Edit, Fix};
+use crate::rule::{Check, Rule, RuleId, StyleRule};
+use reukocyte_macros::check;
+use ruby_prism::*;
+
+const MSG: &str = "Remove the redundant double splat and braces, use keyword arguments directly.";
+
+/// Get the config for this rule
+#[inline]
+fn config<'a>(checker: &'a Checker<'_>) -> &'a crate::config::style::redundant_double_splat_hash_braces::RedundantDoubleSplatHashBraces {
+    &checker.config().style.redundant_double_splat_hash_braces
+}
+
+/// Style/RedundantDoubleSplatHashBraces rule.
+///
+/// Checks for a double-splatted hash literal in keyword arguments, whose
+/// braces and `**` can be dropped.
+///
+/// ```ruby
+/// # bad
+/// do_something(**{foo: bar, baz: qux})
+///
+/// # good
+/// do_something(foo: bar, baz: qux)
+/// ```
+pub struct RedundantDoubleSplatHashBraces;
+impl Rule for RedundantDoubleSplatHashBraces {
+    const ID: RuleId = RuleId::Style(StyleRule::RedundantDoubleSplatHashBraces);
+}
+#[check(KeywordHashNode)]
+impl Check<KeywordHashNode<'_>> for RedundantDoubleSplatHashBraces {
+    fn check(node: &KeywordHashNode, checker: &mut Checker) {
+        for element in node.elements().iter() {
+            let Some(assoc_splat) = element.as_assoc_splat_node() else {
+                continue;
+            };
+            let Some(hash) = assoc_splat.value().and_then(|value| value.as_hash_node()) else {
+                continue;
+            };
+            check_double_splat(&assoc_splat, &hash, checker);
+        }
+    }
+}
+
+fn check_double_splat(assoc_splat: &AssocSplatNode, hash: &HashNode, checker: &mut Checker) {
+    let elements: Vec<Node> = hash.elements().iter().collect();
+    let (Some(first), Some(last)) = (elements.first(), elements.last()) else {
+        return;
+    };
+    if !elements.iter().all(is_keyword_argument) {
+        return;
+    }
+
+    // Remove `**{` up to the first pair and everything after the last pair up to `}`.
+    let location = assoc_splat.location();
+    let edits = vec![
+        Edit::deletion(location.start_offset(), first.location().start_offset()),
+        Edit::deletion(last.location().end_offset(), hash.closing_loc().end_offset()),
+    ];
+    let severity = config(checker).base.severity;
+    checker.report(
+        RedundantDoubleSplatHashBraces::ID,
+        MSG.to_string(),
+        severity,
+        location.start_offset(),
+        location.end_offset(),
+        Some(Fix::safe(edits)),
+    );
+}
+
+/// Check if a hash element can be passed directly as a keyword argument.
+fn is_keyword_argument(element: &Node) -> bool {
+    if let Some(assoc) = element.as_assoc_node() {
+        return assoc.key().as_symbol_node().is_some();
+    }
+    element.as_assoc_splat_node().is_some()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::check;
+    use crate::fix::apply_fixes;
+
+    fn redundant_braces_diagnostics(source: &[u8]) -> Vec<crate::Diagnostic> {
+        check(source).into_iter().filter(|d| d.rule_id == RedundantDoubleSplatHashBraces::ID).collect()
+    }
+
+    fn fixed(source: &[u8]) -> String {
+        let diagnostics = redundant_braces_diagnostics(source);
+        let (fixed, _) = apply_fixes(None, source, &diagnostics, false);
+        String::from_utf8(fixed).unwrap()
+    }
+
+    #[test]
+    fn test_redundant_braces() {
+        let source = b"bar(**{x: 1})\n";
+        let diagnostics = redundant_braces_diagnostics(source);
+        assert_eq!(diagnostics.len(), 1);
+        assert_eq!(diagnostics[0].message, MSG);
+        assert_eq!(diagnostics[0].column_start, 5);
+        assert_eq!(diagnostics[0].column_end, 13);
+        assert_eq!(fixed(source), "bar(x: 1)\n");
+    }
+
+    #[test]
+    fn test_multiple_pairs_with_spaces() {
+        assert_eq!(fixed(b"bar(**{ x: 1, y: 2 })\n"), "bar(x: 1, y: 2)\n");
+        assert_eq!(fixed(b"bar(a, z: 0, **{x: 1, **opts})\n"), "bar(a, z: 0, x: 1, **opts)\n");
+    }
+
+    #[test]
+    fn test_no_offense() {
+        let source = b"bar(**opts)\nbar(**{})\nbar(**{'x' => 1})\nbar(**{x: 1}.merge(y))\nbar({x: 1})\n";
+        assert!(redundant_braces_diagnostics(source).is_empty());
+    }
+}
@@ -0,0 +1,156 @@
+use crate::checker::Checker;
+use crate::diagnostic::{Edit, Fix};
+use crate::rule::{Check, Rule, RuleId, StyleRule};
+use reukocyte_macros::check;
+use ruby_prism::*;
+
+const MSG: &str = "Redundant escape inside regexp literal";
+
+/// Characters whose escape is always meaningful (or harmless enough to keep).
+const ALLOWED_ALWAYS_ESCAPES: &[u8] = b" \n[]^\\#";
+/// Metacharacters that need an escape inside a character class.
+const ALLOWED_WITHIN_CHAR_CLASS_METACHAR_ESCAPES: &[u8] = b"-";
+/// Metacharacters that need an escape outside a character class.
+const ALLOWED_OUTSIDE_CHAR_CLASS_METACHAR_ESCAPES: &[u8] = b".*+?{}()|$";
+
+/// Get the config for this rule
+#[inline]
+fn config<'a>(checker: &'a Checker<'_>) -> &'a crate::config::style::redundant_regexp_escape::RedundantRegexpEscape {
+    &checker.config().style.redundant_regexp_escape
+}
+
+/// Style/RedundantRegexpEscape rule.
+///
+/// Detects backslashes in regexp literals that escape a character with no
+/// special meaning where it appears, such as `-` outside a character class or
+/// `.` inside one. Escaped letters and digits (`\d`, `\1`) and the literal's
+/// own delimiters are never reported.
+///
+/// ```ruby
+/// # bad
+/// %r{foo\/bar}
+/// /a\-b/
+/// /[\.]/
+///
+/// # good
+/// %r{foo/bar}
+/// /a-b/
+/// /[.]/
+/// /[\-]/
+/// ```
+pub struct RedundantRegexpEscape;
+impl Rule for RedundantRegexpEscape {
+    const ID: RuleId = RuleId::Style(StyleRule::RedundantRegexpEscape);
+}
+#[check(RegularExpressionNode)]
+impl Check<RegularExpressionNode<'_>> for RedundantRegexpEscape {
+    fn check(node: &RegularExpressionNode, checker: &mut Checker) {
+        let delimiters = [node.opening_loc().as_slice().last().copied(), node.closing_loc().as_slice().first().copied()];
+        let content = node.content_loc();
+        let offset = content.start_offset();
+        let redundant: Vec<usize> = redundant_escapes(content.as_slice(), &delimiters)
+            .into_iter()
+            .map(|index| offset + index)
+            .collect();
+        let severity = config(checker).base.severity;
+        for backslash in redundant {
+            let fix = Fix::safe(vec![Edit::deletion(backslash, backslash + 1)]);
+            checker.report(RedundantRegexpEscape::ID, MSG.to_string(), severity, backslash, backslash + 2, Some(fix));
+        }
+    }
+}
+
+/// Get the indices of backslashes in `content` whose escape is redundant.
+fn redundant_escapes(content: &[u8], delimiters: &[Option<u8>]) -> Vec<usize> {
+    let mut redundant = Vec::new();
+    // Nesting depth of character classes, which `[[:alpha:]]` and `[a[b]]` can open.
+    let mut char_class_depth = 0usize;
+    let mut index = 0;
+    while index < content.len() {
+        match content[index] {
+            b'\\' => {
+                let Some(&escaped) = content.get(index + 1) else {
+                    break;
+                };
+                if !is_allowed_escape(escaped, char_class_depth > 0, delimiters) {
+                    redundant.push(index);
+                }
+                index += 2;
+                continue;
+            }
+            b'[' => char_class_depth += 1,
+            b']' => char_class_depth = char_class_depth.saturating_sub(1),
+            _ => {}
+        }
+        index += 1;
+    }
+    redundant
+}
+
+/// Check if escaping `escaped` changes (or may change) what the regexp matches.
+fn is_allowed_escape(escaped: u8, within_char_class: bool, delimiters: &[Option<u8>]) -> bool {
+    // Letters and digits form escape sequences; multibyte characters are left alone.
+    if escaped.is_ascii_alphanumeric() || !escaped.is_ascii() {
+        return true;
+    }
+    if ALLOWED_ALWAYS_ESCAPES.contains(&escaped) || delimiters.contains(&Some(escaped)) {
+        return true;
+    }
+    if within_char_class {
+        ALLOWED_WITHIN_CHAR_CLASS_METACHAR_ESCAPES.contains(&escaped)
+    } else {
+        ALLOWED_OUTSIDE_CHAR_CLASS_METACHAR_ESCAPES.contains(&escaped)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::check;
+    use crate::corrector::Corrector;
+
+    fn redundant_regexp_escape_diagnostics(source: &[u8]) -> Vec<crate::Diagnostic> {
+        check(source).into_iter().filter(|d| d.rule_id == RedundantRegexpEscape::ID).collect()
+    }
+
+    fn fixed(source: &[u8]) -> String {
+        let mut corrector = Corrector::new();
+        for diagnostic in redundant_regexp_escape_diagnostics(source) {
+            corrector.merge(diagnostic.fix.as_ref().unwrap()).unwrap();
+        }
+        String::from_utf8(corrector.apply(source)).unwrap()
+    }
+
+    #[test]
+    fn test_redundant_escape_outside_char_class() {
+        let diagnostics = redundant_regexp_escape_diagnostics(b"x =~ /a\\-b/\n");
+        assert_eq!(diagnostics.len(), 1);
+        assert_eq!(diagnostics[0].message, MSG);
+        assert_eq!((diagnostics[0].column_start, diagnostics[0].column_end), (8, 10));
+        assert_eq!(fixed(b"x =~ /a\\-b/\n"), "x =~ /a-b/\n");
+        assert_eq!(fixed(b"x =~ /\\:\\=\\\"/\n"), "x =~ /:=\"/\n");
+    }
+
+    #[test]
+    fn test_char_class_context() {
+        assert!(redundant_regexp_escape_diagnostics(b"x =~ /[\\-]/\n").is_empty());
+        assert!(redundant_regexp_escape_diagnostics(b"x =~ /[a\\-z\\]\\[]/\n").is_empty());
+        assert_eq!(fixed(b"x =~ /[\\.\\*]\\./\n"), "x =~ /[.*]\\./\n");
+        // An escaped `[` does not open a character class, and nested classes are tracked.
+        assert_eq!(fixed(b"x =~ /\\[\\-\\]/\n"), "x =~ /\\[-\\]/\n");
+        assert_eq!(fixed(b"x =~ /[[:alpha:]\\.]\\./\n"), "x =~ /[[:alpha:].]\\./\n");
+    }
+
+    #[test]
+    fn test_delimiters() {
+        assert!(redundant_regexp_escape_diagnostics(b"x =~ /a\\/b/\n").is_empty());
+        assert!(redundant_regexp_escape_diagnostics(b"x =~ %r{a\\}b}\n").is_empty());
+        assert_eq!(fixed(b"x =~ %r{a\\/b}\n"), "x =~ %r{a/b}\n");
+    }
+
+    #[test]
+    fn test_meaningful_escapes() {
+        assert!(redundant_regexp_escape_diagnostics(b"x =~ /\\d\\s\\1\\.\\*\\+\\?\\(\\)\\{\\}\\|\\$\\^\\\\\\#\\ /\n").is_empty());
+        assert!(redundant_regexp_escape_diagnostics(b"x =~ /\\\xc3\xa9/\n").is_empty());
+    }
+}
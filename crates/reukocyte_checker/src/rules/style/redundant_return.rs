@@ -0,0 +1,238 @@
+use crate::checker::Checker;
+use crate::diagnostic::{Edit, Fix};
+use crate::rule::{Check, Rule, RuleId, StyleRule};
+use reukocyte_macros::check;
+use ruby_prism::*;
+
+const MSG: &str = "Redundant `return` detected.";
+const MULTI_RETURN_MSG: &str = "Redundant `return` detected. To return multiple values, use an array.";
+
+/// Get the config for this rule
+#[inline]
+fn config<'a>(checker: &'a Checker<'_>) -> &'a crate::config::style::redundant_return::RedundantReturn {
+    &checker.config().style.redundant_return
+}
+
+/// Style/RedundantReturn rule.
+///
+/// Checks for `return` as the last expression of a method body, including the
+/// last expression of each `if`/`case`/`begin` branch in tail position.
+///
+/// ```ruby
+/// # bad
+/// def foo
+///   return a, b
+/// end
+///
+/// # good
+/// def foo
+///   [a, b]
+/// end
+/// ```
+pub struct RedundantReturn;
+impl Rule for RedundantReturn {
+    const ID: RuleId = RuleId::Style(StyleRule::RedundantReturn);
+}
+#[check(DefNode)]
+impl Check<DefNode<'_>> for RedundantReturn {
+    fn check(node: &DefNode, checker: &mut Checker) {
+        if let Some(body) = node.body() {
+            check_branch(&body, checker);
+        }
+    }
+}
+
+/// Check the expression in tail position of `node`.
+fn check_branch(node: &Node, checker: &mut Checker) {
+    if let Some(return_node) = node.as_return_node() {
+        check_return_node(&return_node, checker);
+    } else if let Some(statements) = node.as_statements_node() {
+        if let Some(last) = statements.body().iter().last() {
+            check_branch(&last, checker);
+        }
+    } else if let Some(if_node) = node.as_if_node() {
+        // Ternaries and modifier forms are left alone.
+        let Some(keyword) = if_node.if_keyword_loc() else {
+            return;
+        };
+        if keyword.as_slice() != b"elsif" && if_node.end_keyword_loc().is_none() {
+            return;
+        }
+        if let Some(statements) = if_node.statements() {
+            check_branch(&statements.as_node(), checker);
+        }
+        if let Some(subsequent) = if_node.subsequent() {
+            check_branch(&subsequent, checker);
+        }
+    } else if let Some(unless_node) = node.as_unless_node() {
+        if unless_node.end_keyword_loc().is_none() {
+            return;
+        }
+        if let Some(statements) = unless_node.statements() {
+            check_branch(&statements.as_node(), checker);
+        }
+        if let Some(else_clause) = unless_node.else_clause() {
+            check_branch(&else_clause.as_node(), checker);
+        }
+    } else if let Some(else_node) = node.as_else_node() {
+        if let Some(statements) = else_node.statements() {
+            check_branch(&statements.as_node(), checker);
+        }
+    } else if let Some(case_node) = node.as_case_node() {
+        for condition in case_node.conditions().iter() {
+            if let Some(statements) = condition.as_when_node().and_then(|when| when.statements()) {
+                check_branch(&statements.as_node(), checker);
+            }
+        }
+        if let Some(else_clause) = case_node.else_clause() {
+            check_branch(&else_clause.as_node(), checker);
+        }
+    } else if let Some(case_match_node) = node.as_case_match_node() {
+        for condition in case_match_node.conditions().iter() {
+            if let Some(statements) = condition.as_in_node().and_then(|in_node| in_node.statements()) {
+                check_branch(&statements.as_node(), checker);
+            }
+        }
+        if let Some(else_clause) = case_match_node.else_clause() {
+            check_branch(&else_clause.as_node(), checker);
+        }
+    } else if let Some(begin_node) = node.as_begin_node() {
+        // With an `else` clause, the body is no longer in tail position.
+        if let Some(else_clause) = begin_node.else_clause() {
+            check_branch(&else_clause.as_node(), checker);
+        } else if let Some(statements) = begin_node.statements() {
+            check_branch(&statements.as_node(), checker);
+        }
+        let mut rescue_clause = begin_node.rescue_clause();
+        while let Some(rescue_node) = rescue_clause {
+            if let Some(statements) = rescue_node.statements() {
+                check_branch(&statements.as_node(), checker);
+            }
+            rescue_clause = rescue_node.subsequent();
+        }
+    }
+}
+
+fn check_return_node(node: &ReturnNode, checker: &mut Checker) {
+    let cfg = config(checker);
+    let arguments: Vec<Node> = node.arguments().map(|arguments| arguments.arguments().iter().collect()).unwrap_or_default();
+    if arguments.len() > 1 && cfg.allow_multiple_return_values {
+        return;
+    }
+    let severity = cfg.base.severity;
+    let keyword = node.keyword_loc();
+
+    let (message, edits) = match arguments.as_slice() {
+        [] => {
+            let location = node.location();
+            (MSG, vec![Edit::replacement(location.start_offset(), location.end_offset(), "nil".to_string())])
+        }
+        [single] if single.as_splat_node().is_none() => (MSG, vec![remove_keyword(&keyword, checker)]),
+        [first, ..] => {
+            let last = arguments.last().unwrap_or(first);
+            let mut edits = vec![remove_keyword(&keyword, checker)];
+            edits.extend(wrap_in_brackets(first.location().start_offset(), last.location().end_offset()));
+            (if arguments.len() > 1 { MULTI_RETURN_MSG } else { MSG }, edits)
+        }
+    };
+
+    checker.report(
+        RedundantReturn::ID,
+        message.to_string(),
+        severity,
+        keyword.start_offset(),
+        keyword.end_offset(),
+        Some(Fix::safe(edits)),
+    );
+}
+
+/// Remove the `return` keyword along with the whitespace following it.
+fn remove_keyword(keyword: &Location, checker: &Checker) -> Edit {
+    let source = checker.source();
+    let mut end = keyword.end_offset();
+    while end < source.len() && matches!(source[end], b' ' | b'\t') {
+        end += 1;
+    }
+    Edit::deletion(keyword.start_offset(), end)
+}
+
+/// Surround the range with brackets.
+fn wrap_in_brackets(start: usize, end: usize) -> [Edit; 2] {
+    [Edit::insertion(start, "[".to_string()), Edit::insertion(end, "]".to_string())]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::Config;
+    use crate::fix::apply_fixes;
+    use crate::{check, check_with_config};
+
+    fn redundant_return_diagnostics(source: &[u8]) -> Vec<crate::Diagnostic> {
+        check(source).into_iter().filter(|d| d.rule_id == RedundantReturn::ID).collect()
+    }
+
+    fn fixed(source: &[u8]) -> String {
+        let diagnostics = redundant_return_diagnostics(source);
+        let (fixed, _) = apply_fixes(None, source, &diagnostics, false);
+        String::from_utf8(fixed).unwrap()
+    }
+
+    #[test]
+    fn test_multiple_return_values() {
+        let source = b"def f; return a, b; end\n";
+        let diagnostics = redundant_return_diagnostics(source);
+        assert_eq!(diagnostics.len(), 1);
+        assert_eq!(diagnostics[0].message, MULTI_RETURN_MSG);
+        assert_eq!(diagnostics[0].column_start, 8);
+        assert_eq!(fixed(source), "def f; [a, b]; end\n");
+    }
+
+    #[test]
+    fn test_single_return_value_is_not_wrapped() {
+        let source = b"def f; return a; end\n";
+        let diagnostics = redundant_return_diagnostics(source);
+        assert_eq!(diagnostics.len(), 1);
+        assert_eq!(diagnostics[0].message, MSG);
+        assert_eq!(fixed(source), "def f; a; end\n");
+    }
+
+    #[test]
+    fn test_bare_return() {
+        let source = b"def f\n  foo\n  return\nend\n";
+        assert_eq!(fixed(source), "def f\n  foo\n  nil\nend\n");
+    }
+
+    #[test]
+    fn test_splat_return_value() {
+        let source = b"def f\n  return *a\nend\n";
+        assert_eq!(fixed(source), "def f\n  [*a]\nend\n");
+    }
+
+    #[test]
+    fn test_allow_multiple_return_values() {
+        let source = b"def f; return a, b; end\n";
+        let mut config = Config::default();
+        config.style.redundant_return.allow_multiple_return_values = true;
+        let diagnostics = check_with_config(source, &config);
+        assert!(diagnostics.iter().all(|d| d.rule_id != RedundantReturn::ID));
+    }
+
+    #[test]
+    fn test_branches_in_tail_position() {
+        let source = b"def f\n  if x\n    return 1\n  elsif y\n    return 2\n  else\n    return 3\n  end\nend\n";
+        assert_eq!(redundant_return_diagnostics(source).len(), 3);
+        let source = b"def f\n  case x\n  when 1 then return a\n  else return b\n  end\nend\n";
+        assert_eq!(redundant_return_diagnostics(source).len(), 2);
+        let source = b"def f\n  foo\nrescue\n  return bar\nend\n";
+        assert_eq!(redundant_return_diagnostics(source).len(), 1);
+    }
+
+    #[test]
+    fn test_no_offense_for_early_return() {
+        let source = b"def f\n  return a if x\n  return b unless y\n  b\nend\n";
+        assert!(redundant_return_diagnostics(source).is_empty());
+        let source = b"def f\n  return 1 if x\nend\n";
+        assert!(redundant_return_diagnostics(source).is_empty());
+    }
+}
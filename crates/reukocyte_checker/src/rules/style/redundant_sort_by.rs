@@ -0,0 +1,150 @@
+use crate::checker::Checker;
+use crate::diagnostic::{Edit, Fix};
+use crate::rule::{Check, Rule, RuleId, StyleRule};
+use reukocyte_macros::check;
+use ruby_prism::*;
+
+/// Get the config for this rule
+#[inline]
+fn config<'a>(checker: &'a Checker<'_>) -> &'a crate::config::style::redundant_sort_by::RedundantSortBy {
+    &checker.config().style.redundant_sort_by
+}
+
+/// Style/RedundantSortBy rule.
+///
+/// Detects `sort_by`, `max_by` and `min_by` with a block that returns its
+/// parameter unchanged, which is the same as `sort`, `max` and `min`.
+///
+/// ```ruby
+/// # bad
+/// array.sort_by { |x| x }
+/// array.max_by { _1 }
+///
+/// # good
+/// array.sort
+/// array.max
+/// ```
+pub struct RedundantSortBy;
+impl Rule for RedundantSortBy {
+    const ID: RuleId = RuleId::Style(StyleRule::RedundantSortBy);
+}
+#[check(BlockNode)]
+impl Check<BlockNode<'_>> for RedundantSortBy {
+    fn check(node: &BlockNode, checker: &mut Checker) {
+        let Some(call) = checker.semantic().parent().and_then(|parent| parent.as_call_node()) else {
+            return;
+        };
+        let replacement = match call.name().as_slice() {
+            b"sort_by" => "sort",
+            b"max_by" => "max",
+            b"min_by" => "min",
+            _ => return,
+        };
+        let (Some(selector), None) = (call.message_loc(), call.arguments()) else {
+            return;
+        };
+        let Some(block) = identity_block(node) else {
+            return;
+        };
+        let method = String::from_utf8_lossy(selector.as_slice());
+        let message = format!("Use `{replacement}` instead of `{method} {{ {block} }}`.");
+        let start = selector.start_offset();
+        let end = node.location().end_offset();
+        let fix = Fix::safe(vec![Edit::replacement(start, end, replacement.to_string())]);
+        let severity = config(checker).base.severity;
+        checker.report(RedundantSortBy::ID, message, severity, start, end, Some(fix));
+    }
+}
+
+/// Get the body of the block for the message (`|x| x`, `_1` or `it`) if the
+/// block returns its only parameter unchanged.
+fn identity_block(node: &BlockNode) -> Option<String> {
+    let mut body = node.body()?.as_statements_node()?.body().iter();
+    let (Some(returned), None) = (body.next(), body.next()) else {
+        return None;
+    };
+    let parameters = node.parameters()?;
+    if let Some(parameters) = parameters.as_block_parameters_node() {
+        let list = parameters.parameters()?;
+        let mut requireds = list.requireds().iter();
+        let (Some(required), None) = (requireds.next(), requireds.next()) else {
+            return None;
+        };
+        let has_others = list.optionals().iter().next().is_some()
+            || list.rest().is_some()
+            || list.posts().iter().next().is_some()
+            || list.keywords().iter().next().is_some()
+            || list.keyword_rest().is_some()
+            || list.block().is_some();
+        let name = required.as_required_parameter_node()?.name();
+        let returned = returned.as_local_variable_read_node()?;
+        if has_others || returned.name().as_slice() != name.as_slice() || returned.depth() != 0 {
+            return None;
+        }
+        let name = String::from_utf8_lossy(name.as_slice());
+        return Some(format!("|{name}| {name}"));
+    }
+    if let Some(numbered) = parameters.as_numbered_parameters_node() {
+        let returned = returned.as_local_variable_read_node()?;
+        return (numbered.maximum() == 1 && returned.name().as_slice() == b"_1").then(|| "_1".to_string());
+    }
+    parameters.as_it_parameters_node()?;
+    returned.as_it_local_variable_read_node().map(|_| "it".to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::check;
+    use crate::corrector::Corrector;
+
+    fn redundant_sort_by_diagnostics(source: &[u8]) -> Vec<crate::Diagnostic> {
+        check(source).into_iter().filter(|d| d.rule_id == RedundantSortBy::ID).collect()
+    }
+
+    fn fixed(source: &[u8]) -> String {
+        let mut corrector = Corrector::new();
+        for diagnostic in redundant_sort_by_diagnostics(source) {
+            corrector.merge(diagnostic.fix.as_ref().unwrap()).unwrap();
+        }
+        String::from_utf8(corrector.apply(source)).unwrap()
+    }
+
+    #[test]
+    fn test_sort_by_identity() {
+        let diagnostics = redundant_sort_by_diagnostics(b"arr.sort_by { |e| e }\n");
+        assert_eq!(diagnostics.len(), 1);
+        assert_eq!(diagnostics[0].message, "Use `sort` instead of `sort_by { |e| e }`.");
+        assert_eq!(fixed(b"arr.sort_by { |e| e }\n"), "arr.sort\n");
+        assert_eq!(fixed(b"arr.sort_by do |e|\n  e\nend\n"), "arr.sort\n");
+        assert_eq!(fixed(b"sort_by { |e| e }\n"), "sort\n");
+    }
+
+    #[test]
+    fn test_max_by_and_min_by() {
+        assert_eq!(fixed(b"arr.max_by { |e| e }\n"), "arr.max\n");
+        assert_eq!(fixed(b"arr.min_by { |e| e }\n"), "arr.min\n");
+    }
+
+    #[test]
+    fn test_numbered_and_it_parameters() {
+        let diagnostics = redundant_sort_by_diagnostics(b"arr.sort_by { _1 }\n");
+        assert_eq!(diagnostics[0].message, "Use `sort` instead of `sort_by { _1 }`.");
+        assert_eq!(fixed(b"arr.sort_by { _1 }\n"), "arr.sort\n");
+        let diagnostics = redundant_sort_by_diagnostics(b"arr.sort_by { it }\n");
+        assert_eq!(diagnostics[0].message, "Use `sort` instead of `sort_by { it }`.");
+        assert_eq!(fixed(b"arr.sort_by { it }\n"), "arr.sort\n");
+    }
+
+    #[test]
+    fn test_non_identity_blocks_are_ignored() {
+        assert!(redundant_sort_by_diagnostics(b"arr.sort_by { |e| e.size }\n").is_empty());
+        assert!(redundant_sort_by_diagnostics(b"arr.sort_by { |e| -e }\n").is_empty());
+        assert!(redundant_sort_by_diagnostics(b"arr.sort_by { |a, b| a }\n").is_empty());
+        assert!(redundant_sort_by_diagnostics(b"e = 1\narr.sort_by { |x| e }\n").is_empty());
+        assert!(redundant_sort_by_diagnostics(b"arr.sort_by { |e| puts e; e }\n").is_empty());
+        assert!(redundant_sort_by_diagnostics(b"arr.sort_by { _2 }\n").is_empty());
+        assert!(redundant_sort_by_diagnostics(b"arr.sort_by(&:itself)\n").is_empty());
+        assert!(redundant_sort_by_diagnostics(b"arr.each { |e| e }\n").is_empty());
+    }
+}
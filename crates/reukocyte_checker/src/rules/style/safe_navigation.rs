@@ -0,0 +1,209 @@
+use crate::checker::Checker;
+use crate::diagnostic::{Edit, Fix};
+use crate::rule::{Check, Rule, RuleId, StyleRule};
+use reukocyte_macros::check;
+use ruby_prism::*;
+
+const MSG: &str = "Use safe navigation (`&.`) instead of checking if an object exists before calling the method.";
+
+/// Safe navigation (`&.`) was introduced in Ruby 2.3.
+const MINIMUM_TARGET_RUBY_VERSION: f32 = 2.3;
+
+/// Methods that `nil` responds to; `foo&.bar` would change their result for `nil`.
+const NIL_METHODS: &[&[u8]] = &[
+    b"nil?",
+    b"to_a",
+    b"to_s",
+    b"to_i",
+    b"to_f",
+    b"to_h",
+    b"to_r",
+    b"to_c",
+    b"inspect",
+    b"is_a?",
+    b"kind_of?",
+    b"instance_of?",
+    b"respond_to?",
+    b"frozen?",
+    b"dup",
+    b"clone",
+    b"tap",
+    b"then",
+    b"class",
+    b"object_id",
+    b"equal?",
+    b"eql?",
+    b"hash",
+];
+
+/// Get the config for this rule
+#[inline]
+fn config<'a>(checker: &'a Checker<'_>) -> &'a crate::config::style::safe_navigation::SafeNavigation {
+    &checker.config().style.safe_navigation
+}
+
+/// Style/SafeNavigation rule.
+///
+/// Suggests `&.` over guarding a method call with a check of its receiver.
+/// Only active when `AllCops.TargetRubyVersion` is 2.3 or later.
+///
+/// ```ruby
+/// # bad
+/// foo && foo.bar
+/// foo && foo.bar(baz)
+///
+/// # good
+/// foo&.bar
+/// foo&.bar(baz)
+/// foo && foo.blank?
+/// ```
+pub struct SafeNavigation;
+impl Rule for SafeNavigation {
+    const ID: RuleId = RuleId::Style(StyleRule::SafeNavigation);
+}
+#[check(AndNode)]
+impl Check<AndNode<'_>> for SafeNavigation {
+    fn check(node: &AndNode, checker: &mut Checker) {
+        if checker.target_ruby_version() < MINIMUM_TARGET_RUBY_VERSION {
+            return;
+        }
+        let left = node.left();
+        if !is_checked_receiver(&left) {
+            return;
+        }
+        let Some(call) = node.right().as_call_node() else {
+            return;
+        };
+        let Some(receiver) = call.receiver() else {
+            return;
+        };
+        if receiver.location().as_slice() != left.location().as_slice() {
+            return;
+        }
+        let Some(call_operator) = call.call_operator_loc().filter(|op| op.as_slice() == b".") else {
+            return;
+        };
+        if call.message_loc().is_none() || !is_convertible_method(call.name().as_slice(), checker) {
+            return;
+        }
+
+        let location = node.location();
+        let right_location = call.location();
+        let fix = Fix::r#unsafe(vec![
+            Edit::deletion(location.start_offset(), right_location.start_offset()),
+            Edit::replacement(call_operator.start_offset(), call_operator.end_offset(), "&.".to_string()),
+        ]);
+        let severity = config(checker).base.severity;
+        checker.report(
+            SafeNavigation::ID,
+            MSG.to_string(),
+            severity,
+            location.start_offset(),
+            location.end_offset(),
+            Some(fix),
+        );
+    }
+}
+
+/// Check if the node is a receiver that can be nil-checked: a variable or a bare method call.
+fn is_checked_receiver(node: &Node) -> bool {
+    match node {
+        Node::LocalVariableReadNode { .. }
+        | Node::InstanceVariableReadNode { .. }
+        | Node::ClassVariableReadNode { .. }
+        | Node::GlobalVariableReadNode { .. } => true,
+        Node::CallNode { .. } => {
+            let call = node.as_call_node().unwrap();
+            call.receiver().is_none() && call.arguments().is_none() && call.block().is_none() && call.name().as_slice().iter().all(is_identifier_byte)
+        }
+        _ => false,
+    }
+}
+
+/// Check if calling the method with `&.` keeps its meaning.
+fn is_convertible_method(name: &[u8], checker: &Checker) -> bool {
+    let Some((&last, body)) = name.split_last() else {
+        return false;
+    };
+    // Operators (`foo + 1`, `foo[0]`) and setters (`foo.bar = 1`) read badly with `&.`.
+    if !body.iter().all(is_identifier_byte) || !(is_identifier_byte(&last) || last == b'?' || last == b'!') {
+        return false;
+    }
+    if NIL_METHODS.contains(&name) {
+        return false;
+    }
+    !config(checker).allowed_methods.iter().any(|method| method.as_bytes() == name)
+}
+
+#[inline]
+fn is_identifier_byte(byte: &u8) -> bool {
+    byte.is_ascii_alphanumeric() || *byte == b'_' || !byte.is_ascii()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::Config;
+    use crate::corrector::Corrector;
+    use crate::{check, check_with_config};
+
+    fn safe_navigation_diagnostics(diagnostics: Vec<crate::Diagnostic>) -> Vec<crate::Diagnostic> {
+        diagnostics.into_iter().filter(|d| d.rule_id == SafeNavigation::ID).collect()
+    }
+
+    fn config_with_target(version: f32) -> Config {
+        let mut config = Config::default();
+        config.all_cops.target_ruby_version = Some(version);
+        config
+    }
+
+    fn fixed(source: &[u8], diagnostics: &[crate::Diagnostic]) -> String {
+        let mut corrector = Corrector::new();
+        for fix in diagnostics.iter().filter_map(|d| d.fix.as_ref()) {
+            corrector.merge(fix).unwrap();
+        }
+        String::from_utf8(corrector.apply(source)).unwrap()
+    }
+
+    #[test]
+    fn test_guarded_call() {
+        let source = b"foo && foo.bar(1)\n";
+        let diagnostics = safe_navigation_diagnostics(check(source));
+        assert_eq!(diagnostics.len(), 1);
+        assert_eq!(diagnostics[0].message, MSG);
+        assert_eq!(diagnostics[0].fix_applicability(), Some(crate::Applicability::Unsafe));
+        assert_eq!(fixed(source, &diagnostics), "foo&.bar(1)\n");
+    }
+
+    #[test]
+    fn test_instance_variable_receiver() {
+        let source = b"@user && @user.name\n";
+        let diagnostics = safe_navigation_diagnostics(check(source));
+        assert_eq!(fixed(source, &diagnostics), "@user&.name\n");
+    }
+
+    #[test]
+    fn test_accepts_unrelated_or_unsafe_calls() {
+        assert!(safe_navigation_diagnostics(check(b"foo && bar.baz\n")).is_empty());
+        assert!(safe_navigation_diagnostics(check(b"foo && foo&.bar\n")).is_empty());
+        assert!(safe_navigation_diagnostics(check(b"foo && foo.nil?\n")).is_empty());
+        assert!(safe_navigation_diagnostics(check(b"foo && foo.blank?\n")).is_empty());
+        assert!(safe_navigation_diagnostics(check(b"foo && foo < 1\n")).is_empty());
+        assert!(safe_navigation_diagnostics(check(b"foo && foo.bar = 1\n")).is_empty());
+        assert!(safe_navigation_diagnostics(check(b"foo(1) && foo(1).bar\n")).is_empty());
+    }
+
+    #[test]
+    fn test_target_ruby_version_gates_rule() {
+        let source = b"foo && foo.bar\n";
+        assert!(safe_navigation_diagnostics(check_with_config(source, &config_with_target(2.2))).is_empty());
+        assert_eq!(safe_navigation_diagnostics(check_with_config(source, &config_with_target(3.0))).len(), 1);
+    }
+
+    #[test]
+    fn test_target_ruby_version_from_yaml() {
+        let yaml = crate::config::parse_rubocop_yaml("AllCops:\n  TargetRubyVersion: 2.2\n").unwrap();
+        let config = Config::from_rubocop_yaml(&yaml);
+        assert!(safe_navigation_diagnostics(check_with_config(b"foo && foo.bar\n", &config)).is_empty());
+    }
+}
@@ -0,0 +1,107 @@
+use crate::checker::Checker;
+use crate::diagnostic::{Edit, Fix};
+use crate::rule::{Check, Rule, RuleId, StyleRule};
+use reukocyte_macros::check;
+use ruby_prism::*;
+
+/// Get the config for this rule
+#[inline]
+fn config<'a>(checker: &'a Checker<'_>) -> &'a crate::config::style::stderr_puts::StderrPuts {
+    &checker.config().style.stderr_puts
+}
+
+/// Style/StderrPuts rule.
+///
+/// Prefers `warn` over `$stderr.puts`, since `warn` output can be disabled
+/// with `-W0`.
+///
+/// ```ruby
+/// # bad
+/// $stderr.puts('hello')
+///
+/// # good
+/// warn('hello')
+/// ```
+pub struct StderrPuts;
+impl Rule for StderrPuts {
+    const ID: RuleId = RuleId::Style(StyleRule::StderrPuts);
+}
+#[check(CallNode)]
+impl Check<CallNode<'_>> for StderrPuts {
+    fn check(node: &CallNode, checker: &mut Checker) {
+        if node.name().as_slice() != b"puts" || node.arguments().is_none() || node.block().is_some() || node.is_safe_navigation() {
+            return;
+        }
+        let (Some(receiver), Some(selector)) = (node.receiver(), node.message_loc()) else {
+            return;
+        };
+        if !is_stderr(&receiver) {
+            return;
+        }
+        let start = receiver.location().start_offset();
+        let end = selector.end_offset();
+        let message = format!(
+            "Use `warn` instead of `{}.puts` to allow such output to be disabled.",
+            String::from_utf8_lossy(receiver.location().as_slice())
+        );
+        let fix = Fix::safe(vec![Edit::replacement(start, end, "warn".to_string())]);
+        let severity = config(checker).base.severity;
+        checker.report(StderrPuts::ID, message, severity, start, end, Some(fix));
+    }
+}
+
+/// Check if the node is `$stderr`, `STDERR` or `::STDERR`.
+fn is_stderr(node: &Node) -> bool {
+    if let Some(gvar) = node.as_global_variable_read_node() {
+        return gvar.name().as_slice() == b"$stderr";
+    }
+    if let Some(constant) = node.as_constant_read_node() {
+        return constant.name().as_slice() == b"STDERR";
+    }
+    node.as_constant_path_node()
+        .is_some_and(|path| path.parent().is_none() && path.name().is_some_and(|name| name.as_slice() == b"STDERR"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{apply_fixes, check};
+
+    fn stderr_puts_diagnostics(source: &[u8]) -> Vec<crate::Diagnostic> {
+        check(source).into_iter().filter(|d| d.rule_id == StderrPuts::ID).collect()
+    }
+
+    #[test]
+    fn test_stderr_puts() {
+        let source = b"$stderr.puts \"oops\"\n";
+        let diagnostics = stderr_puts_diagnostics(source);
+        assert_eq!(diagnostics.len(), 1);
+        assert_eq!(
+            diagnostics[0].message,
+            "Use `warn` instead of `$stderr.puts` to allow such output to be disabled."
+        );
+        let (fixed, _) = apply_fixes(None, source, &diagnostics, false);
+        assert_eq!(fixed, b"warn \"oops\"\n");
+    }
+
+    #[test]
+    fn test_parenthesized_and_constant_receivers() {
+        let (fixed, _) = apply_fixes(None, b"$stderr.puts(x)\n", &stderr_puts_diagnostics(b"$stderr.puts(x)\n"), false);
+        assert_eq!(fixed, b"warn(x)\n");
+        let diagnostics = stderr_puts_diagnostics(b"STDERR.puts('a', 'b')\n::STDERR.puts('c')\n");
+        assert_eq!(diagnostics.len(), 2);
+        assert_eq!(
+            diagnostics[0].message,
+            "Use `warn` instead of `STDERR.puts` to allow such output to be disabled."
+        );
+    }
+
+    #[test]
+    fn test_other_calls_are_ignored() {
+        assert!(stderr_puts_diagnostics(b"$stderr.puts\n").is_empty());
+        assert!(stderr_puts_diagnostics(b"$stdout.puts 'a'\n").is_empty());
+        assert!(stderr_puts_diagnostics(b"$stderr.print 'a'\n").is_empty());
+        assert!(stderr_puts_diagnostics(b"Foo::STDERR.puts 'a'\n").is_empty());
+        assert!(stderr_puts_diagnostics(b"puts 'a'\n").is_empty());
+    }
+}
@@ -2,11 +2,14 @@ use crate::checker::Checker;
 use crate::diagnostic::Edit;
 use crate::diagnostic::Fix;
 use crate::diagnostic::Severity;
+use crate::locator::LineIndex;
 use crate::rule::RuleId;
 use ruby_prism::Location;
 
 /// Check alignment of given locations against a base column.
-pub fn check_alignment(locs: Vec<Location>, base_column: Option<usize>, rule_id: RuleId, checker: &mut Checker) {
+///
+/// When `base_column` is `None`, the column of the first location is used.
+pub fn check_alignment(locs: Vec<Location>, base_column: Option<usize>, rule_id: RuleId, message: &str, severity: Severity, checker: &mut Checker) {
     if locs.is_empty() {
         return;
     }
@@ -24,14 +27,7 @@ pub fn check_alignment(locs: Vec<Location>, base_column: Option<usize>, rule_id:
         if prev_line < curr_line && line_index.is_first_on_line(curr_loc.start_offset()) {
             let column_delta = base_column as isize - line_index.column_number(curr_loc.start_offset()) as isize;
             if column_delta != 0 {
-                let line_start = line_index.line_start_offset(curr_loc.start_offset());
-                let fix = if column_delta > 0 {
-                    Fix::safe(Vec::from([Edit::insertion(line_start, " ".repeat(column_delta as usize))]))
-                } else {
-                    let remove_count = (-column_delta) as usize;
-                    let end = line_start + remove_count.min(curr_loc.start_offset() - line_start);
-                    Fix::safe(Vec::from([Edit::deletion(line_start, end)]))
-                };
+                let fix = indentation_fix(line_index, curr_loc.start_offset(), column_delta);
                 reports.push((curr_loc.start_offset(), curr_loc.end_offset(), fix));
             }
         }
@@ -39,7 +35,21 @@ pub fn check_alignment(locs: Vec<Location>, base_column: Option<usize>, rule_id:
     }
 
     for (start, end, fix) in reports {
-        // TODO: message
-        checker.report(rule_id, "".to_string(), Severity::Convention, start, end, Some(fix));
+        checker.report(rule_id, message.to_string(), severity, start, end, Some(fix));
+    }
+}
+
+/// Build a fix that shifts the line containing `offset` by `column_delta` columns.
+///
+/// A positive delta inserts spaces at the start of the line, a negative one
+/// removes leading whitespace (never more than there is before `offset`).
+pub fn indentation_fix(line_index: &LineIndex, offset: usize, column_delta: isize) -> Fix {
+    let line_start = line_index.line_start_offset(offset);
+    if column_delta > 0 {
+        Fix::safe(Vec::from([Edit::insertion(line_start, " ".repeat(column_delta as usize))]))
+    } else {
+        let remove_count = (-column_delta) as usize;
+        let end = line_start + remove_count.min(offset - line_start);
+        Fix::safe(Vec::from([Edit::deletion(line_start, end)]))
     }
 }
@@ -0,0 +1,98 @@
+use ruby_prism::*;
+
+/// The parameter after the element of a block like `each_with_object`'s or `each_with_index`'s.
+pub enum SecondParameter<'pr> {
+    /// The block only takes the element (`|v|`, `_1` or `it`).
+    Omitted,
+    /// The block takes the element and a second parameter (`|v, memo|`).
+    Declared { element: Node<'pr>, second: RequiredParameterNode<'pr> },
+}
+
+/// Get the second parameter of the block, if its parameters are the element and an optional second one.
+pub fn second_parameter<'pr>(node: &BlockNode<'pr>) -> Option<SecondParameter<'pr>> {
+    let parameters = node.parameters()?;
+    if let Some(numbered) = parameters.as_numbered_parameters_node() {
+        return (numbered.maximum() == 1).then_some(SecondParameter::Omitted);
+    }
+    if parameters.as_it_parameters_node().is_some() {
+        return Some(SecondParameter::Omitted);
+    }
+    let list = parameters.as_block_parameters_node()?.parameters()?;
+    let has_others = list.optionals().iter().next().is_some()
+        || list.rest().is_some()
+        || list.posts().iter().next().is_some()
+        || list.keywords().iter().next().is_some()
+        || list.keyword_rest().is_some()
+        || list.block().is_some();
+    if has_others {
+        return None;
+    }
+    let mut requireds = list.requireds().iter();
+    match (requireds.next(), requireds.next(), requireds.next()) {
+        (Some(_), None, None) => Some(SecondParameter::Omitted),
+        (Some(element), Some(second), None) => Some(SecondParameter::Declared {
+            element,
+            second: second.as_required_parameter_node()?,
+        }),
+        _ => None,
+    }
+}
+
+/// Check if the block body reads or writes the local variable `name` of the block's scope.
+pub fn is_local_variable_used(node: &BlockNode, name: &[u8]) -> bool {
+    let Some(body) = node.body() else {
+        return false;
+    };
+    let mut finder = LocalVariableFinder { name, depth: 0, found: false };
+    finder.visit(&body);
+    finder.found
+}
+
+/// Finds references to a local variable, following it into nested blocks.
+struct LocalVariableFinder<'a> {
+    name: &'a [u8],
+    /// How many blocks and lambdas the visitor is nested in.
+    depth: u32,
+    found: bool,
+}
+impl LocalVariableFinder<'_> {
+    fn check(&mut self, name: ConstantId, depth: u32) {
+        self.found |= name.as_slice() == self.name && depth == self.depth;
+    }
+}
+impl<'pr> Visit<'pr> for LocalVariableFinder<'_> {
+    fn visit_local_variable_read_node(&mut self, node: &LocalVariableReadNode<'pr>) {
+        self.check(node.name(), node.depth());
+    }
+    fn visit_local_variable_write_node(&mut self, node: &LocalVariableWriteNode<'pr>) {
+        self.check(node.name(), node.depth());
+        visit_local_variable_write_node(self, node);
+    }
+    fn visit_local_variable_operator_write_node(&mut self, node: &LocalVariableOperatorWriteNode<'pr>) {
+        self.check(node.name(), node.depth());
+        visit_local_variable_operator_write_node(self, node);
+    }
+    fn visit_local_variable_and_write_node(&mut self, node: &LocalVariableAndWriteNode<'pr>) {
+        self.check(node.name(), node.depth());
+        visit_local_variable_and_write_node(self, node);
+    }
+    fn visit_local_variable_or_write_node(&mut self, node: &LocalVariableOrWriteNode<'pr>) {
+        self.check(node.name(), node.depth());
+        visit_local_variable_or_write_node(self, node);
+    }
+    fn visit_local_variable_target_node(&mut self, node: &LocalVariableTargetNode<'pr>) {
+        self.check(node.name(), node.depth());
+    }
+    fn visit_block_node(&mut self, node: &BlockNode<'pr>) {
+        self.depth += 1;
+        visit_block_node(self, node);
+        self.depth -= 1;
+    }
+    fn visit_lambda_node(&mut self, node: &LambdaNode<'pr>) {
+        self.depth += 1;
+        visit_lambda_node(self, node);
+        self.depth -= 1;
+    }
+    // A method body is a new scope.
+    fn visit_def_node(&mut self, _node: &DefNode<'pr>) {}
+}
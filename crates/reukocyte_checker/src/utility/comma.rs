@@ -0,0 +1,112 @@
+use ruby_prism::*;
+
+/// A comma separating the elements of a list-like node.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Comma {
+    /// Byte offset of the comma.
+    pub offset: usize,
+    /// End offset of the element before the comma.
+    pub previous_end: usize,
+    /// Start offset of the element after the comma, or `None` for a trailing comma.
+    pub next_start: Option<usize>,
+}
+
+/// Collect the commas between the elements of `node`.
+///
+/// Only the bytes between element boundaries are inspected, so commas inside
+/// the elements themselves (string literals, nested calls, ...) are never seen.
+pub fn commas(node: &Node, source: &[u8]) -> Vec<Comma> {
+    let (locations, closing_start) = element_locations(node);
+    let mut commas = Vec::new();
+    for pair in locations.windows(2) {
+        let (previous_end, next_start) = (pair[0].end_offset(), pair[1].start_offset());
+        if let Some(offset) = find_comma(source, previous_end, next_start) {
+            commas.push(Comma {
+                offset,
+                previous_end,
+                next_start: Some(next_start),
+            });
+        }
+    }
+    if let (Some(last), Some(closing_start)) = (locations.last(), closing_start) {
+        let previous_end = last.end_offset();
+        if let Some(offset) = find_comma(source, previous_end, closing_start) {
+            commas.push(Comma {
+                offset,
+                previous_end,
+                next_start: None,
+            });
+        }
+    }
+    commas
+}
+
+/// Get the element locations of a list-like node (sorted by position), and the
+/// start of its closing delimiter if a trailing comma is allowed before it.
+fn element_locations<'pr>(node: &Node<'pr>) -> (Vec<Location<'pr>>, Option<usize>) {
+    let locations_of = |nodes: NodeList<'pr>| nodes.iter().map(|n| n.location()).collect::<Vec<_>>();
+    if let Some(array) = node.as_array_node() {
+        (locations_of(array.elements()), array.closing_loc().map(|loc| loc.start_offset()))
+    } else if let Some(hash) = node.as_hash_node() {
+        (locations_of(hash.elements()), Some(hash.closing_loc().start_offset()))
+    } else if let Some(keyword_hash) = node.as_keyword_hash_node() {
+        (locations_of(keyword_hash.elements()), None)
+    } else if let Some(arguments) = node.as_arguments_node() {
+        (locations_of(arguments.arguments()), None)
+    } else if let Some(call) = node.as_call_node() {
+        // The arguments themselves are handled by `ArgumentsNode`; here only the
+        // comma before a block argument (`foo(a, &b)`) and a trailing comma remain.
+        let Some(last_argument) = call.arguments().and_then(|arguments| arguments.arguments().iter().last()) else {
+            return (Vec::new(), None);
+        };
+        let mut locations = vec![last_argument.location()];
+        if let Some(block_argument) = call.block().filter(|block| block.as_block_argument_node().is_some()) {
+            locations.push(block_argument.location());
+        }
+        (locations, call.closing_loc().map(|loc| loc.start_offset()))
+    } else if let Some(parameters) = node.as_parameters_node() {
+        let mut locations = locations_of(parameters.requireds());
+        locations.extend(locations_of(parameters.optionals()));
+        locations.extend(parameters.rest().map(|rest| rest.location()));
+        locations.extend(locations_of(parameters.posts()));
+        locations.extend(locations_of(parameters.keywords()));
+        locations.extend(parameters.keyword_rest().map(|rest| rest.location()));
+        locations.extend(parameters.block().map(|block| block.location()));
+        locations.sort_by_key(|loc| loc.start_offset());
+        (locations, None)
+    } else if let Some(multi_write) = node.as_multi_write_node() {
+        let mut locations = locations_of(multi_write.lefts());
+        locations.extend(multi_write.rest().map(|rest| rest.location()));
+        locations.extend(locations_of(multi_write.rights()));
+        (locations, None)
+    } else if let Some(when) = node.as_when_node() {
+        (locations_of(when.conditions()), None)
+    } else if let Some(rescue) = node.as_rescue_node() {
+        (locations_of(rescue.exceptions()), None)
+    } else if let Some(multi_target) = node.as_multi_target_node() {
+        let mut locations = locations_of(multi_target.lefts());
+        locations.extend(multi_target.rest().map(|rest| rest.location()));
+        locations.extend(locations_of(multi_target.rights()));
+        (locations, None)
+    } else {
+        (Vec::new(), None)
+    }
+}
+
+/// Find the comma between two elements, skipping whitespace, line continuations and comments.
+fn find_comma(source: &[u8], start: usize, end: usize) -> Option<usize> {
+    let mut offset = start;
+    while offset < end {
+        match source[offset] {
+            b',' => return Some(offset),
+            b' ' | b'\t' | b'\r' | b'\n' | b'\\' => offset += 1,
+            b'#' => {
+                while offset < end && source[offset] != b'\n' {
+                    offset += 1;
+                }
+            }
+            _ => return None,
+        }
+    }
+    None
+}
@@ -0,0 +1,117 @@
+use crate::diagnostic::{Applicability, Edit, Fix};
+use ruby_prism::*;
+
+/// A deprecated method called on a constant, and what to write instead.
+///
+/// Cops of the "`X.foo` is deprecated, use `X.bar`" kind are a table of these
+/// checked with `deprecated_call`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DeprecatedCall {
+    /// The constant receiver, e.g. `BigDecimal` or `Foo::Bar`. A leading `::` on the call is ignored.
+    pub receiver: &'static str,
+    /// The deprecated method, e.g. `new`.
+    pub method: &'static str,
+    /// What replaces the operator and method name, e.g. `.bar`, or `""` to call the receiver directly.
+    pub replacement: &'static str,
+    /// How safe the rewrite is.
+    pub applicability: Applicability,
+}
+
+/// An offense found by `deprecated_call`.
+#[derive(Debug, Clone)]
+pub struct DeprecatedCallOffense {
+    /// Offsets of the method name, where the offense is reported.
+    pub start: usize,
+    pub end: usize,
+    pub message: String,
+    pub fix: Fix,
+}
+
+/// Check a call against a table of deprecated calls.
+///
+/// `BigDecimal.new(1)` and `::BigDecimal::new(1)` both match
+/// `BigDecimal`/`new`; with the replacement `""` they become
+/// `BigDecimal(1)` and `::BigDecimal(1)`. Safe navigation calls are not matched.
+pub fn deprecated_call(table: &[DeprecatedCall], node: &CallNode) -> Option<DeprecatedCallOffense> {
+    let receiver = node.receiver()?;
+    if receiver.as_constant_read_node().is_none() && receiver.as_constant_path_node().is_none() {
+        return None;
+    }
+    let operator = node.call_operator_loc()?;
+    if operator.as_slice() == b"&." {
+        return None;
+    }
+    let receiver_source = receiver.location().as_slice();
+    let receiver_source = receiver_source.strip_prefix(b"::").unwrap_or(receiver_source);
+    let message_loc = node.message_loc()?;
+    let entry = table
+        .iter()
+        .find(|entry| entry.receiver.as_bytes() == receiver_source && entry.method.as_bytes() == message_loc.as_slice())?;
+    let message = format!(
+        "`{}.{}()` is deprecated. Use `{}{}()` instead.",
+        entry.receiver, entry.method, entry.receiver, entry.replacement
+    );
+    let fix = Fix {
+        applicability: entry.applicability,
+        edits: vec![Edit::replacement(
+            operator.start_offset(),
+            message_loc.end_offset(),
+            entry.replacement.to_string(),
+        )],
+    };
+    Some(DeprecatedCallOffense {
+        start: message_loc.start_offset(),
+        end: message_loc.end_offset(),
+        message,
+        fix,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const TABLE: &[DeprecatedCall] = &[
+        DeprecatedCall {
+            receiver: "Foo::Bar",
+            method: "old",
+            replacement: ".new_name",
+            applicability: Applicability::Unsafe,
+        },
+        DeprecatedCall {
+            receiver: "Baz",
+            method: "new",
+            replacement: "",
+            applicability: Applicability::Safe,
+        },
+    ];
+
+    /// Check the first statement of `source` and apply the fix.
+    fn corrected(source: &str) -> Option<(String, String, Applicability)> {
+        let result = ruby_prism::parse(source.as_bytes());
+        let statement = result.node().as_program_node()?.statements().body().iter().next()?;
+        let offense = deprecated_call(TABLE, &statement.as_call_node()?)?;
+        let edit = &offense.fix.edits[0];
+        let fixed = format!("{}{}{}", &source[..edit.start], edit.content, &source[edit.end..]);
+        Some((fixed, offense.message, offense.fix.applicability))
+    }
+
+    #[test]
+    fn test_matches_table_entries() {
+        let (fixed, message, applicability) = corrected("Foo::Bar.old(1)").unwrap();
+        assert_eq!(fixed, "Foo::Bar.new_name(1)");
+        assert_eq!(message, "`Foo::Bar.old()` is deprecated. Use `Foo::Bar.new_name()` instead.");
+        assert_eq!(applicability, Applicability::Unsafe);
+        assert_eq!(corrected("::Baz::new 1").unwrap().0, "::Baz 1");
+        assert_eq!(corrected("Baz.new(1)").unwrap().2, Applicability::Safe);
+    }
+
+    #[test]
+    fn test_skips_other_calls() {
+        assert!(corrected("Baz.old(1)").is_none());
+        assert!(corrected("Bar.old(1)").is_none());
+        assert!(corrected("baz.new(1)").is_none());
+        assert!(corrected("Baz&.new(1)").is_none());
+        assert!(corrected("new(1)").is_none());
+    }
+}
@@ -1,5 +1,9 @@
 pub mod access_modifier;
 pub mod alignment;
+pub mod block_parameter;
 pub mod call_node;
+pub mod comma;
+pub mod deprecated_call;
+pub mod multiline_brace_layout;
 pub mod node;
 pub mod source;
@@ -0,0 +1,54 @@
+use crate::checker::Checker;
+use crate::config::layout::multiline_method_definition_brace_layout::EnforcedStyle;
+use crate::diagnostic::{Edit, Fix};
+use ruby_prism::Location;
+use std::ops::Range;
+
+/// Check the closing brace of a multiline literal, argument or parameter list
+/// against the brace layout style (RuboCop's `MultilineLiteralBraceLayout`).
+///
+/// `elements` spans from the start of the first element to the end of the last
+/// one, and `brace`/`element` name them in the message (e.g. `"method definition
+/// brace"` and `"parameter"`). Returns the message and fix of the offense on the
+/// closing brace, if any. Single-line lists and lists whose last element is
+/// followed by a heredoc body are ignored.
+pub fn brace_layout_offense(
+    opening: &Location,
+    closing: &Location,
+    elements: Range<usize>,
+    (brace, element): (&str, &str),
+    style: EnforcedStyle,
+    checker: &Checker,
+) -> Option<(String, Option<Fix>)> {
+    let (first_start, last_end) = (elements.start, elements.end);
+    let line_index = checker.line_index();
+    let (opening_start, closing_start) = (opening.start_offset(), closing.start_offset());
+    if line_index.are_on_same_line(opening_start, closing_start) || (last_end..closing_start).any(|offset| checker.is_in_literal(offset)) {
+        return None;
+    }
+    let opening_on_same_line = line_index.are_on_same_line(opening_start, first_start);
+    let closing_on_same_line = line_index.are_on_same_line(last_end, closing_start);
+    let message = match style {
+        EnforcedStyle::Symmetrical if opening_on_same_line && !closing_on_same_line => {
+            format!("Closing {brace} must be on the same line as the last {element} when opening brace is on the same line as the first {element}.")
+        }
+        EnforcedStyle::Symmetrical if !opening_on_same_line && closing_on_same_line => {
+            format!("Closing {brace} must be on the line after the last {element} when opening brace is on a separate line from the first {element}.")
+        }
+        EnforcedStyle::NewLine if closing_on_same_line => format!("Closing {brace} must be on the line after the last {element}."),
+        EnforcedStyle::SameLine if !closing_on_same_line => format!("Closing {brace} must be on the same line as the last {element}."),
+        _ => return None,
+    };
+
+    let gap = &checker.source()[last_end..closing_start];
+    let fix = if closing_on_same_line {
+        let indentation = " ".repeat(line_index.indentation(opening_start));
+        Some(Fix::safe(vec![Edit::replacement(last_end, closing_start, format!("\n{indentation}"))]))
+    } else if gap.iter().all(u8::is_ascii_whitespace) {
+        Some(Fix::safe(vec![Edit::deletion(last_end, closing_start)]))
+    } else {
+        // A comment after the last element would swallow the closing brace.
+        None
+    };
+    Some((message, fix))
+}